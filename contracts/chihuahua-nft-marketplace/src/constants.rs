@@ -11,7 +11,15 @@ pub const MIN_EXPIRATION_SECONDS: u64 = 86_400u64;
 
 pub const MAX_NFT_PER_COLLECTION: u32 = 10_000u32;
 
+// Older sales of a token are still in the activity log
+pub const MAX_TOKEN_SALE_HISTORY_LENGTH: usize = 50;
+
+// A new username can be registered 7 days after the last one, unless updated by the admin
+pub const DEFAULT_USERNAME_RENAME_COOLDOWN_SECONDS: u64 = 604_800u64;
+
 pub const MADHUAHUA_NFTS: &str =
     "chihuahua1xv4zzcs3hqfwjfcpaq3swtj9unfa7qa4km00rxclwg98zuvw82tsnwxnw7";
 pub const SANCTUARY_NFTS: &str =
     "chihuahua1s6uhncxycfakk27ja765rmt05g5zzxjtw0kx3pad64077w0aar7qm64akt";
+// Default partner collection, updated or removed by the admin like the others
+pub const SANCTUARY_NFTS_FEES_DISCOUNT_PERCENT: u64 = 10u64;
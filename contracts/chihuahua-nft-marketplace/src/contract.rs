@@ -9,7 +9,12 @@ use nft_marketplace_utils::marketplace_statistics::{CollectionVolume, GeneralSta
 use nft_marketplace_utils::nft_collection::{NftCollectionInfoAndUsdcVol, NftContractInfo, NftContractType};
 use crate::constants::MARKETPLACE_USDC_INDICATOR;
 
-use crate::execute_functions::{add_new_nft_collection, answer_offer, buy_nft, cancel_nft_sale, cancel_offer, claim_marketplace_fees, create_profile, instantiate, level_up_profile, offer, remove_expired_sales, sell_nft, send_message, transfer_my_nft, update_config, update_nft_sale, update_profile};
+use nft_marketplace_utils::circuit_breaker::MarketplaceCapability;
+use nft_marketplace_utils::tokenfactory::TokenFactoryMsg;
+
+use crate::execute_functions::{add_new_nft_collection, answer_offer, block_address, buy_nft, buy_username, cancel_nft_sale, cancel_offer, cancel_username_sale, claim_competition_prize, claim_marketplace_fees, claim_rewards, claim_staking_fees, create_competition, create_profile, delete_message, finalize_competition, follow, fund_reward_pool, instantiate, level_up_profile, mark_thread_read, offer, remove_expired_sales, renew_vip_level, sell_nft, sell_username, send_message, set_circuit_breaker, set_message_filter, stake, start_leaderboard_season, transfer_my_nft, transfer_username, unstake, update_config, update_nft_sale, update_profile, verify, watch_token, withdraw_unbonded};
+use crate::helpers::circuit_breakers::if_capability_enabled;
+use crate::migrations;
 use crate::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
 use crate::state::{CONFIG, GENERAL_STATS, LISTED_NFT_COLLECTIONS_INFO_AND_USDC_VOL, MARKETPLACE_STATS_BY_DENOM};
 
@@ -23,7 +28,7 @@ pub fn instantiate(
     _env: Env,
     info: MessageInfo,
     init_msg: InstantiateMsg,
-) -> Result<Response, ContractError> {
+) -> Result<Response<TokenFactoryMsg>, ContractError> {
     cw2::set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
     instantiate::instantiate_contract(deps, info, init_msg)
 }
@@ -34,8 +39,23 @@ pub fn execute(
     env: Env,
     info: MessageInfo,
     msg: ExecuteMsg,
-) -> Result<Response, ContractError> {
+) -> Result<Response<TokenFactoryMsg>, ContractError> {
     let config = CONFIG.load(deps.storage)?;
+    // Users wait for an unfinished migration, the admin entry points stay open. Exit paths wait
+    // too: a sale or an offer can still be under its legacy key until the last batch is done
+    let admin_msg = matches!(
+        msg,
+        ExecuteMsg::UpdateConfig { .. }
+            | ExecuteMsg::ClaimMarketplaceFees {}
+            | ExecuteMsg::FundRewardPool {}
+            | ExecuteMsg::SetCircuitBreaker { .. }
+            | ExecuteMsg::StartLeaderboardSeason {}
+            | ExecuteMsg::CreateCompetition { .. }
+            | ExecuteMsg::AddNewCollection { .. }
+    );
+    if !admin_msg {
+        migrations::if_not_migrating(deps.storage)?;
+    }
     match msg {
         // Admin entry points
         ExecuteMsg::UpdateConfig { list_of_updates } => {
@@ -46,6 +66,41 @@ pub fn execute(
             if_admin(&config.contract_owner, info.sender.as_ref())?;
             claim_marketplace_fees::execute_claim_marketplace_fees(deps)
         }
+        ExecuteMsg::FundRewardPool {} => {
+            if_admin(&config.contract_owner, info.sender.as_ref())?;
+            fund_reward_pool::execute_fund_reward_pool(deps, env, info)
+        }
+        ExecuteMsg::SetCircuitBreaker {
+            capability,
+            paused,
+            reason,
+        } => {
+            if_admin(&config.contract_owner, info.sender.as_ref())?;
+            set_circuit_breaker::execute_set_circuit_breaker(
+                deps, env, info, capability, paused, reason,
+            )
+        }
+        ExecuteMsg::StartLeaderboardSeason {} => {
+            if_admin(&config.contract_owner, info.sender.as_ref())?;
+            start_leaderboard_season::execute_start_leaderboard_season(deps, env)
+        }
+        ExecuteMsg::CreateCompetition {
+            start_time,
+            end_time,
+            nft_collection_addresses,
+            prizes,
+        } => {
+            if_admin(&config.contract_owner, info.sender.as_ref())?;
+            create_competition::execute_create_competition(
+                deps,
+                env,
+                info,
+                start_time,
+                end_time,
+                nft_collection_addresses,
+                prizes,
+            )
+        }
         // AddNewCollection is only used by admins for now and a PR on GitHub will be used to add a
         // collection on the marketplace
         ExecuteMsg::AddNewCollection {
@@ -65,6 +120,9 @@ pub fn execute(
         ExecuteMsg::RemoveSomeExpiredSales {} => {
             remove_expired_sales::remove_expired_sales_function(deps, env, info)
         }
+        ExecuteMsg::FinalizeCompetition { competition_id } => {
+            finalize_competition::execute_finalize_competition(deps, env, competition_id)
+        }
         ExecuteMsg::TransferMyNft {
             nft_collection_address,
             token_id,
@@ -92,6 +150,10 @@ pub fn execute(
                 info.sender.to_string(),
             )?;
             if_enabled(config.contract_enabled)?;
+            // Internal sales (update sale, accepted offer) were checked by their entry point
+            if info.sender != env.contract.address {
+                if_capability_enabled(deps.storage, MarketplaceCapability::Listing)?;
+            }
             sell_nft::execute_sell_nft(deps, env, info, sale_info)
         }
         ExecuteMsg::UpdateSale { sale_info } => {
@@ -101,6 +163,7 @@ pub fn execute(
                 info.sender.to_string(),
             )?;
             if_enabled(config.contract_enabled)?;
+            if_capability_enabled(deps.storage, MarketplaceCapability::Listing)?;
             update_nft_sale::execute_update_nft_sale(deps, env, info, sale_info)
         }
         ExecuteMsg::CancelSale {
@@ -113,7 +176,7 @@ pub fn execute(
                 deps.as_ref(),
                 info.sender.to_string(),
             )?;
-            if_enabled(config.contract_enabled)?;
+            // Exit path: always open
             cancel_nft_sale::execute_cancel_nft_sale(
                 deps,
                 env,
@@ -134,6 +197,7 @@ pub fn execute(
                 info.sender.to_string(),
             )?;
             if_enabled(config.contract_enabled)?;
+            if_capability_enabled(deps.storage, MarketplaceCapability::Buying)?;
             buy_nft::execute_buy_nft(
                 deps,
                 env,
@@ -150,6 +214,7 @@ pub fn execute(
                 info.sender.to_string(),
             )?;
             if_enabled(config.contract_enabled)?;
+            if_capability_enabled(deps.storage, MarketplaceCapability::Offering)?;
             offer::execute_offer(deps, env, info, offer)
         }
         ExecuteMsg::CancelOffer {
@@ -162,7 +227,7 @@ pub fn execute(
                 deps.as_ref(),
                 info.sender.to_string(),
             )?;
-            if_enabled(config.contract_enabled)?;
+            // Exit path: always open
             cancel_offer::execute_cancel_offer(
                 deps,
                 env,
@@ -184,7 +249,11 @@ pub fn execute(
                 deps.as_ref(),
                 info.sender.to_string(),
             )?;
-            if_enabled(config.contract_enabled)?;
+            // Rejecting an offer refunds the offerer and always stays open
+            if if_accepted {
+                if_enabled(config.contract_enabled)?;
+                if_capability_enabled(deps.storage, MarketplaceCapability::Buying)?;
+            }
             answer_offer::execute_answer_offer(
                 deps,
                 env,
@@ -203,8 +272,120 @@ pub fn execute(
                 info.sender.to_string(),
             )?;
             if_enabled(config.contract_enabled)?;
+            if_capability_enabled(deps.storage, MarketplaceCapability::Messaging)?;
             send_message::execute_send_message(deps, env, info, to, message)
         }
+        // Reading, deleting and blocking stay open when messaging is paused
+        ExecuteMsg::MarkThreadRead {
+            counterparty,
+            up_to_message_id,
+        } => {
+            if_enabled(config.contract_enabled)?;
+            mark_thread_read::execute_mark_thread_read(
+                deps,
+                env,
+                info,
+                counterparty,
+                up_to_message_id,
+            )
+        }
+        ExecuteMsg::SetMessageFilter { message_filter } => {
+            if_enabled(config.contract_enabled)?;
+            set_message_filter::execute_set_message_filter(deps, env, info, message_filter)
+        }
+        ExecuteMsg::DeleteMessage { message_id } => {
+            if_enabled(config.contract_enabled)?;
+            delete_message::execute_delete_message(deps, env, info, message_id)
+        }
+        ExecuteMsg::BlockAddress { address } => {
+            if_enabled(config.contract_enabled)?;
+            block_address::execute_block_address(deps, env, info, address, true)
+        }
+        ExecuteMsg::UnblockAddress { address } => {
+            if_enabled(config.contract_enabled)?;
+            block_address::execute_block_address(deps, env, info, address, false)
+        }
+        ExecuteMsg::Follow { target } => {
+            if_enabled(config.contract_enabled)?;
+            follow::execute_follow(deps, env, info, target, true)
+        }
+        ExecuteMsg::Unfollow { target } => {
+            if_enabled(config.contract_enabled)?;
+            follow::execute_follow(deps, env, info, target, false)
+        }
+        ExecuteMsg::WatchToken {
+            nft_collection_address,
+            token_id,
+        } => {
+            if_enabled(config.contract_enabled)?;
+            watch_token::execute_watch_token(
+                deps,
+                env,
+                info,
+                nft_collection_address,
+                token_id,
+                true,
+            )
+        }
+        ExecuteMsg::UnwatchToken {
+            nft_collection_address,
+            token_id,
+        } => {
+            if_enabled(config.contract_enabled)?;
+            watch_token::execute_watch_token(
+                deps,
+                env,
+                info,
+                nft_collection_address,
+                token_id,
+                false,
+            )
+        }
+        ExecuteMsg::SellUsername {
+            price_value,
+            price_denom,
+        } => {
+            validate_address(
+                env.contract.address.to_string(),
+                deps.as_ref(),
+                info.sender.to_string(),
+            )?;
+            if_enabled(config.contract_enabled)?;
+            if_capability_enabled(deps.storage, MarketplaceCapability::Profiles)?;
+            sell_username::execute_sell_username(deps, env, info, price_value, price_denom)
+        }
+        // Sellers can always withdraw their username
+        ExecuteMsg::CancelUsernameSale {} => {
+            if_enabled(config.contract_enabled)?;
+            cancel_username_sale::execute_cancel_username_sale(deps, env, info)
+        }
+        ExecuteMsg::BuyUsername { username } => {
+            validate_address(
+                env.contract.address.to_string(),
+                deps.as_ref(),
+                info.sender.to_string(),
+            )?;
+            if_enabled(config.contract_enabled)?;
+            if_capability_enabled(deps.storage, MarketplaceCapability::Profiles)?;
+            buy_username::execute_buy_username(deps, env, info, username)
+        }
+        ExecuteMsg::TransferUsername { recipient } => {
+            validate_address(
+                env.contract.address.to_string(),
+                deps.as_ref(),
+                info.sender.to_string(),
+            )?;
+            if_enabled(config.contract_enabled)?;
+            if_capability_enabled(deps.storage, MarketplaceCapability::Profiles)?;
+            transfer_username::execute_transfer_username(deps, env, info, recipient)
+        }
+        // Moderation keeps working when the contract is disabled
+        ExecuteMsg::Verify { subject, kind } => {
+            verify::execute_verify(deps, env, info, subject, kind, true)
+        }
+        ExecuteMsg::RevokeVerification { subject, kind } => {
+            verify::execute_verify(deps, env, info, subject, kind, false)
+        }
         ExecuteMsg::CreateMyProfile { additional_info } => {
             validate_address(
                 env.contract.address.to_string(),
@@ -212,6 +393,10 @@ pub fn execute(
                 info.sender.to_string(),
             )?;
             if_enabled(config.contract_enabled)?;
+            // Profiles created on the fly while trading are not blocked
+            if info.sender != env.contract.address {
+                if_capability_enabled(deps.storage, MarketplaceCapability::Profiles)?;
+            }
             create_profile::execute_create_profile(deps, env, info, additional_info)
         }
         ExecuteMsg::UpdateMyProfile {
@@ -224,24 +409,71 @@ pub fn execute(
                 info.sender.to_string(),
             )?;
             if_enabled(config.contract_enabled)?;
+            if_capability_enabled(deps.storage, MarketplaceCapability::Profiles)?;
             update_profile::execute_update_profile(deps, env, info, profile, profile_update_action)
         }
         ExecuteMsg::LevelUpProfile {} => {
             if_enabled(config.contract_enabled)?;
+            if_capability_enabled(deps.storage, MarketplaceCapability::Profiles)?;
             level_up_profile::execute_level_up_profile(deps, env, info)
         }
+        ExecuteMsg::RenewVipLevel {} => {
+            if_enabled(config.contract_enabled)?;
+            if_capability_enabled(deps.storage, MarketplaceCapability::Profiles)?;
+            renew_vip_level::execute_renew_vip_level(deps, env, info)
+        }
+        ExecuteMsg::Stake {} => {
+            if_enabled(config.contract_enabled)?;
+            stake::execute_stake(deps, env, info)
+        }
+        // Earned rewards, stakes and prizes can always be taken out
+        ExecuteMsg::ClaimRewards {} => {
+            claim_rewards::execute_claim_rewards(deps, env, info)
+        }
+        ExecuteMsg::Unstake { amount } => {
+            unstake::execute_unstake(deps, env, info, amount)
+        }
+        ExecuteMsg::WithdrawUnbonded {} => {
+            withdraw_unbonded::execute_withdraw_unbonded(deps, env, info)
+        }
+        ExecuteMsg::ClaimStakingFees {} => {
+            claim_staking_fees::execute_claim_staking_fees(deps, env, info)
+        }
+        ExecuteMsg::ClaimCompetitionPrize { competition_id } => {
+            claim_competition_prize::execute_claim_competition_prize(
+                deps,
+                env,
+                info,
+                competition_id,
+            )
+        }
     }
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
-    Ok(Response::default())
+pub fn migrate(
+    deps: DepsMut,
+    env: Env,
+    msg: MigrateMsg,
+) -> Result<Response<TokenFactoryMsg>, ContractError> {
+    migrations::migrate_contract(deps, env, msg, CONTRACT_NAME, CONTRACT_VERSION)
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
-        QueryMsg::GetConfig {} => to_binary(&crate::query::query_config(deps)?),
+        QueryMsg::GetConfig {} => to_binary(&crate::query::query_config(deps, env)?),
+        QueryMsg::GetRewardPool {} => to_binary(&crate::query::query_reward_pool(deps)?),
+        QueryMsg::GetRewardMinting {} => to_binary(&crate::query::query_reward_minting(deps)?),
+        QueryMsg::GetRewardsToClaim { address } => {
+            to_binary(&crate::query::query_rewards_to_claim(deps, env, address)?)
+        }
+        QueryMsg::GetRewardVesting { address } => {
+            to_binary(&crate::query::query_reward_vesting(deps, env, address)?)
+        }
+        QueryMsg::GetCircuitBreakers {} => {
+            to_binary(&crate::query::query_circuit_breakers(deps)?)
+        }
         QueryMsg::GetNftCollectionInfo {
             nft_collection_address,
         } => to_binary(&crate::query::query_nft_collection(
@@ -301,6 +533,97 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
             start_after_token_id,
             output_length,
         )?),
+        QueryMsg::GetCollectionNftsForSaleByPrice {
+            nft_collection_address,
+            denom,
+            order,
+            min_price,
+            max_price,
+            start_after,
+            output_length,
+        } => to_binary(&crate::query::query_nfts_for_sale_by_price(
+            deps,
+            nft_collection_address,
+            denom,
+            order,
+            min_price,
+            max_price,
+            start_after,
+            output_length,
+        )?),
+        QueryMsg::GetRecentlyListed {
+            nft_collection_address,
+            start_after,
+            output_length,
+        } => to_binary(&crate::query::query_recently_listed(
+            deps,
+            nft_collection_address,
+            start_after,
+            output_length,
+        )?),
+        QueryMsg::GetEndingSoon {
+            nft_collection_address,
+            start_after,
+            output_length,
+        } => to_binary(&crate::query::query_ending_soon(
+            deps,
+            env,
+            nft_collection_address,
+            start_after,
+            output_length,
+        )?),
+        QueryMsg::GetRecentlySold {
+            nft_collection_address,
+            start_after,
+            output_length,
+        } => to_binary(&crate::query::query_recently_sold(
+            deps,
+            nft_collection_address,
+            start_after,
+            output_length,
+        )?),
+        QueryMsg::GetActivity {
+            start_after,
+            output_length,
+        } => to_binary(&crate::query::query_activity(
+            deps,
+            start_after,
+            output_length,
+        )?),
+        QueryMsg::GetCollectionActivity {
+            nft_collection_address,
+            start_after,
+            output_length,
+        } => to_binary(&crate::query::query_collection_activity(
+            deps,
+            nft_collection_address,
+            start_after,
+            output_length,
+        )?),
+        QueryMsg::GetTokenActivity {
+            nft_collection_address,
+            token_id,
+            start_after,
+            output_length,
+        } => to_binary(&crate::query::query_token_activity(
+            deps,
+            nft_collection_address,
+            token_id,
+            start_after,
+            output_length,
+        )?),
+        QueryMsg::GetAddressActivity {
+            address,
+            kinds,
+            start_after,
+            output_length,
+        } => to_binary(&crate::query::query_address_activity(
+            deps,
+            address,
+            kinds,
+            start_after,
+            output_length,
+        )?),
         QueryMsg::GetMarketplaceInfo {} => to_binary(&crate::query::query_marketplace_info(deps)?),
         QueryMsg::GetTokenIdSaleHistory {
             nft_collection_address,
@@ -310,12 +633,223 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
             nft_collection_address,
             token_id,
         )?),
+        QueryMsg::GetNftCollectionRollingStats {
+            nft_collection_address,
+        } => to_binary(&crate::query::query_nft_collection_rolling_stats(
+            deps,
+            env,
+            nft_collection_address,
+        )?),
+        QueryMsg::GetCollectionDailyPriceHistory {
+            nft_collection_address,
+            denom,
+            start_time,
+            end_time,
+            start_after,
+            output_length,
+        } => to_binary(&crate::query::query_collection_daily_price_history(
+            deps,
+            nft_collection_address,
+            denom,
+            start_time,
+            end_time,
+            start_after,
+            output_length,
+        )?),
+        QueryMsg::GetTraderLeaderboard {
+            ranking,
+            period,
+            start_after,
+            output_length,
+        } => to_binary(&crate::query::query_trader_leaderboard(
+            deps,
+            ranking,
+            period,
+            start_after,
+            output_length,
+        )?),
+        QueryMsg::GetLeaderboardSeason {} => {
+            to_binary(&crate::query::query_leaderboard_season(deps)?)
+        }
+        QueryMsg::GetCompetition { competition_id } => {
+            to_binary(&crate::query::query_competition(deps, competition_id)?)
+        }
+        QueryMsg::GetCompetitions {
+            start_after,
+            output_length,
+        } => to_binary(&crate::query::query_competitions(
+            deps,
+            start_after,
+            output_length,
+        )?),
+        QueryMsg::GetCompetitionLeaderboard {
+            competition_id,
+            start_after,
+            output_length,
+        } => to_binary(&crate::query::query_competition_leaderboard(
+            deps,
+            competition_id,
+            start_after,
+            output_length,
+        )?),
+        QueryMsg::GetCompetitionPrizes { competition_id } => to_binary(
+            &crate::query::query_competition_prizes(deps, competition_id)?,
+        ),
+        QueryMsg::GetInbox {
+            address,
+            start_after,
+            output_length,
+        } => to_binary(&crate::query::query_inbox(
+            deps,
+            address,
+            start_after,
+            output_length,
+        )?),
+        QueryMsg::GetInboxThreads {
+            address,
+            start_after,
+            output_length,
+        } => to_binary(&crate::query::query_inbox_threads(
+            deps,
+            address,
+            start_after,
+            output_length,
+        )?),
+        QueryMsg::GetInboxThread {
+            address,
+            counterparty,
+            start_after,
+            output_length,
+        } => to_binary(&crate::query::query_inbox_thread(
+            deps,
+            address,
+            counterparty,
+            start_after,
+            output_length,
+        )?),
+        QueryMsg::GetMessagingConfig {} => {
+            to_binary(&crate::query::query_messaging_config(deps)?)
+        }
+        QueryMsg::GetWashTradingConfig {} => {
+            to_binary(&crate::query::query_wash_trading_config(deps)?)
+        }
+        QueryMsg::GetStakingConfig {} => to_binary(&crate::query::query_staking_config(deps)?),
+        QueryMsg::GetStakingStats {} => to_binary(&crate::query::query_staking_stats(deps)?),
+        QueryMsg::GetStakerInfo { address } => {
+            to_binary(&crate::query::query_staker_info(deps, address)?)
+        }
+        QueryMsg::GetMessageFilter { address } => {
+            to_binary(&crate::query::query_message_filter(deps, address)?)
+        }
+        QueryMsg::GetBlockedAddresses {
+            address,
+            start_after,
+            output_length,
+        } => to_binary(&crate::query::query_blocked_addresses(
+            deps,
+            address,
+            start_after,
+            output_length,
+        )?),
+        QueryMsg::GetFollowers {
+            target,
+            start_after,
+            output_length,
+        } => to_binary(&crate::query::query_followers(
+            deps,
+            target,
+            start_after,
+            output_length,
+        )?),
+        QueryMsg::GetFollowing {
+            address,
+            start_after,
+            output_length,
+        } => to_binary(&crate::query::query_following(
+            deps,
+            address,
+            start_after,
+            output_length,
+        )?),
+        QueryMsg::GetWatchlist {
+            address,
+            start_after,
+            output_length,
+        } => to_binary(&crate::query::query_watchlist(
+            deps,
+            address,
+            start_after,
+            output_length,
+        )?),
+        QueryMsg::GetWatchlistListings {
+            address,
+            start_after,
+            output_length,
+        } => to_binary(&crate::query::query_watchlist_listings(
+            deps,
+            env,
+            address,
+            start_after,
+            output_length,
+        )?),
         QueryMsg::GetProfileInfo {
             address_or_username,
         } => to_binary(&crate::query::query_profile_info(
             deps,
+            env,
             address_or_username,
         )?),
+        QueryMsg::GetVipProgress { address } => {
+            to_binary(&crate::query::query_vip_progress(deps, env, address)?)
+        }
+        QueryMsg::GetPartnerPerks {} => to_binary(&crate::query::query_partner_perks(deps)?),
+        QueryMsg::GetHolderPerks { address } => {
+            to_binary(&crate::query::query_holder_perks(deps, env, address)?)
+        }
+        QueryMsg::GetUsername { address } => {
+            to_binary(&crate::query::query_username(deps, address)?)
+        }
+        QueryMsg::GetUsernameStatus { username } => {
+            to_binary(&crate::query::query_username_status(deps, username)?)
+        }
+        QueryMsg::GetUsernameRenameCooldown {} => {
+            to_binary(&crate::query::query_username_rename_cooldown(deps)?)
+        }
+        QueryMsg::GetUsernameSale { username } => {
+            to_binary(&crate::query::query_username_sale(deps, username)?)
+        }
+        QueryMsg::GetUsernamesForSale {
+            start_after,
+            output_length,
+        } => to_binary(&crate::query::query_usernames_for_sale(
+            deps,
+            start_after,
+            output_length,
+        )?),
+        QueryMsg::GetVerifications {
+            subject,
+            include_revoked,
+        } => to_binary(&crate::query::query_verifications(
+            deps,
+            subject,
+            include_revoked,
+        )?),
+        QueryMsg::GetModerators {
+            start_after,
+            output_length,
+        } => to_binary(&crate::query::query_moderators(
+            deps,
+            start_after,
+            output_length,
+        )?),
+        QueryMsg::GetReservedUsernames {
+            start_after,
+            output_length,
+        } => to_binary(&crate::query::query_reserved_usernames(
+            deps,
+            start_after,
+            output_length,
+        )?),
         QueryMsg::GetAllOffersTokenId {
             token_id,
             nft_collection_address,
@@ -9,6 +9,7 @@ use nft_marketplace_utils::nft_collection::{
     NftCollectionInfoAndUsdcVol, NftCollectionInfoByDenom, NftContractInfo,
 };
 use nft_marketplace_utils::response_handler::ResponseHandler;
+use nft_marketplace_utils::tokenfactory::TokenFactoryMsg;
 
 use crate::helpers::royalties::validate_contract_type_and_royalty;
 use crate::state::{CONFIG, GENERAL_STATS, LISTED_NFT_COLLECTIONS_INFO_AND_USDC_VOL};
@@ -19,7 +20,7 @@ pub fn execute_add_new_nft_collection(
     _info: MessageInfo,
     nft_collection_address: NftCollectionAddress,
     nft_contract_info: NftContractInfo,
-) -> Result<Response, ContractError> {
+) -> Result<Response<TokenFactoryMsg>, ContractError> {
     let config = CONFIG.load(deps.storage)?;
     if !config
         .accepted_nft_code_ids
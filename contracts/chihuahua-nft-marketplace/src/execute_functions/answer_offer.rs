@@ -1,11 +1,14 @@
 use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, WasmMsg};
 
 use general_utils::error::ContractError;
+use nft_marketplace_utils::activity::{Activity, ActivityType};
 use nft_marketplace_utils::nft_collection::{NftCollectionAddress, TokenId};
 use nft_marketplace_utils::nft_offer::{define_unique_offer, nft_offers};
 use nft_marketplace_utils::nft_sale::{define_unique_collection_nft_id, nfts_for_sale, NftSale};
 use nft_marketplace_utils::response_handler::ResponseHandler;
+use nft_marketplace_utils::tokenfactory::TokenFactoryMsg;
 
+use crate::helpers::activity::record_activity;
 use crate::msg::ExecuteMsg;
 use crate::state::CONFIG;
 
@@ -19,7 +22,7 @@ pub fn execute_answer_offer(
     from: String,
     if_accepted: bool,
     answer_msg: Option<String>,
-) -> Result<Response, ContractError> {
+) -> Result<Response<TokenFactoryMsg>, ContractError> {
     let config = CONFIG.load(deps.storage)?;
 
     let unique_offer = define_unique_offer(&nft_collection_address, &token_id, &from);
@@ -27,10 +30,30 @@ pub fn execute_answer_offer(
     // Validate: Offer needs to exist
     let nft_offer_loaded = nft_offers().load(deps.storage, unique_offer.clone())?;
 
+    // Activity log: from the owner answering to the offerer
+    let is_expired = nft_offer_loaded.offer_expiration.seconds() < env.block.time.seconds();
+    record_activity(
+        deps.storage,
+        Activity::new(
+            if if_accepted && !is_expired {
+                ActivityType::AcceptOffer
+            } else {
+                ActivityType::RejectOffer
+            },
+            nft_collection_address.clone(),
+            token_id.clone(),
+            info.sender.to_string(),
+            env.block.time,
+        )
+        .counterparty(nft_offer_loaded.offerer_address.clone())
+        .price(
+            nft_offer_loaded.offer_price_value,
+            nft_offer_loaded.offer_price_denom.clone(),
+        ),
+    )?;
+
     // If offer expired OR rejected -> cancel the offer + reimburse
-    if !if_accepted
-        || nft_offer_loaded.offer_expiration.clone().seconds() < env.block.time.seconds()
-    {
+    if !if_accepted || is_expired {
         // If expired or rejected, outcome should be the same as if the offer is cancelled
         // Cancel an offer refunds the offerr
         let exec_cancel_sale_msg_inner = ExecuteMsg::wasm_execute_cancel_offer(
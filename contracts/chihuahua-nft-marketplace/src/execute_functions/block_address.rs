@@ -0,0 +1,35 @@
+use cosmwasm_std::{ensure, DepsMut, Env, MessageInfo, Response};
+
+use general_utils::error::ContractError;
+use general_utils::error::NftMarketplaceError::CantBlockYourself;
+use nft_marketplace_utils::response_handler::ResponseHandler;
+use nft_marketplace_utils::tokenfactory::TokenFactoryMsg;
+
+use crate::state::BLOCKED_ADDRESSES;
+
+pub fn execute_block_address(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    mut address: String,
+    block: bool,
+) -> Result<Response<TokenFactoryMsg>, ContractError> {
+    address = deps.api.addr_validate(&address)?.to_string();
+    ensure!(
+        address != info.sender,
+        ContractError::NftMarketplaceError(CantBlockYourself {})
+    );
+
+    // Blocked addresses can't send messages to the sender anymore
+    if block {
+        BLOCKED_ADDRESSES.save(
+            deps.storage,
+            (info.sender.as_ref(), &address),
+            &env.block.time,
+        )?;
+    } else {
+        BLOCKED_ADDRESSES.remove(deps.storage, (info.sender.as_ref(), &address));
+    }
+
+    Ok(ResponseHandler::block_address(info.sender.to_string(), address, block).response)
+}
@@ -4,27 +4,39 @@ use cosmwasm_std::{
 };
 
 use general_utils::error::ContractError;
-use general_utils::error::GenericError::DivisionError;
 use general_utils::error::NftMarketplaceError::{
     AdditionalInfoNeedsToBeFilled, CantUseAdditionalInfoIfNotContract,
 };
+use nft_marketplace_utils::activity::{Activity, ActivityType};
 use nft_marketplace_utils::inputs::Buyer;
+use nft_marketplace_utils::leaderboard::record_trade_in_leaderboards;
 use nft_marketplace_utils::marketplace_statistics::CollectionVolume;
 use nft_marketplace_utils::nft_collection::{
     define_unique_collection_by_denom_id, nft_collection_denoms, NftCollectionAddress, TokenId,
 };
 use nft_marketplace_utils::nft_sale::{
     compute_floor_collection_and_denom, define_unique_collection_nft_id, nfts_for_sale,
-    TokenSaleHistory,
+    remove_nfts_for_sale, sold_nfts, NftSale, TokenSaleHistory,
 };
+use nft_marketplace_utils::price_history::RollingWindow;
 use nft_marketplace_utils::profile::{Profile, TradeInfo};
 use nft_marketplace_utils::response_handler::ResponseHandler;
+use nft_marketplace_utils::reward_system::{RewardSystem, VipUpgrade};
+use nft_marketplace_utils::tokenfactory::TokenFactoryMsg;
 
-use crate::constants::{MARKETPLACE_USDC_INDICATOR, MAX_PRICE};
+use crate::constants::{MARKETPLACE_USDC_INDICATOR, MAX_PRICE, MAX_TOKEN_SALE_HISTORY_LENGTH};
+use crate::helpers::activity::record_activity;
+use crate::helpers::competitions::record_trade_in_competitions;
+use crate::helpers::partner_perks::holder_perks;
+use crate::helpers::rewards::accrue_trade_rewards;
+use crate::helpers::rolling_stats::{
+    compute_rolling_window_stats, record_sale_in_collection_buckets,
+};
 use crate::helpers::royalties::compute_royalty;
 use crate::state::{
-    CONFIG, GENERAL_STATS, LISTED_NFT_COLLECTIONS_INFO_AND_USDC_VOL, MARKETPLACE_STATS_BY_DENOM,
-    PROFILES, REWARD_SYSTEM, TOKEN_SALE_HISTORY,
+    CONFIG, GENERAL_STATS, LEADERBOARD_SEASON, LISTED_NFT_COLLECTIONS_INFO_AND_USDC_VOL,
+    MARKETPLACE_STATS_BY_DENOM, NEXT_SOLD_NFT_ID, PROFILES, REWARD_MINTING, REWARD_SYSTEM,
+    TOKEN_SALE_HISTORY, WASH_TRADING_CONFIG,
 };
 
 pub fn execute_buy_nft(
@@ -34,7 +46,7 @@ pub fn execute_buy_nft(
     mut nft_collection_address: NftCollectionAddress,
     token_id: TokenId,
     additional_info: Option<String>,
-) -> Result<Response, ContractError> {
+) -> Result<Response<TokenFactoryMsg>, ContractError> {
     let config = CONFIG.load(deps.storage)?;
 
     // Validate the given NFT address
@@ -69,7 +81,26 @@ pub fn execute_buy_nft(
         .validate_buying_information(&buyer)?;
 
     // If buyer is valid, can remove the sale
-    nfts_for_sale().remove(deps.storage, collection_token_id_unique.clone())?;
+    remove_nfts_for_sale(deps.storage, collection_token_id_unique.clone())?;
+
+    // Discounts of both sides from their VIP level and partner holdings
+    let reward_system = REWARD_SYSTEM.load(deps.storage)?;
+    let seller_fees_discount = holder_perks(
+        deps.as_ref(),
+        &reward_system,
+        env.block.time,
+        &nft_for_sale_info.seller,
+    )?
+    .marketplace_fees_discount;
+    let buyer_fees_discount =
+        holder_perks(deps.as_ref(), &reward_system, env.block.time, &buyer.sender)?
+            .marketplace_fees_discount;
+    let (marketplace_revenues, buyer_fees_refund) = NftSale::split_marketplace_fees(
+        config.marketplace_pct_fees,
+        nft_for_sale_info.sale_price_value,
+        seller_fees_discount,
+        buyer_fees_discount,
+    )?;
 
     // Update the states and if it was expired -> refund the buyer and cancel the sale
     let new_floor = compute_floor_collection_and_denom(
@@ -83,6 +114,7 @@ pub fn execute_buy_nft(
         &nft_for_sale_info.sale_price_denom,
     );
     let is_expired = nft_for_sale_info.sale_expiration.seconds() <= env.block.time.seconds();
+    let mut opening_floor = Uint128::zero();
     MARKETPLACE_STATS_BY_DENOM.update(
         deps.storage,
         &nft_for_sale_info.clone().sale_price_denom,
@@ -93,7 +125,7 @@ pub fn execute_buy_nft(
             } else {
                 mp_info.execute_sale(
                     nft_for_sale_info.clone().sale_price_value,
-                    config.marketplace_pct_fees,
+                    marketplace_revenues,
                 );
             }
             Ok(mp_info)
@@ -101,9 +133,13 @@ pub fn execute_buy_nft(
     )?;
     nft_collection_denoms().update(
         deps.storage,
-        collection_denom_unique,
+        collection_denom_unique.clone(),
         |nft_coll_denom| -> Result<_, ContractError> {
             let mut nft_coll_denom = nft_coll_denom.unwrap();
+            // No listing is stored as MAX_PRICE
+            if nft_coll_denom.current_floor < MAX_PRICE {
+                opening_floor = nft_coll_denom.current_floor;
+            }
             if is_expired {
                 nft_coll_denom = nft_coll_denom.expired_sale(new_floor);
             } else {
@@ -130,12 +166,45 @@ pub fn execute_buy_nft(
         sale_time: Timestamp::from_seconds(env.block.time.seconds()),
     };
     let mut new_vector_of_transactions = maybe_history.unwrap_or_default();
-    new_vector_of_transactions.push(transaction_info);
+    // Wash trading rules look at the previous sales of the token and the floor of the others
+    let wash_trading_config = WASH_TRADING_CONFIG
+        .may_load(deps.storage)?
+        .unwrap_or_default();
+    let withholding_reason = wash_trading_config.withholding_reason(
+        &new_vector_of_transactions,
+        &transaction_info,
+        (new_floor < MAX_PRICE).then_some(new_floor),
+    );
+    new_vector_of_transactions.push(transaction_info.clone());
+    // Only the latest sales are kept, the full history is in the activity log
+    if new_vector_of_transactions.len() > MAX_TOKEN_SALE_HISTORY_LENGTH {
+        let excess = new_vector_of_transactions.len() - MAX_TOKEN_SALE_HISTORY_LENGTH;
+        new_vector_of_transactions.drain(..excess);
+    }
     TOKEN_SALE_HISTORY.save(
         deps.storage,
         &collection_token_id_unique,
         &new_vector_of_transactions,
     )?;
+    // Recently sold feeds
+    let sold_nft_id = NEXT_SOLD_NFT_ID.may_load(deps.storage)?.unwrap_or_default();
+    sold_nfts().save(deps.storage, sold_nft_id, &transaction_info)?;
+    NEXT_SOLD_NFT_ID.save(deps.storage, &(sold_nft_id + 1))?;
+    record_activity(
+        deps.storage,
+        Activity::new(
+            ActivityType::Sale,
+            nft_for_sale_info.nft_collection_address.clone(),
+            nft_for_sale_info.token_id.clone(),
+            nft_for_sale_info.seller.clone(),
+            env.block.time,
+        )
+        .counterparty(buyer.sender.clone())
+        .price(
+            nft_for_sale_info.sale_price_value,
+            nft_for_sale_info.sale_price_denom.clone(),
+        ),
+    )?;
 
     // Calculation of royalties and marketplace revenues
     let nft_collection_info =
@@ -173,6 +242,17 @@ pub fn execute_buy_nft(
             },
         )?;
     }
+    // Daily and hourly buckets of the collection used for charts and rolling stats
+    record_sale_in_collection_buckets(
+        deps.storage,
+        &nft_for_sale_info.nft_collection_address,
+        &nft_for_sale_info.sale_price_denom,
+        env.block.time,
+        nft_for_sale_info.sale_price_value,
+        nft_price_usdc,
+        opening_floor,
+    )?;
+
     let mut gen_stats = GENERAL_STATS.load(deps.storage)?;
     let current_collection_usdc_volume = LISTED_NFT_COLLECTIONS_INFO_AND_USDC_VOL
         .load(deps.storage, &nft_for_sale_info.nft_collection_address)?
@@ -185,8 +265,30 @@ pub fn execute_buy_nft(
             nft_collection_address: nft_collection_address.clone(),
             usdc_volume: current_collection_usdc_volume,
         });
-        GENERAL_STATS.save(deps.storage, &gen_stats)?;
     }
+    // Trending collections by USDC volume on the rolling windows
+    let collection_usdc_unique = define_unique_collection_by_denom_id(
+        &nft_for_sale_info.nft_collection_address,
+        MARKETPLACE_USDC_INDICATOR,
+    );
+    for window in [RollingWindow::Last24h, RollingWindow::Last7d] {
+        let window_stats = compute_rolling_window_stats(
+            deps.storage,
+            &collection_usdc_unique,
+            window.clone(),
+            env.block.time,
+            Uint128::zero(),
+        )?;
+        gen_stats.compute_new_trending(
+            window,
+            CollectionVolume {
+                nft_collection_address: nft_collection_address.clone(),
+                usdc_volume: window_stats.volume_usdc,
+            },
+            env.block.time,
+        );
+    }
+    GENERAL_STATS.save(deps.storage, &gen_stats)?;
 
     // BUYER profile (sender is the buyer)
     let mut buyer_profile: Profile = Profile::new(buyer.sender.clone());
@@ -199,21 +301,32 @@ pub fn execute_buy_nft(
     {
         PROFILES.save(deps.storage, &nft_for_sale_info.seller, &seller_profile)?;
     }
-    // Update the profiles
+    // Update the profiles, withheld trades don't count towards the VIP volume
+    let vip_volume_usdc = if withholding_reason.is_none() {
+        nft_price_usdc
+    } else {
+        Uint128::zero()
+    };
     let sale_price_trade_info = TradeInfo {
         denom: nft_for_sale_info.sale_price_denom.clone(),
         volume_value: nft_for_sale_info.sale_price_value,
     };
 
     // Seller update
-    PROFILES.update(
+    let updated_seller_profile = PROFILES.update(
         deps.storage,
         &nft_for_sale_info.seller,
         |profile| -> Result<_, ContractError> {
             let mut profile_u = profile.unwrap();
             seller_profile = profile_u.clone();
             profile_u = profile_u
-                .realise_transaction(None, Some(sale_price_trade_info.clone()))
+                .expire_vip_level(&reward_system, env.block.time)
+                .realise_transaction(
+                    None,
+                    Some(sale_price_trade_info.clone()),
+                    vip_volume_usdc,
+                    &reward_system,
+                )
                 .unwrap();
             profile_u = profile_u.nft_used_in_profile_check_and_reset(
                 token_id.clone(),
@@ -223,41 +336,129 @@ pub fn execute_buy_nft(
         },
     )?;
     // Buyer update
-    PROFILES.update(
+    let updated_buyer_profile = PROFILES.update(
         deps.storage,
         info.sender.as_ref(),
         |profile| -> Result<_, ContractError> {
             let mut profile_u = profile.unwrap();
             buyer_profile = profile_u.clone();
             profile_u = profile_u
-                .realise_transaction(Some(sale_price_trade_info.clone()), None)
+                .expire_vip_level(&reward_system, env.block.time)
+                .realise_transaction(
+                    Some(sale_price_trade_info.clone()),
+                    None,
+                    vip_volume_usdc,
+                    &reward_system,
+                )
                 .unwrap();
             Ok(profile_u)
         },
     )?;
-    let reward_system = REWARD_SYSTEM.load(deps.storage)?;
+    // Profiles upgraded by reaching the volume threshold of a tier
+    let vip_upgrades: Vec<VipUpgrade> = [
+        (&seller_profile, &updated_seller_profile),
+        (&buyer_profile, &updated_buyer_profile),
+    ]
+    .into_iter()
+    .filter_map(|(previous_profile, updated_profile)| {
+        vip_upgrade(
+            &reward_system,
+            env.block.time,
+            previous_profile,
+            updated_profile,
+        )
+    })
+    .collect();
+    // Trader leaderboards (all time and current season) in USDC
+    let season_id = LEADERBOARD_SEASON
+        .may_load(deps.storage)?
+        .unwrap_or_default()
+        .season_id;
+    record_trade_in_leaderboards(
+        deps.storage,
+        &nft_for_sale_info.seller,
+        season_id,
+        Uint128::zero(),
+        nft_price_usdc,
+        seller_profile.display_trade_info.unwrap_or(false),
+    )?;
+    record_trade_in_leaderboards(
+        deps.storage,
+        info.sender.as_ref(),
+        season_id,
+        nft_price_usdc,
+        Uint128::zero(),
+        buyer_profile.display_trade_info.unwrap_or(false),
+    )?;
+    // Trading competitions running for this collection, withheld trades don't count
+    if withholding_reason.is_none() {
+        record_trade_in_competitions(
+            deps.storage,
+            env.block.time,
+            &nft_collection_address,
+            nft_price_usdc,
+            &[buyer.sender.as_ref(), &nft_for_sale_info.seller],
+        )?;
+    }
+    // Rewards are claimed later with ClaimRewards, an empty pool never fails the trade
+    let (credited_rewards, withheld_rewards) = accrue_trade_rewards(
+        deps.storage,
+        env.block.time,
+        &reward_system,
+        &wash_trading_config,
+        withholding_reason,
+        nft_price_usdc,
+        &[buyer.sender.as_ref(), &nft_for_sale_info.seller],
+    )?;
+    let total_credited_rewards = credited_rewards.iter().sum::<Uint128>();
     REWARD_SYSTEM.update(
         deps.storage,
         |mut updated_reward_system| -> Result<_, ContractError> {
-            let current_distribution: Uint128 = nft_price_usdc
-                .checked_div(updated_reward_system.reward_token_per_1usdc_volume)
-                .map_err(|_| ContractError::Generic(DivisionError {}))?
-                .checked_mul(Uint128::new(2_000_000u128))
-                .map_err(|_| ContractError::Generic(DivisionError {}))?;
-            updated_reward_system.total_reward_tokens_distributed += current_distribution;
+            updated_reward_system.total_reward_tokens_distributed += total_credited_rewards;
             Ok(updated_reward_system)
         },
     )?;
-    Ok(ResponseHandler::execute_succes_nft_sale_response(
+    let mut response = ResponseHandler::execute_succes_nft_sale_response(
         deps.as_ref(),
         buyer,
         nft_for_sale_info,
-        config.marketplace_pct_fees,
+        marketplace_revenues,
+        buyer_fees_refund,
         nft_royalties,
-        seller_profile,
-        buyer_profile,
-        reward_system,
-        nft_price_usdc,
+        credited_rewards[0],
+        credited_rewards[1],
+        withheld_rewards,
+        vip_upgrades,
     )?
-    .response)
+    .response;
+    // Minted rewards are held by the marketplace until claimed, like the pre-funded ones
+    if let Some(reward_minting) = REWARD_MINTING.may_load(deps.storage)? {
+        if !total_credited_rewards.is_zero() {
+            response = response.add_message(
+                reward_minting.mint_msg(total_credited_rewards, env.contract.address.to_string()),
+            );
+        }
+    }
+    Ok(response)
+}
+
+fn vip_upgrade(
+    reward_system: &RewardSystem,
+    time: Timestamp,
+    previous_profile: &Profile,
+    updated_profile: &Profile,
+) -> Option<VipUpgrade> {
+    let previous_level = previous_profile
+        .clone()
+        .expire_vip_level(reward_system, time)
+        .vip_level
+        .unwrap_or_default();
+    let vip_level = updated_profile.vip_level.unwrap_or_default();
+    let vip_tier = reward_system.vip_tier(vip_level)?;
+    (vip_level > previous_level).then(|| VipUpgrade {
+        address: updated_profile.address.clone(),
+        previous_level,
+        vip_level,
+        tier_name: vip_tier.name.clone(),
+    })
 }
@@ -0,0 +1,56 @@
+use cosmwasm_std::{coins, ensure, DepsMut, Env, MessageInfo, Response};
+
+use general_utils::error::ContractError;
+use general_utils::error::NftMarketplaceError::{
+    CantBuyYourOwnUsername, InvalidBuyerInformation, UsernameIsNotForSale,
+};
+use nft_marketplace_utils::nft_sale::NftSale;
+use nft_marketplace_utils::response_handler::ResponseHandler;
+use nft_marketplace_utils::tokenfactory::TokenFactoryMsg;
+use nft_marketplace_utils::username::normalize_username;
+
+use crate::helpers::marketplace_stats::add_fees;
+use crate::helpers::username::move_username;
+use crate::state::{CONFIG, USERNAME_SALES};
+
+pub fn execute_buy_username(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    username: String,
+) -> Result<Response<TokenFactoryMsg>, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let username_sale = USERNAME_SALES
+        .may_load(deps.storage, &normalize_username(&username))?
+        .ok_or(ContractError::NftMarketplaceError(UsernameIsNotForSale {}))?;
+    ensure!(
+        info.sender != username_sale.seller,
+        ContractError::NftMarketplaceError(CantBuyYourOwnUsername {})
+    );
+    ensure!(
+        info.funds
+            == coins(
+                username_sale.price_value.u128(),
+                username_sale.price_denom.clone()
+            ),
+        ContractError::NftMarketplaceError(InvalidBuyerInformation {})
+    );
+
+    // Same fees as an NFT sale, the seller receives the rest
+    let marketplace_fees =
+        NftSale::compute_marketplace_fees(config.marketplace_pct_fees, username_sale.price_value);
+    add_fees(deps.storage, &username_sale.price_denom, marketplace_fees)?;
+
+    // The sale is removed with the username ownership
+    move_username(
+        deps.storage,
+        env.block.time,
+        &username_sale.seller,
+        info.sender.as_ref(),
+    )?;
+
+    Ok(
+        ResponseHandler::buy_username(username_sale, info.sender.to_string(), marketplace_fees)
+            .response,
+    )
+}
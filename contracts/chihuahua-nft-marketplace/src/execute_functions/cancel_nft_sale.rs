@@ -8,15 +8,19 @@ use general_utils::error::NftMarketplaceError::{
     AdditionalInfoNeedsToBeFilled, CantUseAdditionalInfoIfNotContract,
     RevokeYourApprovalBeforeCancellingSale,
 };
+use nft_marketplace_utils::activity::{Activity, ActivityType};
 use nft_marketplace_utils::nft_collection::{
     define_unique_collection_by_denom_id, nft_collection_denoms, NftCollectionAddress, TokenId,
 };
 use nft_marketplace_utils::nft_sale::{
     compute_floor_collection_and_denom, define_unique_collection_nft_id, nfts_for_sale,
+    remove_nfts_for_sale,
 };
 use nft_marketplace_utils::response_handler::ResponseHandler;
+use nft_marketplace_utils::tokenfactory::TokenFactoryMsg;
 
 use crate::constants::MAX_PRICE;
+use crate::helpers::activity::record_activity;
 use crate::state::MARKETPLACE_STATS_BY_DENOM;
 
 pub fn execute_cancel_nft_sale(
@@ -26,7 +30,7 @@ pub fn execute_cancel_nft_sale(
     mut nft_collection_address: NftCollectionAddress,
     token_id: TokenId,
     additional_info: Option<String>,
-) -> Result<Response, ContractError> {
+) -> Result<Response<TokenFactoryMsg>, ContractError> {
     nft_collection_address = deps.api.addr_validate(&nft_collection_address)?.to_string();
     // Validation: additional_info can only be used by contract
     // When the contract uses this entry point it is for a cancellation for:
@@ -104,7 +108,7 @@ pub fn execute_cancel_nft_sale(
         &nft_for_sale_info.sale_price_denom,
     );
 
-    nfts_for_sale().remove(deps.storage, collection_token_id_unique)?;
+    remove_nfts_for_sale(deps.storage, collection_token_id_unique)?;
 
     // Execute: Compute new floor; if needed and update collection's stats (floor f.e.)
     let new_floor = compute_floor_collection_and_denom(
@@ -121,5 +125,18 @@ pub fn execute_cancel_nft_sale(
             Ok(nft_coll_denom.unwrap().remove_sale(new_floor))
         },
     )?;
+    // Activity log: internal cancellations are part of an update or an accepted offer
+    if additional_info.is_none() {
+        record_activity(
+            deps.storage,
+            Activity::new(
+                ActivityType::CancelListing,
+                nft_for_sale_info.nft_collection_address.clone(),
+                nft_for_sale_info.token_id.clone(),
+                nft_for_sale_info.seller.clone(),
+                env.block.time,
+            ),
+        )?;
+    }
     Ok(ResponseHandler::cancel_nft_sale_response(nft_for_sale_info).response)
 }
@@ -4,9 +4,13 @@ use general_utils::error::ContractError;
 use general_utils::error::NftMarketplaceError::{
     AdditionalInfoNeedsToBeFilled, CantUseAdditionalInfoIfNotContract,
 };
+use nft_marketplace_utils::activity::{Activity, ActivityType};
 use nft_marketplace_utils::nft_collection::{NftCollectionAddress, TokenId};
 use nft_marketplace_utils::nft_offer::{define_unique_offer, nft_offers};
 use nft_marketplace_utils::response_handler::ResponseHandler;
+use nft_marketplace_utils::tokenfactory::TokenFactoryMsg;
+
+use crate::helpers::activity::record_activity;
 
 pub fn execute_cancel_offer(
     deps: DepsMut,
@@ -15,7 +19,7 @@ pub fn execute_cancel_offer(
     nft_collection_address: NftCollectionAddress,
     token_id: TokenId,
     additional_info: Option<String>,
-) -> Result<Response, ContractError> {
+) -> Result<Response<TokenFactoryMsg>, ContractError> {
     // If the sender is the contract, can be cancelled
     let sender = match (additional_info.clone(), info.sender.clone()) {
         (Some(_), sender) if sender != env.contract.address => {
@@ -37,8 +41,20 @@ pub fn execute_cancel_offer(
     let nft_offer_loaded = nft_offers().load(deps.storage, unique_offer.clone())?;
 
     // Validate: If the offer exists; sender and offerer should be the same
+    // Activity log: internal cancellations are logged as rejected offers by answer_offer
     if nft_offer_loaded.offerer_address != info.sender && info.sender == env.contract.address {
         info.sender = deps.api.addr_validate(&additional_info.unwrap())?;
+    } else if additional_info.is_none() {
+        record_activity(
+            deps.storage,
+            Activity::new(
+                ActivityType::CancelOffer,
+                nft_offer_loaded.nft_collection_address.clone(),
+                nft_offer_loaded.token_id.clone(),
+                nft_offer_loaded.offerer_address.clone(),
+                env.block.time,
+            ),
+        )?;
     }
 
     // Update: Remove the offer
@@ -0,0 +1,28 @@
+use cosmwasm_std::{ensure, DepsMut, Env, MessageInfo, Response};
+
+use general_utils::error::ContractError;
+use general_utils::error::NftMarketplaceError::{UsernameIsNotForSale, YouDontHaveAUsername};
+use nft_marketplace_utils::response_handler::ResponseHandler;
+use nft_marketplace_utils::tokenfactory::TokenFactoryMsg;
+use nft_marketplace_utils::username::normalize_username;
+
+use crate::state::{PROFILES, USERNAME_SALES};
+
+pub fn execute_cancel_username_sale(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+) -> Result<Response<TokenFactoryMsg>, ContractError> {
+    let username = PROFILES
+        .may_load(deps.storage, info.sender.as_ref())?
+        .and_then(|profile| profile.username)
+        .ok_or(ContractError::NftMarketplaceError(YouDontHaveAUsername {}))?;
+    let key = normalize_username(&username);
+    ensure!(
+        USERNAME_SALES.has(deps.storage, &key),
+        ContractError::NftMarketplaceError(UsernameIsNotForSale {})
+    );
+    USERNAME_SALES.remove(deps.storage, &key);
+
+    Ok(ResponseHandler::cancel_username_sale(username, info.sender.to_string()).response)
+}
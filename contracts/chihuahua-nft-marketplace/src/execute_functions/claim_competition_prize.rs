@@ -0,0 +1,29 @@
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+
+use general_utils::error::ContractError;
+use general_utils::error::NftMarketplaceError::NoCompetitionPrizeToClaim;
+use nft_marketplace_utils::response_handler::ResponseHandler;
+use nft_marketplace_utils::tokenfactory::TokenFactoryMsg;
+
+use crate::state::{COMPETITIONS, COMPETITION_PRIZES};
+
+pub fn execute_claim_competition_prize(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    competition_id: u64,
+) -> Result<Response<TokenFactoryMsg>, ContractError> {
+    let key = (competition_id, info.sender.as_str());
+    let mut prize = COMPETITION_PRIZES
+        .may_load(deps.storage, key)?
+        .filter(|prize| !prize.claimed)
+        .ok_or(ContractError::NftMarketplaceError(
+            NoCompetitionPrizeToClaim {},
+        ))?;
+    // Kept with the claimed flag so the results of the competition can still be queried
+    prize.claimed = true;
+    COMPETITION_PRIZES.save(deps.storage, key, &prize)?;
+    let competition = COMPETITIONS.load(deps.storage, competition_id)?;
+
+    Ok(ResponseHandler::claim_competition_prize(prize, competition.prize_denom).response)
+}
@@ -3,11 +3,17 @@ use cosmwasm_std::{DepsMut, Response, Uint128};
 use general_utils::denominations::DenominationValue;
 use general_utils::error::ContractError;
 use nft_marketplace_utils::response_handler::ResponseHandler;
+use nft_marketplace_utils::tokenfactory::TokenFactoryMsg;
 
-use crate::state::{CONFIG, MARKETPLACE_STATS_BY_DENOM};
+use crate::helpers::staking::distribute_staking_fees;
+use crate::state::{CONFIG, FEE_INDEXES, MARKETPLACE_STATS_BY_DENOM};
 
-pub fn execute_claim_marketplace_fees(deps: DepsMut) -> Result<Response, ContractError> {
+pub fn execute_claim_marketplace_fees(
+    deps: DepsMut,
+) -> Result<Response<TokenFactoryMsg>, ContractError> {
     let config = CONFIG.load(deps.storage)?;
+    // The share of the stakers is left out of the fees claimed
+    distribute_staking_fees(deps.storage)?;
 
     // States Update: Gather the fees into a vec and reset to 0
     let all_accepted_denoms_value: Vec<DenominationValue> = config
@@ -26,6 +32,10 @@ pub fn execute_claim_marketplace_fees(deps: DepsMut) -> Result<Response, Contrac
                     Ok(mp_info_u)
                 },
             )?;
+            if let Some(mut fee_index) = FEE_INDEXES.may_load(deps.storage, denom)? {
+                fee_index.fees_checkpoint = Uint128::zero();
+                FEE_INDEXES.save(deps.storage, denom, &fee_index)?;
+            }
             Ok::<DenominationValue, ContractError>(DenominationValue {
                 denom: denom.clone(),
                 value: mp_fees,
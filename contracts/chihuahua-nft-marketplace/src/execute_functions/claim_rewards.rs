@@ -0,0 +1,41 @@
+use cosmwasm_std::{ensure, DepsMut, Env, MessageInfo, Response};
+
+use general_utils::error::ContractError;
+use general_utils::error::NftMarketplaceError::NoRewardsToClaim;
+use nft_marketplace_utils::response_handler::ResponseHandler;
+use nft_marketplace_utils::tokenfactory::TokenFactoryMsg;
+
+use crate::helpers::rewards::claim_vested_rewards;
+use crate::state::{REWARDS_TO_CLAIM, REWARD_POOL, REWARD_SYSTEM};
+
+pub fn execute_claim_rewards(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response<TokenFactoryMsg>, ContractError> {
+    let reward_system = REWARD_SYSTEM.load(deps.storage)?;
+    // Liquid rewards and what has vested so far
+    let rewards_to_claim = REWARDS_TO_CLAIM
+        .may_load(deps.storage, info.sender.as_ref())?
+        .unwrap_or_default()
+        + claim_vested_rewards(deps.storage, env.block.time, info.sender.as_ref())?;
+    ensure!(
+        !rewards_to_claim.is_zero(),
+        ContractError::NftMarketplaceError(NoRewardsToClaim {})
+    );
+    REWARDS_TO_CLAIM.remove(deps.storage, info.sender.as_ref());
+    REWARD_POOL.update(
+        deps.storage,
+        |mut reward_pool| -> Result<_, ContractError> {
+            reward_pool.total_to_claim -= rewards_to_claim;
+            Ok(reward_pool)
+        },
+    )?;
+
+    Ok(ResponseHandler::claim_rewards(
+        info.sender.to_string(),
+        rewards_to_claim,
+        reward_system.reward_token_address,
+    )
+    .response)
+}
@@ -0,0 +1,36 @@
+use cosmwasm_std::{ensure, DepsMut, Env, MessageInfo, Response, Uint128};
+
+use general_utils::denominations::DenominationValue;
+use general_utils::error::ContractError;
+use general_utils::error::NftMarketplaceError::NoStakingFeesToClaim;
+use nft_marketplace_utils::response_handler::ResponseHandler;
+use nft_marketplace_utils::tokenfactory::TokenFactoryMsg;
+
+use crate::helpers::staking::settle_staker;
+use crate::state::STAKER_FEES;
+
+pub fn execute_claim_staking_fees(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+) -> Result<Response<TokenFactoryMsg>, ContractError> {
+    let address = info.sender.to_string();
+    let mut fees_to_claim: Vec<DenominationValue> = vec![];
+    for (denom, mut staker_fees) in settle_staker(deps.storage, &address)? {
+        if staker_fees.fees_to_claim.is_zero() {
+            continue;
+        }
+        fees_to_claim.push(DenominationValue {
+            denom: denom.clone(),
+            value: staker_fees.fees_to_claim,
+        });
+        staker_fees.fees_to_claim = Uint128::zero();
+        STAKER_FEES.save(deps.storage, (&address, &denom), &staker_fees)?;
+    }
+    ensure!(
+        !fees_to_claim.is_empty(),
+        ContractError::NftMarketplaceError(NoStakingFeesToClaim {})
+    );
+
+    Ok(ResponseHandler::claim_staking_fees(address, fees_to_claim).response)
+}
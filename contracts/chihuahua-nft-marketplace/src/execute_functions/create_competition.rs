@@ -0,0 +1,70 @@
+use cosmwasm_std::{ensure, DepsMut, Empty, Env, MessageInfo, Order, Response, Timestamp, Uint128};
+
+use general_utils::error::ContractError;
+use general_utils::error::GenericError::InvalidFundsReceived;
+use general_utils::error::NftMarketplaceError::{NftCollectionNotListed, TooManyOpenCompetitions};
+use nft_marketplace_utils::competition::{Competition, MAX_OPEN_COMPETITIONS};
+use nft_marketplace_utils::nft_collection::NftCollectionAddress;
+use nft_marketplace_utils::response_handler::ResponseHandler;
+use nft_marketplace_utils::tokenfactory::TokenFactoryMsg;
+
+use crate::state::{
+    COMPETITIONS, LISTED_NFT_COLLECTIONS_INFO_AND_USDC_VOL, NEXT_COMPETITION_ID, OPEN_COMPETITIONS,
+};
+
+pub fn execute_create_competition(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    start_time: Timestamp,
+    end_time: Timestamp,
+    nft_collection_addresses: Vec<NftCollectionAddress>,
+    prizes: Vec<Uint128>,
+) -> Result<Response<TokenFactoryMsg>, ContractError> {
+    let nft_collection_addresses = nft_collection_addresses
+        .iter()
+        .map(|address| -> Result<_, ContractError> {
+            let address = deps.api.addr_validate(address)?.to_string();
+            ensure!(
+                LISTED_NFT_COLLECTIONS_INFO_AND_USDC_VOL.has(deps.storage, &address),
+                ContractError::NftMarketplaceError(NftCollectionNotListed {})
+            );
+            Ok(address)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    // The whole prize pool is sent with the message, in the denom of the prizes
+    ensure!(
+        info.funds.len() == 1,
+        ContractError::Generic(InvalidFundsReceived {})
+    );
+    let competition_id = NEXT_COMPETITION_ID.may_load(deps.storage)?.unwrap_or(1);
+    let competition = Competition {
+        competition_id,
+        start_time,
+        end_time,
+        nft_collection_addresses,
+        prize_denom: info.funds[0].denom.clone(),
+        prizes,
+        finalized: false,
+    };
+    competition.validate(env.block.time)?;
+    ensure!(
+        info.funds[0].amount == competition.prize_pool(),
+        ContractError::Generic(InvalidFundsReceived {})
+    );
+
+    // Ended competitions stay open until finalized
+    ensure!(
+        OPEN_COMPETITIONS
+            .keys(deps.storage, None, None, Order::Ascending)
+            .count()
+            < MAX_OPEN_COMPETITIONS,
+        ContractError::NftMarketplaceError(TooManyOpenCompetitions {})
+    );
+
+    COMPETITIONS.save(deps.storage, competition_id, &competition)?;
+    OPEN_COMPETITIONS.save(deps.storage, competition_id, &Empty {})?;
+    NEXT_COMPETITION_ID.save(deps.storage, &(competition_id + 1))?;
+
+    Ok(ResponseHandler::create_competition(competition).response)
+}
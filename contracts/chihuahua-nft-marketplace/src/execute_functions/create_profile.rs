@@ -6,6 +6,7 @@ use general_utils::error::NftMarketplaceError::{
 };
 use nft_marketplace_utils::profile::Profile;
 use nft_marketplace_utils::response_handler::ResponseHandler;
+use nft_marketplace_utils::tokenfactory::TokenFactoryMsg;
 
 use crate::state::PROFILES;
 
@@ -14,7 +15,7 @@ pub fn execute_create_profile(
     env: Env,
     mut info: MessageInfo,
     additional_info: Option<String>,
-) -> Result<Response, ContractError> {
+) -> Result<Response<TokenFactoryMsg>, ContractError> {
     // Validate: who is the sender
     if let Some(addr) = additional_info {
         ensure!(
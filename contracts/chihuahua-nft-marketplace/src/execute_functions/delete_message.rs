@@ -0,0 +1,23 @@
+use cosmwasm_std::{ensure, DepsMut, Env, MessageInfo, Response};
+
+use general_utils::error::ContractError;
+use general_utils::error::NftMarketplaceError::OnlyTheRecipientCanDeleteAMessage;
+use nft_marketplace_utils::inbox::inbox_messages;
+use nft_marketplace_utils::response_handler::ResponseHandler;
+use nft_marketplace_utils::tokenfactory::TokenFactoryMsg;
+
+pub fn execute_delete_message(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    message_id: u64,
+) -> Result<Response<TokenFactoryMsg>, ContractError> {
+    let message = inbox_messages().load(deps.storage, message_id)?;
+    ensure!(
+        message.to_address == info.sender,
+        ContractError::NftMarketplaceError(OnlyTheRecipientCanDeleteAMessage {})
+    );
+    inbox_messages().remove(deps.storage, message_id)?;
+
+    Ok(ResponseHandler::delete_message(message).response)
+}
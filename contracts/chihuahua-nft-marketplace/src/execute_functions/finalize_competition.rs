@@ -0,0 +1,66 @@
+use cosmwasm_std::{ensure, DepsMut, Env, Order, Response, StdResult, Uint128};
+
+use general_utils::error::ContractError;
+use general_utils::error::NftMarketplaceError::{
+    CompetitionAlreadyFinalized, CompetitionDoesNotExist, CompetitionNotEnded,
+};
+use nft_marketplace_utils::competition::{competition_traders, CompetitionPrize};
+use nft_marketplace_utils::response_handler::ResponseHandler;
+use nft_marketplace_utils::tokenfactory::TokenFactoryMsg;
+
+use crate::state::{COMPETITIONS, COMPETITION_PRIZES, CONFIG, OPEN_COMPETITIONS};
+
+pub fn execute_finalize_competition(
+    deps: DepsMut,
+    env: Env,
+    competition_id: u64,
+) -> Result<Response<TokenFactoryMsg>, ContractError> {
+    let mut competition = COMPETITIONS.may_load(deps.storage, competition_id)?.ok_or(
+        ContractError::NftMarketplaceError(CompetitionDoesNotExist {}),
+    )?;
+    ensure!(
+        !competition.finalized,
+        ContractError::NftMarketplaceError(CompetitionAlreadyFinalized {})
+    );
+    ensure!(
+        env.block.time >= competition.end_time,
+        ContractError::NftMarketplaceError(CompetitionNotEnded {})
+    );
+
+    // Top N traders by volume, equal volumes are ranked by the time they were reached
+    let winners = competition_traders()
+        .idx
+        .volume_index
+        .sub_prefix(competition_id)
+        .range(deps.storage, None, None, Order::Descending)
+        .take(competition.prizes.len())
+        .map(|result| result.map(|(_, trader)| trader))
+        .collect::<StdResult<Vec<_>>>()?;
+    let mut awarded = Uint128::zero();
+    for (index, (trader, amount)) in winners.iter().zip(competition.prizes.iter()).enumerate() {
+        COMPETITION_PRIZES.save(
+            deps.storage,
+            (competition_id, &trader.address),
+            &CompetitionPrize {
+                competition_id,
+                rank: index as u32 + 1,
+                address: trader.address.clone(),
+                volume_usdc: trader.volume_usdc,
+                amount: *amount,
+                claimed: false,
+            },
+        )?;
+        awarded += amount;
+    }
+    competition.finalized = true;
+    COMPETITIONS.save(deps.storage, competition_id, &competition)?;
+    OPEN_COMPETITIONS.remove(deps.storage, competition_id);
+
+    // Prizes without a winner go back to the admin
+    let contract_owner = CONFIG.load(deps.storage)?.contract_owner;
+    let refund = competition.prize_pool() - awarded;
+    Ok(
+        ResponseHandler::finalize_competition(competition, winners.len(), contract_owner, refund)
+            .response,
+    )
+}
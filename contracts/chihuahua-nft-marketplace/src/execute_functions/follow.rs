@@ -0,0 +1,72 @@
+use cosmwasm_std::{ensure, DepsMut, Env, MessageInfo, Response};
+
+use general_utils::error::ContractError;
+use general_utils::error::NftMarketplaceError::{
+    AlreadyFollowing, CantFollowYourself, NftCollectionNotListed, NotFollowing,
+    ThisProfileDoesNotExist,
+};
+use nft_marketplace_utils::follow::{Follow, FollowTarget};
+use nft_marketplace_utils::response_handler::ResponseHandler;
+use nft_marketplace_utils::tokenfactory::TokenFactoryMsg;
+
+use crate::state::{FOLLOWERS, FOLLOWING, LISTED_NFT_COLLECTIONS_INFO_AND_USDC_VOL, PROFILES};
+
+pub fn execute_follow(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    target: FollowTarget,
+    is_follow: bool,
+) -> Result<Response<TokenFactoryMsg>, ContractError> {
+    let target_key = target.key();
+    let follow = if is_follow {
+        ensure!(
+            !FOLLOWING.has(deps.storage, (info.sender.as_ref(), &target_key)),
+            ContractError::NftMarketplaceError(AlreadyFollowing {})
+        );
+        // Only existing profiles and listed collections can be followed
+        match &target {
+            FollowTarget::Profile { address } => {
+                let address = deps.api.addr_validate(address)?;
+                ensure!(
+                    address != info.sender,
+                    ContractError::NftMarketplaceError(CantFollowYourself {})
+                );
+                ensure!(
+                    PROFILES.has(deps.storage, address.as_ref()),
+                    ContractError::NftMarketplaceError(ThisProfileDoesNotExist {})
+                );
+            }
+            FollowTarget::Collection {
+                nft_collection_address,
+            } => ensure!(
+                LISTED_NFT_COLLECTIONS_INFO_AND_USDC_VOL.has(
+                    deps.storage,
+                    deps.api.addr_validate(nft_collection_address)?.as_ref()
+                ),
+                ContractError::NftMarketplaceError(NftCollectionNotListed {})
+            ),
+        }
+        let follow = Follow {
+            follower: info.sender.to_string(),
+            target,
+            followed_at: env.block.time,
+        };
+        FOLLOWING.save(deps.storage, (info.sender.as_ref(), &target_key), &follow)?;
+        FOLLOWERS.save(
+            deps.storage,
+            (&target_key, info.sender.as_ref()),
+            &env.block.time,
+        )?;
+        follow
+    } else {
+        let follow = FOLLOWING
+            .may_load(deps.storage, (info.sender.as_ref(), &target_key))?
+            .ok_or(ContractError::NftMarketplaceError(NotFollowing {}))?;
+        FOLLOWING.remove(deps.storage, (info.sender.as_ref(), &target_key));
+        FOLLOWERS.remove(deps.storage, (&target_key, info.sender.as_ref()));
+        follow
+    };
+
+    Ok(ResponseHandler::follow(follow, is_follow).response)
+}
@@ -0,0 +1,41 @@
+use cosmwasm_std::{ensure, DepsMut, Env, MessageInfo, Response};
+
+use general_utils::error::ContractError;
+use general_utils::error::GenericError::{InvalidDenominationReceived, InvalidFundsReceived};
+use general_utils::error::NftMarketplaceError::RewardMintingAlreadyEnabled;
+use nft_marketplace_utils::response_handler::ResponseHandler;
+use nft_marketplace_utils::tokenfactory::TokenFactoryMsg;
+
+use crate::state::{REWARD_MINTING, REWARD_POOL, REWARD_SYSTEM};
+
+pub fn execute_fund_reward_pool(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+) -> Result<Response<TokenFactoryMsg>, ContractError> {
+    let reward_system = REWARD_SYSTEM.load(deps.storage)?;
+    // Minted rewards don't draw on the pool
+    ensure!(
+        REWARD_MINTING.may_load(deps.storage)?.is_none(),
+        ContractError::NftMarketplaceError(RewardMintingAlreadyEnabled {})
+    );
+    ensure!(
+        info.funds.len() == 1 && !info.funds[0].amount.is_zero(),
+        ContractError::Generic(InvalidFundsReceived {})
+    );
+    ensure!(
+        info.funds[0].denom == reward_system.reward_token_address,
+        ContractError::Generic(InvalidDenominationReceived {})
+    );
+
+    let mut reward_pool = REWARD_POOL.may_load(deps.storage)?.unwrap_or_default();
+    reward_pool.balance += info.funds[0].amount;
+    REWARD_POOL.save(deps.storage, &reward_pool)?;
+
+    Ok(ResponseHandler::fund_reward_pool(
+        info.sender.to_string(),
+        info.funds[0].amount,
+        reward_pool,
+    )
+    .response)
+}
@@ -10,21 +10,25 @@ use nft_marketplace_utils::marketplace_statistics::{GeneralStats, MarketplaceSta
 use nft_marketplace_utils::nft_collection::{
     NftCollectionInfoAndUsdcVol, NftContractInfo, NftContractType,
 };
+use nft_marketplace_utils::partner_perks::PartnerCollection;
 use nft_marketplace_utils::response_handler::ResponseHandler;
 use nft_marketplace_utils::reward_system::RewardSystem;
+use nft_marketplace_utils::tokenfactory::TokenFactoryMsg;
 
-use crate::constants::MARKETPLACE_USDC_INDICATOR;
+use crate::constants::{
+    MARKETPLACE_USDC_INDICATOR, SANCTUARY_NFTS, SANCTUARY_NFTS_FEES_DISCOUNT_PERCENT,
+};
 use crate::msg::InstantiateMsg;
 use crate::state::{
     CONFIG, GENERAL_STATS, LISTED_NFT_COLLECTIONS_INFO_AND_USDC_VOL, MARKETPLACE_STATS_BY_DENOM,
-    REWARD_SYSTEM,
+    PARTNER_COLLECTIONS, REWARD_SYSTEM,
 };
 
 pub fn instantiate_contract(
     deps: DepsMut,
     _info: MessageInfo,
     init_msg: InstantiateMsg,
-) -> Result<Response, ContractError> {
+) -> Result<Response<TokenFactoryMsg>, ContractError> {
     // Check if the given price oracle accepts the listing fee denom
     deps.querier
         .query::<Uint128>(&QueryRequest::Wasm(WasmQuery::Smart {
@@ -57,7 +61,8 @@ pub fn instantiate_contract(
             init_msg.reward_system.reward_token_address,
             init_msg.reward_system.reward_token_per_1usdc_volume,
             init_msg.reward_system.total_reward_tokens_distributed,
-            init_msg.reward_system.vip_perks,
+            init_msg.reward_system.vip_tiers,
+            init_msg.reward_system.vesting,
         )?,
     )?;
 
@@ -89,5 +94,15 @@ pub fn instantiate_contract(
 
     GENERAL_STATS.save(deps.storage, &GeneralStats::new())?;
 
+    PARTNER_COLLECTIONS.save(
+        deps.storage,
+        SANCTUARY_NFTS,
+        &PartnerCollection {
+            nft_collection_address: SANCTUARY_NFTS.to_string(),
+            marketplace_fees_discount: Decimal::percent(SANCTUARY_NFTS_FEES_DISCOUNT_PERCENT),
+            vip_level: None,
+        },
+    )?;
+
     Ok(ResponseHandler::init_response().response)
 }
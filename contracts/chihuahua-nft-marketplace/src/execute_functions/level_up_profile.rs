@@ -6,6 +6,7 @@ use general_utils::validations::validate_address;
 use nft_marketplace_utils::profile::Profile;
 use nft_marketplace_utils::response_handler::ResponseHandler;
 use nft_marketplace_utils::reward_system::{VipLevel};
+use nft_marketplace_utils::tokenfactory::TokenFactoryMsg;
 
 use crate::state::{PROFILES, REWARD_SYSTEM};
 
@@ -13,7 +14,7 @@ pub fn execute_level_up_profile(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
-) -> Result<Response, ContractError> {
+) -> Result<Response<TokenFactoryMsg>, ContractError> {
     let reward_system = REWARD_SYSTEM.load(deps.storage)?;
 
     ensure!(info.funds.len() == 1, ContractError::Generic(InvalidFundsReceived {}));
@@ -24,19 +25,29 @@ pub fn execute_level_up_profile(
         info.sender.to_string(),
     )?;
 
-    let mut loaded_profile: Profile = PROFILES.load(deps.storage, info.sender.as_str())?;
-    let previous_level = loaded_profile.vip_level.clone();
+    // An expired subscription levels up from the level it fell back to
+    let mut loaded_profile: Profile = PROFILES
+        .load(deps.storage, info.sender.as_str())?
+        .expire_vip_level(&reward_system, env.block.time);
+    let previous_level = loaded_profile.vip_level.unwrap_or_default();
     if VipLevel::level_up_if_possible(
-        loaded_profile.vip_level.clone().unwrap(),
-        reward_system.vip_perks,
+        previous_level,
+        &reward_system.vip_tiers,
         info.funds[0].amount,
     )? {
-        loaded_profile = loaded_profile.level_up();
+        loaded_profile = loaded_profile.level_up(&reward_system, env.block.time);
         PROFILES.save(deps.storage, info.sender.as_str(), &loaded_profile)?;
     }
+    let current_level = loaded_profile.vip_level.unwrap_or_default();
+    let tier_name = reward_system
+        .vip_tier(current_level)
+        .map(|vip_tier| vip_tier.name.clone())
+        .unwrap_or_default();
     Ok(ResponseHandler::level_up_profile(
-        previous_level.unwrap(),
-        loaded_profile.vip_level.unwrap(),
+        previous_level,
+        current_level,
+        tier_name,
+        loaded_profile.vip_expiration,
     )?
     .response)
 }
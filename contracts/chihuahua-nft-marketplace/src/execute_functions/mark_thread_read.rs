@@ -0,0 +1,32 @@
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+
+use general_utils::error::ContractError;
+use general_utils::error::NftMarketplaceError::InboxThreadDoesNotExist;
+use nft_marketplace_utils::inbox::inbox_threads;
+use nft_marketplace_utils::response_handler::ResponseHandler;
+use nft_marketplace_utils::tokenfactory::TokenFactoryMsg;
+
+pub fn execute_mark_thread_read(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    counterparty: String,
+    up_to_message_id: Option<u64>,
+) -> Result<Response<TokenFactoryMsg>, ContractError> {
+    let thread_key = (info.sender.to_string(), counterparty);
+    let mut thread = inbox_threads()
+        .may_load(deps.storage, thread_key.clone())?
+        .ok_or(ContractError::NftMarketplaceError(
+            InboxThreadDoesNotExist {},
+        ))?;
+
+    // Read markers only move forward, up to the latest message of the thread
+    let last_read_message_id = up_to_message_id
+        .unwrap_or(thread.last_message_id)
+        .min(thread.last_message_id)
+        .max(thread.last_read_message_id.unwrap_or_default());
+    thread.last_read_message_id = Some(last_read_message_id);
+    inbox_threads().save(deps.storage, thread_key, &thread)?;
+
+    Ok(ResponseHandler::mark_thread_read(thread).response)
+}
@@ -1,17 +1,40 @@
 pub mod add_new_nft_collection;
 pub mod answer_offer;
+pub mod block_address;
 pub mod buy_nft;
+pub mod buy_username;
 pub mod cancel_nft_sale;
 pub mod cancel_offer;
+pub mod cancel_username_sale;
+pub mod claim_competition_prize;
 pub mod claim_marketplace_fees;
+pub mod claim_rewards;
+pub mod claim_staking_fees;
+pub mod create_competition;
 pub mod create_profile;
+pub mod delete_message;
+pub mod finalize_competition;
+pub mod follow;
+pub mod fund_reward_pool;
 pub mod instantiate;
 pub mod level_up_profile;
+pub mod mark_thread_read;
 pub mod offer;
 pub mod remove_expired_sales;
+pub mod renew_vip_level;
 pub mod sell_nft;
+pub mod sell_username;
 pub mod send_message;
+pub mod set_circuit_breaker;
+pub mod set_message_filter;
+pub mod stake;
+pub mod start_leaderboard_season;
 pub mod transfer_my_nft;
+pub mod transfer_username;
+pub mod unstake;
 pub mod update_config;
 pub mod update_nft_sale;
 pub mod update_profile;
+pub mod verify;
+pub mod watch_token;
+pub mod withdraw_unbonded;
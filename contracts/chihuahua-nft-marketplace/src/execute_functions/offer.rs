@@ -1,12 +1,18 @@
-use cosmwasm_std::{ensure, DepsMut, Env, MessageInfo, Response, WasmMsg};
+use std::marker::PhantomData;
+
+use cosmwasm_std::{ensure, DepsMut, Empty, Env, MessageInfo, Response, WasmMsg};
+use cw721_base::helpers::Cw721Contract;
 
 use general_utils::error::ContractError;
 use general_utils::error::NftMarketplaceError::{NftCollectionNotListed, OfferAlreadyExists};
+use nft_marketplace_utils::activity::{Activity, ActivityType};
 use nft_marketplace_utils::config::Config;
 use nft_marketplace_utils::nft_offer::{define_unique_offer, nft_offers, NftOffer};
 use nft_marketplace_utils::response_handler::ResponseHandler;
+use nft_marketplace_utils::tokenfactory::TokenFactoryMsg;
 
 use crate::constants::{MAX_EXPIRATION_SECONDS, MAX_PRICE, MIN_EXPIRATION_SECONDS, MIN_PRICE};
+use crate::helpers::activity::record_activity;
 use crate::msg::ExecuteMsg;
 use crate::state::{CONFIG, LISTED_NFT_COLLECTIONS_INFO_AND_USDC_VOL, PROFILES};
 
@@ -15,7 +21,7 @@ pub fn execute_offer(
     env: Env,
     info: MessageInfo,
     mut offer: NftOffer,
-) -> Result<Response, ContractError> {
+) -> Result<Response<TokenFactoryMsg>, ContractError> {
     let config: Config = CONFIG.load(deps.storage)?;
 
     // Validate addresses
@@ -67,5 +73,29 @@ pub fn execute_offer(
     // Update: Save the offer if offer is valid
     nft_offers().save(deps.storage, unique_offer_id, &nft_offer_validated)?;
 
+    // Activity log: the current owner receives the offer
+    let owner_response = Cw721Contract::<Empty, Empty>(
+        deps.api
+            .addr_validate(&nft_offer_validated.nft_collection_address)?,
+        PhantomData,
+        PhantomData,
+    )
+    .owner_of(&deps.querier, nft_offer_validated.token_id.clone(), false)?;
+    record_activity(
+        deps.storage,
+        Activity::new(
+            ActivityType::Offer,
+            nft_offer_validated.nft_collection_address.clone(),
+            nft_offer_validated.token_id.clone(),
+            nft_offer_validated.offerer_address.clone(),
+            env.block.time,
+        )
+        .counterparty(owner_response.owner)
+        .price(
+            nft_offer_validated.offer_price_value,
+            nft_offer_validated.offer_price_denom.clone(),
+        ),
+    )?;
+
     Ok(ResponseHandler::nft_offer_response(nft_offer_validated, create_profile_msg).response)
 }
@@ -2,13 +2,14 @@ use cosmwasm_std::{DepsMut, Env, MessageInfo, Order, Response, StdResult};
 
 use crate::state::CONFIG;
 use general_utils::error::ContractError;
-use nft_marketplace_utils::nft_sale::{nfts_for_sale, NftSale};
+use nft_marketplace_utils::nft_sale::{nfts_for_sale, remove_nfts_for_sale, NftSale};
+use nft_marketplace_utils::tokenfactory::TokenFactoryMsg;
 
 pub fn remove_expired_sales_function(
     deps: DepsMut,
     env: Env,
     _info: MessageInfo,
-) -> Result<Response, ContractError> {
+) -> Result<Response<TokenFactoryMsg>, ContractError> {
     let config = CONFIG.load(deps.storage)?;
 
     for denom in config.accepted_ibc_denominations.list_of_denoms.iter() {
@@ -21,7 +22,7 @@ pub fn remove_expired_sales_function(
             .collect::<StdResult<Vec<_>>>()?;
         for (unique_id, nft_sale) in all_nfts_for_sale.iter() {
             if nft_sale.sale_expiration < env.block.time {
-                nfts_for_sale().remove(deps.storage, unique_id.clone())?;
+                remove_nfts_for_sale(deps.storage, unique_id.clone())?;
             }
         }
     }
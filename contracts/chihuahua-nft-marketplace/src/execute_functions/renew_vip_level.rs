@@ -0,0 +1,46 @@
+use cosmwasm_std::{coins, ensure, DepsMut, Env, MessageInfo, Response};
+
+use general_utils::error::ContractError;
+use general_utils::error::GenericError::InvalidFundsReceived;
+use general_utils::error::NftMarketplaceError::VipLevelNotRenewable;
+use nft_marketplace_utils::response_handler::ResponseHandler;
+use nft_marketplace_utils::tokenfactory::TokenFactoryMsg;
+
+use crate::state::{PROFILES, REWARD_SYSTEM};
+
+pub fn execute_renew_vip_level(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response<TokenFactoryMsg>, ContractError> {
+    let reward_system = REWARD_SYSTEM.load(deps.storage)?;
+    // An expired level cannot be renewed, the profile levels up again from its fallback level
+    let profile = PROFILES
+        .load(deps.storage, info.sender.as_str())?
+        .expire_vip_level(&reward_system, env.block.time);
+    let vip_level = profile.vip_level.unwrap_or_default();
+    let vip_tier = reward_system
+        .vip_tier(vip_level)
+        .ok_or(ContractError::NftMarketplaceError(VipLevelNotRenewable {}))?;
+    let subscription_duration = vip_tier
+        .subscription_duration
+        .ok_or(ContractError::NftMarketplaceError(VipLevelNotRenewable {}))?;
+    ensure!(
+        info.funds
+            == coins(
+                vip_tier.level_up_price_in_reward_tokens.u128(),
+                reward_system.reward_token_address.clone(),
+            ),
+        ContractError::Generic(InvalidFundsReceived {})
+    );
+
+    let profile = profile.renew_vip_level(subscription_duration, env.block.time);
+    PROFILES.save(deps.storage, info.sender.as_str(), &profile)?;
+    Ok(ResponseHandler::renew_vip_level(
+        info.sender.to_string(),
+        vip_level,
+        vip_tier.name.clone(),
+        profile.vip_expiration.unwrap_or(env.block.time),
+    )
+    .response)
+}
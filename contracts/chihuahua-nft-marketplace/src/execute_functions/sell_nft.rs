@@ -2,13 +2,18 @@ use cosmwasm_std::{ensure, DepsMut, Env, MessageInfo, Response, WasmMsg};
 
 use general_utils::error::ContractError;
 use general_utils::error::NftMarketplaceError::{NftCollectionNotListed, SaleAlreadyExists};
+use nft_marketplace_utils::activity::{Activity, ActivityType};
 use nft_marketplace_utils::nft_collection::{
     define_unique_collection_by_denom_id, nft_collection_denoms, NftCollectionInfoByDenom,
 };
-use nft_marketplace_utils::nft_sale::{define_unique_collection_nft_id, nfts_for_sale, NftSale};
+use nft_marketplace_utils::nft_sale::{
+    define_unique_collection_nft_id, nfts_for_sale, save_nfts_for_sale, NftSale,
+};
 use nft_marketplace_utils::response_handler::ResponseHandler;
+use nft_marketplace_utils::tokenfactory::TokenFactoryMsg;
 
 use crate::constants::{MAX_EXPIRATION_SECONDS, MAX_PRICE, MIN_EXPIRATION_SECONDS, MIN_PRICE};
+use crate::helpers::activity::record_activity;
 use crate::msg::ExecuteMsg;
 use crate::state::{
     CONFIG, LISTED_NFT_COLLECTIONS_INFO_AND_USDC_VOL, MARKETPLACE_STATS_BY_DENOM, PROFILES,
@@ -19,7 +24,7 @@ pub fn execute_sell_nft(
     env: Env,
     mut info: MessageInfo,
     mut sale_info: NftSale,
-) -> Result<Response, ContractError> {
+) -> Result<Response<TokenFactoryMsg>, ContractError> {
     let config = CONFIG.load(deps.storage)?;
 
     // Input validation
@@ -48,7 +53,8 @@ pub fn execute_sell_nft(
     // If sender is the contract it means:
     // A. It is for updating a sale or
     // B. We create a sale for an "accept offer"
-    if info.sender == env.contract.address {
+    let is_internal_sale = info.sender == env.contract.address;
+    if is_internal_sale {
         info.sender = deps.api.addr_validate(&sale_info.seller)?;
     } else {
         // Means we can add the marketplace listing fees (so we dont duplicate when its the contract)
@@ -90,7 +96,7 @@ pub fn execute_sell_nft(
         &nft_for_sale_validated.nft_collection_address,
         &nft_for_sale_validated.token_id,
     );
-    if nfts_for_sale().has(deps.storage, collection_token_id_unique.clone()) {
+    if nfts_for_sale().has(deps.storage, collection_token_id_unique) {
         return Err(ContractError::NftMarketplaceError(SaleAlreadyExists {}));
     }
 
@@ -122,11 +128,25 @@ pub fn execute_sell_nft(
     )?;
 
     // Contract states update: Add the Sale info to all the other sales
-    nfts_for_sale().save(
-        deps.storage,
-        collection_token_id_unique,
-        &nft_for_sale_validated,
-    )?;
+    save_nfts_for_sale(deps.storage, &nft_for_sale_validated, env.block.time)?;
+
+    // Activity log: updates and accepted offers are logged by their own entry points
+    if !is_internal_sale {
+        record_activity(
+            deps.storage,
+            Activity::new(
+                ActivityType::List,
+                nft_for_sale_validated.nft_collection_address.clone(),
+                nft_for_sale_validated.token_id.clone(),
+                nft_for_sale_validated.seller.clone(),
+                env.block.time,
+            )
+            .price(
+                nft_for_sale_validated.sale_price_value,
+                nft_for_sale_validated.sale_price_denom.clone(),
+            ),
+        )?;
+    }
 
     Ok(
         ResponseHandler::register_nft_sale_response(nft_for_sale_validated, create_profile_msg)
@@ -0,0 +1,45 @@
+use cosmwasm_std::{ensure, DepsMut, Env, MessageInfo, Response, Uint128};
+
+use general_utils::denominations::Denomination;
+use general_utils::error::ContractError;
+use general_utils::error::NftMarketplaceError::{InvalidPriceForTheSale, YouDontHaveAUsername};
+use nft_marketplace_utils::response_handler::ResponseHandler;
+use nft_marketplace_utils::tokenfactory::TokenFactoryMsg;
+use nft_marketplace_utils::username::{normalize_username, UsernameSale};
+
+use crate::constants::{MAX_PRICE, MIN_PRICE};
+use crate::state::{CONFIG, PROFILES, USERNAME_SALES};
+
+pub fn execute_sell_username(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    price_value: Uint128,
+    price_denom: Denomination,
+) -> Result<Response<TokenFactoryMsg>, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let username = PROFILES
+        .may_load(deps.storage, info.sender.as_ref())?
+        .and_then(|profile| profile.username)
+        .ok_or(ContractError::NftMarketplaceError(YouDontHaveAUsername {}))?;
+
+    config
+        .accepted_ibc_denominations
+        .check_if_denom_is_accepted(&price_denom)?;
+    ensure!(
+        price_value >= MIN_PRICE && price_value <= MAX_PRICE,
+        ContractError::NftMarketplaceError(InvalidPriceForTheSale {})
+    );
+
+    // Listing the username again updates the price
+    let username_sale = UsernameSale {
+        username: username.clone(),
+        seller: info.sender.to_string(),
+        price_value,
+        price_denom,
+        listed_at: env.block.time,
+    };
+    USERNAME_SALES.save(deps.storage, &normalize_username(&username), &username_sale)?;
+
+    Ok(ResponseHandler::sell_username(username_sale).response)
+}
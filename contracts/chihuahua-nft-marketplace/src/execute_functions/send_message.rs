@@ -1,57 +1,138 @@
-use cosmwasm_std::{ensure, Addr, DepsMut, Env, MessageInfo, Response};
+use cosmwasm_std::{coins, ensure, Addr, BankMsg, DepsMut, Env, MessageInfo, Response};
 
 use general_utils::error::ContractError;
-use general_utils::error::NftMarketplaceError::{InvalidMessage, ReceiverDoesNotExist};
+use general_utils::error::NftMarketplaceError::{
+    InvalidMessage, InvalidMessageFee, MessageRateLimitReached, ReceiverDoesNotExist,
+    RecipientDoesNotAcceptYourMessages, SenderIsBlocked, ThisProfileDoesNotExist,
+};
+use nft_marketplace_utils::messaging::{MessageFeeRecipient, MessageRateLimit};
 use nft_marketplace_utils::profile::Profile;
 use nft_marketplace_utils::response_handler::ResponseHandler;
+use nft_marketplace_utils::tokenfactory::TokenFactoryMsg;
 
-use crate::state::{PROFILES, USERNAMES};
+use crate::helpers::inbox::save_inbox_message;
+use crate::helpers::marketplace_stats::add_fees;
+use crate::helpers::username::resolve_username;
+use crate::state::{
+    BLOCKED_ADDRESSES, MESSAGE_FILTERS, MESSAGE_RATE_LIMITS, MESSAGING_CONFIG, PROFILES,
+    REWARD_SYSTEM,
+};
 
 pub fn execute_send_message(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     to: String,
     message: String,
-) -> Result<Response, ContractError> {
+) -> Result<Response<TokenFactoryMsg>, ContractError> {
     ensure!(
         message.is_ascii() && message.len() <= 120,
         ContractError::NftMarketplaceError(InvalidMessage {})
     );
 
-    let sender_username: Option<String> = if !PROFILES.has(deps.storage, info.sender.as_ref()) {
-        let profile = Profile::new(info.sender.to_string());
-        PROFILES.save(deps.storage, info.sender.as_ref(), &profile)?;
-        None
-    } else {
-        let profile = PROFILES.load(deps.storage, info.sender.as_ref())?;
-        profile.username
-    };
+    let reward_system = REWARD_SYSTEM.load(deps.storage)?;
+    let sender_profile: Profile = PROFILES
+        .may_load(deps.storage, info.sender.as_ref())?
+        .ok_or(ContractError::NftMarketplaceError(
+            ThisProfileDoesNotExist {},
+        ))?
+        .expire_vip_level(&reward_system, env.block.time);
+    let sender_username: Option<String> = sender_profile.username.clone();
 
     // To can be a username or an address
     let mut receiver_username = Some("Does not exist".to_string());
     let mut validated_receiver = deps.api.addr_validate(&to);
     if validated_receiver.is_err() {
         // Error could mean its a username - need to check -> if nothing -> profile does not exist
-        if !USERNAMES.has(deps.storage, &to) {
-            return Err(ContractError::NftMarketplaceError(ReceiverDoesNotExist {}));
-        } else {
-            validated_receiver = Ok(Addr::unchecked(USERNAMES.load(deps.storage, &to)?));
-            receiver_username = Some(to);
+        match resolve_username(deps.storage, &to)? {
+            None => return Err(ContractError::NftMarketplaceError(ReceiverDoesNotExist {})),
+            Some(receiver) => {
+                validated_receiver = Ok(Addr::unchecked(receiver));
+                receiver_username = Some(to);
+            }
         }
     }
-    // If reached here, receiver exists -> need to check if the sender exists and create it
+    // If reached here, receiver exists
     let receiver = validated_receiver?.to_string();
-    let receiver_profile = PROFILES.load(deps.storage, &receiver)?;
-    let updated_receiver_profile =
-        receiver_profile.receive_message(info.sender.to_string(), sender_username.clone(), message);
-    PROFILES.save(deps.storage, &receiver, &updated_receiver_profile)?;
+    ensure!(
+        PROFILES.has(deps.storage, &receiver),
+        ContractError::NftMarketplaceError(ReceiverDoesNotExist {})
+    );
+    ensure!(
+        !BLOCKED_ADDRESSES.has(deps.storage, (&receiver, info.sender.as_ref())),
+        ContractError::NftMarketplaceError(SenderIsBlocked {})
+    );
+    if let Some(message_filter) = MESSAGE_FILTERS.may_load(deps.storage, &receiver)? {
+        ensure!(
+            message_filter.accepts(&sender_profile),
+            ContractError::NftMarketplaceError(RecipientDoesNotAcceptYourMessages {})
+        );
+    }
+
+    // Anti-spam: messages per block window depending on the VIP level, fee per message
+    let messaging_config = MESSAGING_CONFIG.may_load(deps.storage)?.unwrap_or_default();
+    if let Some(rate_limit) = messaging_config.rate_limit {
+        let window = env.block.height / rate_limit.window_blocks;
+        let mut sender_rate_limit = MESSAGE_RATE_LIMITS
+            .may_load(deps.storage, info.sender.as_ref())?
+            .filter(|sender_rate_limit| sender_rate_limit.window == window)
+            .unwrap_or(MessageRateLimit {
+                window,
+                messages_sent: 0,
+            });
+        let sender_vip_level = sender_profile.vip_level.unwrap_or_default();
+        ensure!(
+            sender_rate_limit.messages_sent
+                < rate_limit.max_messages_per_window(
+                    sender_vip_level,
+                    reward_system.vip_tier(sender_vip_level),
+                ),
+            ContractError::NftMarketplaceError(MessageRateLimitReached {})
+        );
+        sender_rate_limit.messages_sent += 1;
+        MESSAGE_RATE_LIMITS.save(deps.storage, info.sender.as_ref(), &sender_rate_limit)?;
+    }
+    let mut message_fee_msg: Option<BankMsg> = None;
+    match messaging_config.message_fee {
+        Some(message_fee) => {
+            ensure!(
+                info.funds == coins(message_fee.amount.u128(), message_fee.denom.clone()),
+                ContractError::NftMarketplaceError(InvalidMessageFee {})
+            );
+            match message_fee.paid_to {
+                MessageFeeRecipient::Recipient => {
+                    message_fee_msg = Some(BankMsg::Send {
+                        to_address: receiver.clone(),
+                        amount: info.funds.clone(),
+                    });
+                }
+                MessageFeeRecipient::Treasury => {
+                    add_fees(deps.storage, &message_fee.denom, message_fee.amount)?;
+                }
+            }
+        }
+        // Funds would be stuck in the contract
+        None => ensure!(
+            info.funds.is_empty(),
+            ContractError::NftMarketplaceError(InvalidMessageFee {})
+        ),
+    }
+
+    save_inbox_message(
+        deps.storage,
+        info.sender.as_ref(),
+        sender_username.clone(),
+        &receiver,
+        message,
+        env.block.time,
+    )?;
 
     Ok(ResponseHandler::send_message(
         info.sender.to_string(),
         sender_username,
         receiver,
         receiver_username,
+        message_fee_msg,
     )
     .response)
 }
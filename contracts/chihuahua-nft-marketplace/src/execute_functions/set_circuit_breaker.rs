@@ -0,0 +1,30 @@
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+
+use general_utils::error::ContractError;
+use nft_marketplace_utils::circuit_breaker::{CircuitBreaker, MarketplaceCapability};
+use nft_marketplace_utils::response_handler::ResponseHandler;
+use nft_marketplace_utils::tokenfactory::TokenFactoryMsg;
+
+use crate::state::CIRCUIT_BREAKERS;
+
+pub fn execute_set_circuit_breaker(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    capability: MarketplaceCapability,
+    paused: bool,
+    reason: String,
+) -> Result<Response<TokenFactoryMsg>, ContractError> {
+    let circuit_breaker = CircuitBreaker::new_checked(
+        capability.clone(),
+        paused,
+        reason,
+        info.sender.to_string(),
+        env.block.time,
+    )?;
+
+    // States Update: the last reason is kept so it can be queried
+    CIRCUIT_BREAKERS.save(deps.storage, &capability.to_string(), &circuit_breaker)?;
+
+    Ok(ResponseHandler::set_circuit_breaker(circuit_breaker).response)
+}
@@ -0,0 +1,29 @@
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+
+use general_utils::error::ContractError;
+use nft_marketplace_utils::messaging::MessageFilter;
+use nft_marketplace_utils::response_handler::ResponseHandler;
+use nft_marketplace_utils::tokenfactory::TokenFactoryMsg;
+
+use crate::state::MESSAGE_FILTERS;
+
+pub fn execute_set_message_filter(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    message_filter: Option<MessageFilter>,
+) -> Result<Response<TokenFactoryMsg>, ContractError> {
+    // No filter accepts messages from everyone
+    match message_filter.clone() {
+        Some(message_filter) => {
+            MESSAGE_FILTERS.save(deps.storage, info.sender.as_ref(), &message_filter)?
+        }
+        None => MESSAGE_FILTERS.remove(deps.storage, info.sender.as_ref()),
+    }
+
+    Ok(ResponseHandler::set_message_filter(
+        info.sender.to_string(),
+        message_filter.unwrap_or_default(),
+    )
+    .response)
+}
@@ -0,0 +1,44 @@
+use cosmwasm_std::{ensure, DepsMut, Env, MessageInfo, Response, StdResult};
+
+use general_utils::error::ContractError;
+use general_utils::error::GenericError::{InvalidDenominationReceived, InvalidFundsReceived};
+use general_utils::error::NftMarketplaceError::StakingNotEnabled;
+use nft_marketplace_utils::response_handler::ResponseHandler;
+use nft_marketplace_utils::tokenfactory::TokenFactoryMsg;
+
+use crate::helpers::staking::settle_staker;
+use crate::state::{REWARD_SYSTEM, STAKES, STAKING_CONFIG, STAKING_STATS};
+
+pub fn execute_stake(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+) -> Result<Response<TokenFactoryMsg>, ContractError> {
+    ensure!(
+        STAKING_CONFIG.may_load(deps.storage)?.is_some(),
+        ContractError::NftMarketplaceError(StakingNotEnabled {})
+    );
+    let reward_system = REWARD_SYSTEM.load(deps.storage)?;
+    ensure!(
+        info.funds.len() == 1 && !info.funds[0].amount.is_zero(),
+        ContractError::Generic(InvalidFundsReceived {})
+    );
+    ensure!(
+        info.funds[0].denom == reward_system.reward_token_address,
+        ContractError::Generic(InvalidDenominationReceived {})
+    );
+    let amount = info.funds[0].amount;
+
+    // The fees collected so far are shared with the previous stake only
+    settle_staker(deps.storage, info.sender.as_ref())?;
+    let staked = STAKES.update(
+        deps.storage,
+        info.sender.as_ref(),
+        |staked| -> StdResult<_> { Ok(staked.unwrap_or_default() + amount) },
+    )?;
+    let mut staking_stats = STAKING_STATS.may_load(deps.storage)?.unwrap_or_default();
+    staking_stats.total_staked += amount;
+    STAKING_STATS.save(deps.storage, &staking_stats)?;
+
+    Ok(ResponseHandler::stake(info.sender.to_string(), amount, staked).response)
+}
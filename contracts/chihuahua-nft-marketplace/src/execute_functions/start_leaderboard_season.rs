@@ -0,0 +1,25 @@
+use cosmwasm_std::{DepsMut, Env, Response};
+
+use general_utils::error::ContractError;
+use nft_marketplace_utils::leaderboard::LeaderboardSeason;
+use nft_marketplace_utils::response_handler::ResponseHandler;
+use nft_marketplace_utils::tokenfactory::TokenFactoryMsg;
+
+use crate::state::LEADERBOARD_SEASON;
+
+pub fn execute_start_leaderboard_season(
+    deps: DepsMut,
+    env: Env,
+) -> Result<Response<TokenFactoryMsg>, ContractError> {
+    let current_season = LEADERBOARD_SEASON
+        .may_load(deps.storage)?
+        .unwrap_or_default();
+    // Previous seasons are kept and the new one starts empty
+    let new_season = LeaderboardSeason {
+        season_id: current_season.season_id + 1,
+        start_time: Some(env.block.time),
+    };
+    LEADERBOARD_SEASON.save(deps.storage, &new_season)?;
+
+    Ok(ResponseHandler::start_leaderboard_season(new_season).response)
+}
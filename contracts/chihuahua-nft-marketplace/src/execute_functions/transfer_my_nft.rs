@@ -4,13 +4,16 @@ use general_utils::error::ContractError;
 use general_utils::error::NftMarketplaceError::{
     CantCancelASaleYouDontOwn, NftCollectionNotListed,
 };
+use nft_marketplace_utils::activity::{Activity, ActivityType};
 use nft_marketplace_utils::nft_collection::{NftCollectionAddress, TokenId};
 use nft_marketplace_utils::nft_sale::{
     check_if_sender_is_owner_token_id_exists_and_can_transfer, define_unique_collection_nft_id,
     nfts_for_sale,
 };
 use nft_marketplace_utils::response_handler::ResponseHandler;
+use nft_marketplace_utils::tokenfactory::TokenFactoryMsg;
 
+use crate::helpers::activity::record_activity;
 use crate::msg::ExecuteMsg;
 use crate::state::{LISTED_NFT_COLLECTIONS_INFO_AND_USDC_VOL, PROFILES};
 
@@ -21,7 +24,7 @@ pub fn execute_transfer_my_nft(
     nft_collection_address: NftCollectionAddress,
     token_id: TokenId,
     mut recipient: String,
-) -> Result<Response, ContractError> {
+) -> Result<Response<TokenFactoryMsg>, ContractError> {
     recipient = deps.api.addr_validate(&recipient)?.to_string();
 
     let mut create_profile_msg: Option<WasmMsg> = None;
@@ -85,6 +88,18 @@ pub fn execute_transfer_my_nft(
         )?;
     }
 
+    record_activity(
+        deps.storage,
+        Activity::new(
+            ActivityType::Transfer,
+            nft_collection_address.clone(),
+            token_id.clone(),
+            info.sender.to_string(),
+            env.block.time,
+        )
+        .counterparty(recipient.clone()),
+    )?;
+
     Ok(ResponseHandler::transfer_my_nft(
         token_id,
         nft_collection_address,
@@ -0,0 +1,37 @@
+use cosmwasm_std::{ensure, DepsMut, Env, MessageInfo, Response};
+
+use general_utils::error::ContractError;
+use general_utils::error::NftMarketplaceError::CantTransferUsernameToYourself;
+use nft_marketplace_utils::response_handler::ResponseHandler;
+use nft_marketplace_utils::tokenfactory::TokenFactoryMsg;
+
+use crate::helpers::username::move_username;
+
+pub fn execute_transfer_username(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    recipient: String,
+) -> Result<Response<TokenFactoryMsg>, ContractError> {
+    let recipient = deps.api.addr_validate(&recipient)?;
+    ensure!(
+        recipient != info.sender,
+        ContractError::NftMarketplaceError(CantTransferUsernameToYourself {})
+    );
+
+    let username = move_username(
+        deps.storage,
+        env.block.time,
+        info.sender.as_ref(),
+        recipient.as_ref(),
+    )?;
+
+    Ok(
+        ResponseHandler::transfer_username(
+            username,
+            info.sender.to_string(),
+            recipient.to_string(),
+        )
+        .response,
+    )
+}
@@ -0,0 +1,64 @@
+use cosmwasm_std::{ensure, DepsMut, Env, MessageInfo, Response, Uint128};
+
+use general_utils::error::ContractError;
+use general_utils::error::NftMarketplaceError::{
+    InvalidUnstakeAmount, StakingNotEnabled, TooManyUnbondings,
+};
+use nft_marketplace_utils::response_handler::ResponseHandler;
+use nft_marketplace_utils::staking::{Unbonding, MAX_UNBONDINGS};
+use nft_marketplace_utils::tokenfactory::TokenFactoryMsg;
+
+use crate::helpers::staking::settle_staker;
+use crate::state::{STAKES, STAKING_CONFIG, STAKING_STATS, UNBONDINGS};
+
+pub fn execute_unstake(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    amount: Uint128,
+) -> Result<Response<TokenFactoryMsg>, ContractError> {
+    let staking_config = STAKING_CONFIG
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NftMarketplaceError(StakingNotEnabled {}))?;
+    let address = info.sender.to_string();
+    let staked = STAKES.may_load(deps.storage, &address)?.unwrap_or_default();
+    ensure!(
+        !amount.is_zero() && amount <= staked,
+        ContractError::NftMarketplaceError(InvalidUnstakeAmount {})
+    );
+    let mut unbondings = UNBONDINGS
+        .may_load(deps.storage, &address)?
+        .unwrap_or_default();
+    ensure!(
+        unbondings.len() < MAX_UNBONDINGS,
+        ContractError::NftMarketplaceError(TooManyUnbondings {})
+    );
+
+    // Unbonding tokens earn no fees
+    settle_staker(deps.storage, &address)?;
+    let staked = staked - amount;
+    if staked.is_zero() {
+        STAKES.remove(deps.storage, &address);
+    } else {
+        STAKES.save(deps.storage, &address, &staked)?;
+    }
+    let unbonding = Unbonding {
+        amount,
+        release_time: env
+            .block
+            .time
+            .plus_seconds(staking_config.unbonding_seconds),
+    };
+    unbondings.push(unbonding.clone());
+    UNBONDINGS.save(deps.storage, &address, &unbondings)?;
+    STAKING_STATS.update(
+        deps.storage,
+        |mut staking_stats| -> Result<_, ContractError> {
+            staking_stats.total_staked -= amount;
+            staking_stats.total_unbonding += amount;
+            Ok(staking_stats)
+        },
+    )?;
+
+    Ok(ResponseHandler::unstake(address, unbonding, staked).response)
+}
@@ -1,19 +1,36 @@
-use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+use cosmwasm_std::{
+    coins, ensure, BankMsg, CosmosMsg, DepsMut, Empty, Env, MessageInfo, Order, Response,
+};
 
 use general_utils::error::ContractError;
+use general_utils::error::NftMarketplaceError::{
+    InvalidRewardMinting, RewardMintingAlreadyEnabled, RewardMintingNotEnabled, RewardPoolNotEmpty,
+    RewardTokenStillStaked, RewardsStillToClaim, TooManyPartnerCollections,
+};
 use nft_marketplace_utils::marketplace_statistics::MarketplaceStatsByDenom;
+use nft_marketplace_utils::partner_perks::{PartnerCollection, MAX_PARTNER_COLLECTIONS};
 use nft_marketplace_utils::response_handler::ResponseHandler;
+use nft_marketplace_utils::reward_system::{validate_vip_tiers, RewardPool};
+use nft_marketplace_utils::tokenfactory::{RewardMinting, TokenFactoryMsg, TokenMsg};
+use nft_marketplace_utils::username::normalize_username;
 
+use crate::helpers::staking::distribute_staking_fees;
 use crate::msg::UpdateConfigEnum;
-use crate::state::{CONFIG, MARKETPLACE_STATS_BY_DENOM, REWARD_SYSTEM};
+use crate::state::{
+    CONFIG, MARKETPLACE_STATS_BY_DENOM, MESSAGING_CONFIG, MODERATORS, PARTNER_COLLECTIONS,
+    PERKS_COMBINATION, RESERVED_USERNAMES, REWARD_MINTING,
+    REWARD_POOL, REWARD_SYSTEM, STAKING_CONFIG, STAKING_STATS, USERNAME_RENAME_COOLDOWN,
+    WASH_TRADING_CONFIG,
+};
 
 pub fn execute_update_config(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     _info: MessageInfo,
     list_of_updates: Vec<UpdateConfigEnum>,
-) -> Result<Response, ContractError> {
+) -> Result<Response<TokenFactoryMsg>, ContractError> {
     let mut config = CONFIG.load(deps.storage)?;
+    let mut messages: Vec<CosmosMsg<TokenFactoryMsg>> = vec![];
     for update in list_of_updates {
         match update {
             UpdateConfigEnum::EnableDisable {} => {
@@ -36,8 +53,176 @@ pub fn execute_update_config(
                 config.contract_owner = deps.api.addr_validate(&address.to_string())?.to_string();
             }
             UpdateConfigEnum::UpdateRewardSystem { reward_system } => {
+                validate_vip_tiers(&reward_system.vip_tiers)?;
+                // A new schedule only applies to the next rewards
+                if let Some(vesting) = &reward_system.vesting {
+                    vesting.validate()?;
+                }
+                // Once minted, the reward token can't be swapped for another one
+                ensure!(
+                    REWARD_MINTING
+                        .may_load(deps.storage)?
+                        .map_or(true, |reward_minting| {
+                            reward_minting.denom == reward_system.reward_token_address
+                        }),
+                    ContractError::NftMarketplaceError(InvalidRewardMinting {})
+                );
+                if reward_system.reward_token_address
+                    != REWARD_SYSTEM.load(deps.storage)?.reward_token_address
+                {
+                    // Stakes, funded and credited rewards are paid in the reward token they were
+                    // made with
+                    let staking_stats = STAKING_STATS.may_load(deps.storage)?.unwrap_or_default();
+                    ensure!(
+                        staking_stats.total_staked.is_zero()
+                            && staking_stats.total_unbonding.is_zero(),
+                        ContractError::NftMarketplaceError(RewardTokenStillStaked {})
+                    );
+                    let reward_pool = REWARD_POOL.may_load(deps.storage)?.unwrap_or_default();
+                    ensure!(
+                        reward_pool.total_to_claim.is_zero(),
+                        ContractError::NftMarketplaceError(RewardsStillToClaim {})
+                    );
+                    ensure!(
+                        reward_pool.balance.is_zero(),
+                        ContractError::NftMarketplaceError(RewardPoolNotEmpty {})
+                    );
+                }
                 REWARD_SYSTEM.save(deps.storage, &reward_system)?;
             }
+            UpdateConfigEnum::UpdateMessagingConfig { messaging_config } => {
+                MESSAGING_CONFIG.save(
+                    deps.storage,
+                    &messaging_config.new_checked(&config.accepted_ibc_denominations)?,
+                )?;
+            }
+            UpdateConfigEnum::UpdateUsernameRenameCooldown { seconds } => {
+                USERNAME_RENAME_COOLDOWN.save(deps.storage, &seconds)?;
+            }
+            UpdateConfigEnum::AddReservedUsernames { usernames } => {
+                for username in usernames {
+                    RESERVED_USERNAMES.save(
+                        deps.storage,
+                        &normalize_username(&username),
+                        &Empty {},
+                    )?;
+                }
+            }
+            UpdateConfigEnum::RemoveReservedUsernames { usernames } => {
+                for username in usernames {
+                    RESERVED_USERNAMES.remove(deps.storage, &normalize_username(&username));
+                }
+            }
+            UpdateConfigEnum::AddModerators { addresses } => {
+                for address in addresses {
+                    MODERATORS.save(
+                        deps.storage,
+                        deps.api.addr_validate(&address)?.as_ref(),
+                        &Empty {},
+                    )?;
+                }
+            }
+            UpdateConfigEnum::RemoveModerators { addresses } => {
+                for address in addresses {
+                    MODERATORS.remove(deps.storage, &address);
+                }
+            }
+            UpdateConfigEnum::SetPartnerCollection { partner_collection } => {
+                let nft_collection_address = deps
+                    .api
+                    .addr_validate(&partner_collection.nft_collection_address)?
+                    .to_string();
+                partner_collection.validate(&REWARD_SYSTEM.load(deps.storage)?)?;
+                ensure!(
+                    PARTNER_COLLECTIONS.has(deps.storage, &nft_collection_address)
+                        || PARTNER_COLLECTIONS
+                            .keys(deps.storage, None, None, Order::Ascending)
+                            .count()
+                            < MAX_PARTNER_COLLECTIONS,
+                    ContractError::NftMarketplaceError(TooManyPartnerCollections {})
+                );
+                PARTNER_COLLECTIONS.save(
+                    deps.storage,
+                    &nft_collection_address,
+                    &PartnerCollection {
+                        nft_collection_address: nft_collection_address.clone(),
+                        ..partner_collection
+                    },
+                )?;
+            }
+            UpdateConfigEnum::RemovePartnerCollection {
+                nft_collection_address,
+            } => {
+                let nft_collection_address =
+                    deps.api.addr_validate(&nft_collection_address)?.to_string();
+                PARTNER_COLLECTIONS.remove(deps.storage, &nft_collection_address);
+            }
+            UpdateConfigEnum::UpdatePerksCombination { perks_combination } => {
+                perks_combination.validate()?;
+                PERKS_COMBINATION.save(deps.storage, &perks_combination)?;
+            }
+            UpdateConfigEnum::UpdateWashTradingConfig {
+                wash_trading_config,
+            } => {
+                WASH_TRADING_CONFIG.save(deps.storage, &wash_trading_config.new_checked()?)?;
+            }
+            UpdateConfigEnum::UpdateStakingConfig { staking_config } => {
+                // Fees collected so far are shared with the previous settings
+                distribute_staking_fees(deps.storage)?;
+                STAKING_CONFIG.save(deps.storage, &staking_config.new_checked()?)?;
+            }
+            UpdateConfigEnum::EnableRewardMinting {
+                subdenom,
+                emission_cap,
+            } => {
+                ensure!(
+                    REWARD_MINTING.may_load(deps.storage)?.is_none(),
+                    ContractError::NftMarketplaceError(RewardMintingAlreadyEnabled {})
+                );
+                // Stakes are paid back in the reward token they were made with
+                let staking_stats = STAKING_STATS.may_load(deps.storage)?.unwrap_or_default();
+                ensure!(
+                    staking_stats.total_staked.is_zero() && staking_stats.total_unbonding.is_zero(),
+                    ContractError::NftMarketplaceError(RewardTokenStillStaked {})
+                );
+                let reward_minting = RewardMinting::new_checked(
+                    env.contract.address.as_ref(),
+                    &subdenom,
+                    emission_cap,
+                )?;
+                let reward_pool = REWARD_POOL.may_load(deps.storage)?.unwrap_or_default();
+                // Rewards already credited are claimed in the reward token they were credited in
+                ensure!(
+                    reward_pool.total_to_claim.is_zero(),
+                    ContractError::NftMarketplaceError(RewardsStillToClaim {})
+                );
+                let mut reward_system = REWARD_SYSTEM.load(deps.storage)?;
+                // What is left of the pre-funded pool goes back to the owner
+                if !reward_pool.balance.is_zero() {
+                    messages.push(
+                        BankMsg::Send {
+                            to_address: config.contract_owner.clone(),
+                            amount: coins(
+                                reward_pool.balance.u128(),
+                                reward_system.reward_token_address,
+                            ),
+                        }
+                        .into(),
+                    );
+                }
+                messages.push(TokenMsg::CreateDenom { subdenom }.into());
+                reward_system.reward_token_address = reward_minting.denom.clone();
+                REWARD_SYSTEM.save(deps.storage, &reward_system)?;
+                REWARD_POOL.save(deps.storage, &RewardPool::default())?;
+                REWARD_MINTING.save(deps.storage, &reward_minting)?;
+            }
+            UpdateConfigEnum::UpdateRewardEmissionCap { emission_cap } => {
+                let mut reward_minting = REWARD_MINTING.may_load(deps.storage)?.ok_or(
+                    ContractError::NftMarketplaceError(RewardMintingNotEnabled {}),
+                )?;
+                reward_minting.update_emission_cap(emission_cap)?;
+                REWARD_MINTING.save(deps.storage, &reward_minting)?;
+            }
             UpdateConfigEnum::UpdateAcceptedNftContracts { contracts } => {
                 for nft_contract_info in contracts {
                     let contains_contract_info = config
@@ -58,5 +243,7 @@ pub fn execute_update_config(
 
     CONFIG.save(deps.storage, &config)?;
 
-    Ok(ResponseHandler::update_config().response)
+    Ok(ResponseHandler::update_config()
+        .response
+        .add_messages(messages))
 }
@@ -4,9 +4,12 @@ use cosmwasm_std::{DepsMut, Empty, Env, MessageInfo, Response};
 use cw721_base::helpers::Cw721Contract;
 
 use general_utils::error::ContractError;
+use nft_marketplace_utils::activity::{Activity, ActivityType};
 use nft_marketplace_utils::nft_sale::{define_unique_collection_nft_id, nfts_for_sale, NftSale};
 use nft_marketplace_utils::response_handler::ResponseHandler;
+use nft_marketplace_utils::tokenfactory::TokenFactoryMsg;
 
+use crate::helpers::activity::record_activity;
 use crate::msg::ExecuteMsg;
 use crate::state::CONFIG;
 
@@ -15,7 +18,7 @@ pub fn execute_update_nft_sale(
     env: Env,
     info: MessageInfo,
     mut sale_info: NftSale,
-) -> Result<Response, ContractError> {
+) -> Result<Response<TokenFactoryMsg>, ContractError> {
     sale_info.nft_collection_address = deps
         .api
         .addr_validate(&sale_info.nft_collection_address)?
@@ -42,6 +45,21 @@ pub fn execute_update_nft_sale(
         &owner_response.owner,
     )?;
 
+    record_activity(
+        deps.storage,
+        Activity::new(
+            ActivityType::UpdateListing,
+            sale_info.nft_collection_address.clone(),
+            sale_info.token_id.clone(),
+            nft_for_sale_info.seller.clone(),
+            env.block.time,
+        )
+        .price(
+            sale_info.sale_price_value,
+            sale_info.sale_price_denom.clone(),
+        ),
+    )?;
+
     // Updating a sale = Cancelling + Adding a new sale with waived fees
     let cancel_sale_msg = ExecuteMsg::wasm_execute_message_cancel_sale(
         nft_for_sale_info.nft_collection_address.clone(),
@@ -1,53 +1,63 @@
 use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
 
 use general_utils::error::ContractError;
-use general_utils::error::NftMarketplaceError::UsernameUnexpectedError;
+use nft_marketplace_utils::leaderboard::update_trader_visibility;
 use nft_marketplace_utils::profile::{Profile, ProfileUpdateAction};
 use nft_marketplace_utils::response_handler::ResponseHandler;
+use nft_marketplace_utils::tokenfactory::TokenFactoryMsg;
 
-use crate::state::{PROFILES, REWARD_SYSTEM, USERNAMES};
+use crate::helpers::partner_perks::holder_perks;
+use crate::helpers::username::{register_username, release_username};
+use crate::state::{PROFILES, REWARD_SYSTEM};
 
 pub fn execute_update_profile(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     new_profile: Profile,
     profile_update_action: ProfileUpdateAction,
-) -> Result<Response, ContractError> {
+) -> Result<Response<TokenFactoryMsg>, ContractError> {
     let sender_addr = info.sender.to_string();
     let reward_system = REWARD_SYSTEM.load(deps.storage)?;
     let current_profile = PROFILES.load(deps.storage, &sender_addr)?;
-
-    if new_profile.username.is_some() && profile_update_action == ProfileUpdateAction::Add {
-        // Means the user wants to change or add a username
-        if USERNAMES.has(deps.storage, &new_profile.username.clone().unwrap()) {
-            // Username is already taken -> cannot be changed so set to None
-            return Err(ContractError::NftMarketplaceError(UsernameUnexpectedError {}));
-        }
-        // Otherwise -> continue and can be changed below if it respects the username constraints
-    }
+    // Holding a partner collection can unlock the perks of a higher tier
+    let perks_vip_level =
+        holder_perks(deps.as_ref(), &reward_system, env.block.time, &sender_addr)?.perks_vip_level;
 
     let updated_profile = current_profile.clone().user_update_profile(
         deps.as_ref(),
         new_profile,
         reward_system,
+        perks_vip_level,
         profile_update_action,
+        env.block.time,
     )?;
 
-    // Now need to check if the profile already had a username or not. If yes, need to change it and remove the old one
+    // Keep the username registry in sync: register the new username or release the removed one
     if updated_profile.username != current_profile.username {
-        // Already checked if the current profile had a username and if the new username was taken
-        if updated_profile.username.is_some() {
-            if current_profile.username.is_some() {
-                USERNAMES.remove(deps.storage, &current_profile.username.unwrap());
-            }
-            USERNAMES.save(
+        match &updated_profile.username {
+            Some(username) => register_username(
                 deps.storage,
-                &updated_profile.username.clone().unwrap(),
+                env.block.time,
                 &sender_addr,
-            )?;
+                current_profile.username.as_deref(),
+                username,
+            )?,
+            None => {
+                if let Some(current_username) = &current_profile.username {
+                    release_username(deps.storage, current_username);
+                }
+            }
         }
     }
+    // Traders hiding their trade info are removed from the leaderboards
+    if updated_profile.display_trade_info != current_profile.display_trade_info {
+        update_trader_visibility(
+            deps.storage,
+            &sender_addr,
+            updated_profile.display_trade_info.unwrap_or(false),
+        )?;
+    }
     PROFILES.save(deps.storage, &sender_addr, &updated_profile)?;
     Ok(ResponseHandler::create_or_update_profile(updated_profile)?.response)
 }
@@ -0,0 +1,64 @@
+use cosmwasm_std::{ensure, DepsMut, Env, MessageInfo, Response};
+
+use general_utils::error::ContractError;
+use general_utils::error::NftMarketplaceError::{
+    NftCollectionNotListed, OnlyTheAdminOrAModeratorCanVerify, ThisProfileDoesNotExist,
+    VerificationDoesNotExist,
+};
+use nft_marketplace_utils::response_handler::ResponseHandler;
+use nft_marketplace_utils::tokenfactory::TokenFactoryMsg;
+use nft_marketplace_utils::verification::{Verification, VerificationKind, VerificationSubject};
+
+use crate::state::{
+    CONFIG, LISTED_NFT_COLLECTIONS_INFO_AND_USDC_VOL, MODERATORS, PROFILES, VERIFICATIONS,
+};
+
+pub fn execute_verify(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    subject: VerificationSubject,
+    kind: VerificationKind,
+    verify: bool,
+) -> Result<Response<TokenFactoryMsg>, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    ensure!(
+        info.sender == config.contract_owner || MODERATORS.has(deps.storage, info.sender.as_ref()),
+        ContractError::NftMarketplaceError(OnlyTheAdminOrAModeratorCanVerify {})
+    );
+
+    let subject_key = subject.key();
+    let kind_key = kind.to_string();
+    let verification = if verify {
+        // Only existing profiles and listed collections can be verified
+        match &subject {
+            VerificationSubject::Profile { address } => ensure!(
+                PROFILES.has(deps.storage, deps.api.addr_validate(address)?.as_ref()),
+                ContractError::NftMarketplaceError(ThisProfileDoesNotExist {})
+            ),
+            VerificationSubject::Collection {
+                nft_collection_address,
+            } => ensure!(
+                LISTED_NFT_COLLECTIONS_INFO_AND_USDC_VOL.has(
+                    deps.storage,
+                    deps.api.addr_validate(nft_collection_address)?.as_ref()
+                ),
+                ContractError::NftMarketplaceError(NftCollectionNotListed {})
+            ),
+        }
+        // Verifying again replaces the previous attestation, even a revoked one
+        Verification::new(subject, kind, info.sender.to_string(), env.block.time)
+    } else {
+        let mut verification = VERIFICATIONS
+            .may_load(deps.storage, (&subject_key, &kind_key))?
+            .filter(|verification| verification.is_active())
+            .ok_or(ContractError::NftMarketplaceError(
+                VerificationDoesNotExist {},
+            ))?;
+        verification.revoke(info.sender.to_string(), env.block.time);
+        verification
+    };
+    VERIFICATIONS.save(deps.storage, (&subject_key, &kind_key), &verification)?;
+
+    Ok(ResponseHandler::verification(verification, info.sender.to_string(), verify).response)
+}
@@ -0,0 +1,54 @@
+use cosmwasm_std::{ensure, DepsMut, Env, MessageInfo, Response};
+
+use general_utils::error::ContractError;
+use general_utils::error::NftMarketplaceError::{
+    NftCollectionNotListed, TokenAlreadyInWatchlist, TokenNotInWatchlist,
+};
+use nft_marketplace_utils::follow::WatchedToken;
+use nft_marketplace_utils::nft_collection::{NftCollectionAddress, TokenId};
+use nft_marketplace_utils::nft_sale::define_unique_collection_nft_id;
+use nft_marketplace_utils::response_handler::ResponseHandler;
+use nft_marketplace_utils::tokenfactory::TokenFactoryMsg;
+
+use crate::state::{LISTED_NFT_COLLECTIONS_INFO_AND_USDC_VOL, WATCHLISTS};
+
+pub fn execute_watch_token(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    mut nft_collection_address: NftCollectionAddress,
+    token_id: TokenId,
+    watch: bool,
+) -> Result<Response<TokenFactoryMsg>, ContractError> {
+    nft_collection_address = deps.api.addr_validate(&nft_collection_address)?.to_string();
+    // Same key as nfts_for_sale() to find the sale of a watched token
+    let collection_token_id_unique =
+        define_unique_collection_nft_id(&nft_collection_address, &token_id);
+    let key = (info.sender.as_ref(), collection_token_id_unique.as_str());
+
+    let watched_token = if watch {
+        ensure!(
+            !WATCHLISTS.has(deps.storage, key),
+            ContractError::NftMarketplaceError(TokenAlreadyInWatchlist {})
+        );
+        ensure!(
+            LISTED_NFT_COLLECTIONS_INFO_AND_USDC_VOL.has(deps.storage, &nft_collection_address),
+            ContractError::NftMarketplaceError(NftCollectionNotListed {})
+        );
+        let watched_token = WatchedToken {
+            nft_collection_address,
+            token_id,
+            added_at: env.block.time,
+        };
+        WATCHLISTS.save(deps.storage, key, &watched_token)?;
+        watched_token
+    } else {
+        let watched_token = WATCHLISTS
+            .may_load(deps.storage, key)?
+            .ok_or(ContractError::NftMarketplaceError(TokenNotInWatchlist {}))?;
+        WATCHLISTS.remove(deps.storage, key);
+        watched_token
+    };
+
+    Ok(ResponseHandler::watch_token(info.sender.to_string(), watched_token, watch).response)
+}
@@ -0,0 +1,45 @@
+use cosmwasm_std::{ensure, DepsMut, Env, MessageInfo, Response, Uint128};
+
+use general_utils::error::ContractError;
+use general_utils::error::NftMarketplaceError::NoUnbondedTokens;
+use nft_marketplace_utils::response_handler::ResponseHandler;
+use nft_marketplace_utils::staking::Unbonding;
+use nft_marketplace_utils::tokenfactory::TokenFactoryMsg;
+
+use crate::state::{REWARD_SYSTEM, STAKING_STATS, UNBONDINGS};
+
+pub fn execute_withdraw_unbonded(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response<TokenFactoryMsg>, ContractError> {
+    let reward_system = REWARD_SYSTEM.load(deps.storage)?;
+    let address = info.sender.to_string();
+    let (released, unbonding): (Vec<Unbonding>, Vec<Unbonding>) = UNBONDINGS
+        .may_load(deps.storage, &address)?
+        .unwrap_or_default()
+        .into_iter()
+        .partition(|unbonding| unbonding.release_time <= env.block.time);
+    let amount: Uint128 = released.iter().map(|unbonding| unbonding.amount).sum();
+    ensure!(
+        !amount.is_zero(),
+        ContractError::NftMarketplaceError(NoUnbondedTokens {})
+    );
+    if unbonding.is_empty() {
+        UNBONDINGS.remove(deps.storage, &address);
+    } else {
+        UNBONDINGS.save(deps.storage, &address, &unbonding)?;
+    }
+    STAKING_STATS.update(
+        deps.storage,
+        |mut staking_stats| -> Result<_, ContractError> {
+            staking_stats.total_unbonding -= amount;
+            Ok(staking_stats)
+        },
+    )?;
+
+    Ok(
+        ResponseHandler::withdraw_unbonded(address, amount, reward_system.reward_token_address)
+            .response,
+    )
+}
@@ -0,0 +1,28 @@
+use cosmwasm_std::{StdResult, Storage};
+
+use nft_marketplace_utils::activity::{activities, Activity, AddressActivityKind};
+
+use crate::state::{ADDRESS_ACTIVITIES, NEXT_ACTIVITY_ID};
+
+// Saves the activity with the next id and indexes it for both addresses
+pub fn record_activity(storage: &mut dyn Storage, mut activity: Activity) -> StdResult<u64> {
+    let activity_id = NEXT_ACTIVITY_ID.may_load(storage)?.unwrap_or_default();
+    activity.activity_id = activity_id;
+    ADDRESS_ACTIVITIES.save(
+        storage,
+        (&activity.from, activity_id),
+        &AddressActivityKind::new(&activity.activity_type, true),
+    )?;
+    if let Some(to) = activity.to.clone() {
+        if to != activity.from {
+            ADDRESS_ACTIVITIES.save(
+                storage,
+                (&to, activity_id),
+                &AddressActivityKind::new(&activity.activity_type, false),
+            )?;
+        }
+    }
+    activities().save(storage, activity_id, &activity)?;
+    NEXT_ACTIVITY_ID.save(storage, &(activity_id + 1))?;
+    Ok(activity_id)
+}
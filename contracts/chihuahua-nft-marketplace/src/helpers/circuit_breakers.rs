@@ -0,0 +1,27 @@
+use cosmwasm_std::{ensure, StdResult, Storage};
+
+use general_utils::error::ContractError;
+use general_utils::error::NftMarketplaceError::CapabilityPaused;
+use nft_marketplace_utils::circuit_breaker::{CircuitBreaker, MarketplaceCapability};
+
+use crate::state::CIRCUIT_BREAKERS;
+
+pub fn load_circuit_breaker(
+    storage: &dyn Storage,
+    capability: MarketplaceCapability,
+) -> StdResult<CircuitBreaker> {
+    Ok(CIRCUIT_BREAKERS
+        .may_load(storage, &capability.to_string())?
+        .unwrap_or_else(|| CircuitBreaker::new(capability)))
+}
+
+pub fn if_capability_enabled(
+    storage: &dyn Storage,
+    capability: MarketplaceCapability,
+) -> Result<(), ContractError> {
+    ensure!(
+        !load_circuit_breaker(storage, capability)?.paused,
+        ContractError::NftMarketplaceError(CapabilityPaused {})
+    );
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use cosmwasm_std::{Order, StdResult, Storage, Timestamp, Uint128};
+
+use nft_marketplace_utils::competition::{
+    competition_traders, CompetitionTrader, MAX_OPEN_COMPETITIONS,
+};
+
+use crate::state::{COMPETITIONS, OPEN_COMPETITIONS};
+
+// Adds the USDC value of the trade to each address in the running competitions of the collection
+pub fn record_trade_in_competitions(
+    storage: &mut dyn Storage,
+    time: Timestamp,
+    nft_collection_address: &str,
+    realised_sale_value_usdc: Uint128,
+    addresses: &[&str],
+) -> StdResult<()> {
+    let open_competition_ids = OPEN_COMPETITIONS
+        .keys(storage, None, None, Order::Ascending)
+        .take(MAX_OPEN_COMPETITIONS)
+        .collect::<StdResult<Vec<u64>>>()?;
+    for competition_id in open_competition_ids {
+        let competition = COMPETITIONS.load(storage, competition_id)?;
+        if !competition.is_running(time) || !competition.counts_collection(nft_collection_address) {
+            continue;
+        }
+        for address in addresses {
+            competition_traders().update(
+                storage,
+                (competition_id, address.to_string()),
+                |trader| -> StdResult<_> {
+                    let mut trader = trader.unwrap_or_else(|| CompetitionTrader {
+                        competition_id,
+                        address: address.to_string(),
+                        volume_usdc: Uint128::zero(),
+                        qualified_at: time,
+                    });
+                    trader.volume_usdc += realised_sale_value_usdc;
+                    trader.qualified_at = time;
+                    Ok(trader)
+                },
+            )?;
+        }
+    }
+    Ok(())
+}
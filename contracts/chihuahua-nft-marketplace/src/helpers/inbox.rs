@@ -0,0 +1,106 @@
+use cosmwasm_std::{Env, Order, StdResult, Storage, Timestamp};
+use cw_storage_plus::{Bound, Map};
+use serde::{Deserialize, Serialize};
+
+use nft_marketplace_utils::inbox::{inbox_messages, inbox_threads, InboxMessage, InboxThread};
+use nft_marketplace_utils::profile::{Profile, ProfileMessages};
+
+use crate::state::{NEXT_INBOX_MESSAGE_ID, PROFILES};
+
+// Profiles saved before 0.0.5 also held the messages received
+#[derive(Serialize, Deserialize)]
+pub struct LegacyProfile {
+    #[serde(flatten)]
+    pub profile: Profile,
+    pub profile_messages: Option<ProfileMessages>,
+}
+
+const LEGACY_PROFILES: Map<&str, LegacyProfile> = Map::new("profiles");
+
+// Saves the message and moves the thread of both participants on top
+pub fn save_inbox_message(
+    storage: &mut dyn Storage,
+    from_address: &str,
+    from_username: Option<String>,
+    to_address: &str,
+    message: String,
+    time: Timestamp,
+) -> StdResult<u64> {
+    let message_id = NEXT_INBOX_MESSAGE_ID.may_load(storage)?.unwrap_or_default();
+    inbox_messages().save(
+        storage,
+        message_id,
+        &InboxMessage {
+            message_id,
+            from_address: from_address.to_string(),
+            from_username,
+            to_address: to_address.to_string(),
+            message,
+            time,
+        },
+    )?;
+    NEXT_INBOX_MESSAGE_ID.save(storage, &(message_id + 1))?;
+    for (address, counterparty) in [(from_address, to_address), (to_address, from_address)] {
+        let is_sender = address == from_address;
+        inbox_threads().update(
+            storage,
+            (address.to_string(), counterparty.to_string()),
+            |thread| -> StdResult<_> {
+                let mut thread = thread.unwrap_or(InboxThread {
+                    address: address.to_string(),
+                    counterparty: counterparty.to_string(),
+                    last_message_id: message_id,
+                    last_message_time: time,
+                    last_read_message_id: None,
+                });
+                thread.last_message_id = message_id;
+                thread.last_message_time = time;
+                // Your own messages are read
+                if is_sender {
+                    thread.last_read_message_id = Some(message_id);
+                }
+                Ok(thread)
+            },
+        )?;
+    }
+    Ok(message_id)
+}
+
+// Migration: the messages received are moved from the profile to the inbox, unread and dated at
+// the migration. Returns the last key processed if the batch was full, None once every profile
+// has been visited
+pub fn migrate_profile_messages(
+    store: &mut dyn Storage,
+    env: &Env,
+    start_after: Option<String>,
+    limit: u32,
+) -> StdResult<Option<String>> {
+    let batch: Vec<(String, LegacyProfile)> = LEGACY_PROFILES
+        .range(
+            store,
+            start_after.as_deref().map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit as usize)
+        .collect::<StdResult<Vec<_>>>()?;
+    for (address, legacy_profile) in batch.iter() {
+        if let Some(profile_messages) = &legacy_profile.profile_messages {
+            for profile_message in profile_messages.messages.iter() {
+                save_inbox_message(
+                    store,
+                    &profile_message.from_address,
+                    profile_message.from_username.clone(),
+                    address,
+                    profile_message.message.clone(),
+                    env.block.time,
+                )?;
+            }
+        }
+        PROFILES.save(store, address, &legacy_profile.profile)?;
+    }
+    if batch.len() < limit as usize {
+        return Ok(None);
+    }
+    Ok(batch.last().map(|(address, _)| address.clone()))
+}
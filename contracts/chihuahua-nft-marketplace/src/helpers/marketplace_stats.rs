@@ -0,0 +1,16 @@
+use cosmwasm_std::{StdResult, Storage, Uint128};
+
+use general_utils::denominations::Denomination;
+use nft_marketplace_utils::marketplace_statistics::MarketplaceStatsByDenom;
+
+use crate::state::MARKETPLACE_STATS_BY_DENOM;
+
+// Fees kept by the marketplace outside of the NFT sales, claimable with the other fees
+pub fn add_fees(storage: &mut dyn Storage, denom: &Denomination, amount: Uint128) -> StdResult<()> {
+    MARKETPLACE_STATS_BY_DENOM.update(storage, denom, |mp_info| -> StdResult<_> {
+        let mut mp_info = mp_info.unwrap_or_else(|| MarketplaceStatsByDenom::new(denom.clone()));
+        mp_info.add_listing_fees(&amount);
+        Ok(mp_info)
+    })?;
+    Ok(())
+}
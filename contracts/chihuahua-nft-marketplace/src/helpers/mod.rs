@@ -1 +1,12 @@
+pub mod activity;
+pub mod circuit_breakers;
+pub mod competitions;
+pub mod inbox;
+pub mod marketplace_stats;
+pub mod partner_perks;
+pub mod rewards;
+pub mod rolling_stats;
 pub mod royalties;
+pub mod staking;
+pub mod username;
+pub mod vip_tiers;
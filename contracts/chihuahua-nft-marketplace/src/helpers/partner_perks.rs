@@ -0,0 +1,40 @@
+use cosmwasm_std::{Deps, Order, StdResult, Timestamp};
+
+use nft_marketplace_utils::helpers::holds_nft_of_collection;
+use nft_marketplace_utils::partner_perks::{HolderPerks, PartnerCollection};
+use nft_marketplace_utils::reward_system::RewardSystem;
+
+use crate::state::{PARTNER_COLLECTIONS, PERKS_COMBINATION, PROFILES};
+
+// Perks of the VIP level combined with the ones of the partner collections held by the address,
+// expired subscriptions fall back to the lower tier. Holdings are queried live so that a sold
+// partner token never keeps its perks
+pub fn holder_perks(
+    deps: Deps,
+    reward_system: &RewardSystem,
+    time: Timestamp,
+    address: &str,
+) -> StdResult<HolderPerks> {
+    let vip_level = PROFILES
+        .may_load(deps.storage, address)?
+        .and_then(|profile| profile.expire_vip_level(reward_system, time).vip_level)
+        .unwrap_or_default();
+    let held_partner_collections = PARTNER_COLLECTIONS
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, partner_collection)| partner_collection))
+        .collect::<StdResult<Vec<PartnerCollection>>>()?
+        .into_iter()
+        .filter(|partner_collection| {
+            holds_nft_of_collection(deps, address, &partner_collection.nft_collection_address)
+        })
+        .collect();
+    Ok(PERKS_COMBINATION
+        .may_load(deps.storage)?
+        .unwrap_or_default()
+        .combine(
+            reward_system,
+            address.to_string(),
+            vip_level,
+            held_partner_collections,
+        ))
+}
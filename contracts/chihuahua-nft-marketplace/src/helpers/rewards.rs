@@ -0,0 +1,121 @@
+use cosmwasm_std::{StdResult, Storage, Timestamp, Uint128};
+
+use nft_marketplace_utils::reward_system::RewardSystem;
+use nft_marketplace_utils::reward_vesting::{add_vesting_entry, VestingEntry};
+use nft_marketplace_utils::wash_trading::{WashTradingConfig, WithheldReward, WithholdingReason};
+
+use crate::state::{EPOCH_REWARDS, REWARDS_TO_CLAIM, REWARD_MINTING, REWARD_POOL, REWARD_VESTINGS};
+
+// Credits the reward tokens of a trade to each address, capped by what is left in the pool or
+// under the emission cap when the reward token is minted.
+// Rewards withheld by the wash trading rules are returned instead of being credited.
+pub fn accrue_trade_rewards(
+    storage: &mut dyn Storage,
+    time: Timestamp,
+    reward_system: &RewardSystem,
+    wash_trading_config: &WashTradingConfig,
+    withholding_reason: Option<WithholdingReason>,
+    realised_sale_value_usdc: Uint128,
+    addresses: &[&str],
+) -> StdResult<(Vec<Uint128>, Vec<WithheldReward>)> {
+    let reward_tokens_per_address = realised_sale_value_usdc
+        .checked_div(Uint128::new(1_000_000u128))?
+        .checked_mul(reward_system.reward_token_per_1usdc_volume)?;
+    let mut reward_pool = REWARD_POOL.may_load(storage)?.unwrap_or_default();
+    let mut reward_minting = REWARD_MINTING.may_load(storage)?;
+    let mut credited_rewards = vec![];
+    let mut withheld_rewards = vec![];
+    for address in addresses {
+        let mut reward_tokens = reward_tokens_per_address;
+        if let Some(reason) = &withholding_reason {
+            withheld_rewards.push(WithheldReward {
+                address: address.to_string(),
+                amount: reward_tokens,
+                reason: reason.clone(),
+            });
+            reward_tokens = Uint128::zero();
+        }
+        let mut epoch_rewards = None;
+        if let Some(epoch_reward_cap) = &wash_trading_config.epoch_reward_cap {
+            let current_epoch_rewards = EPOCH_REWARDS
+                .may_load(storage, address)?
+                .unwrap_or_default()
+                .current(epoch_reward_cap, time);
+            let allowed = reward_tokens.min(current_epoch_rewards.remaining(epoch_reward_cap));
+            if allowed < reward_tokens {
+                withheld_rewards.push(WithheldReward {
+                    address: address.to_string(),
+                    amount: reward_tokens - allowed,
+                    reason: WithholdingReason::EpochCap,
+                });
+                reward_tokens = allowed;
+            }
+            epoch_rewards = Some(current_epoch_rewards);
+        }
+
+        let credited = match &mut reward_minting {
+            Some(reward_minting) => {
+                let minted = reward_minting.mint(reward_tokens);
+                reward_pool.total_to_claim += minted;
+                minted
+            }
+            None => reward_pool.credit(reward_tokens),
+        };
+        credited_rewards.push(credited);
+        if credited.is_zero() {
+            continue;
+        }
+        if let Some(mut epoch_rewards) = epoch_rewards {
+            epoch_rewards.rewards += credited;
+            EPOCH_REWARDS.save(storage, address, &epoch_rewards)?;
+        }
+        match &reward_system.vesting {
+            Some(vesting) => {
+                let mut entries = REWARD_VESTINGS
+                    .may_load(storage, address)?
+                    .unwrap_or_default();
+                add_vesting_entry(
+                    &mut entries,
+                    VestingEntry::new(time, vesting, credited),
+                    time,
+                );
+                REWARD_VESTINGS.save(storage, address, &entries)?;
+            }
+            None => {
+                REWARDS_TO_CLAIM.update(storage, address, |to_claim| -> StdResult<_> {
+                    Ok(to_claim.unwrap_or_default() + credited)
+                })?;
+            }
+        }
+    }
+    // Rewards withheld from an empty trade are not worth an event
+    withheld_rewards.retain(|withheld_reward| !withheld_reward.amount.is_zero());
+    REWARD_POOL.save(storage, &reward_pool)?;
+    if let Some(reward_minting) = &reward_minting {
+        REWARD_MINTING.save(storage, reward_minting)?;
+    }
+    Ok((credited_rewards, withheld_rewards))
+}
+
+// Marks the vested rewards of the address as claimed and returns them
+pub fn claim_vested_rewards(
+    storage: &mut dyn Storage,
+    time: Timestamp,
+    address: &str,
+) -> StdResult<Uint128> {
+    let mut entries = REWARD_VESTINGS
+        .may_load(storage, address)?
+        .unwrap_or_default();
+    let mut claimed = Uint128::zero();
+    for entry in entries.iter_mut() {
+        claimed += entry.claimable(time);
+        entry.claimed = entry.vested(time);
+    }
+    entries.retain(|entry| entry.claimed < entry.total);
+    if entries.is_empty() {
+        REWARD_VESTINGS.remove(storage, address);
+    } else {
+        REWARD_VESTINGS.save(storage, address, &entries)?;
+    }
+    Ok(claimed)
+}
@@ -0,0 +1,166 @@
+use cosmwasm_std::{Order, StdResult, Storage, Timestamp, Uint128};
+use cw_storage_plus::Bound;
+
+use nft_marketplace_utils::marketplace_statistics::GeneralStats;
+use nft_marketplace_utils::nft_collection::{
+    define_unique_collection_by_denom_id, NftCollectionAddress,
+};
+use nft_marketplace_utils::price_history::{
+    day_start_seconds, period_start_seconds, DailyPriceBucket, HourlyTradeStats, RollingWindow,
+    RollingWindowStats, SECONDS_PER_HOUR,
+};
+
+use crate::constants::MARKETPLACE_USDC_INDICATOR;
+use crate::state::{DAILY_PRICE_BUCKETS, HOURLY_TRADE_STATS};
+
+// Updates the daily and hourly buckets of the collection for the sale denom and for the USDC total
+pub fn record_sale_in_collection_buckets(
+    storage: &mut dyn Storage,
+    nft_collection_address: &NftCollectionAddress,
+    denom: &str,
+    sale_time: Timestamp,
+    sale_price: Uint128,
+    sale_price_usdc: Uint128,
+    opening_floor: Uint128,
+) -> StdResult<()> {
+    for (bucket_denom, price, floor) in [
+        (denom, sale_price, opening_floor),
+        (MARKETPLACE_USDC_INDICATOR, sale_price_usdc, Uint128::zero()),
+    ] {
+        let collection_denom_unique =
+            define_unique_collection_by_denom_id(nft_collection_address, bucket_denom);
+        DAILY_PRICE_BUCKETS.update(
+            storage,
+            (&collection_denom_unique, day_start_seconds(sale_time)),
+            |bucket| -> StdResult<_> {
+                match bucket {
+                    Some(mut bucket) => {
+                        bucket.add_sale(price, sale_price_usdc);
+                        Ok(bucket)
+                    }
+                    None => Ok(DailyPriceBucket::new(
+                        nft_collection_address.clone(),
+                        bucket_denom.to_string(),
+                        sale_time,
+                        price,
+                        sale_price_usdc,
+                        floor,
+                    )),
+                }
+            },
+        )?;
+
+        let hour_start = period_start_seconds(sale_time, SECONDS_PER_HOUR);
+        let mut hourly_stats =
+            match HOURLY_TRADE_STATS.may_load(storage, (&collection_denom_unique, hour_start))? {
+                Some(hourly_stats) => hourly_stats,
+                None => {
+                    // New hour: drop the buckets out of the 24h window
+                    prune_hourly_trade_stats(storage, &collection_denom_unique, sale_time)?;
+                    HourlyTradeStats::new(floor)
+                }
+            };
+        hourly_stats.add_sale(price, sale_price_usdc);
+        HOURLY_TRADE_STATS.save(
+            storage,
+            (&collection_denom_unique, hour_start),
+            &hourly_stats,
+        )?;
+    }
+    Ok(())
+}
+
+fn prune_hourly_trade_stats(
+    storage: &mut dyn Storage,
+    collection_denom_unique: &str,
+    now: Timestamp,
+) -> StdResult<()> {
+    let window_start = RollingWindow::Last24h.first_period_start(now);
+    let expired_hours = HOURLY_TRADE_STATS
+        .prefix(collection_denom_unique)
+        .keys(
+            storage,
+            None,
+            Some(Bound::exclusive(window_start)),
+            Order::Ascending,
+        )
+        .collect::<StdResult<Vec<u64>>>()?;
+    for hour_start in expired_hours {
+        HOURLY_TRADE_STATS.remove(storage, (collection_denom_unique, hour_start));
+    }
+    Ok(())
+}
+
+pub fn compute_rolling_window_stats(
+    storage: &dyn Storage,
+    collection_denom_unique: &str,
+    window: RollingWindow,
+    now: Timestamp,
+    current_floor: Uint128,
+) -> StdResult<RollingWindowStats> {
+    let mut window_stats = RollingWindowStats::new(window.clone(), current_floor);
+    let window_start = Some(Bound::inclusive(window.first_period_start(now)));
+    match window {
+        RollingWindow::Last24h => {
+            for item in HOURLY_TRADE_STATS.prefix(collection_denom_unique).range(
+                storage,
+                window_start,
+                None,
+                Order::Ascending,
+            ) {
+                let (_, hourly_stats) = item?;
+                window_stats.add_period(
+                    hourly_stats.volume,
+                    hourly_stats.volume_usdc,
+                    hourly_stats.trades,
+                    hourly_stats.opening_floor,
+                );
+            }
+        }
+        RollingWindow::Last7d | RollingWindow::Last30d => {
+            for item in DAILY_PRICE_BUCKETS.prefix(collection_denom_unique).range(
+                storage,
+                window_start,
+                None,
+                Order::Ascending,
+            ) {
+                let (_, daily_bucket) = item?;
+                window_stats.add_period(
+                    daily_bucket.volume,
+                    daily_bucket.volume_usdc,
+                    daily_bucket.trades,
+                    daily_bucket.opening_floor,
+                );
+            }
+        }
+    }
+    Ok(window_stats)
+}
+
+// Collections that stopped trading keep the volume of their last trade in the saved lists
+pub fn refresh_trending_collections(
+    storage: &dyn Storage,
+    general_stats: &mut GeneralStats,
+    now: Timestamp,
+) -> StdResult<()> {
+    for window in [RollingWindow::Last24h, RollingWindow::Last7d] {
+        if let Some(trending) = general_stats.trending_mut(&window) {
+            for trending_collection in trending.iter_mut() {
+                trending_collection.usdc_volume = compute_rolling_window_stats(
+                    storage,
+                    &define_unique_collection_by_denom_id(
+                        &trending_collection.nft_collection_address,
+                        MARKETPLACE_USDC_INDICATOR,
+                    ),
+                    window.clone(),
+                    now,
+                    Uint128::zero(),
+                )?
+                .volume_usdc;
+            }
+            trending.retain(|x| !x.usdc_volume.is_zero());
+            trending.sort_unstable_by_key(|x| std::cmp::Reverse(x.usdc_volume));
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,92 @@
+use cosmwasm_std::{Decimal, Order, StdResult, Storage, Uint128};
+
+use general_utils::denominations::Denomination;
+use nft_marketplace_utils::staking::{FeeIndex, StakerFees};
+
+use crate::state::{
+    FEE_INDEXES, MARKETPLACE_STATS_BY_DENOM, STAKER_FEES, STAKES, STAKING_CONFIG, STAKING_STATS,
+};
+
+// Fee index of each denom once the fees collected since the last distribution are shared, with
+// the fees moved to the stakers
+pub fn distributed_fee_indexes(
+    storage: &dyn Storage,
+) -> StdResult<Vec<(Denomination, FeeIndex, Uint128)>> {
+    let fees_share = STAKING_CONFIG
+        .may_load(storage)?
+        .map_or(Decimal::zero(), |staking_config| staking_config.fees_share);
+    let total_staked = STAKING_STATS
+        .may_load(storage)?
+        .unwrap_or_default()
+        .total_staked;
+    MARKETPLACE_STATS_BY_DENOM
+        .range(storage, None, None, Order::Ascending)
+        .map(|item| {
+            let (denom, marketplace_stats) = item?;
+            let mut fee_index = FEE_INDEXES.may_load(storage, &denom)?.unwrap_or_default();
+            let shared_fees = fee_index.distribute(
+                marketplace_stats.marketplace_fees_to_claim,
+                fees_share,
+                total_staked,
+            );
+            Ok((denom, fee_index, shared_fees))
+        })
+        .collect()
+}
+
+// Called before any change of the stakes or of the fees to claim
+pub fn distribute_staking_fees(
+    storage: &mut dyn Storage,
+) -> StdResult<Vec<(Denomination, FeeIndex)>> {
+    let fee_indexes = distributed_fee_indexes(storage)?;
+    for (denom, fee_index, shared_fees) in fee_indexes.iter() {
+        FEE_INDEXES.save(storage, denom, fee_index)?;
+        if !shared_fees.is_zero() {
+            MARKETPLACE_STATS_BY_DENOM.update(
+                storage,
+                denom,
+                |marketplace_stats| -> StdResult<_> {
+                    let mut marketplace_stats = marketplace_stats.unwrap();
+                    marketplace_stats.marketplace_fees_to_claim -= *shared_fees;
+                    Ok(marketplace_stats)
+                },
+            )?;
+        }
+    }
+    Ok(fee_indexes
+        .into_iter()
+        .map(|(denom, fee_index, _)| (denom, fee_index))
+        .collect())
+}
+
+// Fees of the staker in each denom at the given indexes
+pub fn settled_staker_fees(
+    storage: &dyn Storage,
+    address: &str,
+    fee_indexes: &[(Denomination, FeeIndex)],
+) -> StdResult<Vec<(Denomination, StakerFees)>> {
+    let staked = STAKES.may_load(storage, address)?.unwrap_or_default();
+    fee_indexes
+        .iter()
+        .map(|(denom, fee_index)| {
+            let mut staker_fees = STAKER_FEES
+                .may_load(storage, (address, denom))?
+                .unwrap_or_default();
+            staker_fees.settle(staked, fee_index)?;
+            Ok((denom.clone(), staker_fees))
+        })
+        .collect()
+}
+
+// Shares the pending fees and settles the ones of the staker, before any change of its stake
+pub fn settle_staker(
+    storage: &mut dyn Storage,
+    address: &str,
+) -> StdResult<Vec<(Denomination, StakerFees)>> {
+    let fee_indexes = distribute_staking_fees(storage)?;
+    let staker_fees = settled_staker_fees(storage, address, &fee_indexes)?;
+    for (denom, denom_staker_fees) in staker_fees.iter() {
+        STAKER_FEES.save(storage, (address, denom), denom_staker_fees)?;
+    }
+    Ok(staker_fees)
+}
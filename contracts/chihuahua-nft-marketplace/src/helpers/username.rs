@@ -0,0 +1,133 @@
+use cosmwasm_std::{ensure, Env, Order, StdResult, Storage, Timestamp};
+use cw_storage_plus::Bound;
+
+use general_utils::error::ContractError;
+use general_utils::error::NftMarketplaceError::{
+    ThisUsernameIsAlreadyTaken, UsernameIsReserved, UsernameRenameCooldownNotOver,
+    YouDontHaveAUsername,
+};
+use nft_marketplace_utils::profile::Profile;
+use nft_marketplace_utils::username::normalize_username;
+
+use crate::constants::DEFAULT_USERNAME_RENAME_COOLDOWN_SECONDS;
+use crate::state::{
+    PROFILES, RESERVED_USERNAMES, USERNAMES, USERNAME_CHANGES, USERNAME_RENAME_COOLDOWN,
+    USERNAME_SALES,
+};
+
+// Address owning the username, whatever the case used
+pub fn resolve_username(storage: &dyn Storage, username: &str) -> StdResult<Option<String>> {
+    USERNAMES.may_load(storage, &normalize_username(username))
+}
+
+// A username released is not for sale anymore
+pub fn release_username(storage: &mut dyn Storage, username: &str) {
+    let key = normalize_username(username);
+    USERNAMES.remove(storage, &key);
+    USERNAME_SALES.remove(storage, &key);
+}
+
+// Registers a new username for the address. Changing the case of your own username is not a rename
+pub fn register_username(
+    storage: &mut dyn Storage,
+    time: Timestamp,
+    address: &str,
+    current_username: Option<&str>,
+    username: &str,
+) -> Result<(), ContractError> {
+    let key = normalize_username(username);
+    if current_username.map(normalize_username) == Some(key.clone()) {
+        return Ok(());
+    }
+    ensure!(
+        !RESERVED_USERNAMES.has(storage, &key),
+        ContractError::NftMarketplaceError(UsernameIsReserved {})
+    );
+    ensure!(
+        !USERNAMES.has(storage, &key),
+        ContractError::NftMarketplaceError(ThisUsernameIsAlreadyTaken {})
+    );
+    if let Some(last_change) = USERNAME_CHANGES.may_load(storage, address)? {
+        let cooldown = USERNAME_RENAME_COOLDOWN
+            .may_load(storage)?
+            .unwrap_or(DEFAULT_USERNAME_RENAME_COOLDOWN_SECONDS);
+        ensure!(
+            time >= last_change.plus_seconds(cooldown),
+            ContractError::NftMarketplaceError(UsernameRenameCooldownNotOver {})
+        );
+    }
+    if let Some(current_username) = current_username {
+        release_username(storage, current_username);
+    }
+    USERNAMES.save(storage, &key, &address.to_string())?;
+    USERNAME_CHANGES.save(storage, address, &time)?;
+    Ok(())
+}
+
+// Moves the username of from to the profile of to, created if needed. The previous username of
+// to is released. Receiving a username counts as a rename for the cooldown
+pub fn move_username(
+    storage: &mut dyn Storage,
+    time: Timestamp,
+    from: &str,
+    to: &str,
+) -> Result<String, ContractError> {
+    let mut from_profile = PROFILES.load(storage, from)?;
+    let username = from_profile
+        .username
+        .take()
+        .ok_or(ContractError::NftMarketplaceError(YouDontHaveAUsername {}))?;
+    let mut to_profile = PROFILES
+        .may_load(storage, to)?
+        .unwrap_or_else(|| Profile::new(to.to_string()));
+    if let Some(to_username) = to_profile.username.take() {
+        release_username(storage, &to_username);
+    }
+    let key = normalize_username(&username);
+    USERNAME_SALES.remove(storage, &key);
+    USERNAMES.save(storage, &key, &to.to_string())?;
+    USERNAME_CHANGES.save(storage, to, &time)?;
+    to_profile.username = Some(username.clone());
+    PROFILES.save(storage, from, &from_profile)?;
+    PROFILES.save(storage, to, &to_profile)?;
+    Ok(username)
+}
+
+// Migration: usernames were saved with their case. Returns the last key processed if the batch
+// was full, None once every username has been visited. When usernames only differ by their case,
+// the one already in lowercase (or else the first visited) is kept, other profiles lose theirs
+pub fn migrate_usernames_keys(
+    store: &mut dyn Storage,
+    _env: &Env,
+    start_after: Option<String>,
+    limit: u32,
+) -> StdResult<Option<String>> {
+    let batch: Vec<(String, String)> = USERNAMES
+        .range(
+            store,
+            start_after.as_deref().map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit as usize)
+        .collect::<StdResult<Vec<_>>>()?;
+    for (username, address) in batch.iter() {
+        let key = normalize_username(username);
+        if *username == key {
+            continue;
+        }
+        USERNAMES.remove(store, username);
+        if USERNAMES.has(store, &key) {
+            if let Some(mut profile) = PROFILES.may_load(store, address)? {
+                profile.username = None;
+                PROFILES.save(store, address, &profile)?;
+            }
+        } else {
+            USERNAMES.save(store, &key, address)?;
+        }
+    }
+    if batch.len() < limit as usize {
+        return Ok(None);
+    }
+    Ok(batch.last().map(|(username, _)| username.clone()))
+}
@@ -0,0 +1,117 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Decimal, Env, Order, StdResult, Storage, Uint128};
+use cw_storage_plus::{Bound, Item, Map};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use nft_marketplace_utils::reward_system::{RewardSystem, VipLevel, VipTier};
+use nft_marketplace_utils::reward_vesting::RewardVesting;
+
+use crate::state::{MESSAGE_FILTERS, PROFILES, REWARD_SYSTEM};
+
+// Perks stored before 0.0.7, one per variant of the former VipLevel enum
+#[cw_serde]
+struct LegacyVipPerk {
+    vip_level: VipLevel,
+    profile_background: bool,
+    profile_nft_showcase: bool,
+    profile_description: bool,
+    profile_links: bool,
+    marketplace_fees_discount: Decimal,
+    level_up_price_in_reward_tokens: Uint128,
+}
+
+#[cw_serde]
+struct LegacyRewardSystem {
+    reward_token_address: String,
+    reward_token_per_1usdc_volume: Uint128,
+    total_reward_tokens_distributed: Uint128,
+    vip_perks: Vec<LegacyVipPerk>,
+    #[serde(default)]
+    vesting: Option<RewardVesting>,
+}
+
+const LEGACY_REWARD_SYSTEM: Item<LegacyRewardSystem> = Item::new("reward_system");
+
+// Each perk becomes the tier of its level, named after the former variant
+pub fn migrate_reward_system_vip_tiers(
+    store: &mut dyn Storage,
+    _env: &Env,
+    _start_after: Option<String>,
+    _limit: u32,
+) -> StdResult<Option<String>> {
+    let legacy_reward_system = LEGACY_REWARD_SYSTEM.load(store)?;
+    let mut vip_perks = legacy_reward_system.vip_perks;
+    // Level0 is the base level and has no tier
+    vip_perks.retain(|perk| perk.vip_level > VipLevel::default());
+    vip_perks.sort_by_key(|perk| perk.vip_level);
+    let vip_tiers = vip_perks
+        .into_iter()
+        .map(|perk| VipTier {
+            name: perk.vip_level.to_string(),
+            level_up_price_in_reward_tokens: perk.level_up_price_in_reward_tokens,
+            marketplace_fees_discount: perk.marketplace_fees_discount,
+            profile_background: perk.profile_background,
+            profile_nft_showcase: perk.profile_nft_showcase,
+            profile_description: perk.profile_description,
+            profile_links: perk.profile_links,
+            max_messages_per_window: None,
+            subscription_duration: None,
+            volume_usdc_threshold: None,
+        })
+        .collect();
+    REWARD_SYSTEM.save(
+        store,
+        &RewardSystem {
+            reward_token_address: legacy_reward_system.reward_token_address,
+            reward_token_per_1usdc_volume: legacy_reward_system.reward_token_per_1usdc_volume,
+            total_reward_tokens_distributed: legacy_reward_system.total_reward_tokens_distributed,
+            vip_tiers,
+            vesting: legacy_reward_system.vesting,
+        },
+    )?;
+    Ok(None)
+}
+
+// VipLevel still reads the former variants, saving the entries again stores the levels as numbers
+pub fn migrate_profiles_vip_level(
+    store: &mut dyn Storage,
+    _env: &Env,
+    start_after: Option<String>,
+    limit: u32,
+) -> StdResult<Option<String>> {
+    save_again_by_batch(store, PROFILES, start_after, limit)
+}
+
+pub fn migrate_message_filters_vip_level(
+    store: &mut dyn Storage,
+    _env: &Env,
+    start_after: Option<String>,
+    limit: u32,
+) -> StdResult<Option<String>> {
+    save_again_by_batch(store, MESSAGE_FILTERS, start_after, limit)
+}
+
+fn save_again_by_batch<T: Serialize + DeserializeOwned>(
+    store: &mut dyn Storage,
+    map: Map<&str, T>,
+    start_after: Option<String>,
+    limit: u32,
+) -> StdResult<Option<String>> {
+    let batch: Vec<(String, T)> = map
+        .range(
+            store,
+            start_after.as_deref().map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit as usize)
+        .collect::<StdResult<Vec<_>>>()?;
+    for (key, value) in batch.iter() {
+        map.save(store, key, value)?;
+    }
+    if batch.len() < limit as usize {
+        return Ok(None);
+    }
+    Ok(batch.last().map(|(key, _)| key.clone()))
+}
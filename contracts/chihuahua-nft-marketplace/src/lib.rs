@@ -3,6 +3,7 @@ pub mod constants;
 pub mod contract;
 pub mod execute_functions;
 pub mod helpers;
+pub mod migrations;
 pub mod msg;
 pub mod query;
 pub mod state;
@@ -0,0 +1,121 @@
+use cosmwasm_std::{ensure, DepsMut, Env, Response, StdResult, Storage};
+
+use general_utils::error::ContractError;
+use general_utils::error::MigrationError::MigrationInProgress;
+use general_utils::migrations::{parse_version, validate_migration_version};
+use nft_marketplace_utils::migration::MigrationProgress;
+use nft_marketplace_utils::nft_sale::{
+    migrate_nfts_for_sale_indexes, migrate_nfts_for_sale_keys, migrate_nfts_for_sale_listing_times,
+};
+use nft_marketplace_utils::response_handler::ResponseHandler;
+use nft_marketplace_utils::tokenfactory::TokenFactoryMsg;
+
+use crate::helpers::inbox::migrate_profile_messages;
+use crate::helpers::username::migrate_usernames_keys;
+use crate::helpers::vip_tiers::{
+    migrate_message_filters_vip_level, migrate_profiles_vip_level, migrate_reward_system_vip_tiers,
+};
+use crate::msg::MigrateMsg;
+use crate::state::MIGRATION_PROGRESS;
+
+const DEFAULT_MIGRATION_BATCH_SIZE: u32 = 100;
+const MAX_MIGRATION_BATCH_SIZE: u32 = 500;
+
+type MigrationFn = fn(&mut dyn Storage, &Env, Option<String>, u32) -> StdResult<Option<String>>;
+
+pub struct MigrationStep {
+    // Contracts stored with a version below this one need the step
+    pub version: &'static str,
+    pub migrate: MigrationFn,
+}
+
+// Steps are executed in order; a step returning a cursor is resumed on the next migrate call
+pub const MIGRATION_STEPS: &[MigrationStep] = &[
+    MigrationStep {
+        version: "0.0.2",
+        migrate: migrate_nfts_for_sale_keys,
+    },
+    MigrationStep {
+        version: "0.0.3",
+        migrate: migrate_nfts_for_sale_indexes,
+    },
+    MigrationStep {
+        version: "0.0.4",
+        migrate: migrate_nfts_for_sale_listing_times,
+    },
+    MigrationStep {
+        version: "0.0.5",
+        migrate: migrate_profile_messages,
+    },
+    MigrationStep {
+        version: "0.0.6",
+        migrate: migrate_usernames_keys,
+    },
+    MigrationStep {
+        version: "0.0.7",
+        migrate: migrate_reward_system_vip_tiers,
+    },
+    MigrationStep {
+        version: "0.0.7",
+        migrate: migrate_profiles_vip_level,
+    },
+    MigrationStep {
+        version: "0.0.7",
+        migrate: migrate_message_filters_vip_level,
+    },
+];
+
+// Sales and usernames are partly rewritten until the last batch is done
+pub fn if_not_migrating(storage: &dyn Storage) -> Result<(), ContractError> {
+    ensure!(
+        MIGRATION_PROGRESS.may_load(storage)?.is_none(),
+        ContractError::MigrationError(MigrationInProgress {})
+    );
+    Ok(())
+}
+
+pub fn migrate_contract(
+    deps: DepsMut,
+    env: Env,
+    msg: MigrateMsg,
+    contract_name: &str,
+    contract_version: &str,
+) -> Result<Response<TokenFactoryMsg>, ContractError> {
+    let stored_version = validate_migration_version(deps.storage, contract_name, contract_version)?;
+    let batch_size = msg
+        .batch_size
+        .unwrap_or(DEFAULT_MIGRATION_BATCH_SIZE)
+        .clamp(1, MAX_MIGRATION_BATCH_SIZE);
+
+    // A previous migrate call can have stopped in the middle of a step
+    let mut progress = MIGRATION_PROGRESS
+        .may_load(deps.storage)?
+        .unwrap_or_else(|| {
+            MigrationProgress::new(stored_version.to_string(), contract_version.to_string())
+        });
+    progress.to_version = contract_version.to_string();
+    let from_version = parse_version(&progress.from_version)?;
+
+    while let Some(step) = MIGRATION_STEPS.get(progress.step as usize) {
+        if from_version >= parse_version(step.version)? {
+            progress.next_step();
+            continue;
+        }
+        match (step.migrate)(deps.storage, &env, progress.cursor.clone(), batch_size)? {
+            Some(cursor) => {
+                // Batch is full: save where we are and wait for the next migrate call
+                progress.cursor = Some(cursor);
+                MIGRATION_PROGRESS.save(deps.storage, &progress)?;
+                return Ok(ResponseHandler::migrate_response(progress, false).response);
+            }
+            None => {
+                progress.next_step();
+            }
+        }
+    }
+
+    // The version only changes once every step is done so an unfinished migration can resume
+    MIGRATION_PROGRESS.remove(deps.storage);
+    cw2::set_contract_version(deps.storage, contract_name, contract_version)?;
+    Ok(ResponseHandler::migrate_response(progress, true).response)
+}
@@ -1,15 +1,25 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{coins, to_binary, Uint128, WasmMsg};
+use cosmwasm_std::{coins, to_binary, Timestamp, Uint128, WasmMsg};
 
 use general_utils::denominations::{AcceptedDenominations, Denomination};
 use general_utils::error::ContractError;
+use nft_marketplace_utils::circuit_breaker::MarketplaceCapability;
+use nft_marketplace_utils::follow::FollowTarget;
+use nft_marketplace_utils::leaderboard::{
+    LeaderboardCursor, LeaderboardPeriod, LeaderboardRanking,
+};
+use nft_marketplace_utils::messaging::{MessageFilter, MessagingConfig};
 use nft_marketplace_utils::nft_collection::{
     NftCollectionAddress, NftCollectionAddressTokenId, NftContractInfo, NftContractType, TokenId,
 };
 use nft_marketplace_utils::nft_offer::NftOffer;
-use nft_marketplace_utils::nft_sale::NftSale;
+use nft_marketplace_utils::nft_sale::{NftSale, NftSaleCursor, PriceOrder};
+use nft_marketplace_utils::partner_perks::{PartnerCollection, PerksCombination};
 use nft_marketplace_utils::profile::{Profile, ProfileUpdateAction, TradeInfo};
 use nft_marketplace_utils::reward_system::RewardSystem;
+use nft_marketplace_utils::staking::StakingConfig;
+use nft_marketplace_utils::verification::{VerificationKind, VerificationSubject};
+use nft_marketplace_utils::wash_trading::WashTradingConfig;
 
 #[cw_serde]
 pub struct InstantiateMsg {
@@ -31,6 +41,25 @@ pub enum ExecuteMsg {
         list_of_updates: Vec<UpdateConfigEnum>,
     },
     ClaimMarketplaceFees {},
+    // Admin only, funds the rewards of the trades with reward tokens
+    FundRewardPool {},
+    SetCircuitBreaker {
+        capability: MarketplaceCapability,
+        paused: bool,
+        reason: String,
+    },
+    StartLeaderboardSeason {},
+    // Admin only, the prize pool is sent with the message in the denom of the prizes
+    CreateCompetition {
+        start_time: Timestamp,
+        end_time: Timestamp,
+        nft_collection_addresses: Vec<NftCollectionAddress>,
+        prizes: Vec<Uint128>,
+    },
+    // Anyone once the competition has ended, ranks the top traders
+    FinalizeCompetition {
+        competition_id: u64,
+    },
     AddNewCollection {
         nft_collection_address: NftCollectionAddress,
         nft_contract_info: NftContractInfo,
@@ -82,7 +111,76 @@ pub enum ExecuteMsg {
         to: String,
         message: String,
     },
-    LevelUpProfile {}
+    // Marks the received messages of the thread as read, up to the latest one by default
+    MarkThreadRead {
+        counterparty: String,
+        up_to_message_id: Option<u64>,
+    },
+    DeleteMessage {
+        message_id: u64,
+    },
+    // Only profiles meeting any of the requirements can send messages to the sender, None removes it
+    SetMessageFilter {
+        message_filter: Option<MessageFilter>,
+    },
+    BlockAddress {
+        address: String,
+    },
+    UnblockAddress {
+        address: String,
+    },
+    // Profiles and listed collections
+    Follow {
+        target: FollowTarget,
+    },
+    Unfollow {
+        target: FollowTarget,
+    },
+    WatchToken {
+        nft_collection_address: NftCollectionAddress,
+        token_id: TokenId,
+    },
+    UnwatchToken {
+        nft_collection_address: NftCollectionAddress,
+        token_id: TokenId,
+    },
+    LevelUpProfile {},
+    // Extends the subscription of the current VIP level, paid at the price of its tier
+    RenewVipLevel {},
+    // Reward tokens earned by trading
+    ClaimRewards {},
+    // Stakes the reward tokens sent for a share of the marketplace fees
+    Stake {},
+    // Unstaked tokens are withdrawable after the unbonding period
+    Unstake {
+        amount: Uint128,
+    },
+    WithdrawUnbonded {},
+    ClaimStakingFees {},
+    ClaimCompetitionPrize {
+        competition_id: u64,
+    },
+    // The username of the sender, listing it again updates the price
+    SellUsername {
+        price_value: Uint128,
+        price_denom: Denomination,
+    },
+    CancelUsernameSale {},
+    BuyUsername {
+        username: String,
+    },
+    TransferUsername {
+        recipient: String,
+    },
+    // Admin or moderators only
+    Verify {
+        subject: VerificationSubject,
+        kind: VerificationKind,
+    },
+    RevokeVerification {
+        subject: VerificationSubject,
+        kind: VerificationKind,
+    },
 }
 
 impl ExecuteMsg {
@@ -176,16 +274,45 @@ pub enum UpdateConfigEnum {
     RemoveDenoms { denoms: Vec<Denomination> },
     UpdateOwner { address: String },
     UpdateRewardSystem { reward_system: RewardSystem },
+    UpdateMessagingConfig { messaging_config: MessagingConfig },
+    UpdateUsernameRenameCooldown { seconds: u64 },
+    AddReservedUsernames { usernames: Vec<String> },
+    RemoveReservedUsernames { usernames: Vec<String> },
+    AddModerators { addresses: Vec<String> },
+    RemoveModerators { addresses: Vec<String> },
+    // Adds the collection or replaces its perks
+    SetPartnerCollection { partner_collection: PartnerCollection },
+    RemovePartnerCollection { nft_collection_address: NftCollectionAddress },
+    UpdatePerksCombination { perks_combination: PerksCombination },
+    UpdateWashTradingConfig { wash_trading_config: WashTradingConfig },
+    UpdateStakingConfig { staking_config: StakingConfig },
+    // Creates factory/{contract}/{subdenom} as the new reward token, minted as trades happen
+    EnableRewardMinting { subdenom: String, emission_cap: Uint128 },
+    UpdateRewardEmissionCap { emission_cap: Uint128 },
 }
 
 #[cw_serde]
-pub struct MigrateMsg {}
+pub struct MigrateMsg {
+    // Max number of entries rewritten per migration step in a single migrate call
+    pub batch_size: Option<u32>,
+}
 
 #[cw_serde]
 #[derive(QueryResponses)]
 pub enum QueryMsg {
     #[returns(nft_marketplace_utils::config::ConfigRewardGenStatsMsg)]
     GetConfig {},
+    #[returns(nft_marketplace_utils::reward_system::RewardPool)]
+    GetRewardPool {},
+    #[returns(Option<nft_marketplace_utils::tokenfactory::RewardMinting>)]
+    GetRewardMinting {},
+    // Liquid rewards and vested rewards not claimed yet
+    #[returns(Uint128)]
+    GetRewardsToClaim { address: String },
+    #[returns(nft_marketplace_utils::reward_vesting::RewardVestingInfo)]
+    GetRewardVesting { address: String },
+    #[returns(Vec<nft_marketplace_utils::circuit_breaker::CircuitBreaker>)]
+    GetCircuitBreakers {},
     #[returns(Uint128)]
     GetMarketplaceVolume {},
     #[returns(Uint128)]
@@ -196,7 +323,7 @@ pub enum QueryMsg {
     GetNftCollectionType {
         nft_collection_address: NftCollectionAddress,
     },
-    #[returns(nft_marketplace_utils::nft_collection::NftCollectionInfoByDenom)]
+    #[returns(nft_marketplace_utils::nft_collection::NftCollectionInfoResponse)]
     GetNftCollectionInfo {
         nft_collection_address: NftCollectionAddress,
     },
@@ -223,6 +350,65 @@ pub enum QueryMsg {
         start_after_token_id: Option<TokenId>,
         output_length: Option<u32>,
     },
+    // Sorted by price, start_after is the (price, token id) of the last sale received
+    #[returns(Vec<nft_marketplace_utils::nft_sale::NftSale>)]
+    GetCollectionNftsForSaleByPrice {
+        nft_collection_address: NftCollectionAddress,
+        denom: Denomination,
+        order: PriceOrder,
+        min_price: Option<Uint128>,
+        max_price: Option<Uint128>,
+        start_after: Option<(Uint128, TokenId)>,
+        output_length: Option<u32>,
+    },
+    // Feeds are marketplace wide when no collection is given
+    #[returns(Vec<nft_marketplace_utils::nft_sale::NftSaleListing>)]
+    GetRecentlyListed {
+        nft_collection_address: Option<NftCollectionAddress>,
+        start_after: Option<NftSaleCursor>,
+        output_length: Option<u32>,
+    },
+    // Sales not expired yet, the closest expiration first
+    #[returns(Vec<nft_marketplace_utils::nft_sale::NftSale>)]
+    GetEndingSoon {
+        nft_collection_address: Option<NftCollectionAddress>,
+        start_after: Option<NftSaleCursor>,
+        output_length: Option<u32>,
+    },
+    // start_after is the sale_id of the last sale received
+    #[returns(Vec<nft_marketplace_utils::nft_sale::SoldNft>)]
+    GetRecentlySold {
+        nft_collection_address: Option<NftCollectionAddress>,
+        start_after: Option<u64>,
+        output_length: Option<u32>,
+    },
+    // Activity log, newest first; start_after is the activity_id of the last activity received
+    #[returns(Vec<nft_marketplace_utils::activity::Activity>)]
+    GetActivity {
+        start_after: Option<u64>,
+        output_length: Option<u32>,
+    },
+    #[returns(Vec<nft_marketplace_utils::activity::Activity>)]
+    GetCollectionActivity {
+        nft_collection_address: NftCollectionAddress,
+        start_after: Option<u64>,
+        output_length: Option<u32>,
+    },
+    #[returns(Vec<nft_marketplace_utils::activity::Activity>)]
+    GetTokenActivity {
+        nft_collection_address: NftCollectionAddress,
+        token_id: TokenId,
+        start_after: Option<u64>,
+        output_length: Option<u32>,
+    },
+    // Activities where the address is either side, only the given kinds if filtered
+    #[returns(Vec<nft_marketplace_utils::activity::AddressActivity>)]
+    GetAddressActivity {
+        address: String,
+        kinds: Option<Vec<nft_marketplace_utils::activity::AddressActivityKind>>,
+        start_after: Option<u64>,
+        output_length: Option<u32>,
+    },
     #[returns(Vec<nft_marketplace_utils::marketplace_statistics::MarketplaceStatsByDenom>)]
     GetMarketplaceInfo {},
     #[returns(Vec<nft_marketplace_utils::nft_sale::TokenSaleHistory>)]
@@ -230,8 +416,155 @@ pub enum QueryMsg {
         token_id: TokenId,
         nft_collection_address: NftCollectionAddress,
     },
-    #[returns(nft_marketplace_utils::profile::Profile)]
+    // Rolling 24h, 7d and 30d stats for each denom of the collection
+    #[returns(Vec<nft_marketplace_utils::price_history::CollectionRollingStats>)]
+    GetNftCollectionRollingStats {
+        nft_collection_address: NftCollectionAddress,
+    },
+    // Daily buckets between start_time and end_time, start_after is the last day_start received
+    #[returns(Vec<nft_marketplace_utils::price_history::DailyPriceBucket>)]
+    GetCollectionDailyPriceHistory {
+        nft_collection_address: NftCollectionAddress,
+        denom: Denomination,
+        start_time: Option<Timestamp>,
+        end_time: Option<Timestamp>,
+        start_after: Option<Timestamp>,
+        output_length: Option<u32>,
+    },
+    // Hidden traders (display_trade_info false) are not ranked, start_after is the last entry received
+    #[returns(Vec<nft_marketplace_utils::leaderboard::TraderStats>)]
+    GetTraderLeaderboard {
+        ranking: LeaderboardRanking,
+        period: LeaderboardPeriod,
+        start_after: Option<LeaderboardCursor>,
+        output_length: Option<u32>,
+    },
+    #[returns(nft_marketplace_utils::leaderboard::LeaderboardSeason)]
+    GetLeaderboardSeason {},
+    #[returns(nft_marketplace_utils::competition::Competition)]
+    GetCompetition { competition_id: u64 },
+    #[returns(Vec<nft_marketplace_utils::competition::Competition>)]
+    GetCompetitions {
+        start_after: Option<u64>,
+        output_length: Option<u32>,
+    },
+    // Traders of the competition by volume, start_after is the last entry received
+    #[returns(Vec<nft_marketplace_utils::competition::CompetitionTrader>)]
+    GetCompetitionLeaderboard {
+        competition_id: u64,
+        start_after: Option<LeaderboardCursor>,
+        output_length: Option<u32>,
+    },
+    // Empty until the competition is finalized, sorted by rank
+    #[returns(Vec<nft_marketplace_utils::competition::CompetitionPrize>)]
+    GetCompetitionPrizes { competition_id: u64 },
+    #[returns(nft_marketplace_utils::profile::ProfileResponse)]
     GetProfileInfo { address_or_username: String },
+    // USDC volume of the profile and what is left to reach the next tier by volume
+    #[returns(nft_marketplace_utils::reward_system::VipProgress)]
+    GetVipProgress { address: String },
+    #[returns(nft_marketplace_utils::partner_perks::PartnerPerks)]
+    GetPartnerPerks {},
+    // Discount and perk level of the address once its partner holdings are combined
+    #[returns(nft_marketplace_utils::partner_perks::HolderPerks)]
+    GetHolderPerks { address: String },
+    // Reverse lookup of the username of an address
+    #[returns(Option<String>)]
+    GetUsername { address: String },
+    #[returns(nft_marketplace_utils::username::UsernameStatus)]
+    GetUsernameStatus { username: String },
+    #[returns(u64)]
+    GetUsernameRenameCooldown {},
+    #[returns(Option<nft_marketplace_utils::username::UsernameSale>)]
+    GetUsernameSale { username: String },
+    // Sorted by lowercase username, start_after is the username of the last sale received
+    #[returns(Vec<nft_marketplace_utils::username::UsernameSale>)]
+    GetUsernamesForSale {
+        start_after: Option<String>,
+        output_length: Option<u32>,
+    },
+    #[returns(Vec<nft_marketplace_utils::verification::Verification>)]
+    GetVerifications {
+        subject: VerificationSubject,
+        include_revoked: bool,
+    },
+    #[returns(Vec<String>)]
+    GetModerators {
+        start_after: Option<String>,
+        output_length: Option<u32>,
+    },
+    #[returns(Vec<String>)]
+    GetReservedUsernames {
+        start_after: Option<String>,
+        output_length: Option<u32>,
+    },
+    // Received messages, newest first; start_after is the message_id of the last message received
+    #[returns(Vec<nft_marketplace_utils::inbox::InboxMessage>)]
+    GetInbox {
+        address: String,
+        start_after: Option<u64>,
+        output_length: Option<u32>,
+    },
+    // Conversations by latest message; start_after is the last_message_id of the last thread received
+    #[returns(Vec<nft_marketplace_utils::inbox::InboxThreadInfo>)]
+    GetInboxThreads {
+        address: String,
+        start_after: Option<u64>,
+        output_length: Option<u32>,
+    },
+    // Messages of a conversation in both directions, newest first
+    #[returns(Vec<nft_marketplace_utils::inbox::InboxMessage>)]
+    GetInboxThread {
+        address: String,
+        counterparty: String,
+        start_after: Option<u64>,
+        output_length: Option<u32>,
+    },
+    #[returns(nft_marketplace_utils::messaging::MessagingConfig)]
+    GetMessagingConfig {},
+    #[returns(nft_marketplace_utils::wash_trading::WashTradingConfig)]
+    GetWashTradingConfig {},
+    #[returns(Option<nft_marketplace_utils::staking::StakingConfig>)]
+    GetStakingConfig {},
+    #[returns(nft_marketplace_utils::staking::StakingStats)]
+    GetStakingStats {},
+    // Fees to claim include the ones collected since the last distribution
+    #[returns(nft_marketplace_utils::staking::StakerInfo)]
+    GetStakerInfo { address: String },
+    #[returns(nft_marketplace_utils::messaging::MessageFilter)]
+    GetMessageFilter { address: String },
+    #[returns(Vec<String>)]
+    GetBlockedAddresses {
+        address: String,
+        start_after: Option<String>,
+        output_length: Option<u32>,
+    },
+    // Sorted by follower address, start_after is the follower of the last follow received
+    #[returns(Vec<nft_marketplace_utils::follow::Follow>)]
+    GetFollowers {
+        target: FollowTarget,
+        start_after: Option<String>,
+        output_length: Option<u32>,
+    },
+    #[returns(Vec<nft_marketplace_utils::follow::Follow>)]
+    GetFollowing {
+        address: String,
+        start_after: Option<FollowTarget>,
+        output_length: Option<u32>,
+    },
+    #[returns(Vec<nft_marketplace_utils::follow::WatchedToken>)]
+    GetWatchlist {
+        address: String,
+        start_after: Option<(NftCollectionAddress, TokenId)>,
+        output_length: Option<u32>,
+    },
+    // Sales of the watched tokens that can be bought now
+    #[returns(Vec<nft_marketplace_utils::nft_sale::NftSale>)]
+    GetWatchlistListings {
+        address: String,
+        start_after: Option<(NftCollectionAddress, TokenId)>,
+        output_length: Option<u32>,
+    },
     #[returns(nft_marketplace_utils::nft_offer::NftOffer)]
     GetAllOffersTokenId {
         token_id: TokenId,
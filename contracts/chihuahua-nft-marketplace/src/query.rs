@@ -1,38 +1,82 @@
 use std::marker::PhantomData;
 
 use cosmwasm_std::{
-    to_binary, Addr, Deps, Empty, Order, QueryRequest, StdResult, Uint128, WasmQuery,
+    to_binary, Addr, Deps, Empty, Env, Order, QueryRequest, StdError, StdResult, Timestamp,
+    Uint128, WasmQuery,
 };
 use cw721::{Cw721QueryMsg, TokensResponse};
 use cw721_base::helpers::Cw721Contract;
 use cw_storage_plus::Bound;
 
+use general_utils::denominations::{Denomination, DenominationValue};
+use nft_marketplace_utils::activity::{activities, Activity, AddressActivity, AddressActivityKind};
+use nft_marketplace_utils::circuit_breaker::{CircuitBreaker, MarketplaceCapability};
+use nft_marketplace_utils::competition::{
+    competition_traders, Competition, CompetitionPrize, CompetitionTrader,
+};
 use nft_marketplace_utils::config::{Config, ConfigRewardGenStatsMsg};
+use nft_marketplace_utils::follow::{Follow, FollowTarget, WatchedToken};
+use nft_marketplace_utils::inbox::{
+    define_thread_id, inbox_messages, inbox_threads, InboxMessage, InboxThreadInfo,
+};
+use nft_marketplace_utils::leaderboard::{
+    trader_stats, LeaderboardCursor, LeaderboardPeriod, LeaderboardRanking, LeaderboardSeason,
+    TraderStats, ALL_TIME_PERIOD,
+};
 use nft_marketplace_utils::marketplace_statistics::MarketplaceStatsByDenom;
+use nft_marketplace_utils::messaging::{MessageFilter, MessagingConfig};
 use nft_marketplace_utils::nft_collection::{
-    nft_collection_denoms, NftCollectionAddress, NftCollectionAddressTokenId,
-    NftCollectionInfoAndUsdcVol, NftCollectionInfoByDenom, NftContractInfo, NftContractType,
-    TokenId,
+    define_unique_collection_by_denom_id, nft_collection_denoms, NftCollectionAddress,
+    NftCollectionAddressTokenId, NftCollectionInfoAndUsdcVol, NftCollectionInfoResponse,
+    NftContractInfo, NftContractType, TokenId,
 };
 use nft_marketplace_utils::nft_offer::{nft_offers, NftOffer};
 use nft_marketplace_utils::nft_sale::{
-    define_unique_collection_nft_id, nfts_for_sale, NftSale, TokenSaleHistory, TokensAndIfSaleInfo,
+    define_unique_collection_nft_id, nft_listing_times, nfts_for_sale, sold_nfts, NftSale,
+    NftSaleCursor, NftSaleListing, PriceOrder, SoldNft, TokenSaleHistory, TokensAndIfSaleInfo,
 };
-use nft_marketplace_utils::profile::Profile;
+use nft_marketplace_utils::partner_perks::{HolderPerks, PartnerPerks};
+use nft_marketplace_utils::price_history::{
+    day_start_seconds, CollectionRollingStats, DailyPriceBucket, RollingWindow,
+};
+use nft_marketplace_utils::profile::{Profile, ProfileResponse};
+use nft_marketplace_utils::reward_system::{RewardPool, VipProgress};
+use nft_marketplace_utils::reward_vesting::RewardVestingInfo;
+use nft_marketplace_utils::staking::{FeeIndex, StakerInfo, StakingConfig, StakingStats};
+use nft_marketplace_utils::tokenfactory::RewardMinting;
+use nft_marketplace_utils::username::{
+    normalize_username, validate_username, UsernameSale, UsernameStatus,
+};
+use nft_marketplace_utils::verification::{Verification, VerificationSubject};
+use nft_marketplace_utils::wash_trading::WashTradingConfig;
 
-use crate::constants::{MARKETPLACE_USDC_INDICATOR, MAX_NFT_PER_COLLECTION};
+use crate::constants::{
+    DEFAULT_USERNAME_RENAME_COOLDOWN_SECONDS, MARKETPLACE_USDC_INDICATOR, MAX_NFT_PER_COLLECTION,
+    MAX_PRICE,
+};
+use crate::helpers::circuit_breakers::load_circuit_breaker;
+use crate::helpers::partner_perks::holder_perks;
+use crate::helpers::rolling_stats::{compute_rolling_window_stats, refresh_trending_collections};
+use crate::helpers::staking::{distributed_fee_indexes, settled_staker_fees};
+use crate::helpers::username::resolve_username;
 use crate::state::{
-    CONFIG, GENERAL_STATS, LISTED_NFT_COLLECTIONS_INFO_AND_USDC_VOL, MARKETPLACE_STATS_BY_DENOM,
-    PROFILES, REWARD_SYSTEM, TOKEN_SALE_HISTORY, USERNAMES,
+    ADDRESS_ACTIVITIES, BLOCKED_ADDRESSES, COMPETITIONS, COMPETITION_PRIZES, CONFIG,
+    DAILY_PRICE_BUCKETS, FOLLOWERS, FOLLOWING, GENERAL_STATS, LEADERBOARD_SEASON,
+    LISTED_NFT_COLLECTIONS_INFO_AND_USDC_VOL, MARKETPLACE_STATS_BY_DENOM, MESSAGE_FILTERS,
+    MESSAGING_CONFIG, MODERATORS, PARTNER_COLLECTIONS, PERKS_COMBINATION, PROFILES,
+    RESERVED_USERNAMES, REWARDS_TO_CLAIM, REWARD_MINTING, REWARD_POOL, REWARD_SYSTEM,
+    REWARD_VESTINGS, STAKES, STAKING_CONFIG, STAKING_STATS, TOKEN_SALE_HISTORY, UNBONDINGS,
+    USERNAME_RENAME_COOLDOWN, USERNAME_SALES, VERIFICATIONS, WASH_TRADING_CONFIG, WATCHLISTS,
 };
 
 const MAX_OUTPUT_LENGTH: u32 = 500;
 const DEFAULT_OUTPUT_LENGTH: u32 = 20;
 
-pub fn query_config(deps: Deps) -> StdResult<ConfigRewardGenStatsMsg> {
+pub fn query_config(deps: Deps, env: Env) -> StdResult<ConfigRewardGenStatsMsg> {
     let config = CONFIG.load(deps.storage)?;
     let reward_system = REWARD_SYSTEM.load(deps.storage)?;
-    let general_stats = GENERAL_STATS.load(deps.storage)?;
+    let mut general_stats = GENERAL_STATS.load(deps.storage)?;
+    refresh_trending_collections(deps.storage, &mut general_stats, env.block.time)?;
     Ok(ConfigRewardGenStatsMsg {
         contract_enabled: config.contract_enabled,
         contract_owner: config.contract_owner,
@@ -46,18 +90,56 @@ pub fn query_config(deps: Deps) -> StdResult<ConfigRewardGenStatsMsg> {
     })
 }
 
+pub fn query_reward_pool(deps: Deps) -> StdResult<RewardPool> {
+    Ok(REWARD_POOL.may_load(deps.storage)?.unwrap_or_default())
+}
+
+pub fn query_reward_minting(deps: Deps) -> StdResult<Option<RewardMinting>> {
+    REWARD_MINTING.may_load(deps.storage)
+}
+
+pub fn query_rewards_to_claim(deps: Deps, env: Env, address: String) -> StdResult<Uint128> {
+    let rewards_to_claim = REWARDS_TO_CLAIM
+        .may_load(deps.storage, &address)?
+        .unwrap_or_default();
+    Ok(rewards_to_claim + query_reward_vesting(deps, env, address)?.vested)
+}
+
+pub fn query_reward_vesting(deps: Deps, env: Env, address: String) -> StdResult<RewardVestingInfo> {
+    let entries = REWARD_VESTINGS
+        .may_load(deps.storage, &address)?
+        .unwrap_or_default();
+    Ok(RewardVestingInfo::new(entries, env.block.time))
+}
+
+pub fn query_circuit_breakers(deps: Deps) -> StdResult<Vec<CircuitBreaker>> {
+    MarketplaceCapability::all()
+        .into_iter()
+        .map(|capability| load_circuit_breaker(deps.storage, capability))
+        .collect()
+}
+
 pub fn query_nft_collection(
     deps: Deps,
     nft_collection_address: NftCollectionAddress,
-) -> StdResult<Vec<NftCollectionInfoByDenom>> {
+) -> StdResult<NftCollectionInfoResponse> {
     let nft_collection_denoms_info = nft_collection_denoms()
         .idx
         .collection_index
-        .prefix(Addr::unchecked(nft_collection_address))
+        .prefix(Addr::unchecked(nft_collection_address.clone()))
         .range(deps.storage, None, None, Order::Ascending)
         .map(|std_result| std_result.map(|item| item.1))
         .collect::<StdResult<Vec<_>>>()?;
-    Ok(nft_collection_denoms_info)
+    let verifications = query_active_verifications(
+        deps,
+        VerificationSubject::Collection {
+            nft_collection_address,
+        },
+    )?;
+    Ok(NftCollectionInfoResponse {
+        info_by_denom: nft_collection_denoms_info,
+        verifications,
+    })
 }
 
 pub fn query_marketplace_info(deps: Deps) -> StdResult<Vec<MarketplaceStatsByDenom>> {
@@ -144,24 +226,146 @@ pub fn query_nft_trade_history(
     }
 }
 
-pub fn query_profile_info(deps: Deps, address_or_username: String) -> StdResult<Profile> {
+pub fn query_trader_leaderboard(
+    deps: Deps,
+    ranking: LeaderboardRanking,
+    period: LeaderboardPeriod,
+    start_after: Option<LeaderboardCursor>,
+    output_length: Option<u32>,
+) -> StdResult<Vec<TraderStats>> {
+    let max_size = output_length
+        .unwrap_or(DEFAULT_OUTPUT_LENGTH)
+        .min(MAX_OUTPUT_LENGTH) as usize;
+    let period_id = match period {
+        LeaderboardPeriod::AllTime => ALL_TIME_PERIOD,
+        LeaderboardPeriod::CurrentSeason => query_leaderboard_season(deps)?.season_id,
+    };
+    let end = start_after
+        .map(|cursor| Bound::exclusive((cursor.value.u128(), (cursor.address, period_id))));
+    let stats = trader_stats();
+    // Only visible traders, from the highest value to the lowest and without the zero values
+    // (sellers that never bought are not top buyers)
+    stats
+        .idx
+        .by_ranking(&ranking)
+        .sub_prefix((period_id, 1u8))
+        .range(deps.storage, None, end, Order::Descending)
+        .map(|result| result.map(|(_, trader)| trader))
+        .take_while(|result| {
+            result
+                .as_ref()
+                .map_or(true, |trader| !trader.ranking_value(&ranking).is_zero())
+        })
+        .take(max_size)
+        .collect()
+}
+
+pub fn query_leaderboard_season(deps: Deps) -> StdResult<LeaderboardSeason> {
+    Ok(LEADERBOARD_SEASON
+        .may_load(deps.storage)?
+        .unwrap_or_default())
+}
+
+pub fn query_competition(deps: Deps, competition_id: u64) -> StdResult<Competition> {
+    COMPETITIONS
+        .may_load(deps.storage, competition_id)?
+        .ok_or_else(|| StdError::not_found("competition"))
+}
+
+pub fn query_competitions(
+    deps: Deps,
+    start_after: Option<u64>,
+    output_length: Option<u32>,
+) -> StdResult<Vec<Competition>> {
+    let max_size = output_length
+        .unwrap_or(DEFAULT_OUTPUT_LENGTH)
+        .min(MAX_OUTPUT_LENGTH) as usize;
+    COMPETITIONS
+        .range(
+            deps.storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(max_size)
+        .map(|result| result.map(|(_, competition)| competition))
+        .collect()
+}
+
+pub fn query_competition_leaderboard(
+    deps: Deps,
+    competition_id: u64,
+    start_after: Option<LeaderboardCursor>,
+    output_length: Option<u32>,
+) -> StdResult<Vec<CompetitionTrader>> {
+    let max_size = output_length
+        .unwrap_or(DEFAULT_OUTPUT_LENGTH)
+        .min(MAX_OUTPUT_LENGTH) as usize;
+    // The time of the trader is part of the index key, the cursor only has its volume
+    let end = start_after
+        .map(|cursor| -> StdResult<_> {
+            let trader = competition_traders()
+                .load(deps.storage, (competition_id, cursor.address.clone()))?;
+            Ok(Bound::exclusive((
+                trader.volume_index_suffix(),
+                (competition_id, cursor.address),
+            )))
+        })
+        .transpose()?;
+    competition_traders()
+        .idx
+        .volume_index
+        .sub_prefix(competition_id)
+        .range(deps.storage, None, end, Order::Descending)
+        .take(max_size)
+        .map(|result| result.map(|(_, trader)| trader))
+        .collect()
+}
+
+pub fn query_competition_prizes(
+    deps: Deps,
+    competition_id: u64,
+) -> StdResult<Vec<CompetitionPrize>> {
+    let mut prizes = COMPETITION_PRIZES
+        .prefix(competition_id)
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|result| result.map(|(_, prize)| prize))
+        .collect::<StdResult<Vec<_>>>()?;
+    prizes.sort_by_key(|prize| prize.rank);
+    Ok(prizes)
+}
+
+// The VIP level is shown with its expiration applied
+pub fn query_profile_info(
+    deps: Deps,
+    env: Env,
+    address_or_username: String,
+) -> StdResult<ProfileResponse> {
     let address: String;
     let username: String;
     let profile: Option<Profile>;
     if deps.api.addr_validate(&address_or_username).is_err() {
         // if it is an error -> will be username
         username = address_or_username;
-        let address_result = USERNAMES.load(deps.storage, &username)?;
-        address = (*address_result).parse().unwrap();
+        address = resolve_username(deps.storage, &username)?
+            .ok_or_else(|| StdError::not_found("username"))?;
         profile = PROFILES.may_load(deps.storage, &address)?;
     } else {
         address = address_or_username;
         profile = PROFILES.may_load(deps.storage, &address)?;
     }
-    Ok(profile.unwrap_or(Profile {
+    let verifications = query_active_verifications(
+        deps,
+        VerificationSubject::Profile {
+            address: address.clone(),
+        },
+    )?;
+    let mut profile = profile.unwrap_or(Profile {
         address,
         username: None,
         vip_level: None,
+        vip_expiration: None,
+        volume_usdc: None,
         profile_nft_collection: None,
         profile_nft_token_id: None,
         background_nft_collection: None,
@@ -169,12 +373,67 @@ pub fn query_profile_info(deps: Deps, address_or_username: String) -> StdResult<
         description: None,
         nft_showcase: None,
         links: None,
-        profile_messages: None,
         number_of_trades: None,
         buy_info: None,
         sell_info: None,
         display_trade_info: None,
-    }))
+    });
+    if profile.vip_expiration.is_some() {
+        profile = profile.expire_vip_level(&REWARD_SYSTEM.load(deps.storage)?, env.block.time);
+    }
+    Ok(ProfileResponse {
+        profile,
+        verifications,
+    })
+}
+
+pub fn query_vip_progress(deps: Deps, env: Env, address: String) -> StdResult<VipProgress> {
+    let reward_system = REWARD_SYSTEM.load(deps.storage)?;
+    let profile = PROFILES
+        .may_load(deps.storage, &address)?
+        .map(|profile| profile.expire_vip_level(&reward_system, env.block.time));
+    let vip_level = profile
+        .as_ref()
+        .and_then(|profile| profile.vip_level)
+        .unwrap_or_default();
+    let volume_usdc = profile
+        .and_then(|profile| profile.volume_usdc)
+        .unwrap_or_default();
+    let next_volume_vip_tier = reward_system.next_volume_vip_tier(vip_level);
+    let next_volume_usdc_threshold =
+        next_volume_vip_tier.and_then(|(_, vip_tier)| vip_tier.volume_usdc_threshold);
+    Ok(VipProgress {
+        address,
+        vip_level,
+        volume_usdc,
+        next_vip_level: next_volume_vip_tier.map(|(next_vip_level, _)| next_vip_level),
+        next_tier_name: next_volume_vip_tier.map(|(_, vip_tier)| vip_tier.name.clone()),
+        next_volume_usdc_threshold,
+        volume_usdc_to_next_tier: next_volume_usdc_threshold
+            .map(|threshold| threshold.saturating_sub(volume_usdc)),
+    })
+}
+
+pub fn query_partner_perks(deps: Deps) -> StdResult<PartnerPerks> {
+    Ok(PartnerPerks {
+        partner_collections: PARTNER_COLLECTIONS
+            .range(deps.storage, None, None, Order::Ascending)
+            .map(|item| item.map(|(_, partner_collection)| partner_collection))
+            .collect::<StdResult<Vec<_>>>()?,
+        perks_combination: PERKS_COMBINATION
+            .may_load(deps.storage)?
+            .unwrap_or_default(),
+    })
+}
+
+pub fn query_holder_perks(deps: Deps, env: Env, address: String) -> StdResult<HolderPerks> {
+    let reward_system = REWARD_SYSTEM.load(deps.storage)?;
+    holder_perks(
+        deps,
+        &reward_system,
+        env.block.time,
+        deps.api.addr_validate(&address)?.as_ref(),
+    )
 }
 
 pub fn query_nfts_for_sale_from_seller(
@@ -368,3 +627,725 @@ pub fn query_nfts_for_sale_from_collection(
         .collect::<StdResult<Vec<_>>>()?;
     Ok(nfts_for_sale_info)
 }
+
+#[allow(clippy::too_many_arguments)]
+pub fn query_nfts_for_sale_by_price(
+    deps: Deps,
+    nft_collection_address: NftCollectionAddress,
+    denom: Denomination,
+    order: PriceOrder,
+    min_price: Option<Uint128>,
+    max_price: Option<Uint128>,
+    start_after: Option<(Uint128, TokenId)>,
+    output_length: Option<u32>,
+) -> StdResult<Vec<NftSale>> {
+    let max_size = output_length
+        .unwrap_or(DEFAULT_OUTPUT_LENGTH)
+        .min(MAX_OUTPUT_LENGTH) as usize;
+    // Index keys are (price, collection + token id), an empty id is the first key of a price
+    let min_bound = min_price.map(|price| Bound::inclusive((price.u128(), String::new())));
+    let max_bound = max_price.and_then(|price| {
+        price
+            .u128()
+            .checked_add(1)
+            .map(|price| Bound::exclusive((price, String::new())))
+    });
+    let cursor = start_after.map(|(price, token_id)| {
+        Bound::exclusive((
+            price.u128(),
+            define_unique_collection_nft_id(&nft_collection_address, &token_id),
+        ))
+    });
+    let (min_bound, max_bound, order) = match order {
+        PriceOrder::Ascending => (cursor.or(min_bound), max_bound, Order::Ascending),
+        PriceOrder::Descending => (min_bound, cursor.or(max_bound), Order::Descending),
+    };
+    nfts_for_sale()
+        .idx
+        .collection_denom_price_index
+        .sub_prefix((nft_collection_address, denom))
+        .range(deps.storage, min_bound, max_bound, order)
+        .take(max_size)
+        .map(|std_result| std_result.map(|item| item.1))
+        .collect()
+}
+
+pub fn query_recently_listed(
+    deps: Deps,
+    nft_collection_address: Option<NftCollectionAddress>,
+    start_after: Option<NftSaleCursor>,
+    output_length: Option<u32>,
+) -> StdResult<Vec<NftSaleListing>> {
+    let max_size = output_length
+        .unwrap_or(DEFAULT_OUTPUT_LENGTH)
+        .min(MAX_OUTPUT_LENGTH) as usize;
+    let end = start_after.map(|cursor| {
+        Bound::exclusive((
+            cursor.time.seconds(),
+            define_unique_collection_nft_id(&cursor.nft_collection_address, &cursor.token_id),
+        ))
+    });
+    let listing_times = nft_listing_times();
+    let listings = match nft_collection_address {
+        Some(nft_collection_address) => listing_times
+            .idx
+            .collection_listed_at_index
+            .sub_prefix(nft_collection_address)
+            .range(deps.storage, None, end, Order::Descending)
+            .take(max_size)
+            .collect::<StdResult<Vec<_>>>()?,
+        None => listing_times
+            .idx
+            .listed_at_index
+            .range(deps.storage, None, end, Order::Descending)
+            .take(max_size)
+            .collect::<StdResult<Vec<_>>>()?,
+    };
+    listings
+        .into_iter()
+        .map(|(collection_token_id_unique, listing)| {
+            Ok(NftSaleListing {
+                listed_at: listing.listed_at,
+                nft_sale: nfts_for_sale().load(deps.storage, collection_token_id_unique)?,
+            })
+        })
+        .collect()
+}
+
+pub fn query_ending_soon(
+    deps: Deps,
+    env: Env,
+    nft_collection_address: Option<NftCollectionAddress>,
+    start_after: Option<NftSaleCursor>,
+    output_length: Option<u32>,
+) -> StdResult<Vec<NftSale>> {
+    let max_size = output_length
+        .unwrap_or(DEFAULT_OUTPUT_LENGTH)
+        .min(MAX_OUTPUT_LENGTH) as usize;
+    // Expired sales can not be bought anymore, an empty id is the first key of a second
+    let start = match start_after {
+        Some(cursor) => Bound::exclusive((
+            cursor.time.seconds(),
+            define_unique_collection_nft_id(&cursor.nft_collection_address, &cursor.token_id),
+        )),
+        None => Bound::exclusive((env.block.time.seconds(), String::new())),
+    };
+    let sales = nfts_for_sale();
+    match nft_collection_address {
+        Some(nft_collection_address) => sales
+            .idx
+            .collection_expiration_index
+            .sub_prefix(nft_collection_address)
+            .range(deps.storage, Some(start), None, Order::Ascending)
+            .take(max_size)
+            .map(|std_result| std_result.map(|item| item.1))
+            .collect(),
+        None => sales
+            .idx
+            .expiration_index
+            .range(deps.storage, Some(start), None, Order::Ascending)
+            .take(max_size)
+            .map(|std_result| std_result.map(|item| item.1))
+            .collect(),
+    }
+}
+
+pub fn query_recently_sold(
+    deps: Deps,
+    nft_collection_address: Option<NftCollectionAddress>,
+    start_after: Option<u64>,
+    output_length: Option<u32>,
+) -> StdResult<Vec<SoldNft>> {
+    let max_size = output_length
+        .unwrap_or(DEFAULT_OUTPUT_LENGTH)
+        .min(MAX_OUTPUT_LENGTH) as usize;
+    let sold = sold_nfts();
+    let sales = match nft_collection_address {
+        Some(nft_collection_address) => sold
+            .idx
+            .collection_index
+            .prefix(nft_collection_address)
+            .range(
+                deps.storage,
+                None,
+                start_after.map(Bound::exclusive),
+                Order::Descending,
+            )
+            .take(max_size)
+            .collect::<StdResult<Vec<_>>>()?,
+        None => sold
+            .range(
+                deps.storage,
+                None,
+                start_after.map(Bound::exclusive),
+                Order::Descending,
+            )
+            .take(max_size)
+            .collect::<StdResult<Vec<_>>>()?,
+    };
+    Ok(sales
+        .into_iter()
+        .map(|(sale_id, sale_info)| SoldNft { sale_id, sale_info })
+        .collect())
+}
+
+pub fn query_activity(
+    deps: Deps,
+    start_after: Option<u64>,
+    output_length: Option<u32>,
+) -> StdResult<Vec<Activity>> {
+    let max_size = output_length
+        .unwrap_or(DEFAULT_OUTPUT_LENGTH)
+        .min(MAX_OUTPUT_LENGTH) as usize;
+    activities()
+        .range(
+            deps.storage,
+            None,
+            start_after.map(Bound::exclusive),
+            Order::Descending,
+        )
+        .take(max_size)
+        .map(|std_result| std_result.map(|item| item.1))
+        .collect()
+}
+
+pub fn query_collection_activity(
+    deps: Deps,
+    nft_collection_address: NftCollectionAddress,
+    start_after: Option<u64>,
+    output_length: Option<u32>,
+) -> StdResult<Vec<Activity>> {
+    let max_size = output_length
+        .unwrap_or(DEFAULT_OUTPUT_LENGTH)
+        .min(MAX_OUTPUT_LENGTH) as usize;
+    activities()
+        .idx
+        .collection_index
+        .prefix(nft_collection_address)
+        .range(
+            deps.storage,
+            None,
+            start_after.map(Bound::exclusive),
+            Order::Descending,
+        )
+        .take(max_size)
+        .map(|std_result| std_result.map(|item| item.1))
+        .collect()
+}
+
+pub fn query_token_activity(
+    deps: Deps,
+    nft_collection_address: NftCollectionAddress,
+    token_id: TokenId,
+    start_after: Option<u64>,
+    output_length: Option<u32>,
+) -> StdResult<Vec<Activity>> {
+    let max_size = output_length
+        .unwrap_or(DEFAULT_OUTPUT_LENGTH)
+        .min(MAX_OUTPUT_LENGTH) as usize;
+    activities()
+        .idx
+        .token_index
+        .prefix(define_unique_collection_nft_id(
+            &nft_collection_address,
+            &token_id,
+        ))
+        .range(
+            deps.storage,
+            None,
+            start_after.map(Bound::exclusive),
+            Order::Descending,
+        )
+        .take(max_size)
+        .map(|std_result| std_result.map(|item| item.1))
+        .collect()
+}
+
+pub fn query_address_activity(
+    deps: Deps,
+    address: String,
+    kinds: Option<Vec<AddressActivityKind>>,
+    start_after: Option<u64>,
+    output_length: Option<u32>,
+) -> StdResult<Vec<AddressActivity>> {
+    let max_size = output_length
+        .unwrap_or(DEFAULT_OUTPUT_LENGTH)
+        .min(MAX_OUTPUT_LENGTH) as usize;
+    ADDRESS_ACTIVITIES
+        .prefix(&address)
+        .range(
+            deps.storage,
+            None,
+            start_after.map(Bound::exclusive),
+            Order::Descending,
+        )
+        .filter(|std_result| match (std_result, &kinds) {
+            (Ok((_, kind)), Some(kinds)) => kinds.contains(kind),
+            _ => true,
+        })
+        .take(max_size)
+        .map(|std_result| {
+            let (activity_id, kind) = std_result?;
+            Ok(AddressActivity {
+                kind,
+                activity: activities().load(deps.storage, activity_id)?,
+            })
+        })
+        .collect()
+}
+
+pub fn query_inbox(
+    deps: Deps,
+    address: String,
+    start_after: Option<u64>,
+    output_length: Option<u32>,
+) -> StdResult<Vec<InboxMessage>> {
+    let max_size = output_length
+        .unwrap_or(DEFAULT_OUTPUT_LENGTH)
+        .min(MAX_OUTPUT_LENGTH) as usize;
+    inbox_messages()
+        .idx
+        .recipient_index
+        .prefix(address)
+        .range(
+            deps.storage,
+            None,
+            start_after.map(Bound::exclusive),
+            Order::Descending,
+        )
+        .take(max_size)
+        .map(|std_result| std_result.map(|item| item.1))
+        .collect()
+}
+
+pub fn query_inbox_threads(
+    deps: Deps,
+    address: String,
+    start_after: Option<u64>,
+    output_length: Option<u32>,
+) -> StdResult<Vec<InboxThreadInfo>> {
+    let max_size = output_length
+        .unwrap_or(DEFAULT_OUTPUT_LENGTH)
+        .min(MAX_OUTPUT_LENGTH) as usize;
+    // Empty keys sort first, so the bound excludes every thread of the cursor message
+    let end = start_after
+        .map(|last_message_id| Bound::exclusive((last_message_id, (String::new(), String::new()))));
+    inbox_threads()
+        .idx
+        .last_message_index
+        .sub_prefix(address.clone())
+        .range(deps.storage, None, end, Order::Descending)
+        .take(max_size)
+        .map(|std_result| {
+            let (_, thread) = std_result?;
+            // Received messages after the read marker
+            let unread_messages = inbox_messages()
+                .idx
+                .thread_index
+                .prefix(define_thread_id(&thread.address, &thread.counterparty))
+                .range(
+                    deps.storage,
+                    thread.last_read_message_id.map(Bound::exclusive),
+                    None,
+                    Order::Ascending,
+                )
+                .filter(|message| {
+                    message
+                        .as_ref()
+                        .map_or(true, |(_, message)| message.to_address == address)
+                })
+                .count() as u64;
+            Ok(InboxThreadInfo {
+                thread,
+                unread_messages,
+            })
+        })
+        .collect()
+}
+
+pub fn query_inbox_thread(
+    deps: Deps,
+    address: String,
+    counterparty: String,
+    start_after: Option<u64>,
+    output_length: Option<u32>,
+) -> StdResult<Vec<InboxMessage>> {
+    let max_size = output_length
+        .unwrap_or(DEFAULT_OUTPUT_LENGTH)
+        .min(MAX_OUTPUT_LENGTH) as usize;
+    inbox_messages()
+        .idx
+        .thread_index
+        .prefix(define_thread_id(&address, &counterparty))
+        .range(
+            deps.storage,
+            None,
+            start_after.map(Bound::exclusive),
+            Order::Descending,
+        )
+        .take(max_size)
+        .map(|std_result| std_result.map(|item| item.1))
+        .collect()
+}
+
+pub fn query_messaging_config(deps: Deps) -> StdResult<MessagingConfig> {
+    Ok(MESSAGING_CONFIG.may_load(deps.storage)?.unwrap_or_default())
+}
+
+pub fn query_wash_trading_config(deps: Deps) -> StdResult<WashTradingConfig> {
+    Ok(WASH_TRADING_CONFIG
+        .may_load(deps.storage)?
+        .unwrap_or_default())
+}
+
+pub fn query_staking_config(deps: Deps) -> StdResult<Option<StakingConfig>> {
+    STAKING_CONFIG.may_load(deps.storage)
+}
+
+pub fn query_staking_stats(deps: Deps) -> StdResult<StakingStats> {
+    Ok(STAKING_STATS.may_load(deps.storage)?.unwrap_or_default())
+}
+
+pub fn query_staker_info(deps: Deps, address: String) -> StdResult<StakerInfo> {
+    let fee_indexes: Vec<(Denomination, FeeIndex)> = distributed_fee_indexes(deps.storage)?
+        .into_iter()
+        .map(|(denom, fee_index, _)| (denom, fee_index))
+        .collect();
+    let fees_to_claim = settled_staker_fees(deps.storage, &address, &fee_indexes)?
+        .into_iter()
+        .filter(|(_, staker_fees)| !staker_fees.fees_to_claim.is_zero())
+        .map(|(denom, staker_fees)| DenominationValue {
+            denom,
+            value: staker_fees.fees_to_claim,
+        })
+        .collect();
+    Ok(StakerInfo {
+        staked: STAKES.may_load(deps.storage, &address)?.unwrap_or_default(),
+        unbondings: UNBONDINGS
+            .may_load(deps.storage, &address)?
+            .unwrap_or_default(),
+        address,
+        fees_to_claim,
+    })
+}
+
+pub fn query_message_filter(deps: Deps, address: String) -> StdResult<MessageFilter> {
+    Ok(MESSAGE_FILTERS
+        .may_load(deps.storage, &address)?
+        .unwrap_or_default())
+}
+
+pub fn query_username(deps: Deps, address: String) -> StdResult<Option<String>> {
+    Ok(PROFILES
+        .may_load(deps.storage, &address)?
+        .and_then(|profile| profile.username))
+}
+
+pub fn query_username_status(deps: Deps, username: String) -> StdResult<UsernameStatus> {
+    if validate_username(deps.api, &username).is_err() {
+        return Ok(UsernameStatus::Invalid);
+    }
+    if RESERVED_USERNAMES.has(deps.storage, &normalize_username(&username)) {
+        return Ok(UsernameStatus::Reserved);
+    }
+    Ok(match resolve_username(deps.storage, &username)? {
+        Some(address) => UsernameStatus::Taken { address },
+        None => UsernameStatus::Available,
+    })
+}
+
+pub fn query_username_rename_cooldown(deps: Deps) -> StdResult<u64> {
+    Ok(USERNAME_RENAME_COOLDOWN
+        .may_load(deps.storage)?
+        .unwrap_or(DEFAULT_USERNAME_RENAME_COOLDOWN_SECONDS))
+}
+
+pub fn query_username_sale(deps: Deps, username: String) -> StdResult<Option<UsernameSale>> {
+    USERNAME_SALES.may_load(deps.storage, &normalize_username(&username))
+}
+
+pub fn query_usernames_for_sale(
+    deps: Deps,
+    start_after: Option<String>,
+    output_length: Option<u32>,
+) -> StdResult<Vec<UsernameSale>> {
+    let max_size = output_length
+        .unwrap_or(DEFAULT_OUTPUT_LENGTH)
+        .min(MAX_OUTPUT_LENGTH) as usize;
+    let start_after = start_after.map(|username| normalize_username(&username));
+    USERNAME_SALES
+        .range(
+            deps.storage,
+            start_after.as_deref().map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(max_size)
+        .map(|item| item.map(|(_, username_sale)| username_sale))
+        .collect()
+}
+
+pub fn query_reserved_usernames(
+    deps: Deps,
+    start_after: Option<String>,
+    output_length: Option<u32>,
+) -> StdResult<Vec<String>> {
+    let max_size = output_length
+        .unwrap_or(DEFAULT_OUTPUT_LENGTH)
+        .min(MAX_OUTPUT_LENGTH) as usize;
+    RESERVED_USERNAMES
+        .keys(
+            deps.storage,
+            start_after.as_deref().map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(max_size)
+        .collect()
+}
+
+// Revoked verifications are only returned with include_revoked
+pub fn query_verifications(
+    deps: Deps,
+    subject: VerificationSubject,
+    include_revoked: bool,
+) -> StdResult<Vec<Verification>> {
+    VERIFICATIONS
+        .prefix(&subject.key())
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, verification)| verification))
+        .filter(|item| {
+            include_revoked
+                || item
+                    .as_ref()
+                    .map_or(true, |verification| verification.is_active())
+        })
+        .collect()
+}
+
+fn query_active_verifications(
+    deps: Deps,
+    subject: VerificationSubject,
+) -> StdResult<Vec<Verification>> {
+    query_verifications(deps, subject, false)
+}
+
+pub fn query_moderators(
+    deps: Deps,
+    start_after: Option<String>,
+    output_length: Option<u32>,
+) -> StdResult<Vec<String>> {
+    let max_size = output_length
+        .unwrap_or(DEFAULT_OUTPUT_LENGTH)
+        .min(MAX_OUTPUT_LENGTH) as usize;
+    MODERATORS
+        .keys(
+            deps.storage,
+            start_after.as_deref().map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(max_size)
+        .collect()
+}
+
+pub fn query_blocked_addresses(
+    deps: Deps,
+    address: String,
+    start_after: Option<String>,
+    output_length: Option<u32>,
+) -> StdResult<Vec<String>> {
+    let max_size = output_length
+        .unwrap_or(DEFAULT_OUTPUT_LENGTH)
+        .min(MAX_OUTPUT_LENGTH) as usize;
+    BLOCKED_ADDRESSES
+        .prefix(&address)
+        .keys(
+            deps.storage,
+            start_after.as_deref().map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(max_size)
+        .collect()
+}
+
+pub fn query_followers(
+    deps: Deps,
+    target: FollowTarget,
+    start_after: Option<String>,
+    output_length: Option<u32>,
+) -> StdResult<Vec<Follow>> {
+    let max_size = output_length
+        .unwrap_or(DEFAULT_OUTPUT_LENGTH)
+        .min(MAX_OUTPUT_LENGTH) as usize;
+    FOLLOWERS
+        .prefix(&target.key())
+        .range(
+            deps.storage,
+            start_after.as_deref().map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(max_size)
+        .map(|std_result| {
+            std_result.map(|(follower, followed_at)| Follow {
+                follower,
+                target: target.clone(),
+                followed_at,
+            })
+        })
+        .collect()
+}
+
+pub fn query_following(
+    deps: Deps,
+    address: String,
+    start_after: Option<FollowTarget>,
+    output_length: Option<u32>,
+) -> StdResult<Vec<Follow>> {
+    let max_size = output_length
+        .unwrap_or(DEFAULT_OUTPUT_LENGTH)
+        .min(MAX_OUTPUT_LENGTH) as usize;
+    let start_after = start_after.map(|target| target.key());
+    FOLLOWING
+        .prefix(&address)
+        .range(
+            deps.storage,
+            start_after.as_deref().map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(max_size)
+        .map(|std_result| std_result.map(|item| item.1))
+        .collect()
+}
+
+pub fn query_watchlist(
+    deps: Deps,
+    address: String,
+    start_after: Option<(NftCollectionAddress, TokenId)>,
+    output_length: Option<u32>,
+) -> StdResult<Vec<WatchedToken>> {
+    let max_size = output_length
+        .unwrap_or(DEFAULT_OUTPUT_LENGTH)
+        .min(MAX_OUTPUT_LENGTH) as usize;
+    let start_after = start_after.map(|(nft_collection_address, token_id)| {
+        define_unique_collection_nft_id(&nft_collection_address, &token_id)
+    });
+    WATCHLISTS
+        .prefix(&address)
+        .range(
+            deps.storage,
+            start_after.as_deref().map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(max_size)
+        .map(|std_result| std_result.map(|item| item.1))
+        .collect()
+}
+
+pub fn query_watchlist_listings(
+    deps: Deps,
+    env: Env,
+    address: String,
+    start_after: Option<(NftCollectionAddress, TokenId)>,
+    output_length: Option<u32>,
+) -> StdResult<Vec<NftSale>> {
+    let max_size = output_length
+        .unwrap_or(DEFAULT_OUTPUT_LENGTH)
+        .min(MAX_OUTPUT_LENGTH) as usize;
+    let start_after = start_after.map(|(nft_collection_address, token_id)| {
+        define_unique_collection_nft_id(&nft_collection_address, &token_id)
+    });
+    // The watchlist shares its keys with nfts_for_sale(), expired sales can not be bought anymore
+    let mut nft_sales = vec![];
+    for collection_token_id_unique in WATCHLISTS.prefix(&address).keys(
+        deps.storage,
+        start_after.as_deref().map(Bound::exclusive),
+        None,
+        Order::Ascending,
+    ) {
+        if nft_sales.len() == max_size {
+            break;
+        }
+        if let Some(nft_sale) =
+            nfts_for_sale().may_load(deps.storage, collection_token_id_unique?)?
+        {
+            if nft_sale.sale_expiration > env.block.time {
+                nft_sales.push(nft_sale);
+            }
+        }
+    }
+    Ok(nft_sales)
+}
+
+pub fn query_nft_collection_rolling_stats(
+    deps: Deps,
+    env: Env,
+    nft_collection_address: NftCollectionAddress,
+) -> StdResult<Vec<CollectionRollingStats>> {
+    query_nft_collection(deps, nft_collection_address)?
+        .info_by_denom
+        .into_iter()
+        .map(|collection_denom| {
+            let collection_denom_unique = define_unique_collection_by_denom_id(
+                &collection_denom.nft_collection_address,
+                &collection_denom.denom,
+            );
+            // No listing is stored as MAX_PRICE
+            let current_floor = if collection_denom.current_floor < MAX_PRICE {
+                collection_denom.current_floor
+            } else {
+                Uint128::zero()
+            };
+            let windows = RollingWindow::all()
+                .into_iter()
+                .map(|window| {
+                    compute_rolling_window_stats(
+                        deps.storage,
+                        &collection_denom_unique,
+                        window,
+                        env.block.time,
+                        current_floor,
+                    )
+                })
+                .collect::<StdResult<Vec<_>>>()?;
+            Ok(CollectionRollingStats {
+                nft_collection_address: collection_denom.nft_collection_address,
+                denom: collection_denom.denom,
+                windows,
+            })
+        })
+        .collect()
+}
+
+pub fn query_collection_daily_price_history(
+    deps: Deps,
+    nft_collection_address: NftCollectionAddress,
+    denom: Denomination,
+    start_time: Option<Timestamp>,
+    end_time: Option<Timestamp>,
+    start_after: Option<Timestamp>,
+    output_length: Option<u32>,
+) -> StdResult<Vec<DailyPriceBucket>> {
+    let max_size = output_length
+        .unwrap_or(DEFAULT_OUTPUT_LENGTH)
+        .min(MAX_OUTPUT_LENGTH) as usize;
+    let collection_denom_unique =
+        define_unique_collection_by_denom_id(&nft_collection_address, &denom);
+    // The cursor takes precedence over the start of the time range
+    let min_bound = match start_after {
+        Some(start_after) => Some(Bound::exclusive(start_after.seconds())),
+        None => start_time.map(|start_time| Bound::inclusive(day_start_seconds(start_time))),
+    };
+    let max_bound = end_time.map(|end_time| Bound::inclusive(end_time.seconds()));
+    let daily_price_buckets = DAILY_PRICE_BUCKETS
+        .prefix(&collection_denom_unique)
+        .range(deps.storage, min_bound, max_bound, Order::Ascending)
+        .take(max_size)
+        .map(|std_result| std_result.map(|item| item.1))
+        .collect::<StdResult<Vec<_>>>()?;
+    Ok(daily_price_buckets)
+}
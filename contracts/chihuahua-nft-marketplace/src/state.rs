@@ -1,16 +1,62 @@
+use cosmwasm_std::{Empty, Timestamp, Uint128};
 use cw_storage_plus::{Item, Map};
 
+use nft_marketplace_utils::activity::AddressActivityKind;
+use nft_marketplace_utils::circuit_breaker::CircuitBreaker;
+use nft_marketplace_utils::competition::{Competition, CompetitionPrize};
 use nft_marketplace_utils::config::Config;
+use nft_marketplace_utils::follow::{Follow, WatchedToken};
+use nft_marketplace_utils::leaderboard::LeaderboardSeason;
 use nft_marketplace_utils::marketplace_statistics::{GeneralStats, MarketplaceStatsByDenom};
+use nft_marketplace_utils::messaging::{MessageFilter, MessageRateLimit, MessagingConfig};
+use nft_marketplace_utils::migration::MigrationProgress;
 use nft_marketplace_utils::nft_collection::NftCollectionInfoAndUsdcVol;
 use nft_marketplace_utils::nft_sale::TokenSaleHistory;
+use nft_marketplace_utils::partner_perks::{PartnerCollection, PerksCombination};
+use nft_marketplace_utils::price_history::{DailyPriceBucket, HourlyTradeStats};
 use nft_marketplace_utils::profile::Profile;
-use nft_marketplace_utils::reward_system::RewardSystem;
+use nft_marketplace_utils::reward_system::{RewardPool, RewardSystem};
+use nft_marketplace_utils::reward_vesting::VestingEntry;
+use nft_marketplace_utils::staking::{
+    FeeIndex, StakerFees, StakingConfig, StakingStats, Unbonding,
+};
+use nft_marketplace_utils::tokenfactory::RewardMinting;
+use nft_marketplace_utils::username::UsernameSale;
+use nft_marketplace_utils::verification::Verification;
+use nft_marketplace_utils::wash_trading::{EpochRewards, WashTradingConfig};
 
 pub const CONFIG: Item<Config> = Item::new("config");
 
 pub const REWARD_SYSTEM: Item<RewardSystem> = Item::new("reward_system");
 
+pub const PARTNER_COLLECTIONS: Map<&str, PartnerCollection> = Map::new("partner_collections");
+
+pub const PERKS_COMBINATION: Item<PerksCombination> = Item::new("perks_combination");
+
+pub const REWARD_POOL: Item<RewardPool> = Item::new("reward_pool");
+
+pub const REWARD_MINTING: Item<RewardMinting> = Item::new("reward_minting");
+
+pub const REWARDS_TO_CLAIM: Map<&str, Uint128> = Map::new("rewards_to_claim");
+
+pub const STAKING_CONFIG: Item<StakingConfig> = Item::new("staking_config");
+
+pub const STAKING_STATS: Item<StakingStats> = Item::new("staking_stats");
+
+pub const STAKES: Map<&str, Uint128> = Map::new("stakes");
+
+pub const UNBONDINGS: Map<&str, Vec<Unbonding>> = Map::new("unbondings");
+
+pub const FEE_INDEXES: Map<&str, FeeIndex> = Map::new("fee_indexes");
+
+pub const STAKER_FEES: Map<(&str, &str), StakerFees> = Map::new("staker_fees");
+
+pub const WASH_TRADING_CONFIG: Item<WashTradingConfig> = Item::new("wash_trading_config");
+
+pub const EPOCH_REWARDS: Map<&str, EpochRewards> = Map::new("epoch_rewards");
+
+pub const REWARD_VESTINGS: Map<&str, Vec<VestingEntry>> = Map::new("reward_vestings");
+
 pub const MARKETPLACE_STATS_BY_DENOM: Map<&str, MarketplaceStatsByDenom> =
     Map::new("marketplace_stats_by_denom");
 
@@ -19,8 +65,61 @@ pub const LISTED_NFT_COLLECTIONS_INFO_AND_USDC_VOL: Map<&str, NftCollectionInfoA
 
 pub const TOKEN_SALE_HISTORY: Map<&str, Vec<TokenSaleHistory>> = Map::new("token_sale_history");
 
+pub const MESSAGING_CONFIG: Item<MessagingConfig> = Item::new("messaging_config");
+
+pub const MESSAGE_RATE_LIMITS: Map<&str, MessageRateLimit> = Map::new("message_rate_limits");
+
+pub const MESSAGE_FILTERS: Map<&str, MessageFilter> = Map::new("message_filters");
+
+pub const NEXT_INBOX_MESSAGE_ID: Item<u64> = Item::new("next_inbox_message_id");
+
+pub const BLOCKED_ADDRESSES: Map<(&str, &str), Timestamp> = Map::new("blocked_addresses");
+
+pub const NEXT_ACTIVITY_ID: Item<u64> = Item::new("next_activity_id");
+
+pub const ADDRESS_ACTIVITIES: Map<(&str, u64), AddressActivityKind> =
+    Map::new("address_activities");
+
+pub const NEXT_SOLD_NFT_ID: Item<u64> = Item::new("next_sold_nft_id");
+
+pub const DAILY_PRICE_BUCKETS: Map<(&str, u64), DailyPriceBucket> = Map::new("daily_price_buckets");
+
+pub const HOURLY_TRADE_STATS: Map<(&str, u64), HourlyTradeStats> = Map::new("hourly_trade_stats");
+
 pub const PROFILES: Map<&str, Profile> = Map::new("profiles");
 
 pub const USERNAMES: Map<&str, String> = Map::new("usernames");
 
+pub const USERNAME_CHANGES: Map<&str, Timestamp> = Map::new("username_changes");
+
+pub const USERNAME_RENAME_COOLDOWN: Item<u64> = Item::new("username_rename_cooldown");
+
+pub const USERNAME_SALES: Map<&str, UsernameSale> = Map::new("username_sales");
+
+pub const RESERVED_USERNAMES: Map<&str, Empty> = Map::new("reserved_usernames");
+
+pub const MODERATORS: Map<&str, Empty> = Map::new("moderators");
+
+pub const VERIFICATIONS: Map<(&str, &str), Verification> = Map::new("verifications");
+
+pub const FOLLOWING: Map<(&str, &str), Follow> = Map::new("following");
+
+pub const FOLLOWERS: Map<(&str, &str), Timestamp> = Map::new("followers");
+
+pub const WATCHLISTS: Map<(&str, &str), WatchedToken> = Map::new("watchlists");
+
 pub const GENERAL_STATS: Item<GeneralStats> = Item::new("general_stats");
+
+pub const LEADERBOARD_SEASON: Item<LeaderboardSeason> = Item::new("leaderboard_season");
+
+pub const NEXT_COMPETITION_ID: Item<u64> = Item::new("next_competition_id");
+
+pub const COMPETITIONS: Map<u64, Competition> = Map::new("competitions");
+
+pub const OPEN_COMPETITIONS: Map<u64, Empty> = Map::new("open_competitions");
+
+pub const COMPETITION_PRIZES: Map<(u64, &str), CompetitionPrize> = Map::new("competition_prizes");
+
+pub const MIGRATION_PROGRESS: Item<MigrationProgress> = Item::new("migration_progress");
+
+pub const CIRCUIT_BREAKERS: Map<&str, CircuitBreaker> = Map::new("circuit_breakers");
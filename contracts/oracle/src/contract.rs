@@ -6,6 +6,8 @@ use cw2;
 
 use general_utils::error::ContractError;
 use general_utils::error::GenericError::Unauthorized;
+use general_utils::migrations::validate_migration_version;
+use price_oracle_utils::response_handler::ResponseHandler;
 
 use crate::execute;
 use crate::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
@@ -54,8 +56,11 @@ pub fn execute(
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(_deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
-    Ok(Response::default())
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    // Refuses downgrades and contracts that are not a price oracle
+    let stored_version = validate_migration_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    cw2::set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    Ok(ResponseHandler::migrate(stored_version.to_string(), CONTRACT_VERSION).response)
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
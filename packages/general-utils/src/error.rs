@@ -66,6 +66,18 @@ pub enum SchedulerError {
 }
 
 
+#[derive(Debug, Error, PartialEq)]
+pub enum MigrationError {
+    #[error("InvalidContractName")]
+    InvalidContractName {},
+    #[error("InvalidVersion")]
+    InvalidVersion {},
+    #[error("CannotMigrateToOlderVersion")]
+    CannotMigrateToOlderVersion {},
+    #[error("MigrationInProgress")]
+    MigrationInProgress {},
+}
+
 #[derive(Debug, Error, PartialEq)]
 pub enum NftCollectionError {
     #[error("NoNftsMintedForThisContract")]
@@ -82,8 +94,8 @@ pub enum NftMarketplaceError {
     InvalidRoyalty {},
     #[error("InvalidAmountReceivedForLevelUp")]
     InvalidAmountReceivedForLevelUp {},
-    #[error("AlreadyLevel3")]
-    AlreadyLevel3 {},
+    #[error("AlreadyMaxVipLevel")]
+    AlreadyMaxVipLevel {},
     #[error("NeedToFillAllThePerks")]
     NeedToFillAllThePerks {},
     #[error("InvalidRewards")]
@@ -170,6 +182,104 @@ pub enum NftMarketplaceError {
     BuyAndSellCannotBeNoneTogether {},
     #[error("BuyAndSellCannotBeFilledTogether")]
     BuyAndSellCannotBeFilledTogether {},
+    #[error("CapabilityPaused")]
+    CapabilityPaused {},
+    #[error("InvalidPauseReason")]
+    InvalidPauseReason {},
+    #[error("SenderIsBlocked")]
+    SenderIsBlocked {},
+    #[error("CantBlockYourself")]
+    CantBlockYourself {},
+    #[error("InboxThreadDoesNotExist")]
+    InboxThreadDoesNotExist {},
+    #[error("OnlyTheRecipientCanDeleteAMessage")]
+    OnlyTheRecipientCanDeleteAMessage {},
+    #[error("InvalidMessagingConfig")]
+    InvalidMessagingConfig {},
+    #[error("MessageRateLimitReached")]
+    MessageRateLimitReached {},
+    #[error("InvalidMessageFee")]
+    InvalidMessageFee {},
+    #[error("RecipientDoesNotAcceptYourMessages")]
+    RecipientDoesNotAcceptYourMessages {},
+    #[error("UsernameIsReserved")]
+    UsernameIsReserved {},
+    #[error("UsernameRenameCooldownNotOver")]
+    UsernameRenameCooldownNotOver {},
+    #[error("YouDontHaveAUsername")]
+    YouDontHaveAUsername {},
+    #[error("UsernameIsNotForSale")]
+    UsernameIsNotForSale {},
+    #[error("CantBuyYourOwnUsername")]
+    CantBuyYourOwnUsername {},
+    #[error("CantTransferUsernameToYourself")]
+    CantTransferUsernameToYourself {},
+    #[error("OnlyTheAdminOrAModeratorCanVerify")]
+    OnlyTheAdminOrAModeratorCanVerify {},
+    #[error("VerificationDoesNotExist")]
+    VerificationDoesNotExist {},
+    #[error("CantFollowYourself")]
+    CantFollowYourself {},
+    #[error("AlreadyFollowing")]
+    AlreadyFollowing {},
+    #[error("NotFollowing")]
+    NotFollowing {},
+    #[error("TokenAlreadyInWatchlist")]
+    TokenAlreadyInWatchlist {},
+    #[error("TokenNotInWatchlist")]
+    TokenNotInWatchlist {},
+    #[error("NoRewardsToClaim")]
+    NoRewardsToClaim {},
+    #[error("InvalidCompetition")]
+    InvalidCompetition {},
+    #[error("CompetitionDoesNotExist")]
+    CompetitionDoesNotExist {},
+    #[error("CompetitionNotEnded")]
+    CompetitionNotEnded {},
+    #[error("CompetitionAlreadyFinalized")]
+    CompetitionAlreadyFinalized {},
+    #[error("NoCompetitionPrizeToClaim")]
+    NoCompetitionPrizeToClaim {},
+    #[error("TooManyOpenCompetitions")]
+    TooManyOpenCompetitions {},
+    #[error("InvalidVipTiers")]
+    InvalidVipTiers {},
+    #[error("VipLevelNotRenewable")]
+    VipLevelNotRenewable {},
+    #[error("InvalidPartnerCollection")]
+    InvalidPartnerCollection {},
+    #[error("TooManyPartnerCollections")]
+    TooManyPartnerCollections {},
+    #[error("InvalidPerksCombination")]
+    InvalidPerksCombination {},
+    #[error("InvalidWashTradingConfig")]
+    InvalidWashTradingConfig {},
+    #[error("StakingNotEnabled")]
+    StakingNotEnabled {},
+    #[error("InvalidStakingConfig")]
+    InvalidStakingConfig {},
+    #[error("InvalidUnstakeAmount")]
+    InvalidUnstakeAmount {},
+    #[error("TooManyUnbondings")]
+    TooManyUnbondings {},
+    #[error("NoUnbondedTokens")]
+    NoUnbondedTokens {},
+    #[error("NoStakingFeesToClaim")]
+    NoStakingFeesToClaim {},
+    #[error("InvalidRewardMinting")]
+    InvalidRewardMinting {},
+    #[error("RewardMintingAlreadyEnabled")]
+    RewardMintingAlreadyEnabled {},
+    #[error("RewardMintingNotEnabled")]
+    RewardMintingNotEnabled {},
+    #[error("RewardEmissionCapBelowMinted")]
+    RewardEmissionCapBelowMinted {},
+    #[error("RewardTokenStillStaked")]
+    RewardTokenStillStaked {},
+    #[error("RewardsStillToClaim")]
+    RewardsStillToClaim {},
+    #[error("RewardPoolNotEmpty")]
+    RewardPoolNotEmpty {},
 }
 
 
@@ -204,4 +314,6 @@ pub enum ContractError {
     NftMarketplaceError(NftMarketplaceError),
     #[error("{0}")]
     NftCollectionError(NftCollectionError),
+    #[error("{0}")]
+    MigrationError(MigrationError),
 }
@@ -2,4 +2,5 @@ pub mod constants;
 pub mod denominations;
 pub mod error;
 pub mod macros;
+pub mod migrations;
 pub mod validations;
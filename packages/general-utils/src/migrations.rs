@@ -0,0 +1,28 @@
+use cosmwasm_std::{ensure, Storage};
+use semver::Version;
+
+use crate::error::ContractError;
+use crate::error::MigrationError::{CannotMigrateToOlderVersion, InvalidContractName, InvalidVersion};
+
+pub fn parse_version(version: &str) -> Result<Version, ContractError> {
+    Version::parse(version).map_err(|_| ContractError::MigrationError(InvalidVersion {}))
+}
+
+// Returns the version currently stored by cw2 after validating that the new code can replace it
+pub fn validate_migration_version(
+    storage: &dyn Storage,
+    contract_name: &str,
+    new_version: &str,
+) -> Result<Version, ContractError> {
+    let stored = cw2::get_contract_version(storage)?;
+    ensure!(
+        stored.contract == contract_name,
+        ContractError::MigrationError(InvalidContractName {})
+    );
+    let stored_version = parse_version(&stored.version)?;
+    ensure!(
+        stored_version <= parse_version(new_version)?,
+        ContractError::MigrationError(CannotMigrateToOlderVersion {})
+    );
+    Ok(stored_version)
+}
@@ -0,0 +1,164 @@
+use std::fmt;
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Timestamp, Uint128};
+use cw_storage_plus::{Index, IndexList, IndexedMap, MultiIndex};
+
+use general_utils::denominations::Denomination;
+
+use crate::nft_collection::{NftCollectionAddress, TokenId};
+use crate::nft_sale::define_unique_collection_nft_id;
+
+#[cw_serde]
+pub enum ActivityType {
+    List,
+    UpdateListing,
+    CancelListing,
+    Sale,
+    Offer,
+    CancelOffer,
+    AcceptOffer,
+    RejectOffer,
+    Transfer,
+}
+
+impl fmt::Display for ActivityType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ActivityType::List => write!(f, "List"),
+            ActivityType::UpdateListing => write!(f, "Update listing"),
+            ActivityType::CancelListing => write!(f, "Cancel listing"),
+            ActivityType::Sale => write!(f, "Sale"),
+            ActivityType::Offer => write!(f, "Offer"),
+            ActivityType::CancelOffer => write!(f, "Cancel offer"),
+            ActivityType::AcceptOffer => write!(f, "Accept offer"),
+            ActivityType::RejectOffer => write!(f, "Reject offer"),
+            ActivityType::Transfer => write!(f, "Transfer"),
+        }
+    }
+}
+
+// Activity seen from one of its addresses, used to filter the history of an address
+#[cw_serde]
+pub enum AddressActivityKind {
+    ListingCreated,
+    ListingUpdated,
+    ListingCancelled,
+    Purchase,
+    Sale,
+    OfferMade,
+    OfferReceived,
+    OfferCancelled,
+    OfferAccepted,
+    OfferRejected,
+    TransferSent,
+    TransferReceived,
+}
+
+impl AddressActivityKind {
+    pub fn new(activity_type: &ActivityType, is_from: bool) -> Self {
+        match (activity_type, is_from) {
+            (ActivityType::List, _) => AddressActivityKind::ListingCreated,
+            (ActivityType::UpdateListing, _) => AddressActivityKind::ListingUpdated,
+            (ActivityType::CancelListing, _) => AddressActivityKind::ListingCancelled,
+            (ActivityType::Sale, true) => AddressActivityKind::Sale,
+            (ActivityType::Sale, false) => AddressActivityKind::Purchase,
+            (ActivityType::Offer, true) => AddressActivityKind::OfferMade,
+            (ActivityType::Offer, false) => AddressActivityKind::OfferReceived,
+            (ActivityType::CancelOffer, _) => AddressActivityKind::OfferCancelled,
+            (ActivityType::AcceptOffer, _) => AddressActivityKind::OfferAccepted,
+            (ActivityType::RejectOffer, _) => AddressActivityKind::OfferRejected,
+            (ActivityType::Transfer, true) => AddressActivityKind::TransferSent,
+            (ActivityType::Transfer, false) => AddressActivityKind::TransferReceived,
+        }
+    }
+}
+
+#[cw_serde]
+pub struct AddressActivity {
+    pub kind: AddressActivityKind,
+    pub activity: Activity,
+}
+
+// from is the address doing the action (seller, offerer, sender), to is the other side
+// (buyer, token owner of an offer, offerer of an answered offer, recipient) when there is one
+#[cw_serde]
+pub struct Activity {
+    pub activity_id: u64,
+    pub activity_type: ActivityType,
+    pub nft_collection_address: NftCollectionAddress,
+    pub token_id: TokenId,
+    pub from: String,
+    pub to: Option<String>,
+    pub price_value: Option<Uint128>,
+    pub price_denom: Option<Denomination>,
+    pub time: Timestamp,
+}
+
+impl Activity {
+    pub fn new(
+        activity_type: ActivityType,
+        nft_collection_address: NftCollectionAddress,
+        token_id: TokenId,
+        from: String,
+        time: Timestamp,
+    ) -> Self {
+        Activity {
+            activity_id: 0,
+            activity_type,
+            nft_collection_address,
+            token_id,
+            from,
+            to: None,
+            price_value: None,
+            price_denom: None,
+            time,
+        }
+    }
+
+    pub fn counterparty(mut self, to: String) -> Self {
+        self.to = Some(to);
+        self
+    }
+
+    pub fn price(mut self, price_value: Uint128, price_denom: Denomination) -> Self {
+        self.price_value = Some(price_value);
+        self.price_denom = Some(price_denom);
+        self
+    }
+}
+
+pub struct ActivityIndexes<'a> {
+    pub collection_index: MultiIndex<'a, String, Activity, u64>,
+    // Collection + token id
+    pub token_index: MultiIndex<'a, String, Activity, u64>,
+}
+
+impl IndexList<Activity> for ActivityIndexes<'_> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<Activity>> + '_> {
+        let v: Vec<&dyn Index<Activity>> = vec![&self.collection_index, &self.token_index];
+        Box::new(v.into_iter())
+    }
+}
+
+// Append-only log keyed by the activity id
+pub fn activities<'a>() -> IndexedMap<'a, u64, Activity, ActivityIndexes<'a>> {
+    let indexes = ActivityIndexes {
+        collection_index: MultiIndex::new(
+            |_, activity| activity.nft_collection_address.clone(),
+            "activities",
+            "activities__collection",
+        ),
+        token_index: MultiIndex::new(
+            |_, activity| {
+                define_unique_collection_nft_id(
+                    &activity.nft_collection_address,
+                    &activity.token_id,
+                )
+            },
+            "activities",
+            "activities__token",
+        ),
+    };
+    IndexedMap::new("activities", indexes)
+}
@@ -0,0 +1,85 @@
+use std::fmt;
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{ensure, Timestamp};
+
+use general_utils::error::ContractError;
+use general_utils::error::NftMarketplaceError::InvalidPauseReason;
+
+pub const MAX_PAUSE_REASON_LENGTH: usize = 256;
+
+// Capabilities that can be paused on their own. Exit paths (cancel offer, cancel sale and
+// refunds) are never part of this list and always stay open.
+#[cw_serde]
+pub enum MarketplaceCapability {
+    Listing,
+    Buying,
+    Offering,
+    Profiles,
+    Messaging,
+}
+
+impl fmt::Display for MarketplaceCapability {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MarketplaceCapability::Listing => write!(f, "Listing"),
+            MarketplaceCapability::Buying => write!(f, "Buying"),
+            MarketplaceCapability::Offering => write!(f, "Offering"),
+            MarketplaceCapability::Profiles => write!(f, "Profiles"),
+            MarketplaceCapability::Messaging => write!(f, "Messaging"),
+        }
+    }
+}
+
+impl MarketplaceCapability {
+    pub fn all() -> Vec<MarketplaceCapability> {
+        vec![
+            MarketplaceCapability::Listing,
+            MarketplaceCapability::Buying,
+            MarketplaceCapability::Offering,
+            MarketplaceCapability::Profiles,
+            MarketplaceCapability::Messaging,
+        ]
+    }
+}
+
+#[cw_serde]
+pub struct CircuitBreaker {
+    pub capability: MarketplaceCapability,
+    pub paused: bool,
+    pub reason: Option<String>,
+    pub updated_by: Option<String>,
+    pub updated_at: Option<Timestamp>,
+}
+
+impl CircuitBreaker {
+    pub fn new(capability: MarketplaceCapability) -> Self {
+        CircuitBreaker {
+            capability,
+            paused: false,
+            reason: None,
+            updated_by: None,
+            updated_at: None,
+        }
+    }
+
+    pub fn new_checked(
+        capability: MarketplaceCapability,
+        paused: bool,
+        reason: String,
+        updated_by: String,
+        updated_at: Timestamp,
+    ) -> Result<Self, ContractError> {
+        ensure!(
+            !reason.trim().is_empty() && reason.len() <= MAX_PAUSE_REASON_LENGTH,
+            ContractError::NftMarketplaceError(InvalidPauseReason {})
+        );
+        Ok(CircuitBreaker {
+            capability,
+            paused,
+            reason: Some(reason),
+            updated_by: Some(updated_by),
+            updated_at: Some(updated_at),
+        })
+    }
+}
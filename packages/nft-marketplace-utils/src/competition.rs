@@ -0,0 +1,116 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{ensure, Timestamp, Uint128};
+use cw_storage_plus::{Index, IndexList, IndexedMap, MultiIndex};
+
+use general_utils::error::ContractError;
+use general_utils::error::NftMarketplaceError::InvalidCompetition;
+
+use crate::nft_collection::NftCollectionAddress;
+
+// Bounds the work done when a competition is finalized
+pub const MAX_COMPETITION_WINNERS: usize = 100;
+// Bounds the competitions updated on each trade
+pub const MAX_OPEN_COMPETITIONS: usize = 10;
+
+#[cw_serde]
+pub struct Competition {
+    pub competition_id: u64,
+    pub start_time: Timestamp,
+    pub end_time: Timestamp,
+    // Empty: trades of every listed collection count
+    pub nft_collection_addresses: Vec<NftCollectionAddress>,
+    pub prize_denom: String,
+    // Prize of each rank, the first one goes to the trader with the highest volume
+    pub prizes: Vec<Uint128>,
+    pub finalized: bool,
+}
+
+impl Competition {
+    pub fn validate(&self, time: Timestamp) -> Result<(), ContractError> {
+        ensure!(
+            self.start_time < self.end_time
+                && self.end_time > time
+                && !self.prizes.is_empty()
+                && self.prizes.len() <= MAX_COMPETITION_WINNERS
+                && self.prizes.iter().all(|prize| !prize.is_zero()),
+            ContractError::NftMarketplaceError(InvalidCompetition {})
+        );
+        Ok(())
+    }
+
+    pub fn prize_pool(&self) -> Uint128 {
+        self.prizes.iter().sum()
+    }
+
+    pub fn is_running(&self, time: Timestamp) -> bool {
+        !self.finalized && self.start_time <= time && time < self.end_time
+    }
+
+    pub fn counts_collection(&self, nft_collection_address: &str) -> bool {
+        self.nft_collection_addresses.is_empty()
+            || self
+                .nft_collection_addresses
+                .iter()
+                .any(|address| address == nft_collection_address)
+    }
+}
+
+// Both the buyer and the seller of a trade get its USDC value
+#[cw_serde]
+pub struct CompetitionTrader {
+    pub competition_id: u64,
+    pub address: String,
+    pub volume_usdc: Uint128,
+    // Time of the last trade counted, the first to reach a volume ranks first
+    pub qualified_at: Timestamp,
+}
+
+impl CompetitionTrader {
+    // Reversed so equal volumes are ranked by the earliest time in a descending read
+    pub fn volume_index_suffix(&self) -> (u128, u64) {
+        (
+            self.volume_usdc.u128(),
+            u64::MAX - self.qualified_at.nanos(),
+        )
+    }
+}
+
+// Index keys are (competition id, volume, reversed time) so the ranking is a single descending
+// range read
+pub type CompetitionVolumeIndexKey = (u64, (u128, u64));
+
+pub struct CompetitionTraderIndexes<'a> {
+    pub volume_index: MultiIndex<'a, CompetitionVolumeIndexKey, CompetitionTrader, (u64, String)>,
+}
+
+impl IndexList<CompetitionTrader> for CompetitionTraderIndexes<'_> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<CompetitionTrader>> + '_> {
+        let v: Vec<&dyn Index<CompetitionTrader>> = vec![&self.volume_index];
+        Box::new(v.into_iter())
+    }
+}
+
+// Primary key is (competition id, address)
+pub fn competition_traders<'a>(
+) -> IndexedMap<'a, (u64, String), CompetitionTrader, CompetitionTraderIndexes<'a>> {
+    let indexes = CompetitionTraderIndexes {
+        volume_index: MultiIndex::new(
+            |_, trader| (trader.competition_id, trader.volume_index_suffix()),
+            "competition_traders",
+            "competition_traders__volume",
+        ),
+    };
+    IndexedMap::new("competition_traders", indexes)
+}
+
+// Set when the competition is finalized, paid out by ClaimCompetitionPrize
+#[cw_serde]
+pub struct CompetitionPrize {
+    pub competition_id: u64,
+    // Starts at 1
+    pub rank: u32,
+    pub address: String,
+    pub volume_usdc: Uint128,
+    pub amount: Uint128,
+    pub claimed: bool,
+}
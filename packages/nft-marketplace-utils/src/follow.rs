@@ -0,0 +1,53 @@
+use std::fmt;
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Timestamp;
+
+use crate::nft_collection::{NftCollectionAddress, TokenId};
+
+#[cw_serde]
+pub enum FollowTarget {
+    Profile {
+        address: String,
+    },
+    Collection {
+        nft_collection_address: NftCollectionAddress,
+    },
+}
+
+impl FollowTarget {
+    // Key of the target in the follow graph
+    pub fn key(&self) -> String {
+        match self {
+            FollowTarget::Profile { address } => format!("profile_{}", address),
+            FollowTarget::Collection {
+                nft_collection_address,
+            } => format!("collection_{}", nft_collection_address),
+        }
+    }
+}
+
+impl fmt::Display for FollowTarget {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FollowTarget::Profile { address } => write!(f, "Profile {}", address),
+            FollowTarget::Collection {
+                nft_collection_address,
+            } => write!(f, "Collection {}", nft_collection_address),
+        }
+    }
+}
+
+#[cw_serde]
+pub struct Follow {
+    pub follower: String,
+    pub target: FollowTarget,
+    pub followed_at: Timestamp,
+}
+
+#[cw_serde]
+pub struct WatchedToken {
+    pub nft_collection_address: NftCollectionAddress,
+    pub token_id: TokenId,
+    pub added_at: Timestamp,
+}
@@ -1,6 +1,6 @@
 use std::marker::PhantomData;
 
-use cosmwasm_std::{Deps, Empty};
+use cosmwasm_std::{Addr, Deps, Empty};
 use cw721_base::helpers::Cw721Contract;
 
 use general_utils::error::ContractError;
@@ -23,3 +23,15 @@ pub fn check_if_nft_is_owned(
     }
     Ok(())
 }
+
+// Whether the owner holds any token of the collection, a collection failing to answer counts as
+// not held so that a broken partner contract never blocks a trade
+pub fn holds_nft_of_collection(deps: Deps, owner: &str, nft_collection_address: &str) -> bool {
+    Cw721Contract::<Empty, Empty>(
+        Addr::unchecked(nft_collection_address),
+        PhantomData,
+        PhantomData,
+    )
+    .tokens(&deps.querier, owner, None, Some(1))
+    .map_or(false, |tokens_response| !tokens_response.tokens.is_empty())
+}
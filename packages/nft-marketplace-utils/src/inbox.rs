@@ -0,0 +1,93 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Timestamp;
+use cw_storage_plus::{Index, IndexList, IndexedMap, MultiIndex};
+
+#[cw_serde]
+pub struct InboxMessage {
+    pub message_id: u64,
+    pub from_address: String,
+    pub from_username: Option<String>,
+    pub to_address: String,
+    pub message: String,
+    pub time: Timestamp,
+}
+
+// One per participant, counterparty is the other address of the conversation
+#[cw_serde]
+pub struct InboxThread {
+    pub address: String,
+    pub counterparty: String,
+    pub last_message_id: u64,
+    pub last_message_time: Timestamp,
+    // Every received message up to this id has been read
+    pub last_read_message_id: Option<u64>,
+}
+
+#[cw_serde]
+pub struct InboxThreadInfo {
+    pub thread: InboxThread,
+    pub unread_messages: u64,
+}
+
+// Same id for both participants, the separator keeps two pairs of addresses from colliding
+pub fn define_thread_id(address1: &str, address2: &str) -> String {
+    if address1 < address2 {
+        format!("{}/{}", address1, address2)
+    } else {
+        format!("{}/{}", address2, address1)
+    }
+}
+
+pub struct InboxMessageIndexes<'a> {
+    pub recipient_index: MultiIndex<'a, String, InboxMessage, u64>,
+    pub thread_index: MultiIndex<'a, String, InboxMessage, u64>,
+}
+
+impl IndexList<InboxMessage> for InboxMessageIndexes<'_> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<InboxMessage>> + '_> {
+        let v: Vec<&dyn Index<InboxMessage>> = vec![&self.recipient_index, &self.thread_index];
+        Box::new(v.into_iter())
+    }
+}
+
+// Keyed by the message id, a deleted message is removed for both participants
+pub fn inbox_messages<'a>() -> IndexedMap<'a, u64, InboxMessage, InboxMessageIndexes<'a>> {
+    let indexes = InboxMessageIndexes {
+        recipient_index: MultiIndex::new(
+            |_, message| message.to_address.clone(),
+            "inbox_messages",
+            "inbox_messages__recipient",
+        ),
+        thread_index: MultiIndex::new(
+            |_, message| define_thread_id(&message.from_address, &message.to_address),
+            "inbox_messages",
+            "inbox_messages__thread",
+        ),
+    };
+    IndexedMap::new("inbox_messages", indexes)
+}
+
+pub struct InboxThreadIndexes<'a> {
+    // (address, last message id) to list the threads by latest message
+    pub last_message_index: MultiIndex<'a, (String, u64), InboxThread, (String, String)>,
+}
+
+impl IndexList<InboxThread> for InboxThreadIndexes<'_> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<InboxThread>> + '_> {
+        let v: Vec<&dyn Index<InboxThread>> = vec![&self.last_message_index];
+        Box::new(v.into_iter())
+    }
+}
+
+// Primary key is (address, counterparty)
+pub fn inbox_threads<'a>() -> IndexedMap<'a, (String, String), InboxThread, InboxThreadIndexes<'a>>
+{
+    let indexes = InboxThreadIndexes {
+        last_message_index: MultiIndex::new(
+            |_, thread| (thread.address.clone(), thread.last_message_id),
+            "inbox_threads",
+            "inbox_threads__last_message",
+        ),
+    };
+    IndexedMap::new("inbox_threads", indexes)
+}
@@ -0,0 +1,179 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Order, StdResult, Storage, Timestamp, Uint128};
+use cw_storage_plus::{Index, IndexList, IndexedMap, MultiIndex};
+
+// Period used for the all time leaderboards, seasons start at 1
+pub const ALL_TIME_PERIOD: u64 = 0;
+
+#[cw_serde]
+pub enum LeaderboardRanking {
+    TopBuyers,
+    TopSellers,
+    MostTrades,
+}
+
+#[cw_serde]
+pub enum LeaderboardPeriod {
+    AllTime,
+    CurrentSeason,
+}
+
+#[cw_serde]
+pub struct LeaderboardSeason {
+    pub season_id: u64,
+    pub start_time: Option<Timestamp>,
+}
+
+impl Default for LeaderboardSeason {
+    fn default() -> Self {
+        LeaderboardSeason {
+            season_id: 1,
+            start_time: None,
+        }
+    }
+}
+
+// Last entry received, the next page starts right after it
+#[cw_serde]
+pub struct LeaderboardCursor {
+    pub value: Uint128,
+    pub address: String,
+}
+
+// Volumes are in USDC at the time of the trade
+#[cw_serde]
+pub struct TraderStats {
+    pub address: String,
+    pub period: u64,
+    pub bought_usdc: Uint128,
+    pub sold_usdc: Uint128,
+    pub trades: u64,
+    // Mirrors display_trade_info of the profile, hidden traders are not ranked
+    pub visible: bool,
+}
+
+impl TraderStats {
+    pub fn new(address: String, period: u64, visible: bool) -> Self {
+        TraderStats {
+            address,
+            period,
+            bought_usdc: Uint128::zero(),
+            sold_usdc: Uint128::zero(),
+            trades: 0,
+            visible,
+        }
+    }
+
+    pub fn add_trade(&mut self, bought_usdc: Uint128, sold_usdc: Uint128) -> &mut Self {
+        self.bought_usdc += bought_usdc;
+        self.sold_usdc += sold_usdc;
+        self.trades += 1;
+        self
+    }
+
+    pub fn ranking_value(&self, ranking: &LeaderboardRanking) -> Uint128 {
+        match ranking {
+            LeaderboardRanking::TopBuyers => self.bought_usdc,
+            LeaderboardRanking::TopSellers => self.sold_usdc,
+            LeaderboardRanking::MostTrades => Uint128::from(self.trades),
+        }
+    }
+}
+
+// Index keys are (period, visible, value) so a page is a single descending range read
+pub struct TraderStatsIndexes<'a> {
+    pub bought_usdc_index: MultiIndex<'a, (u64, u8, u128), TraderStats, (String, u64)>,
+    pub sold_usdc_index: MultiIndex<'a, (u64, u8, u128), TraderStats, (String, u64)>,
+    pub trades_index: MultiIndex<'a, (u64, u8, u128), TraderStats, (String, u64)>,
+}
+
+impl IndexList<TraderStats> for TraderStatsIndexes<'_> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<TraderStats>> + '_> {
+        let v: Vec<&dyn Index<TraderStats>> = vec![
+            &self.bought_usdc_index,
+            &self.sold_usdc_index,
+            &self.trades_index,
+        ];
+        Box::new(v.into_iter())
+    }
+}
+
+impl<'a> TraderStatsIndexes<'a> {
+    pub fn by_ranking(
+        &self,
+        ranking: &LeaderboardRanking,
+    ) -> &MultiIndex<'a, (u64, u8, u128), TraderStats, (String, u64)> {
+        match ranking {
+            LeaderboardRanking::TopBuyers => &self.bought_usdc_index,
+            LeaderboardRanking::TopSellers => &self.sold_usdc_index,
+            LeaderboardRanking::MostTrades => &self.trades_index,
+        }
+    }
+}
+
+// Primary key is (address, period)
+pub fn trader_stats<'a>() -> IndexedMap<'a, (String, u64), TraderStats, TraderStatsIndexes<'a>> {
+    let indexes = TraderStatsIndexes {
+        bought_usdc_index: MultiIndex::new(
+            |_, stats| (stats.period, stats.visible as u8, stats.bought_usdc.u128()),
+            "trader_stats",
+            "trader_stats__bought_usdc",
+        ),
+        sold_usdc_index: MultiIndex::new(
+            |_, stats| (stats.period, stats.visible as u8, stats.sold_usdc.u128()),
+            "trader_stats",
+            "trader_stats__sold_usdc",
+        ),
+        trades_index: MultiIndex::new(
+            |_, stats| (stats.period, stats.visible as u8, stats.trades as u128),
+            "trader_stats",
+            "trader_stats__trades",
+        ),
+    };
+    IndexedMap::new("trader_stats", indexes)
+}
+
+pub fn record_trade_in_leaderboards(
+    storage: &mut dyn Storage,
+    address: &str,
+    season_id: u64,
+    bought_usdc: Uint128,
+    sold_usdc: Uint128,
+    visible: bool,
+) -> StdResult<()> {
+    for period in [ALL_TIME_PERIOD, season_id] {
+        trader_stats().update(
+            storage,
+            (address.to_string(), period),
+            |stats| -> StdResult<_> {
+                let mut stats =
+                    stats.unwrap_or_else(|| TraderStats::new(address.to_string(), period, visible));
+                stats.visible = visible;
+                stats.add_trade(bought_usdc, sold_usdc);
+                Ok(stats)
+            },
+        )?;
+    }
+    Ok(())
+}
+
+// Called when the trader changes display_trade_info, every period of the trader is updated
+pub fn update_trader_visibility(
+    storage: &mut dyn Storage,
+    address: &str,
+    visible: bool,
+) -> StdResult<()> {
+    let all_stats: Vec<TraderStats> = trader_stats()
+        .prefix(address.to_string())
+        .range(storage, None, None, Order::Ascending)
+        .map(|result| result.map(|(_, stats)| stats))
+        .collect::<StdResult<_>>()?;
+    for mut stats in all_stats
+        .into_iter()
+        .filter(|stats| stats.visible != visible)
+    {
+        stats.visible = visible;
+        trader_stats().save(storage, (address.to_string(), stats.period), &stats)?;
+    }
+    Ok(())
+}
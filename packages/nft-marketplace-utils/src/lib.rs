@@ -1,11 +1,27 @@
+pub mod activity;
+pub mod circuit_breaker;
+pub mod competition;
 pub mod config;
+pub mod follow;
 pub mod helpers;
+pub mod inbox;
 pub mod inputs;
+pub mod leaderboard;
 pub mod legacy_nft_metadata;
 pub mod marketplace_statistics;
+pub mod messaging;
+pub mod migration;
 pub mod nft_collection;
 pub mod nft_offer;
 pub mod nft_sale;
+pub mod partner_perks;
+pub mod price_history;
 pub mod profile;
 pub mod response_handler;
 pub mod reward_system;
+pub mod reward_vesting;
+pub mod staking;
+pub mod tokenfactory;
+pub mod username;
+pub mod verification;
+pub mod wash_trading;
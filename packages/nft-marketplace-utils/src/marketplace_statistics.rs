@@ -1,10 +1,10 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Decimal, Uint128};
+use cosmwasm_std::{Timestamp, Uint128};
 
 use general_utils::denominations::Denomination;
 
 use crate::nft_collection::NftCollectionAddress;
-use crate::nft_sale::NftSale;
+use crate::price_history::RollingWindow;
 
 #[cw_serde]
 pub struct CollectionVolume {
@@ -12,12 +12,24 @@ pub struct CollectionVolume {
     pub usdc_volume: Uint128,
 }
 
+#[cw_serde]
+pub struct TrendingCollection {
+    pub nft_collection_address: NftCollectionAddress,
+    pub usdc_volume: Uint128,
+    pub last_trade: Timestamp,
+}
+
 #[cw_serde]
 pub struct GeneralStats {
     pub last_collection_added: NftCollectionAddress,
     pub last_collections_traded: Vec<NftCollectionAddress>,
     pub top_10_volume_usdc: Vec<CollectionVolume>,
     pub lowest_volume_usdc: Uint128,
+    // Saved on each trade of a collection, the volumes are computed again when queried
+    #[serde(default)]
+    pub trending_24h_volume_usdc: Vec<TrendingCollection>,
+    #[serde(default)]
+    pub trending_7d_volume_usdc: Vec<TrendingCollection>,
 }
 
 impl Default for GeneralStats {
@@ -33,6 +45,8 @@ impl GeneralStats {
             last_collections_traded: vec![],
             top_10_volume_usdc: vec![],
             lowest_volume_usdc: Uint128::zero(),
+            trending_24h_volume_usdc: vec![],
+            trending_7d_volume_usdc: vec![],
         }
     }
     pub fn compute_new_top_10_and_latest_collection_traded(
@@ -61,6 +75,40 @@ impl GeneralStats {
 
         self
     }
+
+    pub fn trending_mut(&mut self, window: &RollingWindow) -> Option<&mut Vec<TrendingCollection>> {
+        match window {
+            RollingWindow::Last24h => Some(&mut self.trending_24h_volume_usdc),
+            RollingWindow::Last7d => Some(&mut self.trending_7d_volume_usdc),
+            RollingWindow::Last30d => None,
+        }
+    }
+
+    pub fn compute_new_trending(
+        &mut self,
+        window: RollingWindow,
+        window_collection_volume: CollectionVolume,
+        now: Timestamp,
+    ) -> &mut GeneralStats {
+        let window_start = window.first_period_start(now);
+        let trending = match self.trending_mut(&window) {
+            Some(trending) => trending,
+            None => return self,
+        };
+        trending.retain(|x| {
+            x.nft_collection_address != window_collection_volume.nft_collection_address
+                && x.last_trade.seconds() >= window_start
+        });
+        trending.push(TrendingCollection {
+            nft_collection_address: window_collection_volume.nft_collection_address,
+            usdc_volume: window_collection_volume.usdc_volume,
+            last_trade: now,
+        });
+        trending.sort_unstable_by_key(|x| std::cmp::Reverse(x.usdc_volume));
+        trending.truncate(10);
+
+        self
+    }
 }
 
 #[cw_serde]
@@ -108,18 +156,13 @@ impl MarketplaceStatsByDenom {
         self
     }
 
-    pub fn execute_sale(
-        &mut self,
-        sale_price: Uint128,
-        marketplace_fees_pct: Decimal,
-    ) -> &mut Self {
+    // Fees are the marketplace revenues of the sale, after the VIP discounts
+    pub fn execute_sale(&mut self, sale_price: Uint128, marketplace_fees: Uint128) -> &mut Self {
         self.remove_nft_for_sale();
         self.total_realized_sales_volume += sale_price;
         self.realized_sales_counter += 1;
-        self.total_marketplace_fees +=
-            NftSale::compute_marketplace_fees(marketplace_fees_pct, sale_price);
-        self.marketplace_fees_to_claim +=
-            NftSale::compute_marketplace_fees(marketplace_fees_pct, sale_price);
+        self.total_marketplace_fees += marketplace_fees;
+        self.marketplace_fees_to_claim += marketplace_fees;
         self
     }
 }
@@ -0,0 +1,103 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{ensure, Uint128};
+
+use general_utils::denominations::{AcceptedDenominations, Denomination};
+use general_utils::error::ContractError;
+use general_utils::error::NftMarketplaceError::InvalidMessagingConfig;
+
+use crate::profile::Profile;
+use crate::reward_system::{VipLevel, VipTier};
+
+// Each VIP level adds bonus_messages_per_vip_level to the messages allowed per window, unless
+// the tier of the level sets its own limit
+#[cw_serde]
+pub struct MessageRateLimitConfig {
+    pub window_blocks: u64,
+    pub messages_per_window: u32,
+    pub bonus_messages_per_vip_level: u32,
+}
+
+impl MessageRateLimitConfig {
+    pub fn max_messages_per_window(&self, vip_level: VipLevel, vip_tier: Option<&VipTier>) -> u32 {
+        vip_tier
+            .and_then(|vip_tier| vip_tier.max_messages_per_window)
+            .unwrap_or(
+                self.messages_per_window + self.bonus_messages_per_vip_level * vip_level.rank(),
+            )
+    }
+}
+
+#[cw_serde]
+pub enum MessageFeeRecipient {
+    Recipient,
+    // Added to the marketplace fees to claim
+    Treasury,
+}
+
+#[cw_serde]
+pub struct MessageFee {
+    pub denom: Denomination,
+    pub amount: Uint128,
+    pub paid_to: MessageFeeRecipient,
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct MessagingConfig {
+    pub rate_limit: Option<MessageRateLimitConfig>,
+    pub message_fee: Option<MessageFee>,
+}
+
+impl MessagingConfig {
+    pub fn new_checked(
+        self,
+        accepted_ibc_denominations: &AcceptedDenominations,
+    ) -> Result<Self, ContractError> {
+        if let Some(rate_limit) = &self.rate_limit {
+            ensure!(
+                rate_limit.window_blocks > 0 && rate_limit.messages_per_window > 0,
+                ContractError::NftMarketplaceError(InvalidMessagingConfig {})
+            );
+        }
+        if let Some(message_fee) = &self.message_fee {
+            ensure!(
+                !message_fee.amount.is_zero()
+                    && accepted_ibc_denominations
+                        .list_of_denoms
+                        .contains(&message_fee.denom),
+                ContractError::NftMarketplaceError(InvalidMessagingConfig {})
+            );
+        }
+        Ok(self)
+    }
+}
+
+// Messages sent by an address in the current window (block height / window_blocks)
+#[cw_serde]
+pub struct MessageRateLimit {
+    pub window: u64,
+    pub messages_sent: u32,
+}
+
+// Set by a recipient, a sender is accepted when meeting any of the requirements set
+#[cw_serde]
+#[derive(Default)]
+pub struct MessageFilter {
+    pub min_trades: Option<u64>,
+    pub min_vip_level: Option<VipLevel>,
+}
+
+impl MessageFilter {
+    pub fn accepts(&self, sender_profile: &Profile) -> bool {
+        if self.min_trades.is_none() && self.min_vip_level.is_none() {
+            return true;
+        }
+        let enough_trades = self.min_trades.map_or(false, |min_trades| {
+            sender_profile.number_of_trades.unwrap_or_default() >= min_trades
+        });
+        let enough_vip_level = self.min_vip_level.as_ref().map_or(false, |min_vip_level| {
+            sender_profile.vip_level.unwrap_or_default() >= *min_vip_level
+        });
+        enough_trades || enough_vip_level
+    }
+}
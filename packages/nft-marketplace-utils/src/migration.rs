@@ -0,0 +1,26 @@
+use cosmwasm_schema::cw_serde;
+
+#[cw_serde]
+pub struct MigrationProgress {
+    pub from_version: String,
+    pub to_version: String,
+    pub step: u32,
+    pub cursor: Option<String>,
+}
+
+impl MigrationProgress {
+    pub fn new(from_version: String, to_version: String) -> Self {
+        MigrationProgress {
+            from_version,
+            to_version,
+            step: 0,
+            cursor: None,
+        }
+    }
+
+    pub fn next_step(&mut self) -> &mut Self {
+        self.step += 1;
+        self.cursor = None;
+        self
+    }
+}
@@ -11,6 +11,7 @@ use general_utils::error::ContractError;
 use general_utils::error::NftCollectionError::NoNftsMintedForThisContract;
 
 use crate::nft_sale::NftSale;
+use crate::verification::Verification;
 
 pub type NftCollectionAddress = String;
 pub type TokenId = String;
@@ -73,6 +74,13 @@ pub struct NftCollectionInfoByDenom {
     pub current_floor: Uint128,
 }
 
+// Returned by GetNftCollectionInfo with the active verifications of the collection
+#[cw_serde]
+pub struct NftCollectionInfoResponse {
+    pub info_by_denom: Vec<NftCollectionInfoByDenom>,
+    pub verifications: Vec<Verification>,
+}
+
 impl NftCollectionInfoByDenom {
     pub fn new_checked(
         deps_querier: QuerierWrapper,
@@ -2,10 +2,11 @@ use std::marker::PhantomData;
 
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{
-    ensure, Decimal, Deps, Empty, MessageInfo, Order, StdResult, Storage, Timestamp, Uint128,
+    ensure, Decimal, Deps, Empty, Env, MessageInfo, Order, StdResult, Storage, Timestamp,
+    Uint128,
 };
 use cw721_base::helpers::Cw721Contract;
-use cw_storage_plus::{Index, IndexList, IndexedMap, MultiIndex};
+use cw_storage_plus::{Bound, Index, IndexList, IndexedMap, MultiIndex};
 
 use general_utils::denominations::Denomination;
 use general_utils::error::ContractError;
@@ -113,6 +114,26 @@ impl NftSale {
         sale_value * marketplace_fees_pct
     }
 
+    // The seller pays the fees minus its VIP discount, the buyer's VIP discount is refunded to the
+    // buyer out of them. Returns the marketplace revenues and the buyer refund
+    pub fn split_marketplace_fees(
+        marketplace_fees_pct: Decimal,
+        sale_value: Uint128,
+        seller_fees_discount: Decimal,
+        buyer_fees_discount: Decimal,
+    ) -> StdResult<(Uint128, Uint128)> {
+        let seller_fees = Self::compute_marketplace_fees(
+            marketplace_fees_pct.checked_mul(Decimal::one().checked_sub(seller_fees_discount)?)?,
+            sale_value,
+        );
+        let buyer_refund = Self::compute_marketplace_fees(
+            marketplace_fees_pct.checked_mul(buyer_fees_discount)?,
+            sale_value,
+        )
+        .min(seller_fees);
+        Ok((seller_fees - buyer_refund, buyer_refund))
+    }
+
     pub fn validate_sender_is_token_owner(
         self,
         sender_address: &str,
@@ -171,6 +192,10 @@ pub struct NftCollectionSaleIndexes<'a> {
     pub seller_index: MultiIndex<'a, String, NftSale, String>,
     pub denom_index: MultiIndex<'a, String, NftSale, String>,
     pub collection_seller_index: MultiIndex<'a, (String, String), NftSale, String>,
+    // (collection, denom, price) with the collection + token id as primary key
+    pub collection_denom_price_index: MultiIndex<'a, (String, String, u128), NftSale, String>,
+    pub expiration_index: MultiIndex<'a, u64, NftSale, String>,
+    pub collection_expiration_index: MultiIndex<'a, (String, u64), NftSale, String>,
 }
 
 impl IndexList<NftSale> for NftCollectionSaleIndexes<'_> {
@@ -180,6 +205,9 @@ impl IndexList<NftSale> for NftCollectionSaleIndexes<'_> {
             &self.seller_index,
             &self.denom_index,
             &self.collection_seller_index,
+            &self.collection_denom_price_index,
+            &self.expiration_index,
+            &self.collection_expiration_index,
         ];
         Box::new(v.into_iter())
     }
@@ -212,40 +240,241 @@ pub fn nfts_for_sale<'a>() -> IndexedMap<'a, String, NftSale, NftCollectionSaleI
             "sales",
             "sales__collection_seller",
         ),
+        collection_denom_price_index: MultiIndex::new(
+            |_, nft_sale| {
+                (
+                    nft_sale.nft_collection_address.clone(),
+                    nft_sale.sale_price_denom.clone(),
+                    nft_sale.sale_price_value.u128(),
+                )
+            },
+            "sales",
+            "sales__collection_denom_price",
+        ),
+        expiration_index: MultiIndex::new(
+            |_, nft_sale| nft_sale.sale_expiration.seconds(),
+            "sales",
+            "sales__expiration",
+        ),
+        collection_expiration_index: MultiIndex::new(
+            |_, nft_sale| {
+                (
+                    nft_sale.nft_collection_address.clone(),
+                    nft_sale.sale_expiration.seconds(),
+                )
+            },
+            "sales",
+            "sales__collection_expiration",
+        ),
     };
     IndexedMap::new("sales", indexes)
 }
 
+// Time at which a sale was created, used for the recently listed feeds. It has the same
+// primary key as the sale and is removed with it
+#[cw_serde]
+pub struct NftListingTime {
+    pub nft_collection_address: NftCollectionAddress,
+    pub token_id: TokenId,
+    pub listed_at: Timestamp,
+}
+
+pub struct NftListingTimeIndexes<'a> {
+    pub listed_at_index: MultiIndex<'a, u64, NftListingTime, String>,
+    pub collection_listed_at_index: MultiIndex<'a, (String, u64), NftListingTime, String>,
+}
+
+impl IndexList<NftListingTime> for NftListingTimeIndexes<'_> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<NftListingTime>> + '_> {
+        let v: Vec<&dyn Index<NftListingTime>> =
+            vec![&self.listed_at_index, &self.collection_listed_at_index];
+        Box::new(v.into_iter())
+    }
+}
+
+pub fn nft_listing_times<'a>() -> IndexedMap<'a, String, NftListingTime, NftListingTimeIndexes<'a>>
+{
+    let indexes = NftListingTimeIndexes {
+        listed_at_index: MultiIndex::new(
+            |_, listing| listing.listed_at.seconds(),
+            "listing_times",
+            "listing_times__listed_at",
+        ),
+        collection_listed_at_index: MultiIndex::new(
+            |_, listing| {
+                (
+                    listing.nft_collection_address.clone(),
+                    listing.listed_at.seconds(),
+                )
+            },
+            "listing_times",
+            "listing_times__collection_listed_at",
+        ),
+    };
+    IndexedMap::new("listing_times", indexes)
+}
+
+#[cw_serde]
+pub struct NftSaleListing {
+    pub listed_at: Timestamp,
+    pub nft_sale: NftSale,
+}
+
+// Last entry received in a feed sorted by time (listing time or expiration)
+#[cw_serde]
+pub struct NftSaleCursor {
+    pub time: Timestamp,
+    pub nft_collection_address: NftCollectionAddress,
+    pub token_id: TokenId,
+}
+
 pub fn compute_floor_collection_and_denom(
     store: &mut dyn Storage,
     denom: String,
     nft_collection_address: NftCollectionAddress,
     max_price: Uint128,
 ) -> StdResult<Uint128> {
-    let mut nfts_for_sale_info: Vec<Uint128> = Vec::new();
-    for result in
-        nfts_for_sale()
-            .idx
-            .denom_index
-            .prefix(denom)
-            .range(store, None, None, Order::Ascending)
-    {
-        match result {
-            Err(e) => return Err(e),
-            Ok((_, sale_info)) => {
-                if sale_info.nft_collection_address == nft_collection_address {
-                    nfts_for_sale_info.push(sale_info.sale_price_value);
-                }
+    // Lowest price is the first entry of the price index
+    let floor = nfts_for_sale()
+        .idx
+        .collection_denom_price_index
+        .sub_prefix((nft_collection_address, denom))
+        .range(store, None, None, Order::Ascending)
+        .next()
+        .transpose()?;
+    Ok(floor.map_or(max_price, |(_, sale_info)| sale_info.sale_price_value))
+}
+
+pub fn save_nfts_for_sale(
+    store: &mut dyn Storage,
+    nft_for_sale: &NftSale,
+    listed_at: Timestamp,
+) -> StdResult<()> {
+    let collection_token_id_unique = define_unique_collection_nft_id(
+        &nft_for_sale.nft_collection_address,
+        &nft_for_sale.token_id,
+    );
+    nft_listing_times().save(
+        store,
+        collection_token_id_unique.clone(),
+        &NftListingTime {
+            nft_collection_address: nft_for_sale.nft_collection_address.clone(),
+            token_id: nft_for_sale.token_id.clone(),
+            listed_at,
+        },
+    )?;
+    nfts_for_sale().save(store, collection_token_id_unique, nft_for_sale)
+}
+
+pub fn remove_nfts_for_sale(
+    store: &mut dyn Storage,
+    collection_token_id_unique: String,
+) -> StdResult<()> {
+    nft_listing_times().remove(store, collection_token_id_unique.clone())?;
+    nfts_for_sale().remove(store, collection_token_id_unique)
+}
+
+// Migration: Sales used to be saved under the bare token id; re-key them with the collection.
+// Returns the last key processed if the batch was full, None once every sale has been visited
+pub fn migrate_nfts_for_sale_keys(
+    store: &mut dyn Storage,
+    _env: &Env,
+    start_after: Option<String>,
+    limit: u32,
+) -> StdResult<Option<String>> {
+    let batch: Vec<(String, NftSale)> = nfts_for_sale()
+        .range(
+            store,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit as usize)
+        .collect::<StdResult<Vec<_>>>()?;
+    for (key, nft_sale) in batch.iter() {
+        let unique_id =
+            define_unique_collection_nft_id(&nft_sale.nft_collection_address, &nft_sale.token_id);
+        if *key != unique_id {
+            nfts_for_sale().remove(store, key.clone())?;
+            // If a sale already exists under the right key, the legacy one is stale
+            if !nfts_for_sale().has(store, unique_id.clone()) {
+                nfts_for_sale().save(store, unique_id, nft_sale)?;
             }
         }
     }
-    let result = *nfts_for_sale_info.iter().min().unwrap_or(&max_price);
+    if batch.len() < limit as usize {
+        return Ok(None);
+    }
+    Ok(batch.last().map(|(key, _)| key.clone()))
+}
 
-    Ok(result)
+// Migration: Sales saved before the price and expiration indexes existed are saved again to be
+// indexed. Returns the last key processed if the batch was full, None once every sale has been visited
+pub fn migrate_nfts_for_sale_indexes(
+    store: &mut dyn Storage,
+    _env: &Env,
+    start_after: Option<String>,
+    limit: u32,
+) -> StdResult<Option<String>> {
+    let batch: Vec<(String, NftSale)> = nfts_for_sale()
+        .range(
+            store,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit as usize)
+        .collect::<StdResult<Vec<_>>>()?;
+    for (key, nft_sale) in batch.iter() {
+        nfts_for_sale().save(store, key.clone(), nft_sale)?;
+    }
+    if batch.len() < limit as usize {
+        return Ok(None);
+    }
+    Ok(batch.last().map(|(key, _)| key.clone()))
 }
 
-pub fn save_nfts_for_sale(store: &mut dyn Storage, nft_for_sale: &NftSale) -> StdResult<()> {
-    nfts_for_sale().save(store, nft_for_sale.token_id.clone(), nft_for_sale)
+// Migration: Sales listed before the recently listed feeds have no listing time, they are
+// considered listed at the migration. Returns the last key processed if the batch was full, None
+// once every sale has been visited
+pub fn migrate_nfts_for_sale_listing_times(
+    store: &mut dyn Storage,
+    env: &Env,
+    start_after: Option<String>,
+    limit: u32,
+) -> StdResult<Option<String>> {
+    let batch: Vec<(String, NftSale)> = nfts_for_sale()
+        .range(
+            store,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit as usize)
+        .collect::<StdResult<Vec<_>>>()?;
+    for (key, nft_sale) in batch.iter() {
+        if !nft_listing_times().has(store, key.clone()) {
+            nft_listing_times().save(
+                store,
+                key.clone(),
+                &NftListingTime {
+                    nft_collection_address: nft_sale.nft_collection_address.clone(),
+                    token_id: nft_sale.token_id.clone(),
+                    listed_at: env.block.time,
+                },
+            )?;
+        }
+    }
+    if batch.len() < limit as usize {
+        return Ok(None);
+    }
+    Ok(batch.last().map(|(key, _)| key.clone()))
+}
+
+#[cw_serde]
+pub enum PriceOrder {
+    Ascending,
+    Descending,
 }
 
 #[cw_serde]
@@ -259,6 +488,35 @@ pub struct TokenSaleHistory {
     pub sale_time: Timestamp,
 }
 
+#[cw_serde]
+pub struct SoldNft {
+    pub sale_id: u64,
+    pub sale_info: TokenSaleHistory,
+}
+
+pub struct SoldNftIndexes<'a> {
+    pub collection_index: MultiIndex<'a, String, TokenSaleHistory, u64>,
+}
+
+impl IndexList<TokenSaleHistory> for SoldNftIndexes<'_> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<TokenSaleHistory>> + '_> {
+        let v: Vec<&dyn Index<TokenSaleHistory>> = vec![&self.collection_index];
+        Box::new(v.into_iter())
+    }
+}
+
+// Every sale with an increasing id, used for the recently sold feeds
+pub fn sold_nfts<'a>() -> IndexedMap<'a, u64, TokenSaleHistory, SoldNftIndexes<'a>> {
+    let indexes = SoldNftIndexes {
+        collection_index: MultiIndex::new(
+            |_, sale| sale.nft_collection_address.clone(),
+            "sold_nfts",
+            "sold_nfts__collection",
+        ),
+    };
+    IndexedMap::new("sold_nfts", indexes)
+}
+
 #[cw_serde]
 pub struct TokensAndIfSaleInfo {
     pub token_id: TokenId,
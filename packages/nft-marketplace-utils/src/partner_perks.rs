@@ -0,0 +1,119 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{ensure, Decimal};
+
+use general_utils::error::ContractError;
+use general_utils::error::NftMarketplaceError::{
+    InvalidPartnerCollection, InvalidPerksCombination,
+};
+
+use crate::nft_collection::NftCollectionAddress;
+use crate::reward_system::{RewardSystem, VipLevel};
+
+// Every partner collection is queried when the holdings of an address are checked
+pub const MAX_PARTNER_COLLECTIONS: usize = 10;
+
+// Holders of at least one token of the collection get its discount and the perks of its tier
+#[cw_serde]
+pub struct PartnerCollection {
+    pub nft_collection_address: NftCollectionAddress,
+    pub marketplace_fees_discount: Decimal,
+    // Profile perks of this VIP level, None for a discount only
+    pub vip_level: Option<VipLevel>,
+}
+
+impl PartnerCollection {
+    pub fn validate(&self, reward_system: &RewardSystem) -> Result<(), ContractError> {
+        ensure!(
+            self.marketplace_fees_discount <= Decimal::percent(50u64)
+                && (!self.marketplace_fees_discount.is_zero() || self.vip_level.is_some())
+                && self.vip_level.map_or(true, |vip_level| reward_system
+                    .vip_tier(vip_level)
+                    .is_some()),
+            ContractError::NftMarketplaceError(InvalidPartnerCollection {})
+        );
+        Ok(())
+    }
+}
+
+// How the holdings of partner collections combine with the VIP level of a profile.
+// In both cases the profile gets the perks of the highest level among them.
+#[cw_serde]
+#[derive(Default)]
+pub enum PerksCombination {
+    // Only the best discount applies
+    #[default]
+    Best,
+    // Discounts are added up to the cap, holdings never lower the discount of the VIP level
+    Sum {
+        max_marketplace_fees_discount: Decimal,
+    },
+}
+
+impl PerksCombination {
+    pub fn validate(&self) -> Result<(), ContractError> {
+        if let PerksCombination::Sum {
+            max_marketplace_fees_discount,
+        } = self
+        {
+            ensure!(
+                !max_marketplace_fees_discount.is_zero()
+                    && *max_marketplace_fees_discount <= Decimal::percent(50u64),
+                ContractError::NftMarketplaceError(InvalidPerksCombination {})
+            );
+        }
+        Ok(())
+    }
+
+    pub fn combine(
+        &self,
+        reward_system: &RewardSystem,
+        address: String,
+        vip_level: VipLevel,
+        held_partner_collections: Vec<PartnerCollection>,
+    ) -> HolderPerks {
+        let vip_fees_discount = reward_system.marketplace_fees_discount(vip_level);
+        let partner_fees_discounts = held_partner_collections
+            .iter()
+            .map(|partner_collection| partner_collection.marketplace_fees_discount);
+        let marketplace_fees_discount = match self {
+            PerksCombination::Best => partner_fees_discounts.fold(vip_fees_discount, Decimal::max),
+            PerksCombination::Sum {
+                max_marketplace_fees_discount,
+            } => partner_fees_discounts
+                .fold(vip_fees_discount, |total, discount| total + discount)
+                .min(*max_marketplace_fees_discount)
+                .max(vip_fees_discount),
+        };
+        let perks_vip_level = held_partner_collections
+            .iter()
+            .filter_map(|partner_collection| partner_collection.vip_level)
+            .fold(vip_level, VipLevel::max);
+        HolderPerks {
+            address,
+            vip_level,
+            partner_collections: held_partner_collections
+                .into_iter()
+                .map(|partner_collection| partner_collection.nft_collection_address)
+                .collect(),
+            marketplace_fees_discount,
+            perks_vip_level,
+        }
+    }
+}
+
+#[cw_serde]
+pub struct HolderPerks {
+    pub address: String,
+    pub vip_level: VipLevel,
+    // Partner collections held by the address
+    pub partner_collections: Vec<NftCollectionAddress>,
+    pub marketplace_fees_discount: Decimal,
+    // Level whose tier perks apply to the profile updates
+    pub perks_vip_level: VipLevel,
+}
+
+#[cw_serde]
+pub struct PartnerPerks {
+    pub partner_collections: Vec<PartnerCollection>,
+    pub perks_combination: PerksCombination,
+}
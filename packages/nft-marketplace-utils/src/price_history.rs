@@ -0,0 +1,183 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Timestamp, Uint128};
+
+use general_utils::denominations::Denomination;
+
+use crate::nft_collection::NftCollectionAddress;
+
+pub const SECONDS_PER_HOUR: u64 = 3_600u64;
+pub const SECONDS_PER_DAY: u64 = 86_400u64;
+
+// Daily OHLC of the sales of a collection in a given denom
+#[cw_serde]
+pub struct DailyPriceBucket {
+    pub nft_collection_address: NftCollectionAddress,
+    pub denom: Denomination,
+    pub day_start: Timestamp,
+    pub open: Uint128,
+    pub high: Uint128,
+    pub low: Uint128,
+    pub close: Uint128,
+    pub volume: Uint128,
+    pub volume_usdc: Uint128,
+    pub trades: u64,
+    // Collection floor before the first sale of the day
+    pub opening_floor: Uint128,
+}
+
+impl DailyPriceBucket {
+    pub fn new(
+        nft_collection_address: NftCollectionAddress,
+        denom: Denomination,
+        sale_time: Timestamp,
+        sale_price: Uint128,
+        sale_price_usdc: Uint128,
+        opening_floor: Uint128,
+    ) -> Self {
+        DailyPriceBucket {
+            nft_collection_address,
+            denom,
+            day_start: Timestamp::from_seconds(period_start_seconds(sale_time, SECONDS_PER_DAY)),
+            open: sale_price,
+            high: sale_price,
+            low: sale_price,
+            close: sale_price,
+            volume: sale_price,
+            volume_usdc: sale_price_usdc,
+            trades: 1,
+            opening_floor,
+        }
+    }
+
+    pub fn add_sale(&mut self, sale_price: Uint128, sale_price_usdc: Uint128) -> &mut Self {
+        self.high = self.high.max(sale_price);
+        self.low = self.low.min(sale_price);
+        self.close = sale_price;
+        self.volume += sale_price;
+        self.volume_usdc += sale_price_usdc;
+        self.trades += 1;
+        self
+    }
+}
+
+// Hourly volume of a collection in a given denom, only kept for the last 24h window
+#[cw_serde]
+pub struct HourlyTradeStats {
+    pub volume: Uint128,
+    pub volume_usdc: Uint128,
+    pub trades: u64,
+    pub opening_floor: Uint128,
+}
+
+impl HourlyTradeStats {
+    pub fn new(opening_floor: Uint128) -> Self {
+        HourlyTradeStats {
+            volume: Uint128::zero(),
+            volume_usdc: Uint128::zero(),
+            trades: 0,
+            opening_floor,
+        }
+    }
+
+    pub fn add_sale(&mut self, sale_price: Uint128, sale_price_usdc: Uint128) -> &mut Self {
+        self.volume += sale_price;
+        self.volume_usdc += sale_price_usdc;
+        self.trades += 1;
+        self
+    }
+}
+
+#[cw_serde]
+pub enum RollingWindow {
+    Last24h,
+    Last7d,
+    Last30d,
+}
+
+impl RollingWindow {
+    pub fn all() -> Vec<RollingWindow> {
+        vec![
+            RollingWindow::Last24h,
+            RollingWindow::Last7d,
+            RollingWindow::Last30d,
+        ]
+    }
+
+    // 24h is computed with hourly buckets, 7d and 30d with calendar days
+    pub fn number_of_periods(&self) -> u64 {
+        match self {
+            RollingWindow::Last24h => 24,
+            RollingWindow::Last7d => 7,
+            RollingWindow::Last30d => 30,
+        }
+    }
+
+    pub fn period_seconds(&self) -> u64 {
+        match self {
+            RollingWindow::Last24h => SECONDS_PER_HOUR,
+            RollingWindow::Last7d | RollingWindow::Last30d => SECONDS_PER_DAY,
+        }
+    }
+
+    pub fn first_period_start(&self, now: Timestamp) -> u64 {
+        let period_seconds = self.period_seconds();
+        period_start_seconds(now, period_seconds)
+            .saturating_sub((self.number_of_periods() - 1) * period_seconds)
+    }
+}
+
+#[cw_serde]
+pub struct RollingWindowStats {
+    pub window: RollingWindow,
+    pub volume: Uint128,
+    pub volume_usdc: Uint128,
+    pub trades: u64,
+    // Floor at the beginning of the window (the current floor when nothing was traded)
+    pub opening_floor: Uint128,
+    pub current_floor: Uint128,
+}
+
+impl RollingWindowStats {
+    pub fn new(window: RollingWindow, current_floor: Uint128) -> Self {
+        RollingWindowStats {
+            window,
+            volume: Uint128::zero(),
+            volume_usdc: Uint128::zero(),
+            trades: 0,
+            opening_floor: current_floor,
+            current_floor,
+        }
+    }
+
+    // Periods need to be added from the oldest to the newest
+    pub fn add_period(
+        &mut self,
+        volume: Uint128,
+        volume_usdc: Uint128,
+        trades: u64,
+        opening_floor: Uint128,
+    ) -> &mut Self {
+        if self.trades == 0 {
+            self.opening_floor = opening_floor;
+        }
+        self.volume += volume;
+        self.volume_usdc += volume_usdc;
+        self.trades += trades;
+        self
+    }
+}
+
+#[cw_serde]
+pub struct CollectionRollingStats {
+    pub nft_collection_address: NftCollectionAddress,
+    pub denom: Denomination,
+    pub windows: Vec<RollingWindowStats>,
+}
+
+pub fn period_start_seconds(time: Timestamp, period_seconds: u64) -> u64 {
+    time.seconds() - time.seconds() % period_seconds
+}
+
+pub fn day_start_seconds(time: Timestamp) -> u64 {
+    period_start_seconds(time, SECONDS_PER_DAY)
+}
@@ -1,16 +1,17 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Deps, Uint128};
+use cosmwasm_std::{Deps, Timestamp, Uint128};
 
 use general_utils::denominations::Denomination;
 use general_utils::error::ContractError;
 use general_utils::error::NftMarketplaceError::{
     BuyAndSellCannotBeFilledTogether, BuyAndSellCannotBeNoneTogether, InvalidNftShowcaseReceived,
-    InvalidUsername,
 };
 
 use crate::helpers::check_if_nft_is_owned;
 use crate::nft_collection::{NftCollectionAddress, TokenId};
 use crate::reward_system::{RewardSystem, VipLevel};
+use crate::username::validate_username;
+use crate::verification::Verification;
 
 #[cw_serde]
 pub enum ProfileUpdateAction {
@@ -38,6 +39,7 @@ pub struct TradeInfo {
     pub volume_value: Uint128,
 }
 
+// Messages received were saved in the profile before the inbox, see LegacyProfile
 #[cw_serde]
 pub struct ProfileMessage {
     pub from_address: String,
@@ -56,6 +58,13 @@ pub struct Profile {
     pub address: String,
     pub username: Option<String>,
     pub vip_level: Option<VipLevel>,
+    // Set while the VIP level is a subscription, the level falls back once reached
+    #[serde(default)]
+    pub vip_expiration: Option<Timestamp>,
+    // Cumulative USDC volume of the trades, bought and sold, without the ones withheld by the wash
+    // trading rules
+    #[serde(default)]
+    pub volume_usdc: Option<Uint128>,
     pub profile_nft_collection: Option<NftCollectionAddress>,
     pub profile_nft_token_id: Option<TokenId>,
     pub background_nft_collection: Option<NftCollectionAddress>,
@@ -63,19 +72,27 @@ pub struct Profile {
     pub description: Option<String>,
     pub nft_showcase: Option<Vec<NftShowcase>>,
     pub links: Option<Socials>,
-    pub profile_messages: Option<ProfileMessages>,
     pub number_of_trades: Option<u64>,
     pub buy_info: Option<Vec<TradeInfo>>,
     pub sell_info: Option<Vec<TradeInfo>>,
     pub display_trade_info: Option<bool>,
 }
 
+// Returned by GetProfileInfo with the active verifications of the profile
+#[cw_serde]
+pub struct ProfileResponse {
+    pub profile: Profile,
+    pub verifications: Vec<Verification>,
+}
+
 impl Profile {
     pub fn new(address: String) -> Self {
         Profile {
             address,
             username: None,
-            vip_level: Some(VipLevel::Level0),
+            vip_level: Some(VipLevel::default()),
+            vip_expiration: None,
+            volume_usdc: None,
             profile_nft_collection: None,
             profile_nft_token_id: None,
             background_nft_collection: None,
@@ -83,7 +100,6 @@ impl Profile {
             description: None,
             nft_showcase: None,
             links: None,
-            profile_messages: Some(ProfileMessages { display_on_profile: true, messages: vec![] }),
             number_of_trades: Some(0),
             buy_info: Some(vec![]),
             sell_info: Some(vec![]),
@@ -96,8 +112,13 @@ impl Profile {
         deps: Deps,
         new_profile_info: Profile,
         reward_system: RewardSystem,
+        partner_vip_level: VipLevel,
         profile_update_action: ProfileUpdateAction,
+        time: Timestamp,
     ) -> Result<Self, ContractError> {
+        self = self.expire_vip_level(&reward_system, time);
+        // Holders of partner collections get the perks of the highest level, see PerksCombination
+        let perks_vip_level = self.vip_level.unwrap_or_default().max(partner_vip_level);
         if let Some(new_nft_showcase) = new_profile_info.nft_showcase.clone() {
             if new_nft_showcase.len() > 4 {
                 return Err(ContractError::NftMarketplaceError(InvalidNftShowcaseReceived {}));
@@ -109,43 +130,9 @@ impl Profile {
                 if let Some(display_trade_info) = new_profile_info.display_trade_info {
                     self.display_trade_info = Some(display_trade_info);
                 }
-                if let Some(new_profile_messages) = new_profile_info.profile_messages {
-                    let mut current_message_info =
-                        self.profile_messages
-                            .clone()
-                            .unwrap_or_else(|| ProfileMessages {
-                                display_on_profile: false,
-                                messages: vec![],
-                            });
-                    current_message_info.display_on_profile =
-                        new_profile_messages.display_on_profile;
-                    self.profile_messages = Some(ProfileMessages {
-                        display_on_profile: current_message_info.display_on_profile,
-                        messages: self
-                            .profile_messages
-                            .clone()
-                            .unwrap_or_else(|| ProfileMessages {
-                                display_on_profile: current_message_info.display_on_profile,
-                                messages: vec![],
-                            })
-                            .messages,
-                    });
-                }
-                if new_profile_info.username.is_some() {
-                    if new_profile_info.username.clone().unwrap().is_ascii()
-                        && new_profile_info
-                            .username
-                            .clone()
-                            .unwrap()
-                            .chars()
-                            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.')
-                        && new_profile_info.username.clone().unwrap().len() > 50
-                        && new_profile_info.username.clone().unwrap().len() < 2
-                    {
-                        return Err(ContractError::NftMarketplaceError(InvalidUsername {}));
-                    } else {
-                        self.username = Some(new_profile_info.username.clone().unwrap());
-                    }
+                if let Some(username) = new_profile_info.username.clone() {
+                    validate_username(deps.api, &username)?;
+                    self.username = Some(username);
                 }
                 if let (Some(profile_nft_collection), Some(profile_nft_token_id)) = (
                     new_profile_info.profile_nft_collection,
@@ -160,76 +147,70 @@ impl Profile {
                     self.profile_nft_collection = Some(profile_nft_collection);
                     self.profile_nft_token_id = Some(profile_nft_token_id);
                 }
-                for vip_perk_system in reward_system.vip_perks.into_iter() {
-                    if self.vip_level == Some(vip_perk_system.vip_level) {
-                        let new_links = new_profile_info.links.clone();
-                        let new_showcase = new_profile_info.nft_showcase.clone();
-                        let new_background_nft_collection =
-                            new_profile_info.background_nft_collection.clone();
-                        let new_background_nft_token_id =
-                            new_profile_info.background_nft_token_id.clone();
+                if let Some(vip_perk_system) = reward_system.vip_tier(perks_vip_level) {
+                    let new_links = new_profile_info.links.clone();
+                    let new_showcase = new_profile_info.nft_showcase.clone();
+                    let new_background_nft_collection =
+                        new_profile_info.background_nft_collection.clone();
+                    let new_background_nft_token_id =
+                        new_profile_info.background_nft_token_id.clone();
 
-                        if vip_perk_system.profile_description
-                            && new_profile_info.description.is_some()
-                        {
-                            self.description = new_profile_info.description.clone();
-                        }
-                        if vip_perk_system.profile_background {
-                            if let (Some(collection), Some(token_id)) = (
-                                new_background_nft_collection.clone(),
-                                new_background_nft_token_id.clone(),
-                            ) {
-                                check_if_nft_is_owned(deps, &self.address, &collection, &token_id)?;
-                                self.background_nft_token_id = new_background_nft_token_id;
-                                self.background_nft_collection = new_background_nft_collection;
-                            }
+                    if vip_perk_system.profile_description && new_profile_info.description.is_some()
+                    {
+                        self.description = new_profile_info.description.clone();
+                    }
+                    if vip_perk_system.profile_background {
+                        if let (Some(collection), Some(token_id)) = (
+                            new_background_nft_collection.clone(),
+                            new_background_nft_token_id.clone(),
+                        ) {
+                            check_if_nft_is_owned(deps, &self.address, &collection, &token_id)?;
+                            self.background_nft_token_id = new_background_nft_token_id;
+                            self.background_nft_collection = new_background_nft_collection;
                         }
-                        if vip_perk_system.profile_nft_showcase {
-                            if let Some(received_showcase) = new_showcase {
-                                let mut current_showcase =
-                                    self.nft_showcase.clone().unwrap_or_default();
-                                for showcase in received_showcase {
-                                    check_if_nft_is_owned(
-                                        deps,
-                                        &self.address,
-                                        &showcase.collection,
-                                        &showcase.token_id,
-                                    )?;
-                                    if current_showcase.len() >= 4 {
-                                        current_showcase.remove(0);
-                                        current_showcase.push(showcase.clone());
-                                    } else {
-                                        current_showcase.push(showcase.clone());
-                                    }
+                    }
+                    if vip_perk_system.profile_nft_showcase {
+                        if let Some(received_showcase) = new_showcase {
+                            let mut current_showcase =
+                                self.nft_showcase.clone().unwrap_or_default();
+                            for showcase in received_showcase {
+                                check_if_nft_is_owned(
+                                    deps,
+                                    &self.address,
+                                    &showcase.collection,
+                                    &showcase.token_id,
+                                )?;
+                                if current_showcase.len() >= 4 {
+                                    current_showcase.remove(0);
+                                    current_showcase.push(showcase.clone());
+                                } else {
+                                    current_showcase.push(showcase.clone());
                                 }
-                                self.nft_showcase = Some(current_showcase);
                             }
+                            self.nft_showcase = Some(current_showcase);
                         }
-                        if vip_perk_system.profile_links {
-                            if let Some(new_links) = new_links {
-                                let mut current_links = self.links.unwrap_or(Socials {
-                                    twitter_link: None,
-                                    discord_link: None,
-                                    telegram_link: None,
-                                    additional_social_link: None,
-                                });
-                                if let Some(telegram_link) = new_links.telegram_link {
-                                    current_links.telegram_link = Some(telegram_link);
-                                }
-                                if let Some(discord_link) = new_links.discord_link {
-                                    current_links.discord_link = Some(discord_link);
-                                }
-                                if let Some(twitter_link) = new_links.twitter_link {
-                                    current_links.twitter_link = Some(twitter_link);
-                                }
-                                if let Some(additional_social_link) =
-                                    new_links.additional_social_link
-                                {
-                                    current_links.additional_social_link =
-                                        Some(additional_social_link);
-                                }
-                                self.links = Some(current_links);
+                    }
+                    if vip_perk_system.profile_links {
+                        if let Some(new_links) = new_links {
+                            let mut current_links = self.links.unwrap_or(Socials {
+                                twitter_link: None,
+                                discord_link: None,
+                                telegram_link: None,
+                                additional_social_link: None,
+                            });
+                            if let Some(telegram_link) = new_links.telegram_link {
+                                current_links.telegram_link = Some(telegram_link);
+                            }
+                            if let Some(discord_link) = new_links.discord_link {
+                                current_links.discord_link = Some(discord_link);
+                            }
+                            if let Some(twitter_link) = new_links.twitter_link {
+                                current_links.twitter_link = Some(twitter_link);
                             }
+                            if let Some(additional_social_link) = new_links.additional_social_link {
+                                current_links.additional_social_link = Some(additional_social_link);
+                            }
+                            self.links = Some(current_links);
                         }
                     }
                 }
@@ -247,47 +228,44 @@ impl Profile {
                     self.profile_nft_collection = None;
                     self.profile_nft_token_id = None;
                 }
-                for vip_perk_system in reward_system.vip_perks {
-                    if self.vip_level == Some(vip_perk_system.vip_level) {
-                        if vip_perk_system.profile_description
-                            && new_profile_info.description.is_some()
-                        {
-                            self.description = None;
+                if let Some(vip_perk_system) = reward_system.vip_tier(perks_vip_level) {
+                    if vip_perk_system.profile_description && new_profile_info.description.is_some()
+                    {
+                        self.description = None;
+                    }
+                    if vip_perk_system.profile_background
+                        && new_profile_info.background_nft_collection.is_some()
+                        && new_profile_info.background_nft_token_id.is_some()
+                    {
+                        self.background_nft_token_id = None;
+                        self.background_nft_collection = None;
+                    }
+                    if vip_perk_system.profile_nft_showcase
+                        && new_profile_info.nft_showcase.is_some()
+                    {
+                        self.nft_showcase = None;
+                    }
+                    if vip_perk_system.profile_links && new_profile_info.links.is_some() {
+                        let new_links = new_profile_info.links.clone().unwrap();
+                        let mut current_links = self.links.unwrap_or(Socials {
+                            twitter_link: None,
+                            discord_link: None,
+                            telegram_link: None,
+                            additional_social_link: None,
+                        });
+                        if new_links.telegram_link.is_some() {
+                            current_links.telegram_link = None;
                         }
-                        if vip_perk_system.profile_background
-                            && new_profile_info.background_nft_collection.is_some()
-                            && new_profile_info.background_nft_token_id.is_some()
-                        {
-                            self.background_nft_token_id = None;
-                            self.background_nft_collection = None;
+                        if new_links.discord_link.is_some() {
+                            current_links.discord_link = None;
                         }
-                        if vip_perk_system.profile_nft_showcase
-                            && new_profile_info.nft_showcase.is_some()
-                        {
-                            self.nft_showcase = None;
+                        if new_links.twitter_link.is_some() {
+                            current_links.twitter_link = None;
                         }
-                        if vip_perk_system.profile_links && new_profile_info.links.is_some() {
-                            let new_links = new_profile_info.links.clone().unwrap();
-                            let mut current_links = self.links.unwrap_or(Socials {
-                                twitter_link: None,
-                                discord_link: None,
-                                telegram_link: None,
-                                additional_social_link: None,
-                            });
-                            if new_links.telegram_link.is_some() {
-                                current_links.telegram_link = None;
-                            }
-                            if new_links.discord_link.is_some() {
-                                current_links.discord_link = None;
-                            }
-                            if new_links.twitter_link.is_some() {
-                                current_links.twitter_link = None;
-                            }
-                            if new_links.additional_social_link.is_some() {
-                                current_links.additional_social_link = None;
-                            }
-                            self.links = Some(current_links);
+                        if new_links.additional_social_link.is_some() {
+                            current_links.additional_social_link = None;
                         }
+                        self.links = Some(current_links);
                     }
                 }
             }
@@ -295,38 +273,35 @@ impl Profile {
         Ok(self)
     }
 
-    pub fn level_up(mut self) -> Self {
-        self.vip_level = Some(self.vip_level.unwrap().next_level());
+    pub fn level_up(mut self, reward_system: &RewardSystem, time: Timestamp) -> Self {
+        let vip_level = self.vip_level.unwrap_or_default().next_level();
+        self.vip_level = Some(vip_level);
+        self.vip_expiration = reward_system.vip_expiration(vip_level, time);
         self
     }
 
-    pub fn receive_message(
-        mut self,
-        from_address: String,
-        from_username: Option<String>,
-        message: String,
-    ) -> Self {
-        let new_profile_messages = if let Some(mut profile_messages) = self.profile_messages {
-            if profile_messages.messages.len() >= 10 {
-                profile_messages.messages.remove(0);
-            }
-            profile_messages.messages.push(ProfileMessage {
-                from_address,
-                from_username,
-                message,
-            });
-            profile_messages
-        } else {
-            ProfileMessages {
-                display_on_profile: false,
-                messages: vec![ProfileMessage {
-                    from_address,
-                    from_username,
-                    message,
-                }],
-            }
-        };
-        self.profile_messages = Some(new_profile_messages);
+    // Renewals made before the expiration extend it
+    pub fn renew_vip_level(mut self, subscription_duration: u64, time: Timestamp) -> Self {
+        let renewed_from = self
+            .vip_expiration
+            .map_or(time, |expiration| expiration.max(time));
+        self.vip_expiration = Some(renewed_from.plus_seconds(subscription_duration));
+        self
+    }
+
+    // Expirations are applied lazily, when the level is read
+    pub fn expire_vip_level(mut self, reward_system: &RewardSystem, time: Timestamp) -> Self {
+        if self
+            .vip_expiration
+            .map_or(false, |expiration| expiration <= time)
+        {
+            // Levels reached by volume never expire
+            let fallback_vip_level = reward_system
+                .fallback_vip_level(self.vip_level.unwrap_or_default())
+                .max(reward_system.volume_vip_level(self.volume_usdc.unwrap_or_default()));
+            self.vip_level = Some(fallback_vip_level);
+            self.vip_expiration = None;
+        }
         self
     }
 
@@ -334,6 +309,8 @@ impl Profile {
         mut self,
         buy: Option<TradeInfo>,
         sell: Option<TradeInfo>,
+        volume_usdc: Uint128,
+        reward_system: &RewardSystem,
     ) -> Result<Self, ContractError> {
         match (buy, sell) {
             (None, None) => {
@@ -377,6 +354,14 @@ impl Profile {
                 self.number_of_trades = Some(1);
             }
         }
+        let total_volume_usdc = self.volume_usdc.unwrap_or_default() + volume_usdc;
+        self.volume_usdc = Some(total_volume_usdc);
+        // Reaching the threshold of a higher tier upgrades the profile for good
+        let volume_vip_level = reward_system.volume_vip_level(total_volume_usdc);
+        if volume_vip_level > self.vip_level.unwrap_or_default() {
+            self.vip_level = Some(volume_vip_level);
+            self.vip_expiration = None;
+        }
         Ok(self)
     }
 
@@ -1,23 +1,33 @@
-use std::str::FromStr;
-
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{
-    coin, to_binary, Addr, Attribute, BankMsg, Decimal, Deps, Event, Response, StdError, Uint128,
+    coin, to_binary, Addr, Attribute, BankMsg, Deps, Event, Response, StdError, Timestamp, Uint128,
     WasmMsg,
 };
 use cw721::Cw721ExecuteMsg;
 
 use general_utils::denominations::DenominationValue;
 
+use crate::circuit_breaker::CircuitBreaker;
+use crate::competition::{Competition, CompetitionPrize};
+use crate::follow::{Follow, WatchedToken};
+use crate::inbox::{InboxMessage, InboxThread};
 use crate::inputs::Buyer;
+use crate::leaderboard::LeaderboardSeason;
+use crate::messaging::MessageFilter;
+use crate::migration::MigrationProgress;
 use crate::nft_offer::NftOffer;
 use crate::nft_sale::NftSale;
 use crate::profile::Profile;
-use crate::reward_system::{RewardSystem, VipLevel};
+use crate::reward_system::{RewardPool, VipLevel, VipUpgrade};
+use crate::staking::Unbonding;
+use crate::tokenfactory::TokenFactoryMsg;
+use crate::username::UsernameSale;
+use crate::verification::Verification;
+use crate::wash_trading::WithheldReward;
 
 #[cw_serde]
 pub struct ResponseHandler {
-    pub response: Response,
+    pub response: Response<TokenFactoryMsg>,
 }
 
 #[cw_serde]
@@ -42,6 +52,18 @@ impl ResponseHandler {
         }
     }
 
+    pub fn migrate_response(migration_progress: MigrationProgress, completed: bool) -> Self {
+        ResponseHandler {
+            response: Response::new().add_event(
+                Event::new("Migrate NFT Marketplace contract")
+                    .add_attribute("From version", migration_progress.from_version)
+                    .add_attribute("To version", migration_progress.to_version)
+                    .add_attribute("Migration step", migration_progress.step.to_string())
+                    .add_attribute("Migration completed", completed.to_string()),
+            ),
+        }
+    }
+
     pub fn add_nft_collection(nft_collection_address: &str) -> Self {
         let mut response = Response::new();
         response = response
@@ -92,6 +114,31 @@ impl ResponseHandler {
         }
     }
 
+    pub fn set_circuit_breaker(circuit_breaker: CircuitBreaker) -> Self {
+        ResponseHandler {
+            response: Response::new().add_event(
+                Event::new("Circuit Breaker")
+                    .add_attribute("Capability", circuit_breaker.capability.to_string())
+                    .add_attribute("Paused", circuit_breaker.paused.to_string())
+                    .add_attribute("Reason", circuit_breaker.reason.unwrap_or_default())
+                    .add_attribute("Updated by", circuit_breaker.updated_by.unwrap_or_default()),
+            ),
+        }
+    }
+
+    pub fn start_leaderboard_season(season: LeaderboardSeason) -> Self {
+        ResponseHandler {
+            response: Response::new().add_event(
+                Event::new("Leaderboard Season")
+                    .add_attribute("Season id", season.season_id.to_string())
+                    .add_attribute(
+                        "Start time",
+                        season.start_time.unwrap_or_default().seconds().to_string(),
+                    ),
+            ),
+        }
+    }
+
     pub fn claim_marketplace_fees(owner: String, vec_of_fees: Vec<DenominationValue>) -> Self {
         let mut response = Response::new();
         let vec_of_messages: Vec<BankMsg> = vec_of_fees
@@ -111,6 +158,138 @@ impl ResponseHandler {
         ResponseHandler { response }
     }
 
+    pub fn fund_reward_pool(sender: String, amount: Uint128, reward_pool: RewardPool) -> Self {
+        let response = Response::new().add_event(
+            Event::new("Fund Reward Pool")
+                .add_attribute("Sender", sender)
+                .add_attribute("Amount", amount)
+                .add_attribute("Pool Balance", reward_pool.balance),
+        );
+        Self { response }
+    }
+
+    pub fn claim_rewards(address: String, amount: Uint128, reward_token: String) -> Self {
+        let response = Response::new()
+            .add_event(
+                Event::new("Claim Rewards")
+                    .add_attribute("Address", address.clone())
+                    .add_attribute("Amount", amount),
+            )
+            .add_message(BankMsg::Send {
+                to_address: address,
+                amount: vec![coin(amount.u128(), reward_token)],
+            });
+        Self { response }
+    }
+
+    pub fn stake(address: String, amount: Uint128, staked: Uint128) -> Self {
+        let response = Response::new().add_event(
+            Event::new("Stake")
+                .add_attribute("Address", address)
+                .add_attribute("Amount", amount)
+                .add_attribute("Staked", staked),
+        );
+        Self { response }
+    }
+
+    pub fn unstake(address: String, unbonding: Unbonding, staked: Uint128) -> Self {
+        let response = Response::new().add_event(
+            Event::new("Unstake")
+                .add_attribute("Address", address)
+                .add_attribute("Amount", unbonding.amount)
+                .add_attribute("Release time", unbonding.release_time.to_string())
+                .add_attribute("Staked", staked),
+        );
+        Self { response }
+    }
+
+    pub fn withdraw_unbonded(address: String, amount: Uint128, reward_token: String) -> Self {
+        let response = Response::new()
+            .add_event(
+                Event::new("Withdraw Unbonded")
+                    .add_attribute("Address", address.clone())
+                    .add_attribute("Amount", amount),
+            )
+            .add_message(BankMsg::Send {
+                to_address: address,
+                amount: vec![coin(amount.u128(), reward_token)],
+            });
+        Self { response }
+    }
+
+    pub fn claim_staking_fees(address: String, fees: Vec<DenominationValue>) -> Self {
+        let mut response = Response::new();
+        for denom_value in fees {
+            response = response
+                .add_event(
+                    Event::new("Claim Staking Fees")
+                        .add_attribute("Address", address.clone())
+                        .add_attribute("Denom", denom_value.denom.clone())
+                        .add_attribute("Amount", denom_value.value),
+                )
+                .add_message(BankMsg::Send {
+                    to_address: address.clone(),
+                    amount: vec![coin(denom_value.value.u128(), denom_value.denom)],
+                });
+        }
+        Self { response }
+    }
+
+    pub fn create_competition(competition: Competition) -> Self {
+        let collections = if competition.nft_collection_addresses.is_empty() {
+            "all".to_string()
+        } else {
+            competition.nft_collection_addresses.join(",")
+        };
+        let response = Response::new().add_event(
+            Event::new("Create Competition")
+                .add_attribute("Competition id", competition.competition_id.to_string())
+                .add_attribute("Start time", competition.start_time.seconds().to_string())
+                .add_attribute("End time", competition.end_time.seconds().to_string())
+                .add_attribute("Collections", collections)
+                .add_attribute("Prize pool", competition.prize_pool())
+                .add_attribute("Prize denom", competition.prize_denom),
+        );
+        Self { response }
+    }
+
+    pub fn finalize_competition(
+        competition: Competition,
+        winners: usize,
+        contract_owner: String,
+        refund: Uint128,
+    ) -> Self {
+        let mut response = Response::new().add_event(
+            Event::new("Finalize Competition")
+                .add_attribute("Competition id", competition.competition_id.to_string())
+                .add_attribute("Winners", winners.to_string())
+                .add_attribute("Refund", refund),
+        );
+        if !refund.is_zero() {
+            response = response.add_message(BankMsg::Send {
+                to_address: contract_owner,
+                amount: vec![coin(refund.u128(), competition.prize_denom)],
+            });
+        }
+        Self { response }
+    }
+
+    pub fn claim_competition_prize(prize: CompetitionPrize, prize_denom: String) -> Self {
+        let response = Response::new()
+            .add_event(
+                Event::new("Claim Competition Prize")
+                    .add_attribute("Competition id", prize.competition_id.to_string())
+                    .add_attribute("Address", prize.address.clone())
+                    .add_attribute("Rank", prize.rank.to_string())
+                    .add_attribute("Amount", prize.amount),
+            )
+            .add_message(BankMsg::Send {
+                to_address: prize.address,
+                amount: vec![coin(prize.amount.u128(), prize_denom)],
+            });
+        Self { response }
+    }
+
     pub fn execute_update_sale(cancel_sale: WasmMsg, make_sale: WasmMsg) -> Self {
         let mut response = Response::new().add_event(Event::new("Update Existing Sale"));
         response = response.add_message(cancel_sale);
@@ -204,45 +383,16 @@ impl ResponseHandler {
         _deps: Deps,
         buyer: Buyer,
         nft_for_sale_info: NftSale,
-        marketplace_fees_pct: Decimal,
+        marketplace_revenues: Uint128,
+        buyer_fees_refund: Uint128,
         royalties: Vec<RoyaltiesInfoResponse>,
-        seller_profile: Profile,
-        _buyer_profile: Profile,
-        reward_system: RewardSystem,
-        realised_sale_value_usdc: Uint128,
+        buyer_rewards: Uint128,
+        seller_rewards: Uint128,
+        withheld_rewards: Vec<WithheldReward>,
+        vip_upgrades: Vec<VipUpgrade>,
     ) -> Result<Self, StdError> {
-        // Discount for VIP system
-        let mut discount_from_vip: Decimal = Decimal::from_str("1.0").unwrap();
-        for vip_lvl in reward_system.vip_perks {
-            if Some(vip_lvl.vip_level) == seller_profile.vip_level.clone() {
-                discount_from_vip =
-                    discount_from_vip.checked_sub(vip_lvl.marketplace_fees_discount)?;
-            }
-        }
-
-        // For each sale and buy -> send reward tokens
-        let mut reward_transfer_msgs: Vec<BankMsg> = Vec::with_capacity(2_usize);
-        for addr in vec![buyer.sender.clone(), seller_profile.address].into_iter() {
-            let reward_tokens_to_dist: Uint128 = realised_sale_value_usdc
-                .checked_div(Uint128::new(1_000_000u128))?
-                .checked_mul(reward_system.reward_token_per_1usdc_volume)?;
-            if reward_tokens_to_dist >= Uint128::new(1u128) {
-                reward_transfer_msgs.push(BankMsg::Send {
-                    to_address: addr.to_string(),
-                    amount: vec![coin(
-                        reward_tokens_to_dist.u128(),
-                        reward_system.reward_token_address.clone(),
-                    )],
-                });
-            }
-        }
-
         let mut response = Response::new();
         let total_sale_price_value = nft_for_sale_info.sale_price_value;
-        let marketplace_revenues = NftSale::compute_marketplace_fees(
-            marketplace_fees_pct.checked_mul(discount_from_vip)?,
-            nft_for_sale_info.sale_price_value,
-        );
         let attributes_for_royalties: Vec<Attribute> = royalties
             .iter()
             .filter(|royalty| royalty.royalty_amount > Uint128::zero())
@@ -291,13 +441,17 @@ impl ResponseHandler {
                         "Sold in denom",
                         nft_for_sale_info.sale_price_denom.to_string(),
                     )
-                    .add_attribute("Marketplace fees", marketplace_revenues.to_string()),
+                    .add_attribute("Marketplace fees", marketplace_revenues.to_string())
+                    .add_attribute("Buyer fees refund", buyer_fees_refund.to_string())
+                    .add_attribute("Buyer rewards", buyer_rewards.to_string())
+                    .add_attribute("Seller rewards", seller_rewards.to_string()),
             )
             .add_message(BankMsg::Send {
                 to_address: nft_for_sale_info.seller,
                 amount: vec![coin(
                     total_sale_price_value.u128()
                         - marketplace_revenues.clone().u128()
+                        - buyer_fees_refund.u128()
                         - royalties
                             .iter()
                             .map(|r| r.royalty_amount)
@@ -306,12 +460,37 @@ impl ResponseHandler {
                     buyer.denom,
                 )],
             })
-            .add_message(exec_cw721_transfer)
-            .add_messages(reward_transfer_msgs);
+            .add_message(exec_cw721_transfer);
+        if !buyer_fees_refund.is_zero() {
+            response = response.add_message(BankMsg::Send {
+                to_address: buyer.sender.to_string(),
+                amount: vec![coin(
+                    buyer_fees_refund.u128(),
+                    nft_for_sale_info.sale_price_denom.clone(),
+                )],
+            });
+        }
         if !messages_for_royalties.is_empty() {
             response = response.add_attributes(attributes_for_royalties);
             response = response.add_messages(messages_for_royalties);
         }
+        for withheld_reward in withheld_rewards {
+            response = response.add_event(
+                Event::new("Withheld Rewards")
+                    .add_attribute("Address", withheld_reward.address)
+                    .add_attribute("Amount", withheld_reward.amount.to_string())
+                    .add_attribute("Reason", withheld_reward.reason.to_string()),
+            );
+        }
+        for vip_upgrade in vip_upgrades {
+            response = response.add_event(
+                Event::new("VIP Volume Upgrade")
+                    .add_attribute("Address", vip_upgrade.address)
+                    .add_attribute("From level", vip_upgrade.previous_level.to_string())
+                    .add_attribute("To level", vip_upgrade.vip_level.to_string())
+                    .add_attribute("Tier", vip_upgrade.tier_name),
+            );
+        }
         Ok(ResponseHandler { response })
     }
 
@@ -346,21 +525,48 @@ impl ResponseHandler {
     pub fn level_up_profile(
         previous_level: VipLevel,
         current_level: VipLevel,
+        tier_name: String,
+        vip_expiration: Option<Timestamp>,
     ) -> Result<Self, StdError> {
-        let response = Response::new()
+        let mut response = Response::new()
             .add_event(Event::new("Level Up Profile"))
             .add_attribute("From level", previous_level.to_string())
-            .add_attribute("To level", current_level.to_string());
+            .add_attribute("To level", current_level.to_string())
+            .add_attribute("Tier", tier_name);
+        if let Some(vip_expiration) = vip_expiration {
+            response = response.add_attribute("Expiration", vip_expiration.to_string());
+        }
         Ok(Self { response })
     }
 
+    pub fn renew_vip_level(
+        address: String,
+        vip_level: VipLevel,
+        tier_name: String,
+        vip_expiration: Timestamp,
+    ) -> Self {
+        let response = Response::new().add_event(
+            Event::new("Renew VIP Level")
+                .add_attribute("Address", address)
+                .add_attribute("Level", vip_level.to_string())
+                .add_attribute("Tier", tier_name)
+                .add_attribute("Expiration", vip_expiration.to_string()),
+        );
+        Self { response }
+    }
+
     pub fn send_message(
         sender_address: String,
         sender_username: Option<String>,
         receiver_address: String,
         receiver_username: Option<String>,
+        message_fee_msg: Option<BankMsg>,
     ) -> Self {
-        let response = Response::new()
+        let mut response = Response::new();
+        if let Some(message_fee_msg) = message_fee_msg {
+            response = response.add_message(message_fee_msg);
+        }
+        let response = response
             .add_event(Event::new("Send Message"))
             .add_attribute("From Address", sender_address)
             .add_attribute(
@@ -375,6 +581,157 @@ impl ResponseHandler {
         Self { response }
     }
 
+    pub fn set_message_filter(address: String, message_filter: MessageFilter) -> Self {
+        let response = Response::new().add_event(
+            Event::new("Message Filter")
+                .add_attribute("Address", address)
+                .add_attribute(
+                    "Min Trades",
+                    message_filter
+                        .min_trades
+                        .map_or_else(|| "None".to_string(), |min_trades| min_trades.to_string()),
+                )
+                .add_attribute(
+                    "Min Vip Level",
+                    message_filter
+                        .min_vip_level
+                        .map_or_else(|| "None".to_string(), |vip_level| vip_level.to_string()),
+                ),
+        );
+        Self { response }
+    }
+
+    pub fn mark_thread_read(thread: InboxThread) -> Self {
+        let response = Response::new().add_event(
+            Event::new("Mark Thread Read")
+                .add_attribute("Address", thread.address)
+                .add_attribute("Counterparty", thread.counterparty)
+                .add_attribute(
+                    "Last Read Message Id",
+                    thread.last_read_message_id.unwrap_or_default().to_string(),
+                ),
+        );
+        Self { response }
+    }
+
+    pub fn delete_message(message: InboxMessage) -> Self {
+        let response = Response::new().add_event(
+            Event::new("Delete Message")
+                .add_attribute("Message Id", message.message_id.to_string())
+                .add_attribute("From Address", message.from_address)
+                .add_attribute("To Address", message.to_address),
+        );
+        Self { response }
+    }
+
+    pub fn block_address(address: String, blocked_address: String, block: bool) -> Self {
+        let response = Response::new().add_event(
+            Event::new(if block {
+                "Block Address"
+            } else {
+                "Unblock Address"
+            })
+            .add_attribute("Address", address)
+            .add_attribute("Blocked Address", blocked_address),
+        );
+        Self { response }
+    }
+
+    pub fn sell_username(username_sale: UsernameSale) -> Self {
+        let response = Response::new().add_event(
+            Event::new("Sell Username")
+                .add_attribute("Username", username_sale.username)
+                .add_attribute("Seller", username_sale.seller)
+                .add_attribute("Price Value", username_sale.price_value)
+                .add_attribute("Price Denom", username_sale.price_denom),
+        );
+        Self { response }
+    }
+
+    pub fn cancel_username_sale(username: String, seller: String) -> Self {
+        let response = Response::new().add_event(
+            Event::new("Cancel Username Sale")
+                .add_attribute("Username", username)
+                .add_attribute("Seller", seller),
+        );
+        Self { response }
+    }
+
+    pub fn buy_username(
+        username_sale: UsernameSale,
+        buyer: String,
+        marketplace_fees: Uint128,
+    ) -> Self {
+        let seller_revenues = username_sale.price_value - marketplace_fees;
+        let mut response = Response::new();
+        if !seller_revenues.is_zero() {
+            response = response.add_message(BankMsg::Send {
+                to_address: username_sale.seller.clone(),
+                amount: vec![coin(
+                    seller_revenues.u128(),
+                    username_sale.price_denom.clone(),
+                )],
+            });
+        }
+        let response = response.add_event(
+            Event::new("Buy Username")
+                .add_attribute("Username", username_sale.username)
+                .add_attribute("Seller", username_sale.seller)
+                .add_attribute("Buyer", buyer)
+                .add_attribute("Price Value", username_sale.price_value)
+                .add_attribute("Price Denom", username_sale.price_denom)
+                .add_attribute("Marketplace Fees", marketplace_fees),
+        );
+        Self { response }
+    }
+
+    pub fn transfer_username(username: String, from: String, to: String) -> Self {
+        let response = Response::new().add_event(
+            Event::new("Transfer Username")
+                .add_attribute("Username", username)
+                .add_attribute("From", from)
+                .add_attribute("To", to),
+        );
+        Self { response }
+    }
+
+    pub fn verification(verification: Verification, sender: String, verify: bool) -> Self {
+        let response = Response::new().add_event(
+            Event::new(if verify {
+                "Verify"
+            } else {
+                "Revoke Verification"
+            })
+            .add_attribute("Subject", verification.subject.to_string())
+            .add_attribute("Kind", verification.kind.to_string())
+            .add_attribute("Sender", sender),
+        );
+        Self { response }
+    }
+
+    pub fn follow(follow: Follow, is_follow: bool) -> Self {
+        let response = Response::new().add_event(
+            Event::new(if is_follow { "Follow" } else { "Unfollow" })
+                .add_attribute("Follower", follow.follower)
+                .add_attribute("Target", follow.target.to_string()),
+        );
+        Self { response }
+    }
+
+    pub fn watch_token(address: String, watched_token: WatchedToken, watch: bool) -> Self {
+        let response = Response::new().add_event(
+            Event::new(if watch {
+                "Watch Token"
+            } else {
+                "Unwatch Token"
+            })
+            .add_attribute("Address", address)
+            .add_attribute("Collection", watched_token.nft_collection_address)
+            .add_attribute("Token ID", watched_token.token_id),
+        );
+        Self { response }
+    }
+
     pub fn create_or_update_profile(profile: Profile) -> Result<Self, StdError> {
         let response = Response::new()
             .add_event(Event::new("Profile"))
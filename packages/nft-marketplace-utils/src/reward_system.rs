@@ -1,20 +1,31 @@
 use std::fmt;
 
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{ensure, Api, Decimal, Uint128};
+use cosmwasm_std::{ensure, Api, Decimal, Timestamp, Uint128};
+use schemars::JsonSchema;
+use serde::de::{self, Unexpected, Visitor};
+use serde::{Deserialize, Deserializer, Serialize};
 
 use general_utils::error::ContractError;
 use general_utils::error::NftMarketplaceError::{
-    AlreadyLevel3, InvalidAmountReceivedForLevelUp, InvalidLevelUp, InvalidRewards,
+    AlreadyMaxVipLevel, InvalidAmountReceivedForLevelUp, InvalidRewards, InvalidVipTiers,
     NeedToFillAllThePerks,
 };
 
+use crate::reward_vesting::RewardVesting;
+
+pub const MAX_VIP_TIERS: usize = 20;
+
 #[cw_serde]
 pub struct RewardSystem {
     pub reward_token_address: String,
     pub reward_token_per_1usdc_volume: Uint128,
     pub total_reward_tokens_distributed: Uint128,
-    pub vip_perks: Vec<VipPerk>,
+    // Tier n is unlocked at level n, in order
+    pub vip_tiers: Vec<VipTier>,
+    // Rewards are liquid right away without vesting
+    #[serde(default)]
+    pub vesting: Option<RewardVesting>,
 }
 
 impl Default for RewardSystem {
@@ -24,7 +35,8 @@ impl Default for RewardSystem {
             reward_token_address: "".to_string(),
             reward_token_per_1usdc_volume: Default::default(),
             total_reward_tokens_distributed: Default::default(),
-            vip_perks: vec![],
+            vip_tiers: vec![],
+            vesting: None,
         }
     }
 }
@@ -35,7 +47,8 @@ impl RewardSystem {
         reward_token_address: String,
         reward_token_per_1usdc_volume: Uint128,
         total_reward_tokens_distributed: Uint128,
-        vip_perks: Vec<VipPerk>,
+        vip_tiers: Vec<VipTier>,
+        vesting: Option<RewardVesting>,
     ) -> Result<Self, ContractError> {
         ensure!(
             reward_token_per_1usdc_volume >= Uint128::new(1u128),
@@ -47,71 +60,194 @@ impl RewardSystem {
             ContractError::NftMarketplaceError(InvalidRewards {})
         );
 
-        let mut seen = [false; 3];
-        for perk in &vip_perks {
-            match perk.vip_level {
-                VipLevel::Level0 => {}
-                VipLevel::Level1 => seen[0] = true,
-                VipLevel::Level2 => seen[1] = true,
-                VipLevel::Level3 => seen[2] = true,
-            }
-        }
-        ensure!(
-            seen.iter().all(|&p| p),
-            ContractError::NftMarketplaceError(NeedToFillAllThePerks {})
-        );
+        validate_vip_tiers(&vip_tiers)?;
 
-        ensure!(
-            !vip_perks.iter().any(|perk| {
-                perk.marketplace_fees_discount < Decimal::percent(1u64)
-                    || perk.marketplace_fees_discount > Decimal::percent(50u64)
-            }),
-            ContractError::NftMarketplaceError(InvalidRewards {})
-        );
+        if let Some(vesting) = &vesting {
+            vesting.validate()?;
+        }
 
         Ok(RewardSystem {
             reward_token_address,
             reward_token_per_1usdc_volume,
             total_reward_tokens_distributed,
-            vip_perks,
+            vip_tiers,
+            vesting,
         })
     }
+
+    // None for the base level and for levels above the tiers left after an update
+    pub fn vip_tier(&self, vip_level: VipLevel) -> Option<&VipTier> {
+        vip_level
+            .0
+            .checked_sub(1)
+            .and_then(|index| self.vip_tiers.get(index as usize))
+    }
+
+    pub fn marketplace_fees_discount(&self, vip_level: VipLevel) -> Decimal {
+        self.vip_tier(vip_level)
+            .map_or(Decimal::zero(), |vip_tier| {
+                vip_tier.marketplace_fees_discount
+            })
+    }
+
+    // None when the tier of the level is permanent
+    pub fn vip_expiration(&self, vip_level: VipLevel, time: Timestamp) -> Option<Timestamp> {
+        self.vip_tier(vip_level)
+            .and_then(|vip_tier| vip_tier.subscription_duration)
+            .map(|subscription_duration| time.plus_seconds(subscription_duration))
+    }
+
+    // Once a subscription expires the profile keeps the highest permanent tier below it
+    pub fn fallback_vip_level(&self, expired_level: VipLevel) -> VipLevel {
+        (1..expired_level.0)
+            .rev()
+            .map(VipLevel)
+            .find(|vip_level| {
+                self.vip_tier(*vip_level)
+                    .map_or(false, |vip_tier| vip_tier.subscription_duration.is_none())
+            })
+            .unwrap_or_default()
+    }
+
+    // Highest level whose threshold is reached by the cumulative USDC volume of a profile
+    pub fn volume_vip_level(&self, volume_usdc: Uint128) -> VipLevel {
+        self.vip_tiers
+            .iter()
+            .enumerate()
+            .filter(|(_, vip_tier)| {
+                vip_tier
+                    .volume_usdc_threshold
+                    .map_or(false, |threshold| threshold <= volume_usdc)
+            })
+            .map(|(index, _)| VipLevel(index as u32 + 1))
+            .max()
+            .unwrap_or_default()
+    }
+
+    // Next tier above the level that can be reached by volume
+    pub fn next_volume_vip_tier(&self, vip_level: VipLevel) -> Option<(VipLevel, &VipTier)> {
+        self.vip_tiers
+            .iter()
+            .enumerate()
+            .skip(vip_level.0 as usize)
+            .find(|(_, vip_tier)| vip_tier.volume_usdc_threshold.is_some())
+            .map(|(index, vip_tier)| (VipLevel(index as u32 + 1), vip_tier))
+    }
 }
 
+// Tiers are unlocked in order, their names are shown in the events of the level ups
+pub fn validate_vip_tiers(vip_tiers: &[VipTier]) -> Result<(), ContractError> {
+    ensure!(
+        !vip_tiers.is_empty(),
+        ContractError::NftMarketplaceError(NeedToFillAllThePerks {})
+    );
+    ensure!(
+        vip_tiers.len() <= MAX_VIP_TIERS
+            && vip_tiers.iter().enumerate().all(|(index, tier)| {
+                !tier.name.trim().is_empty()
+                    && tier.subscription_duration != Some(0)
+                    && !vip_tiers[..index]
+                        .iter()
+                        .any(|previous_tier| previous_tier.name == tier.name)
+            }),
+        ContractError::NftMarketplaceError(InvalidVipTiers {})
+    );
+    // Volume thresholds grow with the levels
+    let volume_usdc_thresholds: Vec<Uint128> = vip_tiers
+        .iter()
+        .filter_map(|tier| tier.volume_usdc_threshold)
+        .collect();
+    ensure!(
+        volume_usdc_thresholds
+            .first()
+            .map_or(true, |threshold| !threshold.is_zero())
+            && volume_usdc_thresholds
+                .windows(2)
+                .all(|thresholds| thresholds[0] < thresholds[1]),
+        ContractError::NftMarketplaceError(InvalidVipTiers {})
+    );
+
+    ensure!(
+        !vip_tiers.iter().any(|tier| {
+            tier.marketplace_fees_discount < Decimal::percent(1u64)
+                || tier.marketplace_fees_discount > Decimal::percent(50u64)
+        }),
+        ContractError::NftMarketplaceError(InvalidRewards {})
+    );
+    Ok(())
+}
+
+// Funded by the admin, the rewards of each trade move from the balance to the claimable ledger
 #[cw_serde]
-pub enum VipLevel {
-    Level0,
-    Level1,
-    Level2,
-    Level3,
+#[derive(Default)]
+pub struct RewardPool {
+    pub balance: Uint128,
+    pub total_to_claim: Uint128,
+}
+
+impl RewardPool {
+    // Rewards are capped by what is left in the pool, nothing is credited once it is empty
+    pub fn credit(&mut self, amount: Uint128) -> Uint128 {
+        let credited = amount.min(self.balance);
+        self.balance -= credited;
+        self.total_to_claim += credited;
+        credited
+    }
 }
 
+// Level 0 is the base level, level n unlocks the perks of RewardSystem::vip_tiers[n - 1]
+#[derive(Serialize, Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, JsonSchema)]
+#[serde(transparent)]
+pub struct VipLevel(pub u32);
+
 impl fmt::Display for VipLevel {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self {
-            VipLevel::Level0 => write!(f, "Level0"),
-            VipLevel::Level1 => write!(f, "Level1"),
-            VipLevel::Level2 => write!(f, "Level2"),
-            VipLevel::Level3 => write!(f, "Level3"),
+        write!(f, "Level{}", self.0)
+    }
+}
+
+// Levels saved before 0.0.7 are the variants of the former enum ("level0" to "level3"),
+// rewritten as numbers by the 0.0.7 migration
+impl<'de> Deserialize<'de> for VipLevel {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct VipLevelVisitor;
+
+        impl<'de> Visitor<'de> for VipLevelVisitor {
+            type Value = VipLevel;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a VIP level number")
+            }
+
+            fn visit_u64<E: de::Error>(self, value: u64) -> Result<VipLevel, E> {
+                u32::try_from(value)
+                    .map(VipLevel)
+                    .map_err(|_| E::invalid_value(Unexpected::Unsigned(value), &self))
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<VipLevel, E> {
+                value
+                    .strip_prefix("level")
+                    .and_then(|level| level.parse().ok())
+                    .map(VipLevel)
+                    .ok_or_else(|| E::invalid_value(Unexpected::Str(value), &self))
+            }
         }
+
+        deserializer.deserialize_any(VipLevelVisitor)
     }
 }
 
 impl VipLevel {
     pub fn level_up_if_possible(
         current_level: VipLevel,
-        vip_perks: Vec<VipPerk>,
+        vip_tiers: &[VipTier],
         amount_received: Uint128,
     ) -> Result<bool, ContractError> {
-        let next_level = current_level.clone().next_level();
-        if current_level == next_level {
-            return Err(ContractError::NftMarketplaceError(AlreadyLevel3 {}));
-        }
-        let next_perk = vip_perks
-            .iter()
-            .find(|perk| perk.vip_level == next_level.clone())
-            .ok_or(ContractError::NftMarketplaceError(InvalidLevelUp {}))?;
-        if amount_received != next_perk.level_up_price_in_reward_tokens {
+        let next_tier = vip_tiers
+            .get(current_level.0 as usize)
+            .ok_or(ContractError::NftMarketplaceError(AlreadyMaxVipLevel {}))?;
+        if amount_received != next_tier.level_up_price_in_reward_tokens {
             return Err(ContractError::NftMarketplaceError(
                 InvalidAmountReceivedForLevelUp {},
             ));
@@ -119,23 +255,51 @@ impl VipLevel {
         Ok(true)
     }
 
+    pub fn rank(&self) -> u32 {
+        self.0
+    }
+
     pub fn next_level(self) -> VipLevel {
-        match self {
-            VipLevel::Level0 => VipLevel::Level1,
-            VipLevel::Level1 => VipLevel::Level2,
-            VipLevel::Level2 => VipLevel::Level3,
-            VipLevel::Level3 => VipLevel::Level3,
-        }
+        VipLevel(self.0 + 1)
     }
 }
 
 #[cw_serde]
-pub struct VipPerk {
-    pub vip_level: VipLevel,
+pub struct VipTier {
+    pub name: String,
+    pub level_up_price_in_reward_tokens: Uint128,
+    pub marketplace_fees_discount: Decimal,
     pub profile_background: bool,
     pub profile_nft_showcase: bool,
     pub profile_description: bool,
     pub profile_links: bool,
-    pub marketplace_fees_discount: Decimal,
-    pub level_up_price_in_reward_tokens: Uint128,
+    // Replaces the rate limit of the messaging config, see MessageRateLimitConfig
+    pub max_messages_per_window: Option<u32>,
+    // Seconds bought by a level up or a renewal, the level is permanent when None
+    #[serde(default)]
+    pub subscription_duration: Option<u64>,
+    // Cumulative USDC volume upgrading a profile to the tier for good, only bought when None
+    #[serde(default)]
+    pub volume_usdc_threshold: Option<Uint128>,
+}
+
+// Upgrade of a profile reaching the volume threshold of a tier
+#[cw_serde]
+pub struct VipUpgrade {
+    pub address: String,
+    pub previous_level: VipLevel,
+    pub vip_level: VipLevel,
+    pub tier_name: String,
+}
+
+#[cw_serde]
+pub struct VipProgress {
+    pub address: String,
+    pub vip_level: VipLevel,
+    pub volume_usdc: Uint128,
+    // None once no tier above can be reached by volume
+    pub next_vip_level: Option<VipLevel>,
+    pub next_tier_name: Option<String>,
+    pub next_volume_usdc_threshold: Option<Uint128>,
+    pub volume_usdc_to_next_tier: Option<Uint128>,
 }
@@ -0,0 +1,124 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{ensure, Timestamp, Uint128};
+
+use general_utils::error::ContractError;
+use general_utils::error::NftMarketplaceError::InvalidRewards;
+
+use crate::price_history::{day_start_seconds, SECONDS_PER_DAY};
+
+pub const MAX_VESTING_ENTRIES: usize = 30;
+
+// Linear vesting of the trading rewards, nothing is claimable before the cliff
+#[cw_serde]
+pub struct RewardVesting {
+    pub cliff_seconds: u64,
+    pub duration_seconds: u64,
+}
+
+impl RewardVesting {
+    pub fn validate(&self) -> Result<(), ContractError> {
+        ensure!(
+            self.duration_seconds > 0 && self.cliff_seconds <= self.duration_seconds,
+            ContractError::NftMarketplaceError(InvalidRewards {})
+        );
+        Ok(())
+    }
+}
+
+// Rewards accrued the same day with the same schedule share one entry
+#[cw_serde]
+pub struct VestingEntry {
+    pub start: Timestamp,
+    pub cliff_end: Timestamp,
+    pub end: Timestamp,
+    pub total: Uint128,
+    pub claimed: Uint128,
+}
+
+impl VestingEntry {
+    // Vesting starts at the end of the day the rewards are accrued, never before them
+    pub fn new(time: Timestamp, vesting: &RewardVesting, total: Uint128) -> Self {
+        let start = Timestamp::from_seconds(day_start_seconds(time) + SECONDS_PER_DAY);
+        VestingEntry {
+            start,
+            cliff_end: start.plus_seconds(vesting.cliff_seconds),
+            end: start.plus_seconds(vesting.duration_seconds),
+            total,
+            claimed: Uint128::zero(),
+        }
+    }
+
+    pub fn has_same_schedule(&self, other: &VestingEntry) -> bool {
+        self.start == other.start && self.cliff_end == other.cliff_end && self.end == other.end
+    }
+
+    pub fn vested(&self, time: Timestamp) -> Uint128 {
+        if time < self.cliff_end {
+            Uint128::zero()
+        } else if time >= self.end {
+            self.total
+        } else {
+            self.total.multiply_ratio(
+                time.seconds() - self.start.seconds(),
+                self.end.seconds() - self.start.seconds(),
+            )
+        }
+    }
+
+    pub fn claimable(&self, time: Timestamp) -> Uint128 {
+        self.vested(time) - self.claimed
+    }
+}
+
+// Adds the rewards to the entry with the same schedule or to a new one.
+// Fully vested entries are folded into one before a new entry is added, past
+// MAX_VESTING_ENTRIES the rewards join the latest entry
+pub fn add_vesting_entry(
+    entries: &mut Vec<VestingEntry>,
+    new_entry: VestingEntry,
+    time: Timestamp,
+) {
+    if let Some(entry) = entries
+        .iter_mut()
+        .find(|entry| entry.has_same_schedule(&new_entry))
+    {
+        entry.total += new_entry.total;
+        return;
+    }
+    let (mut vested, unvested): (Vec<_>, Vec<_>) =
+        entries.drain(..).partition(|entry| time >= entry.end);
+    if let Some(mut folded) = vested.pop() {
+        for entry in vested {
+            folded.total += entry.total;
+            folded.claimed += entry.claimed;
+        }
+        entries.push(folded);
+    }
+    entries.extend(unvested);
+    if entries.len() < MAX_VESTING_ENTRIES {
+        entries.push(new_entry);
+    } else if let Some(entry) = entries.iter_mut().max_by_key(|entry| entry.end) {
+        entry.total += new_entry.total;
+    }
+}
+
+#[cw_serde]
+pub struct RewardVestingInfo {
+    // Vested and not claimed yet
+    pub vested: Uint128,
+    pub unvested: Uint128,
+    pub entries: Vec<VestingEntry>,
+}
+
+impl RewardVestingInfo {
+    pub fn new(entries: Vec<VestingEntry>, time: Timestamp) -> Self {
+        RewardVestingInfo {
+            vested: entries.iter().map(|entry| entry.claimable(time)).sum(),
+            unvested: entries
+                .iter()
+                .map(|entry| entry.total - entry.vested(time))
+                .sum(),
+            entries,
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{ensure, Decimal, Decimal256, StdResult, Timestamp, Uint128, Uint256};
+
+use general_utils::denominations::DenominationValue;
+use general_utils::error::ContractError;
+use general_utils::error::NftMarketplaceError::InvalidStakingConfig;
+
+// Unbonding tokens are withdrawn all at once, a new unstake is refused above this
+pub const MAX_UNBONDINGS: usize = 10;
+
+// Stakers of the reward token share fees_share of the marketplace fees of each denom, the rest
+// stays with ClaimMarketplaceFees
+#[cw_serde]
+pub struct StakingConfig {
+    pub fees_share: Decimal,
+    pub unbonding_seconds: u64,
+}
+
+impl StakingConfig {
+    pub fn new_checked(self) -> Result<Self, ContractError> {
+        ensure!(
+            !self.fees_share.is_zero() && self.fees_share <= Decimal::one(),
+            ContractError::NftMarketplaceError(InvalidStakingConfig {})
+        );
+        Ok(self)
+    }
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct StakingStats {
+    pub total_staked: Uint128,
+    pub total_unbonding: Uint128,
+}
+
+// Fees of a denom per staked token since the start, fees_checkpoint is the part of the fees to
+// claim already split with the stakers
+#[cw_serde]
+#[derive(Default)]
+pub struct FeeIndex {
+    pub index: Decimal256,
+    pub fees_checkpoint: Uint128,
+}
+
+impl FeeIndex {
+    // Moves the share of the fees collected since the checkpoint to the stakers and returns it,
+    // fees collected while nothing is staked stay with the marketplace
+    pub fn distribute(
+        &mut self,
+        fees_to_claim: Uint128,
+        fees_share: Decimal,
+        total_staked: Uint128,
+    ) -> Uint128 {
+        let new_fees = fees_to_claim.saturating_sub(self.fees_checkpoint);
+        let shared_fees = if total_staked.is_zero() {
+            Uint128::zero()
+        } else {
+            new_fees * fees_share
+        };
+        if !shared_fees.is_zero() {
+            self.index += Decimal256::from_ratio(shared_fees, total_staked);
+        }
+        self.fees_checkpoint = fees_to_claim - shared_fees;
+        shared_fees
+    }
+}
+
+// Fees of a staker in a denom, settled at index before each change of its stake
+#[cw_serde]
+#[derive(Default)]
+pub struct StakerFees {
+    pub index: Decimal256,
+    pub fees_to_claim: Uint128,
+}
+
+impl StakerFees {
+    pub fn settle(&mut self, staked: Uint128, fee_index: &FeeIndex) -> StdResult<()> {
+        let earned = Uint256::from(staked) * (fee_index.index - self.index);
+        self.fees_to_claim += Uint128::try_from(earned)?;
+        self.index = fee_index.index;
+        Ok(())
+    }
+}
+
+#[cw_serde]
+pub struct Unbonding {
+    pub amount: Uint128,
+    pub release_time: Timestamp,
+}
+
+#[cw_serde]
+pub struct StakerInfo {
+    pub address: String,
+    pub staked: Uint128,
+    pub unbondings: Vec<Unbonding>,
+    pub fees_to_claim: Vec<DenominationValue>,
+}
@@ -0,0 +1,94 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{ensure, CosmosMsg, CustomMsg, Uint128};
+
+use general_utils::error::ContractError;
+use general_utils::error::NftMarketplaceError::{
+    InvalidRewardMinting, RewardEmissionCapBelowMinted,
+};
+
+// Custom messages of the Chihuahua tokenfactory bindings, sent with CosmosMsg::Custom
+#[cw_serde]
+pub enum TokenFactoryMsg {
+    Token(TokenMsg),
+}
+
+#[cw_serde]
+pub enum TokenMsg {
+    // Creates factory/{sender}/{subdenom}, the sender becomes the admin of the denom
+    CreateDenom {
+        subdenom: String,
+    },
+    MintTokens {
+        denom: String,
+        amount: Uint128,
+        mint_to_address: String,
+    },
+}
+
+impl CustomMsg for TokenFactoryMsg {}
+
+impl From<TokenMsg> for CosmosMsg<TokenFactoryMsg> {
+    fn from(msg: TokenMsg) -> Self {
+        CosmosMsg::Custom(TokenFactoryMsg::Token(msg))
+    }
+}
+
+pub fn factory_denom(creator: &str, subdenom: &str) -> String {
+    format!("factory/{creator}/{subdenom}")
+}
+
+// The marketplace mints its own reward token as trades happen instead of drawing on a pre-funded
+// pool, total_minted never goes above emission_cap
+#[cw_serde]
+pub struct RewardMinting {
+    pub denom: String,
+    pub emission_cap: Uint128,
+    pub total_minted: Uint128,
+}
+
+impl RewardMinting {
+    pub fn new_checked(
+        contract_address: &str,
+        subdenom: &str,
+        emission_cap: Uint128,
+    ) -> Result<Self, ContractError> {
+        ensure!(
+            !subdenom.is_empty()
+                && subdenom.len() <= 44
+                && subdenom
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '.' || c == '-'),
+            ContractError::NftMarketplaceError(InvalidRewardMinting {})
+        );
+        Ok(RewardMinting {
+            denom: factory_denom(contract_address, subdenom),
+            emission_cap,
+            total_minted: Uint128::zero(),
+        })
+    }
+
+    pub fn update_emission_cap(&mut self, emission_cap: Uint128) -> Result<(), ContractError> {
+        ensure!(
+            emission_cap >= self.total_minted,
+            ContractError::NftMarketplaceError(RewardEmissionCapBelowMinted {})
+        );
+        self.emission_cap = emission_cap;
+        Ok(())
+    }
+
+    // Rewards are capped by what is left under the emission cap, nothing is minted once reached
+    pub fn mint(&mut self, amount: Uint128) -> Uint128 {
+        let minted = amount.min(self.emission_cap - self.total_minted);
+        self.total_minted += minted;
+        minted
+    }
+
+    pub fn mint_msg(&self, amount: Uint128, mint_to_address: String) -> CosmosMsg<TokenFactoryMsg> {
+        TokenMsg::MintTokens {
+            denom: self.denom.clone(),
+            amount,
+            mint_to_address,
+        }
+        .into()
+    }
+}
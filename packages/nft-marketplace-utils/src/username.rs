@@ -0,0 +1,56 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{ensure, Api, Timestamp, Uint128};
+
+use general_utils::denominations::Denomination;
+use general_utils::error::ContractError;
+use general_utils::error::NftMarketplaceError::InvalidUsername;
+
+pub const USERNAME_MIN_LENGTH: usize = 3;
+pub const USERNAME_MAX_LENGTH: usize = 32;
+
+#[cw_serde]
+pub enum UsernameStatus {
+    Available,
+    Taken { address: String },
+    Reserved,
+    Invalid,
+}
+
+// Keyed by the lowercase username, removed when the username changes owner or is released
+#[cw_serde]
+pub struct UsernameSale {
+    pub username: String,
+    pub seller: String,
+    pub price_value: Uint128,
+    pub price_denom: Denomination,
+    pub listed_at: Timestamp,
+}
+
+// Usernames are unique whatever their case: the registry is keyed by the lowercase username
+pub fn normalize_username(username: &str) -> String {
+    username.to_ascii_lowercase()
+}
+
+fn is_username_separator(c: char) -> bool {
+    c == '-' || c == '_' || c == '.'
+}
+
+// Letters, digits and single separators between them. A username can't be an address
+// since both are accepted wherever an address or a username is expected
+pub fn validate_username(api: &dyn Api, username: &str) -> Result<(), ContractError> {
+    ensure!(
+        (USERNAME_MIN_LENGTH..=USERNAME_MAX_LENGTH).contains(&username.len())
+            && username
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || is_username_separator(c))
+            && username.starts_with(|c: char| c.is_ascii_alphanumeric())
+            && username.ends_with(|c: char| c.is_ascii_alphanumeric())
+            && !username
+                .chars()
+                .zip(username.chars().skip(1))
+                .any(|(a, b)| is_username_separator(a) && is_username_separator(b))
+            && api.addr_validate(username).is_err(),
+        ContractError::NftMarketplaceError(InvalidUsername {})
+    );
+    Ok(())
+}
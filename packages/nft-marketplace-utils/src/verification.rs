@@ -0,0 +1,95 @@
+use std::fmt;
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Timestamp;
+
+use crate::nft_collection::NftCollectionAddress;
+
+#[cw_serde]
+pub enum VerificationKind {
+    VerifiedCreator,
+    OfficialProject,
+    Partner,
+}
+
+impl fmt::Display for VerificationKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            VerificationKind::VerifiedCreator => write!(f, "Verified creator"),
+            VerificationKind::OfficialProject => write!(f, "Official project"),
+            VerificationKind::Partner => write!(f, "Partner"),
+        }
+    }
+}
+
+#[cw_serde]
+pub enum VerificationSubject {
+    Profile {
+        address: String,
+    },
+    Collection {
+        nft_collection_address: NftCollectionAddress,
+    },
+}
+
+impl VerificationSubject {
+    // Key of the subject in the verifications, each kind is saved under it
+    pub fn key(&self) -> String {
+        match self {
+            VerificationSubject::Profile { address } => format!("profile_{}", address),
+            VerificationSubject::Collection {
+                nft_collection_address,
+            } => format!("collection_{}", nft_collection_address),
+        }
+    }
+}
+
+impl fmt::Display for VerificationSubject {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            VerificationSubject::Profile { address } => write!(f, "Profile {}", address),
+            VerificationSubject::Collection {
+                nft_collection_address,
+            } => write!(f, "Collection {}", nft_collection_address),
+        }
+    }
+}
+
+// Issued by the admin or a moderator, revoked attestations are kept with who revoked them
+#[cw_serde]
+pub struct Verification {
+    pub subject: VerificationSubject,
+    pub kind: VerificationKind,
+    pub issuer: String,
+    pub issued_at: Timestamp,
+    pub revoked_by: Option<String>,
+    pub revoked_at: Option<Timestamp>,
+}
+
+impl Verification {
+    pub fn new(
+        subject: VerificationSubject,
+        kind: VerificationKind,
+        issuer: String,
+        issued_at: Timestamp,
+    ) -> Self {
+        Verification {
+            subject,
+            kind,
+            issuer,
+            issued_at,
+            revoked_by: None,
+            revoked_at: None,
+        }
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.revoked_at.is_none()
+    }
+
+    pub fn revoke(&mut self, revoked_by: String, revoked_at: Timestamp) -> &mut Self {
+        self.revoked_by = Some(revoked_by);
+        self.revoked_at = Some(revoked_at);
+        self
+    }
+}
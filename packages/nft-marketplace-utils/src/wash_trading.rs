@@ -0,0 +1,128 @@
+use std::fmt;
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{ensure, Decimal, Timestamp, Uint128};
+
+use general_utils::error::ContractError;
+use general_utils::error::NftMarketplaceError::InvalidWashTradingConfig;
+
+use crate::nft_sale::TokenSaleHistory;
+
+// Rewards of the trades matching a rule are withheld, the trades themselves always go through.
+// Nothing is enforced by default.
+#[cw_serde]
+#[derive(Default)]
+pub struct WashTradingConfig {
+    // A token sold again between the same two addresses within the cooldown earns no rewards
+    pub pair_cooldown_seconds: Option<u64>,
+    // Max relative gap between the price and the floor of the other listings of the collection
+    pub max_floor_deviation: Option<Decimal>,
+    pub epoch_reward_cap: Option<EpochRewardCap>,
+}
+
+// Rewards earned by an address in an epoch (time / epoch_seconds) above the cap are withheld
+#[cw_serde]
+pub struct EpochRewardCap {
+    pub epoch_seconds: u64,
+    pub max_rewards_per_epoch: Uint128,
+}
+
+impl WashTradingConfig {
+    pub fn new_checked(self) -> Result<Self, ContractError> {
+        ensure!(
+            self.pair_cooldown_seconds != Some(0)
+                && self
+                    .max_floor_deviation
+                    .map_or(true, |deviation| !deviation.is_zero()),
+            ContractError::NftMarketplaceError(InvalidWashTradingConfig {})
+        );
+        if let Some(epoch_reward_cap) = &self.epoch_reward_cap {
+            ensure!(
+                epoch_reward_cap.epoch_seconds > 0
+                    && !epoch_reward_cap.max_rewards_per_epoch.is_zero(),
+                ContractError::NftMarketplaceError(InvalidWashTradingConfig {})
+            );
+        }
+        Ok(self)
+    }
+
+    // Reason to withhold all the rewards of the sale, checked against the previous sales of the
+    // token and the floor without the sold listing (None when nothing else is listed)
+    pub fn withholding_reason(
+        &self,
+        previous_sales: &[TokenSaleHistory],
+        sale: &TokenSaleHistory,
+        floor: Option<Uint128>,
+    ) -> Option<WithholdingReason> {
+        if let Some(pair_cooldown_seconds) = self.pair_cooldown_seconds {
+            let same_pair_in_cooldown = previous_sales.iter().any(|previous_sale| {
+                previous_sale.sale_time.plus_seconds(pair_cooldown_seconds) > sale.sale_time
+                    && ((previous_sale.seller == sale.seller && previous_sale.buyer == sale.buyer)
+                        || (previous_sale.seller == sale.buyer
+                            && previous_sale.buyer == sale.seller))
+            });
+            if same_pair_in_cooldown {
+                return Some(WithholdingReason::PairCooldown);
+            }
+        }
+        if let (Some(max_floor_deviation), Some(floor)) = (self.max_floor_deviation, floor) {
+            let price_gap = sale.sale_price_value.abs_diff(floor);
+            if Decimal::from_ratio(price_gap, floor) > max_floor_deviation {
+                return Some(WithholdingReason::FloorDeviation);
+            }
+        }
+        None
+    }
+}
+
+// Rewards credited to an address in its current epoch
+#[cw_serde]
+#[derive(Default)]
+pub struct EpochRewards {
+    pub epoch: u64,
+    pub rewards: Uint128,
+}
+
+impl EpochRewards {
+    pub fn current(self, epoch_reward_cap: &EpochRewardCap, time: Timestamp) -> Self {
+        let epoch = time.seconds() / epoch_reward_cap.epoch_seconds;
+        if self.epoch == epoch {
+            self
+        } else {
+            EpochRewards {
+                epoch,
+                rewards: Uint128::zero(),
+            }
+        }
+    }
+
+    pub fn remaining(&self, epoch_reward_cap: &EpochRewardCap) -> Uint128 {
+        epoch_reward_cap
+            .max_rewards_per_epoch
+            .saturating_sub(self.rewards)
+    }
+}
+
+#[cw_serde]
+pub enum WithholdingReason {
+    PairCooldown,
+    FloorDeviation,
+    EpochCap,
+}
+
+impl fmt::Display for WithholdingReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            WithholdingReason::PairCooldown => write!(f, "Pair cooldown"),
+            WithholdingReason::FloorDeviation => write!(f, "Floor deviation"),
+            WithholdingReason::EpochCap => write!(f, "Epoch cap"),
+        }
+    }
+}
+
+#[cw_serde]
+pub struct WithheldReward {
+    pub address: String,
+    pub amount: Uint128,
+    pub reason: WithholdingReason,
+}
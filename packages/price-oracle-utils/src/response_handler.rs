@@ -19,6 +19,15 @@ impl ResponseHandler {
         }
     }
 
+    pub fn migrate(from_version: String, to_version: &str) -> Self {
+        Self {
+            response: Response::new()
+                .add_attribute("action", "Migrate: Price Oracle contract")
+                .add_attribute("From version", from_version)
+                .add_attribute("To version", to_version),
+        }
+    }
+
     pub fn log_add_new_oracle_prices(at_time: &Timestamp) -> Self {
         Self {
             response: Response::new()
@@ -3,3 +3,4 @@ pub mod utils_common;
 pub mod utils_marketplace_contract_test;
 pub mod utils_nft_contract_test;
 pub mod utils_price_oracle_contract_test;
+pub mod utils_tokenfactory_module;
@@ -1,22 +1,39 @@
 #[cfg(test)]
 pub mod tests {
     use cosmwasm_schema::cw_serde;
-    use cosmwasm_std::{coin, Addr, Coin};
-    use cw_multi_test::{App, BankSudo, SudoMsg};
+    use cosmwasm_std::testing::mock_info;
+    use cosmwasm_std::testing::{MockApi, MockStorage};
+    use cosmwasm_std::{coin, coins, Addr, Coin, Empty};
+    use cw_multi_test::{AppBuilder, BankKeeper, BankSudo, SudoMsg, WasmKeeper};
 
     use general_utils::denominations::AcceptedDenominations;
+    use nft_marketplace_utils::tokenfactory::TokenFactoryMsg;
 
     use crate::common::utils::constants::{IBC_ATOM, INVALID_REWARD_TOKEN, OWNER, REWARD_TOKEN, UHUAHUA, WALLET2, WALLET3, WALLET4, WALLET5};
-    use crate::common::utils::utils_marketplace_contract_test::tests::instantiate_smart_contract_test_nft_marketplace;
+    use crate::common::utils::utils_marketplace_contract_test::tests::{
+        instantiate_smart_contract_test_nft_marketplace, marketplace_test_exec_fund_reward_pool,
+    };
     use crate::common::utils::utils_nft_contract_test::tests::{
         instantiate_smart_contract_test_cw2981_multi, smart_contract_def_test_cw2981_multi,
     };
     use crate::common::utils::utils_price_oracle_contract_test::tests::{
         instantiate_smart_contract_test_price_oracle, oracle_test_exec_feed_prices_default,
     };
+    use crate::common::utils::utils_tokenfactory_module::tests::TokenFactoryModule;
+
+    // The marketplace sends the tokenfactory messages of the chain
+    pub type App = cw_multi_test::App<
+        BankKeeper,
+        MockApi,
+        MockStorage,
+        TokenFactoryModule,
+        WasmKeeper<TokenFactoryMsg, Empty>,
+    >;
 
     pub fn mock_app() -> App {
-        App::default()
+        AppBuilder::new_custom()
+            .with_custom(TokenFactoryModule {})
+            .build(|_, _, _| {})
     }
 
     #[cw_serde]
@@ -64,15 +81,6 @@ pub mod tests {
         let (cw2981_base_smart_contract_address2, _code_id_nft2) =
             instantiate_smart_contract_test_cw2981_multi(&mut app, contract_code_id);
 
-        // Send reward tokens to the marketplace to distribute
-        app.sudo(SudoMsg::Bank({
-            BankSudo::Mint {
-                to_address: nft_marketplace_smart_contract_address.to_string(),
-                amount: vec![coin(100_000_000_000_000_000_000u128, REWARD_TOKEN.to_string())],
-            }
-        }))
-            .unwrap();
-
         // Mint some coins for purchases and reward tokens
         for i in vec![OWNER, WALLET2, WALLET3, WALLET4, WALLET5].iter() {
             for tok in vec![UHUAHUA, IBC_ATOM, REWARD_TOKEN, INVALID_REWARD_TOKEN].iter() {
@@ -86,6 +94,14 @@ pub mod tests {
             }
         }
 
+        // Fund the reward pool of the marketplace to distribute
+        marketplace_test_exec_fund_reward_pool(
+            &mut app,
+            &nft_marketplace_smart_contract_address,
+            mock_info(OWNER, &coins(100_000_000_000_000_000_000u128, REWARD_TOKEN)),
+        )
+        .unwrap();
+
         (
             app,
             InitNecessaryOutcome {
@@ -3,31 +3,56 @@ pub mod tests {
     use std::str::FromStr;
 
     use anyhow::Result as AnyResult;
-    use cosmwasm_std::{Addr, Decimal, Empty, MessageInfo, StdResult, Uint128};
-    use cw_multi_test::{App, AppResponse, Contract, ContractWrapper, Executor};
+    use cosmwasm_std::{from_slice, Addr, Decimal, MessageInfo, StdResult, Timestamp, Uint128};
+    use cw2::ContractVersion;
+    use cw_multi_test::{AppResponse, Contract, ContractWrapper, Executor};
 
     use general_utils::denominations::AcceptedDenominations;
+    use nft_marketplace_utils::activity::{Activity, AddressActivity, AddressActivityKind};
+    use nft_marketplace_utils::circuit_breaker::{CircuitBreaker, MarketplaceCapability};
+    use nft_marketplace_utils::competition::{Competition, CompetitionPrize, CompetitionTrader};
     use nft_marketplace_utils::config::ConfigRewardGenStatsMsg;
+    use nft_marketplace_utils::follow::{Follow, FollowTarget, WatchedToken};
+    use nft_marketplace_utils::inbox::{InboxMessage, InboxThreadInfo};
+    use nft_marketplace_utils::leaderboard::{
+        LeaderboardCursor, LeaderboardPeriod, LeaderboardRanking, LeaderboardSeason, TraderStats,
+    };
     use nft_marketplace_utils::marketplace_statistics::MarketplaceStatsByDenom;
+    use nft_marketplace_utils::messaging::{MessageFilter, MessagingConfig};
     use nft_marketplace_utils::nft_collection::{
         NftCollectionAddress, NftCollectionAddressTokenId, NftCollectionInfoByDenom,
-        NftContractInfo, TokenId,
+        NftCollectionInfoResponse, NftContractInfo, TokenId,
     };
     use nft_marketplace_utils::nft_offer::NftOffer;
-    use nft_marketplace_utils::nft_sale::{NftSale, TokenSaleHistory, TokensAndIfSaleInfo};
-    use nft_marketplace_utils::profile::{Profile, ProfileUpdateAction};
-    use nft_marketplace_utils::reward_system::{RewardSystem, VipLevel, VipPerk};
+    use nft_marketplace_utils::nft_sale::{
+        NftSale, NftSaleCursor, NftSaleListing, PriceOrder, SoldNft, TokenSaleHistory,
+        TokensAndIfSaleInfo,
+    };
+    use nft_marketplace_utils::partner_perks::{HolderPerks, PartnerPerks};
+    use nft_marketplace_utils::price_history::{CollectionRollingStats, DailyPriceBucket};
+    use nft_marketplace_utils::profile::{Profile, ProfileResponse, ProfileUpdateAction};
+    use nft_marketplace_utils::reward_system::{RewardPool, RewardSystem, VipProgress, VipTier};
+    use nft_marketplace_utils::reward_vesting::RewardVestingInfo;
+    use nft_marketplace_utils::staking::{StakerInfo, StakingStats};
+    use nft_marketplace_utils::tokenfactory::{RewardMinting, TokenFactoryMsg};
+    use nft_marketplace_utils::username::{UsernameSale, UsernameStatus};
+    use nft_marketplace_utils::verification::{
+        Verification, VerificationKind, VerificationSubject,
+    };
+    use nft_marketplace_utils::wash_trading::WashTradingConfig;
 
     use crate::common::utils::constants::OWNER;
-    use chihuahua_nft_marketplace::msg::{UpdateConfigEnum};
+    use crate::common::utils::utils_common::tests::App;
     use chihuahua_nft_marketplace::msg::ExecuteMsg::LevelUpProfile;
+    use chihuahua_nft_marketplace::msg::UpdateConfigEnum;
 
-    pub fn smart_contract_def_test_nft_marketplace() -> Box<dyn Contract<Empty>> {
+    pub fn smart_contract_def_test_nft_marketplace() -> Box<dyn Contract<TokenFactoryMsg>> {
         let smart_contract = ContractWrapper::new(
             chihuahua_nft_marketplace::contract::execute,
             chihuahua_nft_marketplace::contract::instantiate,
             chihuahua_nft_marketplace::contract::query,
-        );
+        )
+        .with_migrate(chihuahua_nft_marketplace::contract::migrate);
         Box::new(smart_contract)
     }
 
@@ -49,35 +74,45 @@ pub mod tests {
                 reward_token_address: "".to_string(),
                 reward_token_per_1usdc_volume: Uint128::new(1_000_000u128),
                 total_reward_tokens_distributed: Uint128::zero(),
-                vip_perks: vec![
-                    VipPerk {
-                        vip_level: VipLevel::Level1,
+                vip_tiers: vec![
+                    VipTier {
+                        name: "Level1".to_string(),
                         profile_background: true,
                         profile_nft_showcase: false,
                         profile_description: false,
                         profile_links: false,
                         marketplace_fees_discount: Decimal::from_str("0.025").unwrap(),
                         level_up_price_in_reward_tokens: Uint128::new(1_000u128),
+                        max_messages_per_window: None,
+                        subscription_duration: None,
+                        volume_usdc_threshold: None,
                     },
-                    VipPerk {
-                        vip_level: VipLevel::Level2,
+                    VipTier {
+                        name: "Level2".to_string(),
                         profile_background: true,
                         profile_nft_showcase: false,
                         profile_description: true,
                         profile_links: false,
                         marketplace_fees_discount: Decimal::from_str("0.05").unwrap(),
                         level_up_price_in_reward_tokens: Uint128::new(10_000u128),
+                        max_messages_per_window: None,
+                        subscription_duration: None,
+                        volume_usdc_threshold: None,
                     },
-                    VipPerk {
-                        vip_level: VipLevel::Level3,
+                    VipTier {
+                        name: "Level3".to_string(),
                         profile_background: true,
                         profile_nft_showcase: true,
                         profile_description: true,
                         profile_links: true,
                         marketplace_fees_discount: Decimal::from_str("0.1").unwrap(),
                         level_up_price_in_reward_tokens: Uint128::new(50_000u128),
+                        max_messages_per_window: None,
+                        subscription_duration: None,
+                        volume_usdc_threshold: None,
                     },
                 ],
+                vesting: None,
             },
             accepted_nft_code_ids: vec![],
         }
@@ -299,6 +334,75 @@ pub mod tests {
             info.sender,
             Addr::unchecked(nft_marketplace_contract_addr),
             &msg,
+            &info.funds,
+        )
+    }
+
+    pub fn marketplace_test_exec_mark_thread_read(
+        app: &mut App,
+        nft_marketplace_contract_addr: &Addr,
+        info: MessageInfo,
+        counterparty: String,
+        up_to_message_id: Option<u64>,
+    ) -> AnyResult<AppResponse> {
+        let msg = chihuahua_nft_marketplace::msg::ExecuteMsg::MarkThreadRead {
+            counterparty,
+            up_to_message_id,
+        };
+        app.execute_contract(
+            info.sender,
+            nft_marketplace_contract_addr.clone(),
+            &msg,
+            &[],
+        )
+    }
+
+    pub fn marketplace_test_exec_set_message_filter(
+        app: &mut App,
+        nft_marketplace_contract_addr: &Addr,
+        info: MessageInfo,
+        message_filter: Option<MessageFilter>,
+    ) -> AnyResult<AppResponse> {
+        let msg = chihuahua_nft_marketplace::msg::ExecuteMsg::SetMessageFilter { message_filter };
+        app.execute_contract(
+            info.sender,
+            nft_marketplace_contract_addr.clone(),
+            &msg,
+            &[],
+        )
+    }
+
+    pub fn marketplace_test_exec_delete_message(
+        app: &mut App,
+        nft_marketplace_contract_addr: &Addr,
+        info: MessageInfo,
+        message_id: u64,
+    ) -> AnyResult<AppResponse> {
+        let msg = chihuahua_nft_marketplace::msg::ExecuteMsg::DeleteMessage { message_id };
+        app.execute_contract(
+            info.sender,
+            nft_marketplace_contract_addr.clone(),
+            &msg,
+            &[],
+        )
+    }
+
+    pub fn marketplace_test_exec_block_address(
+        app: &mut App,
+        nft_marketplace_contract_addr: &Addr,
+        info: MessageInfo,
+        address: String,
+        block: bool,
+    ) -> AnyResult<AppResponse> {
+        let msg = if block {
+            chihuahua_nft_marketplace::msg::ExecuteMsg::BlockAddress { address }
+        } else {
+            chihuahua_nft_marketplace::msg::ExecuteMsg::UnblockAddress { address }
+        };
+        app.execute_contract(
+            info.sender,
+            nft_marketplace_contract_addr.clone(),
+            &msg,
             &[],
         )
     }
@@ -430,7 +534,55 @@ pub mod tests {
         app.execute_contract(info.sender, contract_addr.clone(), &msg, &info.funds)
     }
 
+    pub fn marketplace_test_exec_set_circuit_breaker(
+        app: &mut App,
+        contract_addr: &Addr,
+        info: MessageInfo,
+        capability: MarketplaceCapability,
+        paused: bool,
+        reason: String,
+    ) -> AnyResult<AppResponse> {
+        let msg = chihuahua_nft_marketplace::msg::ExecuteMsg::SetCircuitBreaker {
+            capability,
+            paused,
+            reason,
+        };
+        app.execute_contract(info.sender, contract_addr.clone(), &msg, &[])
+    }
+
+    pub fn marketplace_test_exec_start_leaderboard_season(
+        app: &mut App,
+        contract_addr: &Addr,
+        info: MessageInfo,
+    ) -> AnyResult<AppResponse> {
+        let msg = chihuahua_nft_marketplace::msg::ExecuteMsg::StartLeaderboardSeason {};
+        app.execute_contract(info.sender, contract_addr.clone(), &msg, &[])
+    }
+
+    pub fn marketplace_test_exec_migrate(
+        app: &mut App,
+        contract_addr: &Addr,
+        info: MessageInfo,
+        new_code_id: u64,
+        batch_size: Option<u32>,
+    ) -> AnyResult<AppResponse> {
+        let msg = chihuahua_nft_marketplace::msg::MigrateMsg { batch_size };
+        app.migrate_contract(info.sender, contract_addr.clone(), &msg, new_code_id)
+    }
+
     // Query Functions: NFT Marketplace
+    pub fn marketplace_test_query_contract_version<T: Into<String>>(
+        app: &App,
+        contract_addr: T,
+    ) -> ContractVersion {
+        let raw_version = app
+            .wrap()
+            .query_wasm_raw(contract_addr, b"contract_info".as_slice())
+            .unwrap()
+            .unwrap();
+        from_slice(&raw_version).unwrap()
+    }
+
     pub fn marketplace_test_query_get_config<T: Into<String>>(
         app: &App,
         contract_addr: T,
@@ -442,6 +594,15 @@ pub mod tests {
         result
     }
 
+    pub fn marketplace_test_query_get_circuit_breakers<T: Into<String>>(
+        app: &App,
+        contract_addr: T,
+    ) -> Vec<CircuitBreaker> {
+        let msg = chihuahua_nft_marketplace::msg::QueryMsg::GetCircuitBreakers {};
+        let result: Vec<CircuitBreaker> = app.wrap().query_wasm_smart(contract_addr, &msg).unwrap();
+        result
+    }
+
     pub fn marketplace_test_query_get_mkpc_vol<T: Into<String>>(
         app: &App,
         contract_addr: T,
@@ -470,11 +631,20 @@ pub mod tests {
         contract_addr: String,
         address: String,
     ) -> StdResult<Profile> {
+        marketplace_test_query_get_profile_response(app, contract_addr, address)
+            .map(|profile_response| profile_response.profile)
+    }
+
+    pub fn marketplace_test_query_get_profile_response(
+        app: &App,
+        contract_addr: String,
+        address: String,
+    ) -> StdResult<ProfileResponse> {
         let msg: chihuahua_nft_marketplace::msg::QueryMsg =
             chihuahua_nft_marketplace::msg::QueryMsg::GetProfileInfo {
                 address_or_username: address,
             };
-        let result: StdResult<Profile> = app.wrap().query_wasm_smart(contract_addr, &msg);
+        let result: StdResult<ProfileResponse> = app.wrap().query_wasm_smart(contract_addr, &msg);
         result
     }
 
@@ -613,11 +783,20 @@ pub mod tests {
         contract_addr: T,
         nft_collection_address: NftCollectionAddress,
     ) -> StdResult<Vec<NftCollectionInfoByDenom>> {
+        marketplace_test_query_get_nft_coll_response(app, contract_addr, nft_collection_address)
+            .map(|nft_collection_info_response| nft_collection_info_response.info_by_denom)
+    }
+
+    pub fn marketplace_test_query_get_nft_coll_response<T: Into<String>>(
+        app: &App,
+        contract_addr: T,
+        nft_collection_address: NftCollectionAddress,
+    ) -> StdResult<NftCollectionInfoResponse> {
         let msg: chihuahua_nft_marketplace::msg::QueryMsg =
             chihuahua_nft_marketplace::msg::QueryMsg::GetNftCollectionInfo {
                 nft_collection_address,
             };
-        let result: StdResult<Vec<NftCollectionInfoByDenom>> =
+        let result: StdResult<NftCollectionInfoResponse> =
             app.wrap().query_wasm_smart(contract_addr, &msg);
         result
     }
@@ -637,4 +816,853 @@ pub mod tests {
             app.wrap().query_wasm_smart(contract_addr, &msg);
         result
     }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn marketplace_test_query_get_coll_daily_price_history<T: Into<String>>(
+        app: &App,
+        contract_addr: T,
+        nft_collection_address: NftCollectionAddress,
+        denom: String,
+        start_time: Option<Timestamp>,
+        end_time: Option<Timestamp>,
+        start_after: Option<Timestamp>,
+        output_length: Option<u32>,
+    ) -> StdResult<Vec<DailyPriceBucket>> {
+        let msg: chihuahua_nft_marketplace::msg::QueryMsg =
+            chihuahua_nft_marketplace::msg::QueryMsg::GetCollectionDailyPriceHistory {
+                nft_collection_address,
+                denom,
+                start_time,
+                end_time,
+                start_after,
+                output_length,
+            };
+        let result: StdResult<Vec<DailyPriceBucket>> =
+            app.wrap().query_wasm_smart(contract_addr, &msg);
+        result
+    }
+
+    pub fn marketplace_test_query_get_nft_coll_rolling_stats<T: Into<String>>(
+        app: &App,
+        contract_addr: T,
+        nft_collection_address: NftCollectionAddress,
+    ) -> Vec<CollectionRollingStats> {
+        let msg: chihuahua_nft_marketplace::msg::QueryMsg =
+            chihuahua_nft_marketplace::msg::QueryMsg::GetNftCollectionRollingStats {
+                nft_collection_address,
+            };
+        let result: Vec<CollectionRollingStats> =
+            app.wrap().query_wasm_smart(contract_addr, &msg).unwrap();
+        result
+    }
+
+    pub fn marketplace_test_query_get_trader_leaderboard<T: Into<String>>(
+        app: &App,
+        contract_addr: T,
+        ranking: LeaderboardRanking,
+        period: LeaderboardPeriod,
+        start_after: Option<LeaderboardCursor>,
+        output_length: Option<u32>,
+    ) -> Vec<TraderStats> {
+        let msg: chihuahua_nft_marketplace::msg::QueryMsg =
+            chihuahua_nft_marketplace::msg::QueryMsg::GetTraderLeaderboard {
+                ranking,
+                period,
+                start_after,
+                output_length,
+            };
+        let result: Vec<TraderStats> = app.wrap().query_wasm_smart(contract_addr, &msg).unwrap();
+        result
+    }
+
+    pub fn marketplace_test_query_get_leaderboard_season<T: Into<String>>(
+        app: &App,
+        contract_addr: T,
+    ) -> LeaderboardSeason {
+        let msg: chihuahua_nft_marketplace::msg::QueryMsg =
+            chihuahua_nft_marketplace::msg::QueryMsg::GetLeaderboardSeason {};
+        let result: LeaderboardSeason = app.wrap().query_wasm_smart(contract_addr, &msg).unwrap();
+        result
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn marketplace_test_query_get_coll_nfts_for_sale_by_price<T: Into<String>>(
+        app: &App,
+        contract_addr: T,
+        nft_collection_address: NftCollectionAddress,
+        denom: String,
+        order: PriceOrder,
+        min_price: Option<Uint128>,
+        max_price: Option<Uint128>,
+        start_after: Option<(Uint128, TokenId)>,
+        output_length: Option<u32>,
+    ) -> Vec<NftSale> {
+        let msg: chihuahua_nft_marketplace::msg::QueryMsg =
+            chihuahua_nft_marketplace::msg::QueryMsg::GetCollectionNftsForSaleByPrice {
+                nft_collection_address,
+                denom,
+                order,
+                min_price,
+                max_price,
+                start_after,
+                output_length,
+            };
+        let result: Vec<NftSale> = app.wrap().query_wasm_smart(contract_addr, &msg).unwrap();
+        result
+    }
+
+    pub fn marketplace_test_query_get_recently_listed<T: Into<String>>(
+        app: &App,
+        contract_addr: T,
+        nft_collection_address: Option<NftCollectionAddress>,
+        start_after: Option<NftSaleCursor>,
+        output_length: Option<u32>,
+    ) -> Vec<NftSaleListing> {
+        let msg: chihuahua_nft_marketplace::msg::QueryMsg =
+            chihuahua_nft_marketplace::msg::QueryMsg::GetRecentlyListed {
+                nft_collection_address,
+                start_after,
+                output_length,
+            };
+        let result: Vec<NftSaleListing> = app.wrap().query_wasm_smart(contract_addr, &msg).unwrap();
+        result
+    }
+
+    pub fn marketplace_test_query_get_ending_soon<T: Into<String>>(
+        app: &App,
+        contract_addr: T,
+        nft_collection_address: Option<NftCollectionAddress>,
+        start_after: Option<NftSaleCursor>,
+        output_length: Option<u32>,
+    ) -> Vec<NftSale> {
+        let msg: chihuahua_nft_marketplace::msg::QueryMsg =
+            chihuahua_nft_marketplace::msg::QueryMsg::GetEndingSoon {
+                nft_collection_address,
+                start_after,
+                output_length,
+            };
+        let result: Vec<NftSale> = app.wrap().query_wasm_smart(contract_addr, &msg).unwrap();
+        result
+    }
+
+    pub fn marketplace_test_query_get_recently_sold<T: Into<String>>(
+        app: &App,
+        contract_addr: T,
+        nft_collection_address: Option<NftCollectionAddress>,
+        start_after: Option<u64>,
+        output_length: Option<u32>,
+    ) -> Vec<SoldNft> {
+        let msg: chihuahua_nft_marketplace::msg::QueryMsg =
+            chihuahua_nft_marketplace::msg::QueryMsg::GetRecentlySold {
+                nft_collection_address,
+                start_after,
+                output_length,
+            };
+        let result: Vec<SoldNft> = app.wrap().query_wasm_smart(contract_addr, &msg).unwrap();
+        result
+    }
+
+    pub fn marketplace_test_query_get_activity<T: Into<String>>(
+        app: &App,
+        contract_addr: T,
+        start_after: Option<u64>,
+        output_length: Option<u32>,
+    ) -> Vec<Activity> {
+        let msg: chihuahua_nft_marketplace::msg::QueryMsg =
+            chihuahua_nft_marketplace::msg::QueryMsg::GetActivity {
+                start_after,
+                output_length,
+            };
+        let result: Vec<Activity> = app.wrap().query_wasm_smart(contract_addr, &msg).unwrap();
+        result
+    }
+
+    pub fn marketplace_test_query_get_collection_activity<T: Into<String>>(
+        app: &App,
+        contract_addr: T,
+        nft_collection_address: NftCollectionAddress,
+        start_after: Option<u64>,
+        output_length: Option<u32>,
+    ) -> Vec<Activity> {
+        let msg: chihuahua_nft_marketplace::msg::QueryMsg =
+            chihuahua_nft_marketplace::msg::QueryMsg::GetCollectionActivity {
+                nft_collection_address,
+                start_after,
+                output_length,
+            };
+        let result: Vec<Activity> = app.wrap().query_wasm_smart(contract_addr, &msg).unwrap();
+        result
+    }
+
+    pub fn marketplace_test_query_get_token_activity<T: Into<String>>(
+        app: &App,
+        contract_addr: T,
+        nft_collection_address: NftCollectionAddress,
+        token_id: TokenId,
+        start_after: Option<u64>,
+        output_length: Option<u32>,
+    ) -> Vec<Activity> {
+        let msg: chihuahua_nft_marketplace::msg::QueryMsg =
+            chihuahua_nft_marketplace::msg::QueryMsg::GetTokenActivity {
+                nft_collection_address,
+                token_id,
+                start_after,
+                output_length,
+            };
+        let result: Vec<Activity> = app.wrap().query_wasm_smart(contract_addr, &msg).unwrap();
+        result
+    }
+
+    pub fn marketplace_test_query_get_address_activity<T: Into<String>>(
+        app: &App,
+        contract_addr: T,
+        address: String,
+        kinds: Option<Vec<AddressActivityKind>>,
+        start_after: Option<u64>,
+        output_length: Option<u32>,
+    ) -> Vec<AddressActivity> {
+        let msg: chihuahua_nft_marketplace::msg::QueryMsg =
+            chihuahua_nft_marketplace::msg::QueryMsg::GetAddressActivity {
+                address,
+                kinds,
+                start_after,
+                output_length,
+            };
+        let result: Vec<AddressActivity> =
+            app.wrap().query_wasm_smart(contract_addr, &msg).unwrap();
+        result
+    }
+
+    pub fn marketplace_test_query_get_inbox<T: Into<String>>(
+        app: &App,
+        contract_addr: T,
+        address: String,
+        start_after: Option<u64>,
+        output_length: Option<u32>,
+    ) -> Vec<InboxMessage> {
+        let msg: chihuahua_nft_marketplace::msg::QueryMsg =
+            chihuahua_nft_marketplace::msg::QueryMsg::GetInbox {
+                address,
+                start_after,
+                output_length,
+            };
+        let result: Vec<InboxMessage> = app.wrap().query_wasm_smart(contract_addr, &msg).unwrap();
+        result
+    }
+
+    pub fn marketplace_test_query_get_inbox_threads<T: Into<String>>(
+        app: &App,
+        contract_addr: T,
+        address: String,
+        start_after: Option<u64>,
+        output_length: Option<u32>,
+    ) -> Vec<InboxThreadInfo> {
+        let msg: chihuahua_nft_marketplace::msg::QueryMsg =
+            chihuahua_nft_marketplace::msg::QueryMsg::GetInboxThreads {
+                address,
+                start_after,
+                output_length,
+            };
+        let result: Vec<InboxThreadInfo> =
+            app.wrap().query_wasm_smart(contract_addr, &msg).unwrap();
+        result
+    }
+
+    pub fn marketplace_test_query_get_inbox_thread<T: Into<String>>(
+        app: &App,
+        contract_addr: T,
+        address: String,
+        counterparty: String,
+        start_after: Option<u64>,
+        output_length: Option<u32>,
+    ) -> Vec<InboxMessage> {
+        let msg: chihuahua_nft_marketplace::msg::QueryMsg =
+            chihuahua_nft_marketplace::msg::QueryMsg::GetInboxThread {
+                address,
+                counterparty,
+                start_after,
+                output_length,
+            };
+        let result: Vec<InboxMessage> = app.wrap().query_wasm_smart(contract_addr, &msg).unwrap();
+        result
+    }
+
+    pub fn marketplace_test_query_get_blocked_addresses<T: Into<String>>(
+        app: &App,
+        contract_addr: T,
+        address: String,
+        start_after: Option<String>,
+        output_length: Option<u32>,
+    ) -> Vec<String> {
+        let msg: chihuahua_nft_marketplace::msg::QueryMsg =
+            chihuahua_nft_marketplace::msg::QueryMsg::GetBlockedAddresses {
+                address,
+                start_after,
+                output_length,
+            };
+        let result: Vec<String> = app.wrap().query_wasm_smart(contract_addr, &msg).unwrap();
+        result
+    }
+
+    pub fn marketplace_test_query_get_messaging_config<T: Into<String>>(
+        app: &App,
+        contract_addr: T,
+    ) -> MessagingConfig {
+        let msg: chihuahua_nft_marketplace::msg::QueryMsg =
+            chihuahua_nft_marketplace::msg::QueryMsg::GetMessagingConfig {};
+        let result: MessagingConfig = app.wrap().query_wasm_smart(contract_addr, &msg).unwrap();
+        result
+    }
+
+    pub fn marketplace_test_query_get_message_filter<T: Into<String>>(
+        app: &App,
+        contract_addr: T,
+        address: String,
+    ) -> MessageFilter {
+        let msg: chihuahua_nft_marketplace::msg::QueryMsg =
+            chihuahua_nft_marketplace::msg::QueryMsg::GetMessageFilter { address };
+        let result: MessageFilter = app.wrap().query_wasm_smart(contract_addr, &msg).unwrap();
+        result
+    }
+
+    pub fn marketplace_test_query_get_username<T: Into<String>>(
+        app: &App,
+        contract_addr: T,
+        address: String,
+    ) -> Option<String> {
+        let msg: chihuahua_nft_marketplace::msg::QueryMsg =
+            chihuahua_nft_marketplace::msg::QueryMsg::GetUsername { address };
+        let result: Option<String> = app.wrap().query_wasm_smart(contract_addr, &msg).unwrap();
+        result
+    }
+
+    pub fn marketplace_test_query_get_username_status<T: Into<String>>(
+        app: &App,
+        contract_addr: T,
+        username: String,
+    ) -> UsernameStatus {
+        let msg: chihuahua_nft_marketplace::msg::QueryMsg =
+            chihuahua_nft_marketplace::msg::QueryMsg::GetUsernameStatus { username };
+        let result: UsernameStatus = app.wrap().query_wasm_smart(contract_addr, &msg).unwrap();
+        result
+    }
+
+    pub fn marketplace_test_query_get_reserved_usernames<T: Into<String>>(
+        app: &App,
+        contract_addr: T,
+        start_after: Option<String>,
+        output_length: Option<u32>,
+    ) -> Vec<String> {
+        let msg: chihuahua_nft_marketplace::msg::QueryMsg =
+            chihuahua_nft_marketplace::msg::QueryMsg::GetReservedUsernames {
+                start_after,
+                output_length,
+            };
+        let result: Vec<String> = app.wrap().query_wasm_smart(contract_addr, &msg).unwrap();
+        result
+    }
+
+    pub fn marketplace_test_exec_sell_username(
+        app: &mut App,
+        contract_addr: &Addr,
+        info: MessageInfo,
+        price_value: Uint128,
+        price_denom: String,
+    ) -> AnyResult<AppResponse> {
+        let msg = chihuahua_nft_marketplace::msg::ExecuteMsg::SellUsername {
+            price_value,
+            price_denom,
+        };
+        app.execute_contract(info.sender, contract_addr.clone(), &msg, &info.funds)
+    }
+
+    pub fn marketplace_test_exec_cancel_username_sale(
+        app: &mut App,
+        contract_addr: &Addr,
+        info: MessageInfo,
+    ) -> AnyResult<AppResponse> {
+        let msg = chihuahua_nft_marketplace::msg::ExecuteMsg::CancelUsernameSale {};
+        app.execute_contract(info.sender, contract_addr.clone(), &msg, &info.funds)
+    }
+
+    pub fn marketplace_test_exec_buy_username(
+        app: &mut App,
+        contract_addr: &Addr,
+        info: MessageInfo,
+        username: String,
+    ) -> AnyResult<AppResponse> {
+        let msg = chihuahua_nft_marketplace::msg::ExecuteMsg::BuyUsername { username };
+        app.execute_contract(info.sender, contract_addr.clone(), &msg, &info.funds)
+    }
+
+    pub fn marketplace_test_exec_transfer_username(
+        app: &mut App,
+        contract_addr: &Addr,
+        info: MessageInfo,
+        recipient: String,
+    ) -> AnyResult<AppResponse> {
+        let msg = chihuahua_nft_marketplace::msg::ExecuteMsg::TransferUsername { recipient };
+        app.execute_contract(info.sender, contract_addr.clone(), &msg, &info.funds)
+    }
+
+    pub fn marketplace_test_query_get_username_sale<T: Into<String>>(
+        app: &App,
+        contract_addr: T,
+        username: String,
+    ) -> Option<UsernameSale> {
+        let msg: chihuahua_nft_marketplace::msg::QueryMsg =
+            chihuahua_nft_marketplace::msg::QueryMsg::GetUsernameSale { username };
+        let result: Option<UsernameSale> =
+            app.wrap().query_wasm_smart(contract_addr, &msg).unwrap();
+        result
+    }
+
+    pub fn marketplace_test_query_get_usernames_for_sale<T: Into<String>>(
+        app: &App,
+        contract_addr: T,
+        start_after: Option<String>,
+        output_length: Option<u32>,
+    ) -> Vec<UsernameSale> {
+        let msg: chihuahua_nft_marketplace::msg::QueryMsg =
+            chihuahua_nft_marketplace::msg::QueryMsg::GetUsernamesForSale {
+                start_after,
+                output_length,
+            };
+        let result: Vec<UsernameSale> = app.wrap().query_wasm_smart(contract_addr, &msg).unwrap();
+        result
+    }
+
+    pub fn marketplace_test_exec_verify(
+        app: &mut App,
+        contract_addr: &Addr,
+        info: MessageInfo,
+        subject: VerificationSubject,
+        kind: VerificationKind,
+    ) -> AnyResult<AppResponse> {
+        let msg = chihuahua_nft_marketplace::msg::ExecuteMsg::Verify { subject, kind };
+        app.execute_contract(info.sender, contract_addr.clone(), &msg, &info.funds)
+    }
+
+    pub fn marketplace_test_exec_revoke_verification(
+        app: &mut App,
+        contract_addr: &Addr,
+        info: MessageInfo,
+        subject: VerificationSubject,
+        kind: VerificationKind,
+    ) -> AnyResult<AppResponse> {
+        let msg = chihuahua_nft_marketplace::msg::ExecuteMsg::RevokeVerification { subject, kind };
+        app.execute_contract(info.sender, contract_addr.clone(), &msg, &info.funds)
+    }
+
+    pub fn marketplace_test_query_get_verifications<T: Into<String>>(
+        app: &App,
+        contract_addr: T,
+        subject: VerificationSubject,
+        include_revoked: bool,
+    ) -> Vec<Verification> {
+        let msg: chihuahua_nft_marketplace::msg::QueryMsg =
+            chihuahua_nft_marketplace::msg::QueryMsg::GetVerifications {
+                subject,
+                include_revoked,
+            };
+        let result: Vec<Verification> = app.wrap().query_wasm_smart(contract_addr, &msg).unwrap();
+        result
+    }
+
+    pub fn marketplace_test_query_get_moderators<T: Into<String>>(
+        app: &App,
+        contract_addr: T,
+        start_after: Option<String>,
+        output_length: Option<u32>,
+    ) -> Vec<String> {
+        let msg: chihuahua_nft_marketplace::msg::QueryMsg =
+            chihuahua_nft_marketplace::msg::QueryMsg::GetModerators {
+                start_after,
+                output_length,
+            };
+        let result: Vec<String> = app.wrap().query_wasm_smart(contract_addr, &msg).unwrap();
+        result
+    }
+
+    pub fn marketplace_test_exec_follow(
+        app: &mut App,
+        contract_addr: &Addr,
+        info: MessageInfo,
+        target: FollowTarget,
+    ) -> AnyResult<AppResponse> {
+        let msg = chihuahua_nft_marketplace::msg::ExecuteMsg::Follow { target };
+        app.execute_contract(info.sender, contract_addr.clone(), &msg, &info.funds)
+    }
+
+    pub fn marketplace_test_exec_unfollow(
+        app: &mut App,
+        contract_addr: &Addr,
+        info: MessageInfo,
+        target: FollowTarget,
+    ) -> AnyResult<AppResponse> {
+        let msg = chihuahua_nft_marketplace::msg::ExecuteMsg::Unfollow { target };
+        app.execute_contract(info.sender, contract_addr.clone(), &msg, &info.funds)
+    }
+
+    pub fn marketplace_test_exec_watch_token(
+        app: &mut App,
+        contract_addr: &Addr,
+        info: MessageInfo,
+        nft_collection_address: NftCollectionAddress,
+        token_id: TokenId,
+    ) -> AnyResult<AppResponse> {
+        let msg = chihuahua_nft_marketplace::msg::ExecuteMsg::WatchToken {
+            nft_collection_address,
+            token_id,
+        };
+        app.execute_contract(info.sender, contract_addr.clone(), &msg, &info.funds)
+    }
+
+    pub fn marketplace_test_exec_unwatch_token(
+        app: &mut App,
+        contract_addr: &Addr,
+        info: MessageInfo,
+        nft_collection_address: NftCollectionAddress,
+        token_id: TokenId,
+    ) -> AnyResult<AppResponse> {
+        let msg = chihuahua_nft_marketplace::msg::ExecuteMsg::UnwatchToken {
+            nft_collection_address,
+            token_id,
+        };
+        app.execute_contract(info.sender, contract_addr.clone(), &msg, &info.funds)
+    }
+
+    pub fn marketplace_test_query_get_followers<T: Into<String>>(
+        app: &App,
+        contract_addr: T,
+        target: FollowTarget,
+        start_after: Option<String>,
+        output_length: Option<u32>,
+    ) -> Vec<Follow> {
+        let msg: chihuahua_nft_marketplace::msg::QueryMsg =
+            chihuahua_nft_marketplace::msg::QueryMsg::GetFollowers {
+                target,
+                start_after,
+                output_length,
+            };
+        let result: Vec<Follow> = app.wrap().query_wasm_smart(contract_addr, &msg).unwrap();
+        result
+    }
+
+    pub fn marketplace_test_query_get_following<T: Into<String>>(
+        app: &App,
+        contract_addr: T,
+        address: String,
+        start_after: Option<FollowTarget>,
+        output_length: Option<u32>,
+    ) -> Vec<Follow> {
+        let msg: chihuahua_nft_marketplace::msg::QueryMsg =
+            chihuahua_nft_marketplace::msg::QueryMsg::GetFollowing {
+                address,
+                start_after,
+                output_length,
+            };
+        let result: Vec<Follow> = app.wrap().query_wasm_smart(contract_addr, &msg).unwrap();
+        result
+    }
+
+    pub fn marketplace_test_query_get_watchlist<T: Into<String>>(
+        app: &App,
+        contract_addr: T,
+        address: String,
+        start_after: Option<(NftCollectionAddress, TokenId)>,
+        output_length: Option<u32>,
+    ) -> Vec<WatchedToken> {
+        let msg: chihuahua_nft_marketplace::msg::QueryMsg =
+            chihuahua_nft_marketplace::msg::QueryMsg::GetWatchlist {
+                address,
+                start_after,
+                output_length,
+            };
+        let result: Vec<WatchedToken> = app.wrap().query_wasm_smart(contract_addr, &msg).unwrap();
+        result
+    }
+
+    pub fn marketplace_test_query_get_watchlist_listings<T: Into<String>>(
+        app: &App,
+        contract_addr: T,
+        address: String,
+        start_after: Option<(NftCollectionAddress, TokenId)>,
+        output_length: Option<u32>,
+    ) -> Vec<NftSale> {
+        let msg: chihuahua_nft_marketplace::msg::QueryMsg =
+            chihuahua_nft_marketplace::msg::QueryMsg::GetWatchlistListings {
+                address,
+                start_after,
+                output_length,
+            };
+        let result: Vec<NftSale> = app.wrap().query_wasm_smart(contract_addr, &msg).unwrap();
+        result
+    }
+
+    pub fn marketplace_test_exec_fund_reward_pool(
+        app: &mut App,
+        contract_addr: &Addr,
+        info: MessageInfo,
+    ) -> AnyResult<AppResponse> {
+        let msg = chihuahua_nft_marketplace::msg::ExecuteMsg::FundRewardPool {};
+        app.execute_contract(info.sender, contract_addr.clone(), &msg, &info.funds)
+    }
+
+    pub fn marketplace_test_exec_claim_rewards(
+        app: &mut App,
+        contract_addr: &Addr,
+        info: MessageInfo,
+    ) -> AnyResult<AppResponse> {
+        let msg = chihuahua_nft_marketplace::msg::ExecuteMsg::ClaimRewards {};
+        app.execute_contract(info.sender, contract_addr.clone(), &msg, &info.funds)
+    }
+
+    pub fn marketplace_test_query_get_reward_pool<T: Into<String>>(
+        app: &App,
+        contract_addr: T,
+    ) -> RewardPool {
+        let msg: chihuahua_nft_marketplace::msg::QueryMsg =
+            chihuahua_nft_marketplace::msg::QueryMsg::GetRewardPool {};
+        let result: RewardPool = app.wrap().query_wasm_smart(contract_addr, &msg).unwrap();
+        result
+    }
+
+    pub fn marketplace_test_query_get_rewards_to_claim<T: Into<String>>(
+        app: &App,
+        contract_addr: T,
+        address: String,
+    ) -> Uint128 {
+        let msg: chihuahua_nft_marketplace::msg::QueryMsg =
+            chihuahua_nft_marketplace::msg::QueryMsg::GetRewardsToClaim { address };
+        let result: Uint128 = app.wrap().query_wasm_smart(contract_addr, &msg).unwrap();
+        result
+    }
+
+    pub fn marketplace_test_query_get_reward_vesting<T: Into<String>>(
+        app: &App,
+        contract_addr: T,
+        address: String,
+    ) -> RewardVestingInfo {
+        let msg: chihuahua_nft_marketplace::msg::QueryMsg =
+            chihuahua_nft_marketplace::msg::QueryMsg::GetRewardVesting { address };
+        let result: RewardVestingInfo = app.wrap().query_wasm_smart(contract_addr, &msg).unwrap();
+        result
+    }
+
+    pub fn marketplace_test_exec_create_competition(
+        app: &mut App,
+        contract_addr: &Addr,
+        info: MessageInfo,
+        start_time: Timestamp,
+        end_time: Timestamp,
+        nft_collection_addresses: Vec<NftCollectionAddress>,
+        prizes: Vec<Uint128>,
+    ) -> AnyResult<AppResponse> {
+        let msg = chihuahua_nft_marketplace::msg::ExecuteMsg::CreateCompetition {
+            start_time,
+            end_time,
+            nft_collection_addresses,
+            prizes,
+        };
+        app.execute_contract(info.sender, contract_addr.clone(), &msg, &info.funds)
+    }
+
+    pub fn marketplace_test_exec_finalize_competition(
+        app: &mut App,
+        contract_addr: &Addr,
+        info: MessageInfo,
+        competition_id: u64,
+    ) -> AnyResult<AppResponse> {
+        let msg =
+            chihuahua_nft_marketplace::msg::ExecuteMsg::FinalizeCompetition { competition_id };
+        app.execute_contract(info.sender, contract_addr.clone(), &msg, &info.funds)
+    }
+
+    pub fn marketplace_test_exec_claim_competition_prize(
+        app: &mut App,
+        contract_addr: &Addr,
+        info: MessageInfo,
+        competition_id: u64,
+    ) -> AnyResult<AppResponse> {
+        let msg =
+            chihuahua_nft_marketplace::msg::ExecuteMsg::ClaimCompetitionPrize { competition_id };
+        app.execute_contract(info.sender, contract_addr.clone(), &msg, &info.funds)
+    }
+
+    pub fn marketplace_test_query_get_competition<T: Into<String>>(
+        app: &App,
+        contract_addr: T,
+        competition_id: u64,
+    ) -> Competition {
+        let msg: chihuahua_nft_marketplace::msg::QueryMsg =
+            chihuahua_nft_marketplace::msg::QueryMsg::GetCompetition { competition_id };
+        let result: Competition = app.wrap().query_wasm_smart(contract_addr, &msg).unwrap();
+        result
+    }
+
+    pub fn marketplace_test_query_get_competitions<T: Into<String>>(
+        app: &App,
+        contract_addr: T,
+        start_after: Option<u64>,
+        output_length: Option<u32>,
+    ) -> Vec<Competition> {
+        let msg: chihuahua_nft_marketplace::msg::QueryMsg =
+            chihuahua_nft_marketplace::msg::QueryMsg::GetCompetitions {
+                start_after,
+                output_length,
+            };
+        let result: Vec<Competition> = app.wrap().query_wasm_smart(contract_addr, &msg).unwrap();
+        result
+    }
+
+    pub fn marketplace_test_query_get_competition_leaderboard<T: Into<String>>(
+        app: &App,
+        contract_addr: T,
+        competition_id: u64,
+        start_after: Option<LeaderboardCursor>,
+        output_length: Option<u32>,
+    ) -> Vec<CompetitionTrader> {
+        let msg: chihuahua_nft_marketplace::msg::QueryMsg =
+            chihuahua_nft_marketplace::msg::QueryMsg::GetCompetitionLeaderboard {
+                competition_id,
+                start_after,
+                output_length,
+            };
+        let result: Vec<CompetitionTrader> =
+            app.wrap().query_wasm_smart(contract_addr, &msg).unwrap();
+        result
+    }
+
+    pub fn marketplace_test_query_get_competition_prizes<T: Into<String>>(
+        app: &App,
+        contract_addr: T,
+        competition_id: u64,
+    ) -> Vec<CompetitionPrize> {
+        let msg: chihuahua_nft_marketplace::msg::QueryMsg =
+            chihuahua_nft_marketplace::msg::QueryMsg::GetCompetitionPrizes { competition_id };
+        let result: Vec<CompetitionPrize> =
+            app.wrap().query_wasm_smart(contract_addr, &msg).unwrap();
+        result
+    }
+
+    pub fn marketplace_test_exec_renew_vip_level(
+        app: &mut App,
+        contract_addr: &Addr,
+        info: MessageInfo,
+    ) -> AnyResult<AppResponse> {
+        let msg = chihuahua_nft_marketplace::msg::ExecuteMsg::RenewVipLevel {};
+        app.execute_contract(info.sender, contract_addr.clone(), &msg, &info.funds)
+    }
+
+    pub fn marketplace_test_query_get_vip_progress<T: Into<String>>(
+        app: &App,
+        contract_addr: T,
+        address: String,
+    ) -> VipProgress {
+        let msg: chihuahua_nft_marketplace::msg::QueryMsg =
+            chihuahua_nft_marketplace::msg::QueryMsg::GetVipProgress { address };
+        let result: VipProgress = app.wrap().query_wasm_smart(contract_addr, &msg).unwrap();
+        result
+    }
+
+    pub fn marketplace_test_query_get_partner_perks<T: Into<String>>(
+        app: &App,
+        contract_addr: T,
+    ) -> PartnerPerks {
+        let msg: chihuahua_nft_marketplace::msg::QueryMsg =
+            chihuahua_nft_marketplace::msg::QueryMsg::GetPartnerPerks {};
+        let result: PartnerPerks = app.wrap().query_wasm_smart(contract_addr, &msg).unwrap();
+        result
+    }
+
+    pub fn marketplace_test_query_get_holder_perks<T: Into<String>>(
+        app: &App,
+        contract_addr: T,
+        address: String,
+    ) -> HolderPerks {
+        let msg: chihuahua_nft_marketplace::msg::QueryMsg =
+            chihuahua_nft_marketplace::msg::QueryMsg::GetHolderPerks { address };
+        let result: HolderPerks = app.wrap().query_wasm_smart(contract_addr, &msg).unwrap();
+        result
+    }
+
+    pub fn marketplace_test_query_get_wash_trading_config<T: Into<String>>(
+        app: &App,
+        contract_addr: T,
+    ) -> WashTradingConfig {
+        let msg: chihuahua_nft_marketplace::msg::QueryMsg =
+            chihuahua_nft_marketplace::msg::QueryMsg::GetWashTradingConfig {};
+        let result: WashTradingConfig = app.wrap().query_wasm_smart(contract_addr, &msg).unwrap();
+        result
+    }
+
+    pub fn marketplace_test_exec_stake(
+        app: &mut App,
+        contract_addr: &Addr,
+        info: MessageInfo,
+    ) -> AnyResult<AppResponse> {
+        let msg = chihuahua_nft_marketplace::msg::ExecuteMsg::Stake {};
+        app.execute_contract(info.sender, contract_addr.clone(), &msg, &info.funds)
+    }
+
+    pub fn marketplace_test_exec_unstake(
+        app: &mut App,
+        contract_addr: &Addr,
+        info: MessageInfo,
+        amount: Uint128,
+    ) -> AnyResult<AppResponse> {
+        let msg = chihuahua_nft_marketplace::msg::ExecuteMsg::Unstake { amount };
+        app.execute_contract(info.sender, contract_addr.clone(), &msg, &info.funds)
+    }
+
+    pub fn marketplace_test_exec_withdraw_unbonded(
+        app: &mut App,
+        contract_addr: &Addr,
+        info: MessageInfo,
+    ) -> AnyResult<AppResponse> {
+        let msg = chihuahua_nft_marketplace::msg::ExecuteMsg::WithdrawUnbonded {};
+        app.execute_contract(info.sender, contract_addr.clone(), &msg, &info.funds)
+    }
+
+    pub fn marketplace_test_exec_claim_staking_fees(
+        app: &mut App,
+        contract_addr: &Addr,
+        info: MessageInfo,
+    ) -> AnyResult<AppResponse> {
+        let msg = chihuahua_nft_marketplace::msg::ExecuteMsg::ClaimStakingFees {};
+        app.execute_contract(info.sender, contract_addr.clone(), &msg, &info.funds)
+    }
+
+    pub fn marketplace_test_query_get_staking_stats<T: Into<String>>(
+        app: &App,
+        contract_addr: T,
+    ) -> StakingStats {
+        let msg: chihuahua_nft_marketplace::msg::QueryMsg =
+            chihuahua_nft_marketplace::msg::QueryMsg::GetStakingStats {};
+        let result: StakingStats = app.wrap().query_wasm_smart(contract_addr, &msg).unwrap();
+        result
+    }
+
+    pub fn marketplace_test_query_get_staker_info<T: Into<String>>(
+        app: &App,
+        contract_addr: T,
+        address: String,
+    ) -> StakerInfo {
+        let msg: chihuahua_nft_marketplace::msg::QueryMsg =
+            chihuahua_nft_marketplace::msg::QueryMsg::GetStakerInfo { address };
+        let result: StakerInfo = app.wrap().query_wasm_smart(contract_addr, &msg).unwrap();
+        result
+    }
+
+    pub fn marketplace_test_query_get_reward_minting<T: Into<String>>(
+        app: &App,
+        contract_addr: T,
+    ) -> Option<RewardMinting> {
+        let msg: chihuahua_nft_marketplace::msg::QueryMsg =
+            chihuahua_nft_marketplace::msg::QueryMsg::GetRewardMinting {};
+        let result: Option<RewardMinting> =
+            app.wrap().query_wasm_smart(contract_addr, &msg).unwrap();
+        result
+    }
 }
@@ -4,17 +4,19 @@ pub mod tests {
     use cosmwasm_std::{Addr, Empty, MessageInfo, Timestamp, Uint128};
     use cw721_base::MintMsg;
     use cw721_metadata_onchain;
-    use cw_multi_test::{App, AppResponse, Contract, ContractWrapper, Executor};
+    use cw_multi_test::{AppResponse, Contract, ContractWrapper, Executor};
     use cw_utils::Expiration;
 
     use cw2981_multiroyalties::msg::Cw2981QueryMsg;
     use cw2981_multiroyalties::{Metadata, Royalty};
     use nft_marketplace_utils::nft_collection::TokenId;
+    use nft_marketplace_utils::tokenfactory::TokenFactoryMsg;
 
     use crate::common::utils::constants::OWNER;
+    use crate::common::utils::utils_common::tests::App;
 
-    pub fn smart_contract_def_test_cw2981_multi() -> Box<dyn Contract<Empty>> {
-        let contract = ContractWrapper::new(
+    pub fn smart_contract_def_test_cw2981_multi() -> Box<dyn Contract<TokenFactoryMsg>> {
+        let contract = ContractWrapper::new_with_empty(
             cw2981_multiroyalties::entry::execute,
             cw2981_multiroyalties::entry::instantiate,
             cw2981_multiroyalties::entry::query,
@@ -22,8 +24,8 @@ pub mod tests {
         Box::new(contract)
     }
 
-    pub fn smart_contract_def_test_cw721_metadata_onchain() -> Box<dyn Contract<Empty>> {
-        let contract = ContractWrapper::new(
+    pub fn smart_contract_def_test_cw721_metadata_onchain() -> Box<dyn Contract<TokenFactoryMsg>> {
+        let contract = ContractWrapper::new_with_empty(
             cw721_metadata_onchain::entry::execute,
             cw721_metadata_onchain::entry::instantiate,
             cw721_metadata_onchain::entry::query,
@@ -2,21 +2,24 @@
 pub mod tests {
     use anyhow::Result as AnyResult;
     use cosmwasm_std::testing::mock_info;
-    use cosmwasm_std::{Addr, Empty, MessageInfo, Timestamp, Uint128};
-    use cw_multi_test::{App, AppResponse, Contract, ContractWrapper, Executor};
+    use cosmwasm_std::{Addr, MessageInfo, Timestamp, Uint128};
+    use cw_multi_test::{AppResponse, Contract, ContractWrapper, Executor};
 
     use general_utils::denominations::AcceptedDenominations;
+    use nft_marketplace_utils::tokenfactory::TokenFactoryMsg;
     use oracle::msg::UpdateConfigEnum;
     use price_oracle_utils::oracle::{OraclePrice, OraclePrices};
 
     use crate::common::utils::constants::{FEEDER, OWNER};
+    use crate::common::utils::utils_common::tests::App;
 
-    pub fn smart_contract_def_test_price_oracle() -> Box<dyn Contract<Empty>> {
-        let smart_contract = ContractWrapper::new(
+    pub fn smart_contract_def_test_price_oracle() -> Box<dyn Contract<TokenFactoryMsg>> {
+        let smart_contract = ContractWrapper::new_with_empty(
             oracle::contract::execute,
             oracle::contract::instantiate,
             oracle::contract::query,
-        );
+        )
+        .with_migrate_empty(oracle::contract::migrate);
         Box::new(smart_contract)
     }
 
@@ -0,0 +1,86 @@
+#[cfg(test)]
+pub mod tests {
+    use anyhow::{bail, Result as AnyResult};
+    use cosmwasm_std::{coins, Addr, Api, Binary, BlockInfo, CustomQuery, Empty, Querier, Storage};
+    use cw_multi_test::{AppResponse, BankSudo, CosmosRouter, Module, SudoMsg};
+    use cw_storage_plus::Map;
+
+    use nft_marketplace_utils::tokenfactory::{factory_denom, TokenFactoryMsg, TokenMsg};
+
+    // Creator of each denom, the only address allowed to mint it
+    const DENOM_ADMINS: Map<&str, Addr> = Map::new("tokenfactory_denom_admins");
+
+    // Stand-in for the tokenfactory module of the chain, the minted tokens go through the bank
+    pub struct TokenFactoryModule {}
+
+    impl Module for TokenFactoryModule {
+        type ExecT = TokenFactoryMsg;
+        type QueryT = Empty;
+        type SudoT = Empty;
+
+        fn execute<ExecC, QueryC>(
+            &self,
+            api: &dyn Api,
+            storage: &mut dyn Storage,
+            router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+            block: &BlockInfo,
+            sender: Addr,
+            msg: TokenFactoryMsg,
+        ) -> AnyResult<AppResponse>
+        where
+            QueryC: CustomQuery,
+        {
+            let TokenFactoryMsg::Token(msg) = msg;
+            match msg {
+                TokenMsg::CreateDenom { subdenom } => {
+                    let denom = factory_denom(sender.as_str(), &subdenom);
+                    if DENOM_ADMINS.has(storage, &denom) {
+                        bail!("denom {denom} already exists");
+                    }
+                    DENOM_ADMINS.save(storage, &denom, &sender)?;
+                    Ok(AppResponse::default())
+                }
+                TokenMsg::MintTokens {
+                    denom,
+                    amount,
+                    mint_to_address,
+                } => {
+                    if DENOM_ADMINS.may_load(storage, &denom)? != Some(sender) {
+                        bail!("unauthorized to mint {denom}");
+                    }
+                    router.sudo(
+                        api,
+                        storage,
+                        block,
+                        SudoMsg::Bank(BankSudo::Mint {
+                            to_address: mint_to_address,
+                            amount: coins(amount.u128(), denom),
+                        }),
+                    )
+                }
+            }
+        }
+
+        fn sudo<ExecC, QueryC>(
+            &self,
+            _api: &dyn Api,
+            _storage: &mut dyn Storage,
+            _router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+            _block: &BlockInfo,
+            _msg: Empty,
+        ) -> AnyResult<AppResponse> {
+            bail!("sudo is not supported by the tokenfactory module")
+        }
+
+        fn query(
+            &self,
+            _api: &dyn Api,
+            _storage: &dyn Storage,
+            _querier: &dyn Querier,
+            _block: &BlockInfo,
+            _request: Empty,
+        ) -> AnyResult<Binary> {
+            bail!("queries are not supported by the tokenfactory module")
+        }
+    }
+}
@@ -0,0 +1,407 @@
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::mock_info;
+    use cosmwasm_std::{coins, Addr, Uint128};
+
+    use nft_marketplace_utils::activity::{
+        Activity, ActivityType, AddressActivity, AddressActivityKind,
+    };
+    use nft_marketplace_utils::nft_collection::{
+        NftCollectionAddress, NftContractInfo, NftContractType,
+    };
+    use nft_marketplace_utils::nft_offer::NftOffer;
+    use nft_marketplace_utils::nft_sale::NftSale;
+
+    use crate::common::utils::constants::{OWNER, UHUAHUA, WALLET2, WALLET3};
+    use crate::common::utils::utils_common::tests::{instantiate_necessary_for_tests, App};
+    use crate::common::utils::utils_marketplace_contract_test::tests::{
+        marketplace_test_exec_add_new_collection, marketplace_test_exec_add_nft_code_id,
+        marketplace_test_exec_answer_offer, marketplace_test_exec_cancel_offer,
+        marketplace_test_exec_cancel_sale, marketplace_test_exec_enable_disable,
+        marketplace_test_exec_offer, marketplace_test_exec_sell_nft,
+        marketplace_test_exec_transfer_my_nft, marketplace_test_exec_update_sale,
+        marketplace_test_query_get_activity, marketplace_test_query_get_address_activity,
+        marketplace_test_query_get_collection_activity, marketplace_test_query_get_token_activity,
+    };
+    use crate::common::utils::utils_nft_contract_test::tests::{
+        cw2981_multi_test_exec_approve, cw2981_multi_test_exec_mint, cw2981_multi_test_exec_revoke,
+    };
+
+    const PRICE: u128 = 100_000_000u128;
+
+    fn nft_sale(app: &App, nft_collection: &str, token_id: &str, price: u128) -> NftSale {
+        NftSale {
+            seller: OWNER.to_string(),
+            nft_collection_address: nft_collection.to_string(),
+            token_id: token_id.to_string(),
+            sale_price_value: Uint128::new(price),
+            sale_price_denom: UHUAHUA.to_string(),
+            sale_expiration: app.block_info().time.plus_seconds(87000u64),
+        }
+    }
+
+    fn make_offer(
+        app: &mut App,
+        nft_marketplace_smart_contract_addr: &Addr,
+        nft_collection: &str,
+        offerer: &str,
+    ) {
+        let offer_expiration = app.block_info().time.plus_seconds(87000u64);
+        marketplace_test_exec_offer(
+            app,
+            nft_marketplace_smart_contract_addr,
+            mock_info(offerer, &coins(PRICE, UHUAHUA)),
+            NftOffer {
+                offerer_address: offerer.to_string(),
+                nft_collection_address: nft_collection.to_string(),
+                token_id: "Token1".to_string(),
+                offer_price_value: Uint128::new(PRICE),
+                offer_price_denom: UHUAHUA.to_string(),
+                offer_expiration,
+            },
+        )
+        .unwrap();
+    }
+
+    fn activity_types(activities: Vec<Activity>) -> Vec<ActivityType> {
+        activities
+            .into_iter()
+            .map(|activity| activity.activity_type)
+            .collect()
+    }
+
+    fn address_activity_kinds(activities: Vec<AddressActivity>) -> Vec<AddressActivityKind> {
+        activities
+            .into_iter()
+            .map(|address_activity| address_activity.kind)
+            .collect()
+    }
+
+    #[test]
+    fn test_marketplace_activity_log() {
+        // - Every user action is logged once, internal messages are not logged twice
+        // - Logs are read newest first, marketplace wide, by collection, token and address
+        // - The history of an address can be filtered by kind (purchases, sales, offers received...)
+        // - Cursors are the activity_id of the last activity received
+        let (mut app, necessary) = instantiate_necessary_for_tests();
+        let nft_marketplace_smart_contract_addr =
+            Addr::unchecked(necessary.nft_marketplace_smart_contract_addr);
+        let nft_collection = necessary.cw2981_nft_contract_addr1;
+        let nft_contract_info = NftContractInfo {
+            code_id: necessary.cw2981_nft_code_id,
+            nft_contract_type: NftContractType::Cw2981MultiRoyalties,
+        };
+
+        marketplace_test_exec_enable_disable(
+            &mut app,
+            nft_marketplace_smart_contract_addr.to_string(),
+            mock_info(OWNER, &[]),
+        )
+        .unwrap();
+        marketplace_test_exec_add_nft_code_id(
+            &mut app,
+            nft_marketplace_smart_contract_addr.to_string(),
+            vec![nft_contract_info.clone()],
+            mock_info(OWNER, &[]),
+        )
+        .unwrap();
+        for token_id in ["Token1", "Token2"] {
+            cw2981_multi_test_exec_mint(
+                &mut app,
+                &Addr::unchecked(nft_collection.clone()),
+                mock_info(OWNER, &[]),
+                token_id.to_string(),
+                OWNER.to_string(),
+                None,
+            )
+            .unwrap();
+        }
+        marketplace_test_exec_add_new_collection(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(OWNER, &[]),
+            NftCollectionAddress::from(nft_collection.clone()),
+            nft_contract_info,
+        )
+        .unwrap();
+
+        // Token1: list, update, offer cancelled, offer accepted, transfer
+        let info = mock_info(OWNER, &coins(6_900_000u128, UHUAHUA));
+        let approval_expiration = app.block_info().time.plus_seconds(87000u64).seconds();
+        for token_id in ["Token1", "Token2"] {
+            cw2981_multi_test_exec_approve(
+                &mut app,
+                &Addr::unchecked(nft_collection.clone()),
+                &nft_marketplace_smart_contract_addr,
+                info.clone(),
+                token_id.to_string(),
+                approval_expiration,
+            )
+            .unwrap();
+        }
+        let sale = nft_sale(&app, &nft_collection, "Token1", PRICE);
+        marketplace_test_exec_sell_nft(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            info.clone(),
+            sale,
+        )
+        .unwrap();
+        let sale = nft_sale(&app, &nft_collection, "Token1", 2 * PRICE);
+        marketplace_test_exec_update_sale(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(OWNER, &[]),
+            sale,
+        )
+        .unwrap();
+        make_offer(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            &nft_collection,
+            WALLET2,
+        );
+        marketplace_test_exec_cancel_offer(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(WALLET2, &[]),
+            nft_collection.clone(),
+            "Token1".to_string(),
+        )
+        .unwrap();
+        make_offer(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            &nft_collection,
+            WALLET3,
+        );
+        marketplace_test_exec_answer_offer(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(OWNER, &[]),
+            nft_collection.clone(),
+            "Token1".to_string(),
+            WALLET3.to_string(),
+            true,
+            None,
+        )
+        .unwrap();
+        cw2981_multi_test_exec_approve(
+            &mut app,
+            &Addr::unchecked(nft_collection.clone()),
+            &nft_marketplace_smart_contract_addr,
+            mock_info(WALLET3, &[]),
+            "Token1".to_string(),
+            approval_expiration,
+        )
+        .unwrap();
+        marketplace_test_exec_transfer_my_nft(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(WALLET3, &[]),
+            nft_collection.clone(),
+            "Token1".to_string(),
+            WALLET2.to_string(),
+        )
+        .unwrap();
+
+        // Token2: list and cancel
+        let sale = nft_sale(&app, &nft_collection, "Token2", PRICE);
+        marketplace_test_exec_sell_nft(&mut app, &nft_marketplace_smart_contract_addr, info, sale)
+            .unwrap();
+        cw2981_multi_test_exec_revoke(
+            &mut app,
+            &Addr::unchecked(nft_collection.clone()),
+            &nft_marketplace_smart_contract_addr,
+            mock_info(OWNER, &[]),
+            "Token2".to_string(),
+        )
+        .unwrap();
+        marketplace_test_exec_cancel_sale(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(OWNER, &[]),
+            nft_collection.clone(),
+            "Token2".to_string(),
+            None,
+        )
+        .unwrap();
+
+        // Marketplace wide
+        let query_output = marketplace_test_query_get_activity(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            None,
+            None,
+        );
+        assert_eq!(
+            activity_types(query_output.clone()),
+            vec![
+                ActivityType::CancelListing,
+                ActivityType::List,
+                ActivityType::Transfer,
+                ActivityType::Sale,
+                ActivityType::AcceptOffer,
+                ActivityType::Offer,
+                ActivityType::CancelOffer,
+                ActivityType::Offer,
+                ActivityType::UpdateListing,
+                ActivityType::List,
+            ]
+        );
+        assert_eq!(
+            query_output
+                .iter()
+                .map(|activity| activity.activity_id)
+                .collect::<Vec<_>>(),
+            (0..10).rev().collect::<Vec<u64>>()
+        );
+        let sale = &query_output[3];
+        assert_eq!(sale.from, OWNER.to_string());
+        assert_eq!(sale.to, Some(WALLET3.to_string()));
+        assert_eq!(sale.price_value, Some(Uint128::new(PRICE)));
+        assert_eq!(query_output[8].price_value, Some(Uint128::new(2 * PRICE)));
+        assert_eq!(query_output[2].to, Some(WALLET2.to_string()));
+
+        // Pagination
+        let query_output = marketplace_test_query_get_activity(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            Some(6),
+            Some(2),
+        );
+        assert_eq!(
+            activity_types(query_output),
+            vec![ActivityType::AcceptOffer, ActivityType::Offer]
+        );
+
+        // By collection and token
+        assert_eq!(
+            marketplace_test_query_get_collection_activity(
+                &app,
+                &nft_marketplace_smart_contract_addr,
+                nft_collection.clone(),
+                None,
+                None,
+            )
+            .len(),
+            10
+        );
+        let query_output = marketplace_test_query_get_token_activity(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            nft_collection.clone(),
+            "Token2".to_string(),
+            None,
+            None,
+        );
+        assert_eq!(
+            activity_types(query_output),
+            vec![ActivityType::CancelListing, ActivityType::List]
+        );
+        let query_output = marketplace_test_query_get_token_activity(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            nft_collection,
+            "Token1".to_string(),
+            Some(7),
+            None,
+        );
+        assert_eq!(query_output.len(), 7);
+        assert_eq!(query_output[0].activity_id, 6);
+
+        // By address, both sides of an activity
+        let query_output = marketplace_test_query_get_address_activity(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            WALLET3.to_string(),
+            None,
+            None,
+            None,
+        );
+        assert_eq!(
+            address_activity_kinds(query_output),
+            vec![
+                AddressActivityKind::TransferSent,
+                AddressActivityKind::Purchase,
+                AddressActivityKind::OfferAccepted,
+                AddressActivityKind::OfferMade,
+            ]
+        );
+        let query_output = marketplace_test_query_get_address_activity(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            WALLET2.to_string(),
+            None,
+            Some(7),
+            None,
+        );
+        assert_eq!(
+            address_activity_kinds(query_output),
+            vec![
+                AddressActivityKind::OfferCancelled,
+                AddressActivityKind::OfferMade
+            ]
+        );
+
+        // Filtered by kind
+        let query_output = marketplace_test_query_get_address_activity(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            OWNER.to_string(),
+            Some(vec![AddressActivityKind::OfferReceived]),
+            None,
+            None,
+        );
+        assert_eq!(query_output.len(), 2);
+        assert_eq!(query_output[0].activity.from, WALLET3.to_string());
+        assert_eq!(query_output[1].activity.from, WALLET2.to_string());
+        let query_output = marketplace_test_query_get_address_activity(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            OWNER.to_string(),
+            Some(vec![
+                AddressActivityKind::Sale,
+                AddressActivityKind::ListingCreated,
+            ]),
+            None,
+            Some(2),
+        );
+        assert_eq!(
+            address_activity_kinds(query_output.clone()),
+            vec![
+                AddressActivityKind::ListingCreated,
+                AddressActivityKind::Sale
+            ]
+        );
+        assert_eq!(
+            query_output[1].activity.price_value,
+            Some(Uint128::new(PRICE))
+        );
+        assert_eq!(query_output[1].activity.time, app.block_info().time);
+        let query_output = marketplace_test_query_get_address_activity(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            OWNER.to_string(),
+            Some(vec![
+                AddressActivityKind::Sale,
+                AddressActivityKind::ListingCreated,
+            ]),
+            Some(query_output[1].activity.activity_id),
+            None,
+        );
+        assert_eq!(
+            address_activity_kinds(query_output),
+            vec![AddressActivityKind::ListingCreated]
+        );
+        let query_output = marketplace_test_query_get_address_activity(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            WALLET2.to_string(),
+            Some(vec![AddressActivityKind::Purchase]),
+            None,
+            None,
+        );
+        assert!(query_output.is_empty());
+    }
+}
@@ -12,16 +12,18 @@ mod tests {
 
     use crate::common::utils::constants::{OWNER, REWARD_TOKEN, ROYALTY_RECEIVER1, ROYALTY_RECEIVER2, WALLET2};
     use crate::common::utils::utils_common::tests::{
-        instantiate_necessary_for_tests, query_account_native_denom_balance,
+        instantiate_necessary_for_tests, query_account_native_denom_balance, App,
     };
     use crate::common::utils::utils_marketplace_contract_test::tests::{
         marketplace_test_exec_add_new_collection, marketplace_test_exec_add_nft_code_id,
         marketplace_test_exec_buy_nft, marketplace_test_exec_claim_mkpc_fees,
+        marketplace_test_exec_claim_rewards,
         marketplace_test_exec_create_my_profile, marketplace_test_exec_enable_disable,
         marketplace_test_exec_sell_nft, marketplace_test_query_get_coll_all_nfts_for_sale,
         marketplace_test_query_get_mkpc_info, marketplace_test_query_get_mkpc_vol,
         marketplace_test_query_get_nft_coll_info, marketplace_test_query_get_nft_coll_vol,
         marketplace_test_query_get_nft_for_sale_info, marketplace_test_query_get_profile_info,
+        marketplace_test_query_get_reward_pool, marketplace_test_query_get_rewards_to_claim,
         marketplace_test_query_get_token_sale_hist, marketplace_test_query_get_tokens_by_coll,
     };
     use crate::common::utils::utils_nft_contract_test::tests::{
@@ -789,20 +791,25 @@ mod tests {
         );
         assert!(execute_output.is_ok());
 
-        // Check if the received rewards are accurate as per the sale
-        let output_query =
-            query_account_native_denom_balance(&app, OWNER, REWARD_TOKEN.to_string());
-        // initial value
-        assert_eq!(output_query.amount, Uint128::new(100000000000000000000));
-        let output_query =
-            query_account_native_denom_balance(&app, WALLET2, REWARD_TOKEN.to_string());
-        // initial value
-        assert_eq!(output_query.amount, Uint128::new(100000000000000000000));
+        // Rewards are credited to the claimable balances, nothing is sent with the sale
+        let rewards_to_claim = |app: &App, address: &str| {
+            marketplace_test_query_get_rewards_to_claim(
+                app,
+                nft_marketplace_smart_contract_addr.clone(),
+                address.to_string(),
+            )
+        };
+        let owner_rewards = rewards_to_claim(&app, OWNER);
+        let wallet2_rewards = rewards_to_claim(&app, WALLET2);
+        let wallet2_balance =
+            query_account_native_denom_balance(&app, WALLET2, REWARD_TOKEN.to_string()).amount;
+        let reward_pool =
+            marketplace_test_query_get_reward_pool(&app, nft_marketplace_smart_contract_addr.clone());
 
         let info = mock_info(WALLET2, &coins(100_000_000_000_000u128, native_huahua));
         let execute_output = marketplace_test_exec_buy_nft(
             &mut app,
-            &Addr::unchecked(nft_marketplace_smart_contract_addr),
+            &Addr::unchecked(nft_marketplace_smart_contract_addr.clone()),
             info,
             cw721_base_smart_contract_addr1,
             "Token20".to_string(),
@@ -810,18 +817,51 @@ mod tests {
         );
         assert!(execute_output.is_ok());
 
-        // Check if the received rewards are accurate as per the sale
-        let output_query =
-            query_account_native_denom_balance(&app, WALLET2, REWARD_TOKEN.to_string());
+        // Check if the credited rewards are accurate as per the sale
         // 100_000_000_000_000u128 * 120u128 / 1_000_000u128 = 12000$USDC
         assert_eq!(
-            output_query.amount,
-            Uint128::new(12_000_000_000u128 + 100000000000000000000u128)
+            query_account_native_denom_balance(&app, WALLET2, REWARD_TOKEN.to_string()).amount,
+            wallet2_balance
+        );
+        assert_eq!(
+            rewards_to_claim(&app, WALLET2),
+            wallet2_rewards + Uint128::new(12_000_000_000u128)
+        );
+        assert_eq!(
+            rewards_to_claim(&app, OWNER),
+            owner_rewards + Uint128::new(12_000_000_000u128)
+        );
+        let new_reward_pool =
+            marketplace_test_query_get_reward_pool(&app, nft_marketplace_smart_contract_addr.clone());
+        assert_eq!(
+            new_reward_pool.balance,
+            reward_pool.balance - Uint128::new(24_000_000_000u128)
+        );
+        assert_eq!(
+            new_reward_pool.total_to_claim,
+            reward_pool.total_to_claim + Uint128::new(24_000_000_000u128)
+        );
+
+        // Claim the rewards
+        let execute_output = marketplace_test_exec_claim_rewards(
+            &mut app,
+            &Addr::unchecked(nft_marketplace_smart_contract_addr.clone()),
+            mock_info(WALLET2, &[]),
+        );
+        assert!(execute_output.is_ok());
+        assert_eq!(
+            query_account_native_denom_balance(&app, WALLET2, REWARD_TOKEN.to_string()).amount,
+            wallet2_balance + wallet2_rewards + Uint128::new(12_000_000_000u128)
+        );
+        assert_eq!(rewards_to_claim(&app, WALLET2), Uint128::zero());
+        let execute_output = marketplace_test_exec_claim_rewards(
+            &mut app,
+            &Addr::unchecked(nft_marketplace_smart_contract_addr),
+            mock_info(WALLET2, &[]),
         );
-        let output_query = query_account_native_denom_balance(&app, OWNER, REWARD_TOKEN.to_string());
         assert_eq!(
-            output_query.amount,
-            Uint128::new(100000000000000000000u128 + 12_000_000_000u128)
+            execute_output.unwrap_err().root_cause().to_string(),
+            "NoRewardsToClaim".to_string()
         );
     }
 }
@@ -0,0 +1,381 @@
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::mock_info;
+    use cosmwasm_std::{coins, Addr, Timestamp, Uint128};
+
+    use nft_marketplace_utils::circuit_breaker::MarketplaceCapability;
+    use nft_marketplace_utils::nft_collection::{
+        NftCollectionAddress, NftContractInfo, NftContractType,
+    };
+    use nft_marketplace_utils::nft_offer::NftOffer;
+    use nft_marketplace_utils::nft_sale::NftSale;
+
+    use crate::common::utils::constants::{OWNER, WALLET2, WALLET3, WALLET5};
+    use crate::common::utils::utils_common::tests::{
+        instantiate_necessary_for_tests, query_account_native_denom_balance,
+    };
+    use crate::common::utils::utils_marketplace_contract_test::tests::{
+        marketplace_test_exec_add_new_collection, marketplace_test_exec_add_nft_code_id,
+        marketplace_test_exec_answer_offer, marketplace_test_exec_buy_nft,
+        marketplace_test_exec_cancel_offer, marketplace_test_exec_cancel_sale,
+        marketplace_test_exec_create_my_profile, marketplace_test_exec_enable_disable,
+        marketplace_test_exec_offer, marketplace_test_exec_sell_nft,
+        marketplace_test_exec_send_message, marketplace_test_exec_set_circuit_breaker,
+        marketplace_test_query_get_circuit_breakers, marketplace_test_query_get_nft_for_sale_info,
+    };
+    use crate::common::utils::utils_nft_contract_test::tests::{
+        cw2981_multi_test_exec_approve, cw2981_multi_test_exec_mint, cw2981_multi_test_exec_revoke,
+    };
+
+    #[test]
+    fn test_marketplace_circuit_breakers() {
+        // - Only the admin can pause a capability, and a reason is mandatory
+        // - A paused capability blocks its entry points only
+        // - Cancel sale and cancel offer stay open, even when the whole contract is disabled
+        let (mut app, necessary) = instantiate_necessary_for_tests();
+        let native_huahua = necessary.native_huahua;
+        let nft_marketplace_smart_contract_addr =
+            Addr::unchecked(necessary.nft_marketplace_smart_contract_addr);
+        let nft_collection = necessary.cw2981_nft_contract_addr1;
+
+        // Enable the contract, mint and add the collection
+        let info = mock_info(OWNER, &[]);
+        marketplace_test_exec_enable_disable(
+            &mut app,
+            nft_marketplace_smart_contract_addr.to_string(),
+            info.clone(),
+        )
+        .unwrap();
+        marketplace_test_exec_add_nft_code_id(
+            &mut app,
+            nft_marketplace_smart_contract_addr.to_string(),
+            vec![NftContractInfo {
+                code_id: necessary.cw2981_nft_code_id,
+                nft_contract_type: NftContractType::Cw2981MultiRoyalties,
+            }],
+            info.clone(),
+        )
+        .unwrap();
+        for nft_to_mint in [["Token1", OWNER], ["Token2", WALLET2], ["Token3", OWNER]] {
+            cw2981_multi_test_exec_mint(
+                &mut app,
+                &Addr::unchecked(nft_collection.clone()),
+                info.clone(),
+                nft_to_mint[0].to_string(),
+                nft_to_mint[1].to_string(),
+                None,
+            )
+            .unwrap();
+        }
+        marketplace_test_exec_add_new_collection(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            info,
+            NftCollectionAddress::from(nft_collection.clone()),
+            NftContractInfo {
+                code_id: necessary.cw2981_nft_code_id,
+                nft_contract_type: NftContractType::Cw2981MultiRoyalties,
+            },
+        )
+        .unwrap();
+
+        // Token1 is for sale and WALLET3 offers on Token2
+        let info = mock_info(OWNER, &coins(6_900_000u128, native_huahua.clone()));
+        cw2981_multi_test_exec_approve(
+            &mut app,
+            &Addr::unchecked(nft_collection.clone()),
+            &nft_marketplace_smart_contract_addr,
+            info.clone(),
+            "Token1".to_string(),
+            1571797419u64 + 87000u64,
+        )
+        .unwrap();
+        marketplace_test_exec_sell_nft(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            info,
+            NftSale {
+                seller: OWNER.to_string(),
+                nft_collection_address: nft_collection.clone(),
+                token_id: "Token1".to_string(),
+                sale_price_value: Uint128::new(100_000_000u128),
+                sale_price_denom: native_huahua.clone(),
+                sale_expiration: Timestamp::from_seconds(1571797419u64 + 87000u64),
+            },
+        )
+        .unwrap();
+        let wallet3_balance_before_offer =
+            query_account_native_denom_balance(&app, WALLET3, native_huahua.clone());
+        let nft_offer = NftOffer {
+            offerer_address: WALLET3.to_string(),
+            nft_collection_address: nft_collection.clone(),
+            token_id: "Token2".to_string(),
+            offer_price_value: Uint128::new(200_000_000u128),
+            offer_price_denom: native_huahua.clone(),
+            offer_expiration: Timestamp::from_seconds(1571797419u64 + 87000u64),
+        };
+        marketplace_test_exec_offer(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(WALLET3, &coins(200_000_000u128, native_huahua.clone())),
+            nft_offer.clone(),
+        )
+        .unwrap();
+
+        // Error: only the admin can pause
+        let execute_output = marketplace_test_exec_set_circuit_breaker(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(WALLET2, &[]),
+            MarketplaceCapability::Buying,
+            true,
+            "Maintenance".to_string(),
+        );
+        assert_eq!(
+            execute_output.unwrap_err().source().unwrap().to_string(),
+            "Unauthorized"
+        );
+
+        // Error: a reason is mandatory
+        let execute_output = marketplace_test_exec_set_circuit_breaker(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(OWNER, &[]),
+            MarketplaceCapability::Buying,
+            true,
+            " ".to_string(),
+        );
+        assert_eq!(
+            execute_output.unwrap_err().source().unwrap().to_string(),
+            "InvalidPauseReason"
+        );
+
+        // Pause buying and check the event
+        let execute_output = marketplace_test_exec_set_circuit_breaker(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(OWNER, &[]),
+            MarketplaceCapability::Buying,
+            true,
+            "Oracle maintenance".to_string(),
+        )
+        .unwrap();
+        let event = execute_output
+            .events
+            .iter()
+            .find(|event| event.ty == "wasm-Circuit Breaker")
+            .unwrap();
+        assert!(event
+            .attributes
+            .iter()
+            .any(|attr| attr.key == "Capability" && attr.value == "Buying"));
+        assert!(event
+            .attributes
+            .iter()
+            .any(|attr| attr.key == "Reason" && attr.value == "Oracle maintenance"));
+
+        let circuit_breakers =
+            marketplace_test_query_get_circuit_breakers(&app, &nft_marketplace_smart_contract_addr);
+        assert_eq!(circuit_breakers.len(), 5);
+        for circuit_breaker in circuit_breakers {
+            if circuit_breaker.capability == MarketplaceCapability::Buying {
+                assert!(circuit_breaker.paused);
+                assert_eq!(
+                    circuit_breaker.reason,
+                    Some("Oracle maintenance".to_string())
+                );
+                assert_eq!(circuit_breaker.updated_by, Some(OWNER.to_string()));
+            } else {
+                assert!(!circuit_breaker.paused);
+                assert_eq!(circuit_breaker.reason, None);
+            }
+        }
+
+        // Error: buying and accepting an offer are paused
+        let execute_output = marketplace_test_exec_buy_nft(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(WALLET2, &coins(100_000_000u128, native_huahua.clone())),
+            nft_collection.clone(),
+            "Token1".to_string(),
+            None,
+        );
+        assert_eq!(
+            execute_output.unwrap_err().source().unwrap().to_string(),
+            "CapabilityPaused"
+        );
+        let execute_output = marketplace_test_exec_answer_offer(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(WALLET2, &[]),
+            nft_collection.clone(),
+            "Token2".to_string(),
+            WALLET3.to_string(),
+            true,
+            None,
+        );
+        assert_eq!(
+            execute_output.unwrap_err().source().unwrap().to_string(),
+            "CapabilityPaused"
+        );
+
+        // Listing is still open
+        let info = mock_info(OWNER, &coins(6_900_000u128, native_huahua.clone()));
+        cw2981_multi_test_exec_approve(
+            &mut app,
+            &Addr::unchecked(nft_collection.clone()),
+            &nft_marketplace_smart_contract_addr,
+            info.clone(),
+            "Token3".to_string(),
+            1571797419u64 + 87000u64,
+        )
+        .unwrap();
+        let token3_sale = NftSale {
+            seller: OWNER.to_string(),
+            nft_collection_address: nft_collection.clone(),
+            token_id: "Token3".to_string(),
+            sale_price_value: Uint128::new(100_000_000u128),
+            sale_price_denom: native_huahua.clone(),
+            sale_expiration: Timestamp::from_seconds(1571797419u64 + 87000u64),
+        };
+        let execute_output = marketplace_test_exec_sell_nft(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            info.clone(),
+            token3_sale.clone(),
+        );
+        assert!(execute_output.is_ok());
+
+        // Pause everything else
+        for capability in [
+            MarketplaceCapability::Listing,
+            MarketplaceCapability::Offering,
+            MarketplaceCapability::Profiles,
+            MarketplaceCapability::Messaging,
+        ] {
+            marketplace_test_exec_set_circuit_breaker(
+                &mut app,
+                &nft_marketplace_smart_contract_addr,
+                mock_info(OWNER, &[]),
+                capability,
+                true,
+                "Incident".to_string(),
+            )
+            .unwrap();
+        }
+
+        // Error: listing, offering, profiles and messaging are paused
+        let execute_output = marketplace_test_exec_sell_nft(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            info,
+            token3_sale,
+        );
+        assert_eq!(
+            execute_output.unwrap_err().source().unwrap().to_string(),
+            "CapabilityPaused"
+        );
+        let execute_output = marketplace_test_exec_offer(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(WALLET3, &coins(200_000_000u128, native_huahua.clone())),
+            NftOffer {
+                token_id: "Token1".to_string(),
+                ..nft_offer
+            },
+        );
+        assert_eq!(
+            execute_output.unwrap_err().source().unwrap().to_string(),
+            "CapabilityPaused"
+        );
+        let execute_output = marketplace_test_exec_create_my_profile(
+            &mut app,
+            nft_marketplace_smart_contract_addr.to_string(),
+            mock_info(WALLET5, &[]),
+            None,
+        );
+        assert_eq!(
+            execute_output.unwrap_err().source().unwrap().to_string(),
+            "CapabilityPaused"
+        );
+        let execute_output = marketplace_test_exec_send_message(
+            &mut app,
+            nft_marketplace_smart_contract_addr.to_string(),
+            mock_info(WALLET3, &[]),
+            OWNER.to_string(),
+            "Hello".to_string(),
+        );
+        assert_eq!(
+            execute_output.unwrap_err().source().unwrap().to_string(),
+            "CapabilityPaused"
+        );
+
+        // Disable the whole contract: the exit paths are still open
+        marketplace_test_exec_enable_disable(
+            &mut app,
+            nft_marketplace_smart_contract_addr.to_string(),
+            mock_info(OWNER, &[]),
+        )
+        .unwrap();
+
+        // Cancel a sale
+        cw2981_multi_test_exec_revoke(
+            &mut app,
+            &Addr::unchecked(nft_collection.clone()),
+            &nft_marketplace_smart_contract_addr,
+            mock_info(OWNER, &[]),
+            "Token1".to_string(),
+        )
+        .unwrap();
+        let execute_output = marketplace_test_exec_cancel_sale(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(OWNER, &[]),
+            nft_collection.clone(),
+            "Token1".to_string(),
+            None,
+        );
+        assert!(execute_output.is_ok());
+        let query_output = marketplace_test_query_get_nft_for_sale_info(
+            &app,
+            nft_marketplace_smart_contract_addr.to_string(),
+            nft_collection.clone(),
+            "Token1".to_string(),
+        )
+        .unwrap();
+        assert_eq!(query_output.seller, "".to_string());
+
+        // Cancel an offer and get the escrow back
+        let execute_output = marketplace_test_exec_cancel_offer(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(WALLET3, &[]),
+            nft_collection,
+            "Token2".to_string(),
+        );
+        assert!(execute_output.is_ok());
+        assert_eq!(
+            query_account_native_denom_balance(&app, WALLET3, native_huahua),
+            wallet3_balance_before_offer
+        );
+
+        // Unpause buying: the last reason is kept
+        marketplace_test_exec_set_circuit_breaker(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(OWNER, &[]),
+            MarketplaceCapability::Buying,
+            false,
+            "Oracle is back".to_string(),
+        )
+        .unwrap();
+        let circuit_breakers =
+            marketplace_test_query_get_circuit_breakers(&app, &nft_marketplace_smart_contract_addr);
+        let buying = circuit_breakers
+            .iter()
+            .find(|circuit_breaker| circuit_breaker.capability == MarketplaceCapability::Buying)
+            .unwrap();
+        assert!(!buying.paused);
+        assert_eq!(buying.reason, Some("Oracle is back".to_string()));
+    }
+}
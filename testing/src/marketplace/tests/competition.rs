@@ -0,0 +1,344 @@
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::mock_info;
+    use cosmwasm_std::{coins, Addr, Uint128};
+
+    use nft_marketplace_utils::leaderboard::LeaderboardCursor;
+    use nft_marketplace_utils::nft_collection::{NftContractInfo, NftContractType};
+    use nft_marketplace_utils::nft_sale::NftSale;
+
+    use crate::common::utils::constants::{IBC_ATOM, OWNER, UHUAHUA, WALLET2, WALLET3, WALLET4};
+    use crate::common::utils::utils_common::tests::{instantiate_necessary_for_tests, App};
+    use crate::common::utils::utils_marketplace_contract_test::tests::{
+        marketplace_test_exec_add_new_collection, marketplace_test_exec_add_nft_code_id,
+        marketplace_test_exec_buy_nft, marketplace_test_exec_claim_competition_prize,
+        marketplace_test_exec_create_competition, marketplace_test_exec_enable_disable,
+        marketplace_test_exec_finalize_competition, marketplace_test_exec_sell_nft,
+        marketplace_test_query_get_competition, marketplace_test_query_get_competition_leaderboard,
+        marketplace_test_query_get_competition_prizes, marketplace_test_query_get_competitions,
+    };
+    use crate::common::utils::utils_nft_contract_test::tests::{
+        cw2981_multi_test_exec_approve, cw2981_multi_test_exec_mint,
+    };
+
+    // 12_000$USDC per sale
+    const PRICE: u128 = 100_000_000_000_000u128;
+    const SALE_USDC: u128 = 12_000_000_000u128;
+    const DAY: u64 = 86_400u64;
+
+    fn balance(app: &App, address: &str) -> Uint128 {
+        app.wrap().query_balance(address, IBC_ATOM).unwrap().amount
+    }
+
+    #[test]
+    fn test_marketplace_trading_competitions() {
+        // - The admin creates a competition with its prize pool, trades of the filtered collections
+        //   add their USDC value to the buyer and the seller while it runs
+        // - Once ended, anyone finalizes it: the top traders get the prizes by rank, prizes
+        //   without a winner go back to the admin, equal volumes are ranked by the first to reach them
+        // - Winners claim their prize
+        let (mut app, necessary) = instantiate_necessary_for_tests();
+        let nft_marketplace_smart_contract_addr =
+            Addr::unchecked(necessary.nft_marketplace_smart_contract_addr);
+        let nft_collection = necessary.cw2981_nft_contract_addr1;
+        let nft_contract_info = NftContractInfo {
+            code_id: necessary.cw2981_nft_code_id,
+            nft_contract_type: NftContractType::Cw2981MultiRoyalties,
+        };
+        let prizes = vec![
+            Uint128::new(3_000u128),
+            Uint128::new(2_000u128),
+            Uint128::new(1_000u128),
+        ];
+
+        marketplace_test_exec_enable_disable(
+            &mut app,
+            nft_marketplace_smart_contract_addr.to_string(),
+            mock_info(OWNER, &[]),
+        )
+        .unwrap();
+        marketplace_test_exec_add_nft_code_id(
+            &mut app,
+            nft_marketplace_smart_contract_addr.to_string(),
+            vec![nft_contract_info.clone()],
+            mock_info(OWNER, &[]),
+        )
+        .unwrap();
+        for token_id in ["Token1", "Token2", "Token3"] {
+            cw2981_multi_test_exec_mint(
+                &mut app,
+                &Addr::unchecked(nft_collection.clone()),
+                mock_info(OWNER, &[]),
+                token_id.to_string(),
+                OWNER.to_string(),
+                None,
+            )
+            .unwrap();
+        }
+        marketplace_test_exec_add_new_collection(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(OWNER, &[]),
+            nft_collection.clone(),
+            nft_contract_info,
+        )
+        .unwrap();
+        let info = mock_info(OWNER, &coins(6_900_000u128, UHUAHUA));
+        let sale_expiration = app.block_info().time.plus_seconds(20 * DAY);
+        for token_id in ["Token1", "Token2", "Token3"] {
+            cw2981_multi_test_exec_approve(
+                &mut app,
+                &Addr::unchecked(nft_collection.clone()),
+                &nft_marketplace_smart_contract_addr,
+                info.clone(),
+                token_id.to_string(),
+                sale_expiration.seconds(),
+            )
+            .unwrap();
+            marketplace_test_exec_sell_nft(
+                &mut app,
+                &nft_marketplace_smart_contract_addr,
+                info.clone(),
+                NftSale {
+                    seller: OWNER.to_string(),
+                    nft_collection_address: nft_collection.clone(),
+                    token_id: token_id.to_string(),
+                    sale_price_value: Uint128::new(PRICE),
+                    sale_price_denom: UHUAHUA.to_string(),
+                    sale_expiration,
+                },
+            )
+            .unwrap();
+        }
+
+        // Creation: only the admin, with a valid schedule and the exact prize pool
+        let now = app.block_info().time;
+        marketplace_test_exec_create_competition(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(WALLET2, &coins(6_000u128, IBC_ATOM)),
+            now,
+            now.plus_seconds(10 * DAY),
+            vec![nft_collection.clone()],
+            prizes.clone(),
+        )
+        .unwrap_err();
+        let execute_output = marketplace_test_exec_create_competition(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(OWNER, &coins(6_000u128, IBC_ATOM)),
+            now.plus_seconds(10 * DAY),
+            now,
+            vec![nft_collection.clone()],
+            prizes.clone(),
+        );
+        assert_eq!(
+            execute_output.unwrap_err().root_cause().to_string(),
+            "InvalidCompetition".to_string()
+        );
+        let execute_output = marketplace_test_exec_create_competition(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(OWNER, &coins(5_000u128, IBC_ATOM)),
+            now,
+            now.plus_seconds(10 * DAY),
+            vec![nft_collection.clone()],
+            prizes.clone(),
+        );
+        assert_eq!(
+            execute_output.unwrap_err().root_cause().to_string(),
+            "InvalidFundsReceived".to_string()
+        );
+        marketplace_test_exec_create_competition(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(OWNER, &coins(6_000u128, IBC_ATOM)),
+            now,
+            now.plus_seconds(10 * DAY),
+            vec![nft_collection.clone()],
+            prizes.clone(),
+        )
+        .unwrap();
+        // Starts later, no trade counts
+        marketplace_test_exec_create_competition(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(OWNER, &coins(500u128, IBC_ATOM)),
+            now.plus_seconds(30 * DAY),
+            now.plus_seconds(40 * DAY),
+            vec![],
+            vec![Uint128::new(500u128)],
+        )
+        .unwrap();
+        let competition =
+            marketplace_test_query_get_competition(&app, &nft_marketplace_smart_contract_addr, 1);
+        assert_eq!(competition.prize_denom, IBC_ATOM.to_string());
+        assert_eq!(competition.prize_pool(), Uint128::new(6_000u128));
+        assert!(!competition.finalized);
+
+        // OWNER sells the 3 tokens, one per buyer an hour apart
+        for (buyer, token_id) in [
+            (WALLET4, "Token1"),
+            (WALLET3, "Token2"),
+            (WALLET2, "Token3"),
+        ] {
+            app.update_block(|block| {
+                block.time = block.time.plus_seconds(3_600u64);
+            });
+            marketplace_test_exec_buy_nft(
+                &mut app,
+                &nft_marketplace_smart_contract_addr,
+                mock_info(buyer, &coins(PRICE, UHUAHUA)),
+                nft_collection.clone(),
+                token_id.to_string(),
+                None,
+            )
+            .unwrap();
+        }
+        let leaderboard = marketplace_test_query_get_competition_leaderboard(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            1,
+            None,
+            None,
+        );
+        assert_eq!(
+            leaderboard
+                .iter()
+                .map(|trader| (trader.address.as_str(), trader.volume_usdc.u128()))
+                .collect::<Vec<(&str, u128)>>(),
+            vec![
+                (OWNER, 3 * SALE_USDC),
+                (WALLET4, SALE_USDC),
+                (WALLET3, SALE_USDC),
+                (WALLET2, SALE_USDC)
+            ]
+        );
+        let second_page = marketplace_test_query_get_competition_leaderboard(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            1,
+            Some(LeaderboardCursor {
+                value: leaderboard[0].volume_usdc,
+                address: leaderboard[0].address.clone(),
+            }),
+            Some(1),
+        );
+        assert_eq!(second_page, vec![leaderboard[1].clone()]);
+        assert!(marketplace_test_query_get_competition_leaderboard(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            2,
+            None,
+            None,
+        )
+        .is_empty());
+
+        // Finalization once ended
+        let execute_output = marketplace_test_exec_finalize_competition(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(WALLET4, &[]),
+            1,
+        );
+        assert_eq!(
+            execute_output.unwrap_err().root_cause().to_string(),
+            "CompetitionNotEnded".to_string()
+        );
+        app.update_block(|block| {
+            block.time = now.plus_seconds(10 * DAY);
+        });
+        marketplace_test_exec_finalize_competition(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(WALLET4, &[]),
+            1,
+        )
+        .unwrap();
+        let execute_output = marketplace_test_exec_finalize_competition(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(WALLET4, &[]),
+            1,
+        );
+        assert_eq!(
+            execute_output.unwrap_err().root_cause().to_string(),
+            "CompetitionAlreadyFinalized".to_string()
+        );
+        let competition_prizes = marketplace_test_query_get_competition_prizes(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            1,
+        );
+        assert_eq!(
+            competition_prizes
+                .iter()
+                .map(|prize| (prize.rank, prize.address.as_str(), prize.amount))
+                .collect::<Vec<(u32, &str, Uint128)>>(),
+            vec![
+                (1, OWNER, prizes[0]),
+                (2, WALLET4, prizes[1]),
+                (3, WALLET3, prizes[2])
+            ]
+        );
+
+        // Claims
+        let wallet3_balance = balance(&app, WALLET3);
+        marketplace_test_exec_claim_competition_prize(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(WALLET3, &[]),
+            1,
+        )
+        .unwrap();
+        assert_eq!(balance(&app, WALLET3), wallet3_balance + prizes[2]);
+        for address in [WALLET3, WALLET2] {
+            let execute_output = marketplace_test_exec_claim_competition_prize(
+                &mut app,
+                &nft_marketplace_smart_contract_addr,
+                mock_info(address, &[]),
+                1,
+            );
+            assert_eq!(
+                execute_output.unwrap_err().root_cause().to_string(),
+                "NoCompetitionPrizeToClaim".to_string()
+            );
+        }
+        assert!(
+            marketplace_test_query_get_competition_prizes(
+                &app,
+                &nft_marketplace_smart_contract_addr,
+                1,
+            )[2]
+            .claimed
+        );
+
+        // No trader: the prize goes back to the admin
+        app.update_block(|block| {
+            block.time = now.plus_seconds(40 * DAY);
+        });
+        let owner_balance = balance(&app, OWNER);
+        marketplace_test_exec_finalize_competition(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(WALLET4, &[]),
+            2,
+        )
+        .unwrap();
+        assert_eq!(balance(&app, OWNER), owner_balance + Uint128::new(500u128));
+        assert!(marketplace_test_query_get_competition_prizes(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            2,
+        )
+        .is_empty());
+        assert!(marketplace_test_query_get_competitions(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            None,
+            None,
+        )
+        .iter()
+        .all(|competition| competition.finalized));
+    }
+}
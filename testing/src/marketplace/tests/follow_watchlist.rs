@@ -0,0 +1,393 @@
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::mock_info;
+    use cosmwasm_std::{coins, Addr, Uint128};
+
+    use nft_marketplace_utils::follow::FollowTarget;
+    use nft_marketplace_utils::nft_collection::{NftContractInfo, NftContractType};
+    use nft_marketplace_utils::nft_sale::NftSale;
+
+    use crate::common::utils::constants::{OWNER, UHUAHUA, WALLET2, WALLET3, WALLET4};
+    use crate::common::utils::utils_common::tests::instantiate_necessary_for_tests;
+    use crate::common::utils::utils_marketplace_contract_test::tests::{
+        marketplace_test_exec_add_new_collection, marketplace_test_exec_add_nft_code_id,
+        marketplace_test_exec_create_my_profile, marketplace_test_exec_enable_disable,
+        marketplace_test_exec_follow, marketplace_test_exec_sell_nft,
+        marketplace_test_exec_unfollow, marketplace_test_exec_unwatch_token,
+        marketplace_test_exec_watch_token, marketplace_test_query_get_followers,
+        marketplace_test_query_get_following, marketplace_test_query_get_watchlist,
+        marketplace_test_query_get_watchlist_listings,
+    };
+    use crate::common::utils::utils_nft_contract_test::tests::{
+        cw2981_multi_test_exec_approve, cw2981_multi_test_exec_mint,
+    };
+
+    const PRICE: u128 = 100_000_000u128;
+
+    #[test]
+    fn test_marketplace_follow_graph_and_watchlist() {
+        // - Profiles and listed collections can be followed, both directions of the graph are queried
+        // - Tokens of listed collections can be watched, even when they are not for sale
+        // - Listings of the watchlist only return the sales that can be bought now
+        let (mut app, necessary) = instantiate_necessary_for_tests();
+        let nft_marketplace_smart_contract_addr =
+            Addr::unchecked(necessary.nft_marketplace_smart_contract_addr);
+        let nft_collection = necessary.cw2981_nft_contract_addr1;
+        let nft_contract_info = NftContractInfo {
+            code_id: necessary.cw2981_nft_code_id,
+            nft_contract_type: NftContractType::Cw2981MultiRoyalties,
+        };
+        let profile_target = FollowTarget::Profile {
+            address: WALLET2.to_string(),
+        };
+        let collection_target = FollowTarget::Collection {
+            nft_collection_address: nft_collection.clone(),
+        };
+
+        marketplace_test_exec_enable_disable(
+            &mut app,
+            nft_marketplace_smart_contract_addr.to_string(),
+            mock_info(OWNER, &[]),
+        )
+        .unwrap();
+        for address in [OWNER, WALLET2, WALLET3] {
+            marketplace_test_exec_create_my_profile(
+                &mut app,
+                nft_marketplace_smart_contract_addr.to_string(),
+                mock_info(address, &[]),
+                None,
+            )
+            .unwrap();
+        }
+
+        // Following profiles
+        let execute_output = marketplace_test_exec_follow(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(WALLET2, &[]),
+            profile_target.clone(),
+        );
+        assert_eq!(
+            execute_output.unwrap_err().root_cause().to_string(),
+            "CantFollowYourself".to_string()
+        );
+        let execute_output = marketplace_test_exec_follow(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(WALLET2, &[]),
+            FollowTarget::Profile {
+                address: WALLET4.to_string(),
+            },
+        );
+        assert_eq!(
+            execute_output.unwrap_err().root_cause().to_string(),
+            "ThisProfileDoesNotExist".to_string()
+        );
+        let followed_at = app.block_info().time;
+        for follower in [WALLET3, OWNER] {
+            marketplace_test_exec_follow(
+                &mut app,
+                &nft_marketplace_smart_contract_addr,
+                mock_info(follower, &[]),
+                profile_target.clone(),
+            )
+            .unwrap();
+        }
+        let execute_output = marketplace_test_exec_follow(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(WALLET3, &[]),
+            profile_target.clone(),
+        );
+        assert_eq!(
+            execute_output.unwrap_err().root_cause().to_string(),
+            "AlreadyFollowing".to_string()
+        );
+        let followers = marketplace_test_query_get_followers(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            profile_target.clone(),
+            None,
+            None,
+        );
+        let mut expected_followers = vec![OWNER.to_string(), WALLET3.to_string()];
+        expected_followers.sort();
+        assert_eq!(
+            followers
+                .iter()
+                .map(|follow| follow.follower.clone())
+                .collect::<Vec<String>>(),
+            expected_followers
+        );
+        assert!(followers
+            .iter()
+            .all(|follow| follow.target == profile_target && follow.followed_at == followed_at));
+        assert_eq!(
+            marketplace_test_query_get_followers(
+                &app,
+                &nft_marketplace_smart_contract_addr,
+                profile_target.clone(),
+                Some(expected_followers[0].clone()),
+                None,
+            )
+            .len(),
+            1
+        );
+
+        // Following collections, only listed ones
+        let execute_output = marketplace_test_exec_follow(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(WALLET3, &[]),
+            collection_target.clone(),
+        );
+        assert_eq!(
+            execute_output.unwrap_err().root_cause().to_string(),
+            "NftCollectionNotListed".to_string()
+        );
+        marketplace_test_exec_add_nft_code_id(
+            &mut app,
+            nft_marketplace_smart_contract_addr.to_string(),
+            vec![nft_contract_info.clone()],
+            mock_info(OWNER, &[]),
+        )
+        .unwrap();
+        for token_id in ["Token1", "Token2", "Token3"] {
+            cw2981_multi_test_exec_mint(
+                &mut app,
+                &Addr::unchecked(nft_collection.clone()),
+                mock_info(OWNER, &[]),
+                token_id.to_string(),
+                OWNER.to_string(),
+                None,
+            )
+            .unwrap();
+        }
+        marketplace_test_exec_add_new_collection(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(OWNER, &[]),
+            nft_collection.clone(),
+            nft_contract_info,
+        )
+        .unwrap();
+        marketplace_test_exec_follow(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(WALLET3, &[]),
+            collection_target.clone(),
+        )
+        .unwrap();
+        let following = marketplace_test_query_get_following(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            WALLET3.to_string(),
+            None,
+            None,
+        );
+        assert_eq!(
+            following
+                .iter()
+                .map(|follow| follow.target.clone())
+                .collect::<Vec<FollowTarget>>(),
+            vec![collection_target.clone(), profile_target.clone()]
+        );
+        assert_eq!(
+            marketplace_test_query_get_following(
+                &app,
+                &nft_marketplace_smart_contract_addr,
+                WALLET3.to_string(),
+                Some(collection_target.clone()),
+                None,
+            )[0]
+            .target,
+            profile_target
+        );
+
+        // Unfollowing removes both directions
+        marketplace_test_exec_unfollow(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(WALLET3, &[]),
+            profile_target.clone(),
+        )
+        .unwrap();
+        let execute_output = marketplace_test_exec_unfollow(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(WALLET3, &[]),
+            profile_target.clone(),
+        );
+        assert_eq!(
+            execute_output.unwrap_err().root_cause().to_string(),
+            "NotFollowing".to_string()
+        );
+        assert_eq!(
+            marketplace_test_query_get_followers(
+                &app,
+                &nft_marketplace_smart_contract_addr,
+                profile_target,
+                None,
+                None,
+            )
+            .len(),
+            1
+        );
+        assert_eq!(
+            marketplace_test_query_get_following(
+                &app,
+                &nft_marketplace_smart_contract_addr,
+                WALLET3.to_string(),
+                None,
+                None,
+            )
+            .len(),
+            1
+        );
+
+        // Watchlist
+        for token_id in ["Token1", "Token2", "Token3"] {
+            marketplace_test_exec_watch_token(
+                &mut app,
+                &nft_marketplace_smart_contract_addr,
+                mock_info(WALLET3, &[]),
+                nft_collection.clone(),
+                token_id.to_string(),
+            )
+            .unwrap();
+        }
+        let execute_output = marketplace_test_exec_watch_token(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(WALLET3, &[]),
+            nft_collection.clone(),
+            "Token1".to_string(),
+        );
+        assert_eq!(
+            execute_output.unwrap_err().root_cause().to_string(),
+            "TokenAlreadyInWatchlist".to_string()
+        );
+        assert_eq!(
+            marketplace_test_query_get_watchlist(
+                &app,
+                &nft_marketplace_smart_contract_addr,
+                WALLET3.to_string(),
+                Some((nft_collection.clone(), "Token1".to_string())),
+                None,
+            )
+            .into_iter()
+            .map(|watched_token| watched_token.token_id)
+            .collect::<Vec<String>>(),
+            vec!["Token2".to_string(), "Token3".to_string()]
+        );
+        assert!(marketplace_test_query_get_watchlist_listings(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            WALLET3.to_string(),
+            None,
+            None,
+        )
+        .is_empty());
+
+        // Token1 and Token3 are listed, Token3 expires first
+        let info = mock_info(OWNER, &coins(6_900_000u128, UHUAHUA));
+        let approval_expiration = app.block_info().time.plus_seconds(174_000u64).seconds();
+        for (token_id, sale_duration) in [("Token1", 174_000u64), ("Token3", 87_000u64)] {
+            cw2981_multi_test_exec_approve(
+                &mut app,
+                &Addr::unchecked(nft_collection.clone()),
+                &nft_marketplace_smart_contract_addr,
+                info.clone(),
+                token_id.to_string(),
+                approval_expiration,
+            )
+            .unwrap();
+            let sale = NftSale {
+                seller: OWNER.to_string(),
+                nft_collection_address: nft_collection.clone(),
+                token_id: token_id.to_string(),
+                sale_price_value: Uint128::new(PRICE),
+                sale_price_denom: UHUAHUA.to_string(),
+                sale_expiration: app.block_info().time.plus_seconds(sale_duration),
+            };
+            marketplace_test_exec_sell_nft(
+                &mut app,
+                &nft_marketplace_smart_contract_addr,
+                info.clone(),
+                sale,
+            )
+            .unwrap();
+        }
+        let watchlist_listings = marketplace_test_query_get_watchlist_listings(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            WALLET3.to_string(),
+            None,
+            None,
+        );
+        assert_eq!(
+            watchlist_listings
+                .iter()
+                .map(|nft_sale| nft_sale.token_id.clone())
+                .collect::<Vec<String>>(),
+            vec!["Token1".to_string(), "Token3".to_string()]
+        );
+        assert_eq!(watchlist_listings[0].sale_price_value, Uint128::new(PRICE));
+        assert_eq!(
+            marketplace_test_query_get_watchlist_listings(
+                &app,
+                &nft_marketplace_smart_contract_addr,
+                WALLET3.to_string(),
+                None,
+                Some(1),
+            )
+            .len(),
+            1
+        );
+        app.update_block(|block| {
+            block.time = block.time.plus_seconds(87_000);
+        });
+        assert_eq!(
+            marketplace_test_query_get_watchlist_listings(
+                &app,
+                &nft_marketplace_smart_contract_addr,
+                WALLET3.to_string(),
+                None,
+                None,
+            )
+            .into_iter()
+            .map(|nft_sale| nft_sale.token_id)
+            .collect::<Vec<String>>(),
+            vec!["Token1".to_string()]
+        );
+
+        // Unwatching
+        marketplace_test_exec_unwatch_token(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(WALLET3, &[]),
+            nft_collection.clone(),
+            "Token1".to_string(),
+        )
+        .unwrap();
+        let execute_output = marketplace_test_exec_unwatch_token(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(WALLET3, &[]),
+            nft_collection.clone(),
+            "Token1".to_string(),
+        );
+        assert_eq!(
+            execute_output.unwrap_err().root_cause().to_string(),
+            "TokenNotInWatchlist".to_string()
+        );
+        assert!(marketplace_test_query_get_watchlist_listings(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            WALLET3.to_string(),
+            None,
+            None,
+        )
+        .is_empty());
+    }
+}
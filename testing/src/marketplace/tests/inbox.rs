@@ -0,0 +1,300 @@
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::mock_info;
+    use cosmwasm_std::Addr;
+
+    use nft_marketplace_utils::inbox::InboxMessage;
+
+    use crate::common::utils::constants::{OWNER, WALLET2, WALLET3, WALLET4};
+    use crate::common::utils::utils_common::tests::{instantiate_necessary_for_tests, App};
+    use crate::common::utils::utils_marketplace_contract_test::tests::{
+        marketplace_test_exec_block_address, marketplace_test_exec_create_my_profile,
+        marketplace_test_exec_delete_message, marketplace_test_exec_enable_disable,
+        marketplace_test_exec_mark_thread_read, marketplace_test_exec_send_message,
+        marketplace_test_query_get_blocked_addresses, marketplace_test_query_get_inbox,
+        marketplace_test_query_get_inbox_thread, marketplace_test_query_get_inbox_threads,
+    };
+
+    fn send_message(
+        app: &mut App,
+        nft_marketplace_smart_contract_addr: &Addr,
+        from: &str,
+        to: &str,
+        message: &str,
+    ) {
+        marketplace_test_exec_send_message(
+            app,
+            nft_marketplace_smart_contract_addr.to_string(),
+            mock_info(from, &[]),
+            to.to_string(),
+            message.to_string(),
+        )
+        .unwrap();
+    }
+
+    fn message_ids(messages: Vec<InboxMessage>) -> Vec<u64> {
+        messages
+            .into_iter()
+            .map(|message| message.message_id)
+            .collect()
+    }
+
+    #[test]
+    fn test_marketplace_inbox_threads_read_markers_and_block_list() {
+        // - Messages are kept in the inbox of the recipient and grouped by conversation
+        // - Threads are listed by latest message with their unread messages
+        // - Only the recipient can delete a message
+        // - Blocked addresses can't send messages anymore
+        let (mut app, necessary) = instantiate_necessary_for_tests();
+        let nft_marketplace_smart_contract_addr =
+            Addr::unchecked(necessary.nft_marketplace_smart_contract_addr);
+
+        marketplace_test_exec_enable_disable(
+            &mut app,
+            nft_marketplace_smart_contract_addr.to_string(),
+            mock_info(OWNER, &[]),
+        )
+        .unwrap();
+        for address in [OWNER, WALLET2, WALLET3] {
+            marketplace_test_exec_create_my_profile(
+                &mut app,
+                nft_marketplace_smart_contract_addr.to_string(),
+                mock_info(address, &[]),
+                None,
+            )
+            .unwrap();
+        }
+
+        send_message(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            WALLET2,
+            OWNER,
+            "Hi",
+        );
+        send_message(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            OWNER,
+            WALLET2,
+            "Hello",
+        );
+        send_message(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            WALLET3,
+            OWNER,
+            "Hey",
+        );
+        send_message(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            WALLET2,
+            OWNER,
+            "Again",
+        );
+
+        // Messages are still validated
+        marketplace_test_exec_send_message(
+            &mut app,
+            nft_marketplace_smart_contract_addr.to_string(),
+            mock_info(WALLET2, &[]),
+            OWNER.to_string(),
+            "Hé".to_string(),
+        )
+        .unwrap_err();
+        marketplace_test_exec_send_message(
+            &mut app,
+            nft_marketplace_smart_contract_addr.to_string(),
+            mock_info(WALLET2, &[]),
+            OWNER.to_string(),
+            "a".repeat(121),
+        )
+        .unwrap_err();
+
+        // Inbox and threads
+        let inbox = marketplace_test_query_get_inbox(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            OWNER.to_string(),
+            None,
+            None,
+        );
+        assert_eq!(message_ids(inbox.clone()), vec![3, 2, 0]);
+        assert_eq!(inbox[0].message, "Again".to_string());
+        assert_eq!(inbox[0].time, app.block_info().time);
+        let threads = marketplace_test_query_get_inbox_threads(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            OWNER.to_string(),
+            None,
+            None,
+        );
+        assert_eq!(threads.len(), 2);
+        assert_eq!(threads[0].thread.counterparty, WALLET2.to_string());
+        assert_eq!(threads[0].thread.last_message_id, 3);
+        // The answer of the owner marks the first message as read
+        assert_eq!(threads[0].thread.last_read_message_id, Some(1));
+        assert_eq!(threads[0].unread_messages, 1);
+        assert_eq!(threads[1].thread.counterparty, WALLET3.to_string());
+        assert_eq!(threads[1].unread_messages, 1);
+        let threads = marketplace_test_query_get_inbox_threads(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            OWNER.to_string(),
+            Some(3),
+            None,
+        );
+        assert_eq!(threads.len(), 1);
+        assert_eq!(threads[0].thread.counterparty, WALLET3.to_string());
+        let thread = marketplace_test_query_get_inbox_thread(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            WALLET2.to_string(),
+            OWNER.to_string(),
+            None,
+            None,
+        );
+        assert_eq!(message_ids(thread), vec![3, 1, 0]);
+        let thread = marketplace_test_query_get_inbox_thread(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            OWNER.to_string(),
+            WALLET2.to_string(),
+            Some(3),
+            Some(1),
+        );
+        assert_eq!(message_ids(thread), vec![1]);
+
+        // Read markers
+        marketplace_test_exec_mark_thread_read(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(OWNER, &[]),
+            WALLET4.to_string(),
+            None,
+        )
+        .unwrap_err();
+        marketplace_test_exec_mark_thread_read(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(OWNER, &[]),
+            WALLET2.to_string(),
+            None,
+        )
+        .unwrap();
+        let threads = marketplace_test_query_get_inbox_threads(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            OWNER.to_string(),
+            None,
+            None,
+        );
+        assert_eq!(threads[0].thread.last_read_message_id, Some(3));
+        assert_eq!(threads[0].unread_messages, 0);
+        assert_eq!(threads[1].unread_messages, 1);
+
+        // Deletion
+        marketplace_test_exec_delete_message(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(WALLET2, &[]),
+            3,
+        )
+        .unwrap_err();
+        marketplace_test_exec_delete_message(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(OWNER, &[]),
+            3,
+        )
+        .unwrap();
+        let inbox = marketplace_test_query_get_inbox(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            OWNER.to_string(),
+            None,
+            None,
+        );
+        assert_eq!(message_ids(inbox), vec![2, 0]);
+
+        // Block list
+        marketplace_test_exec_block_address(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(OWNER, &[]),
+            OWNER.to_string(),
+            true,
+        )
+        .unwrap_err();
+        marketplace_test_exec_block_address(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(OWNER, &[]),
+            WALLET3.to_string(),
+            true,
+        )
+        .unwrap();
+        assert_eq!(
+            marketplace_test_query_get_blocked_addresses(
+                &app,
+                &nft_marketplace_smart_contract_addr,
+                OWNER.to_string(),
+                None,
+                None,
+            ),
+            vec![WALLET3.to_string()]
+        );
+        let execute_output = marketplace_test_exec_send_message(
+            &mut app,
+            nft_marketplace_smart_contract_addr.to_string(),
+            mock_info(WALLET3, &[]),
+            OWNER.to_string(),
+            "Hey?".to_string(),
+        );
+        assert_eq!(
+            execute_output.unwrap_err().root_cause().to_string(),
+            "SenderIsBlocked".to_string()
+        );
+        // The block only applies to messages sent to the blocker
+        send_message(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            OWNER,
+            WALLET3,
+            "Stop",
+        );
+        marketplace_test_exec_block_address(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(OWNER, &[]),
+            WALLET3.to_string(),
+            false,
+        )
+        .unwrap();
+        assert!(marketplace_test_query_get_blocked_addresses(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            OWNER.to_string(),
+            None,
+            None,
+        )
+        .is_empty());
+        send_message(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            WALLET3,
+            OWNER,
+            "Sorry",
+        );
+        let threads = marketplace_test_query_get_inbox_threads(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            OWNER.to_string(),
+            None,
+            None,
+        );
+        assert_eq!(threads[0].thread.counterparty, WALLET3.to_string());
+        assert_eq!(threads[0].unread_messages, 1);
+    }
+}
@@ -4,15 +4,14 @@ mod tests {
 
     use cosmwasm_std::testing::{mock_dependencies, mock_info};
     use cosmwasm_std::{Addr, Decimal, Timestamp, Uint128};
-    use cw_multi_test::App;
 
     use general_utils::denominations::AcceptedDenominations;
-    use nft_marketplace_utils::reward_system::{VipLevel, VipPerk};
+    use nft_marketplace_utils::reward_system::VipTier;
     use price_oracle_utils::oracle::{OraclePrice, OraclePrices};
 
     use crate::common::utils::constants::{FEEDER, INVALID_REWARD_TOKEN, OWNER, REWARD_TOKEN};
     use crate::common::utils::utils_common::tests::{
-        instantiate_necessary_for_tests, mock_app,
+        instantiate_necessary_for_tests, mock_app, App,
     };
     use crate::common::utils::utils_marketplace_contract_test::tests::{
         default_init_msg_mkpc, instantiate_custom_smart_contract_test_nft_marketplace,
@@ -89,7 +88,7 @@ mod tests {
             query_output.reward_system.reward_token_per_1usdc_volume,
             Uint128::new(1_000_000u128)
         );
-        assert_eq!(query_output.reward_system.vip_perks.len(), 3);
+        assert_eq!(query_output.reward_system.vip_tiers.len(), 3);
 
         // GENERAL_STATS
         assert_eq!(query_output.general_stats.top_10_volume_usdc, []);
@@ -280,7 +279,7 @@ mod tests {
         // Invalid reward system
         let mut init_msg = default_init_msg_mkpc();
         init_msg.reward_system.reward_token_address = REWARD_TOKEN.to_string();
-        init_msg.reward_system.vip_perks = vec![];
+        init_msg.reward_system.vip_tiers = vec![];
         let mkpc_addr = instantiate_custom_smart_contract_test_nft_marketplace(
             &mut app,
             Some(accepted_denoms.clone()),
@@ -297,14 +296,17 @@ mod tests {
         // Invalid reward system
         let mut init_msg = default_init_msg_mkpc();
         init_msg.reward_system.reward_token_address = REWARD_TOKEN.to_string();
-        init_msg.reward_system.vip_perks[0] = VipPerk {
-            vip_level: VipLevel::Level2,
+        init_msg.reward_system.vip_tiers[0] = VipTier {
+            name: "Level2".to_string(),
             profile_background: false,
             profile_nft_showcase: false,
             profile_description: false,
             profile_links: false,
             marketplace_fees_discount: Default::default(),
             level_up_price_in_reward_tokens: Default::default(),
+            max_messages_per_window: None,
+            subscription_duration: None,
+            volume_usdc_threshold: None,
         };
         let mkpc_addr = instantiate_custom_smart_contract_test_nft_marketplace(
             &mut app,
@@ -0,0 +1,348 @@
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::mock_info;
+    use cosmwasm_std::{coins, Addr, Uint128};
+
+    use nft_marketplace_utils::leaderboard::{
+        LeaderboardCursor, LeaderboardPeriod, LeaderboardRanking,
+    };
+    use nft_marketplace_utils::nft_collection::{
+        NftCollectionAddress, NftContractInfo, NftContractType,
+    };
+    use nft_marketplace_utils::nft_sale::NftSale;
+    use nft_marketplace_utils::profile::{Profile, ProfileUpdateAction};
+
+    use crate::common::utils::constants::{OWNER, WALLET2, WALLET3};
+    use crate::common::utils::utils_common::tests::{instantiate_necessary_for_tests, App};
+    use crate::common::utils::utils_marketplace_contract_test::tests::{
+        marketplace_test_exec_add_new_collection, marketplace_test_exec_add_nft_code_id,
+        marketplace_test_exec_buy_nft, marketplace_test_exec_enable_disable,
+        marketplace_test_exec_sell_nft, marketplace_test_exec_start_leaderboard_season,
+        marketplace_test_exec_update_my_profile, marketplace_test_query_get_leaderboard_season,
+        marketplace_test_query_get_trader_leaderboard,
+    };
+    use crate::common::utils::utils_nft_contract_test::tests::{
+        cw2981_multi_test_exec_approve, cw2981_multi_test_exec_mint,
+    };
+
+    fn sell_and_buy(
+        app: &mut App,
+        nft_marketplace_smart_contract_addr: &Addr,
+        nft_collection: &str,
+        token_id: &str,
+        price: u128,
+        seller: &str,
+        buyer: &str,
+    ) {
+        let info = mock_info(seller, &coins(6_900_000u128, "uhuahua"));
+        let expiration = app.block_info().time.plus_seconds(87000u64);
+        cw2981_multi_test_exec_approve(
+            app,
+            &Addr::unchecked(nft_collection),
+            nft_marketplace_smart_contract_addr,
+            info.clone(),
+            token_id.to_string(),
+            expiration.seconds(),
+        )
+        .unwrap();
+        marketplace_test_exec_sell_nft(
+            app,
+            nft_marketplace_smart_contract_addr,
+            info,
+            NftSale {
+                seller: seller.to_string(),
+                nft_collection_address: nft_collection.to_string(),
+                token_id: token_id.to_string(),
+                sale_price_value: Uint128::new(price),
+                sale_price_denom: "uhuahua".to_string(),
+                sale_expiration: expiration,
+            },
+        )
+        .unwrap();
+        marketplace_test_exec_buy_nft(
+            app,
+            nft_marketplace_smart_contract_addr,
+            mock_info(buyer, &coins(price, "uhuahua")),
+            nft_collection.to_string(),
+            token_id.to_string(),
+            None,
+        )
+        .unwrap();
+    }
+
+    fn display_trade_info(
+        app: &mut App,
+        nft_marketplace_smart_contract_addr: &Addr,
+        address: &str,
+        display: bool,
+    ) {
+        marketplace_test_exec_update_my_profile(
+            app,
+            nft_marketplace_smart_contract_addr.to_string(),
+            mock_info(address, &[]),
+            Profile {
+                address: address.to_string(),
+                username: None,
+                vip_level: None,
+                vip_expiration: None,
+                volume_usdc: None,
+                profile_nft_collection: None,
+                profile_nft_token_id: None,
+                background_nft_collection: None,
+                background_nft_token_id: None,
+                description: None,
+                nft_showcase: None,
+                links: None,
+                number_of_trades: None,
+                buy_info: None,
+                sell_info: None,
+                display_trade_info: Some(display),
+            },
+            ProfileUpdateAction::Add,
+        )
+        .unwrap();
+    }
+
+    fn leaderboard_addresses(
+        app: &App,
+        nft_marketplace_smart_contract_addr: &Addr,
+        ranking: LeaderboardRanking,
+        period: LeaderboardPeriod,
+    ) -> Vec<String> {
+        marketplace_test_query_get_trader_leaderboard(
+            app,
+            nft_marketplace_smart_contract_addr,
+            ranking,
+            period,
+            None,
+            None,
+        )
+        .into_iter()
+        .map(|trader| trader.address)
+        .collect()
+    }
+
+    #[test]
+    fn test_marketplace_trader_leaderboards() {
+        // - Buyers, sellers and number of trades are ranked in USDC, all time and by season
+        // - Traders not displaying their trade info are never ranked
+        // - Pages are read after the last entry received
+        let (mut app, necessary) = instantiate_necessary_for_tests();
+        let nft_marketplace_smart_contract_addr =
+            Addr::unchecked(necessary.nft_marketplace_smart_contract_addr);
+        let nft_collection = necessary.cw2981_nft_contract_addr1;
+
+        marketplace_test_exec_enable_disable(
+            &mut app,
+            nft_marketplace_smart_contract_addr.to_string(),
+            mock_info(OWNER, &[]),
+        )
+        .unwrap();
+        marketplace_test_exec_add_nft_code_id(
+            &mut app,
+            nft_marketplace_smart_contract_addr.to_string(),
+            vec![NftContractInfo {
+                code_id: necessary.cw2981_nft_code_id,
+                nft_contract_type: NftContractType::Cw2981MultiRoyalties,
+            }],
+            mock_info(OWNER, &[]),
+        )
+        .unwrap();
+        for token_id in ["Token1", "Token2"] {
+            cw2981_multi_test_exec_mint(
+                &mut app,
+                &Addr::unchecked(nft_collection.clone()),
+                mock_info(OWNER, &[]),
+                token_id.to_string(),
+                OWNER.to_string(),
+                None,
+            )
+            .unwrap();
+        }
+        marketplace_test_exec_add_new_collection(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(OWNER, &[]),
+            NftCollectionAddress::from(nft_collection.clone()),
+            NftContractInfo {
+                code_id: necessary.cw2981_nft_code_id,
+                nft_contract_type: NftContractType::Cw2981MultiRoyalties,
+            },
+        )
+        .unwrap();
+
+        sell_and_buy(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            &nft_collection,
+            "Token1",
+            100_000_000u128,
+            OWNER,
+            WALLET2,
+        );
+        sell_and_buy(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            &nft_collection,
+            "Token2",
+            300_000_000u128,
+            OWNER,
+            WALLET3,
+        );
+
+        // Trade info is hidden by default
+        assert!(leaderboard_addresses(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            LeaderboardRanking::TopBuyers,
+            LeaderboardPeriod::AllTime,
+        )
+        .is_empty());
+
+        for address in [OWNER, WALLET2, WALLET3] {
+            display_trade_info(
+                &mut app,
+                &nft_marketplace_smart_contract_addr,
+                address,
+                true,
+            );
+        }
+        let top_buyers = marketplace_test_query_get_trader_leaderboard(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            LeaderboardRanking::TopBuyers,
+            LeaderboardPeriod::AllTime,
+            None,
+            None,
+        );
+        assert_eq!(top_buyers.len(), 2);
+        assert_eq!(top_buyers[0].address, WALLET3.to_string());
+        assert_eq!(top_buyers[1].address, WALLET2.to_string());
+        assert!(top_buyers[0].bought_usdc > top_buyers[1].bought_usdc);
+        assert!(!top_buyers[1].bought_usdc.is_zero());
+
+        let top_sellers = marketplace_test_query_get_trader_leaderboard(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            LeaderboardRanking::TopSellers,
+            LeaderboardPeriod::AllTime,
+            None,
+            None,
+        );
+        assert_eq!(top_sellers.len(), 1);
+        assert_eq!(top_sellers[0].address, OWNER.to_string());
+        assert_eq!(
+            top_sellers[0].sold_usdc,
+            top_buyers[0].bought_usdc + top_buyers[1].bought_usdc
+        );
+        assert_eq!(top_sellers[0].trades, 2);
+
+        // Pagination
+        let first_page = marketplace_test_query_get_trader_leaderboard(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            LeaderboardRanking::TopBuyers,
+            LeaderboardPeriod::AllTime,
+            None,
+            Some(1),
+        );
+        assert_eq!(first_page, vec![top_buyers[0].clone()]);
+        let second_page = marketplace_test_query_get_trader_leaderboard(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            LeaderboardRanking::TopBuyers,
+            LeaderboardPeriod::AllTime,
+            Some(LeaderboardCursor {
+                value: first_page[0].bought_usdc,
+                address: first_page[0].address.clone(),
+            }),
+            Some(1),
+        );
+        assert_eq!(second_page, vec![top_buyers[1].clone()]);
+
+        // Hiding the trade info removes the trader
+        display_trade_info(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            WALLET2,
+            false,
+        );
+        assert_eq!(
+            leaderboard_addresses(
+                &app,
+                &nft_marketplace_smart_contract_addr,
+                LeaderboardRanking::TopBuyers,
+                LeaderboardPeriod::AllTime,
+            ),
+            vec![WALLET3.to_string()]
+        );
+
+        // Only the admin can start a new season
+        marketplace_test_exec_start_leaderboard_season(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(WALLET2, &[]),
+        )
+        .unwrap_err();
+        marketplace_test_exec_start_leaderboard_season(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(OWNER, &[]),
+        )
+        .unwrap();
+        let season = marketplace_test_query_get_leaderboard_season(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+        );
+        assert_eq!(season.season_id, 2);
+        assert_eq!(season.start_time, Some(app.block_info().time));
+        assert!(leaderboard_addresses(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            LeaderboardRanking::MostTrades,
+            LeaderboardPeriod::CurrentSeason,
+        )
+        .is_empty());
+
+        sell_and_buy(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            &nft_collection,
+            "Token2",
+            50_000_000u128,
+            WALLET3,
+            OWNER,
+        );
+        assert_eq!(
+            leaderboard_addresses(
+                &app,
+                &nft_marketplace_smart_contract_addr,
+                LeaderboardRanking::TopBuyers,
+                LeaderboardPeriod::CurrentSeason,
+            ),
+            vec![OWNER.to_string()]
+        );
+        assert_eq!(
+            leaderboard_addresses(
+                &app,
+                &nft_marketplace_smart_contract_addr,
+                LeaderboardRanking::TopSellers,
+                LeaderboardPeriod::CurrentSeason,
+            ),
+            vec![WALLET3.to_string()]
+        );
+        let most_trades = marketplace_test_query_get_trader_leaderboard(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            LeaderboardRanking::MostTrades,
+            LeaderboardPeriod::AllTime,
+            None,
+            None,
+        );
+        assert_eq!(most_trades.len(), 2);
+        assert_eq!(most_trades[0].address, OWNER.to_string());
+        assert_eq!(most_trades[0].trades, 3);
+        assert_eq!(most_trades[1].address, WALLET3.to_string());
+        assert_eq!(most_trades[1].trades, 2);
+    }
+}
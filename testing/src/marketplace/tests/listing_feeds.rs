@@ -0,0 +1,291 @@
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::mock_info;
+    use cosmwasm_std::{coins, Addr, Uint128};
+
+    use nft_marketplace_utils::nft_collection::{
+        NftCollectionAddress, NftContractInfo, NftContractType,
+    };
+    use nft_marketplace_utils::nft_sale::{NftSale, NftSaleCursor};
+
+    use crate::common::utils::constants::{OWNER, UHUAHUA, WALLET2};
+    use crate::common::utils::utils_common::tests::{instantiate_necessary_for_tests, App};
+    use crate::common::utils::utils_marketplace_contract_test::tests::{
+        marketplace_test_exec_add_new_collection, marketplace_test_exec_add_nft_code_id,
+        marketplace_test_exec_buy_nft, marketplace_test_exec_enable_disable,
+        marketplace_test_exec_sell_nft, marketplace_test_query_get_ending_soon,
+        marketplace_test_query_get_recently_listed, marketplace_test_query_get_recently_sold,
+    };
+    use crate::common::utils::utils_nft_contract_test::tests::{
+        cw2981_multi_test_exec_approve, cw2981_multi_test_exec_mint,
+    };
+
+    const PRICE: u128 = 100_000_000u128;
+
+    fn list_nft(
+        app: &mut App,
+        nft_marketplace_smart_contract_addr: &Addr,
+        nft_collection: &str,
+        token_id: &str,
+        expiration_seconds: u64,
+    ) {
+        let info = mock_info(OWNER, &coins(6_900_000u128, UHUAHUA));
+        let expiration = app.block_info().time.plus_seconds(expiration_seconds);
+        cw2981_multi_test_exec_approve(
+            app,
+            &Addr::unchecked(nft_collection),
+            nft_marketplace_smart_contract_addr,
+            info.clone(),
+            token_id.to_string(),
+            expiration.seconds(),
+        )
+        .unwrap();
+        marketplace_test_exec_sell_nft(
+            app,
+            nft_marketplace_smart_contract_addr,
+            info,
+            NftSale {
+                seller: OWNER.to_string(),
+                nft_collection_address: nft_collection.to_string(),
+                token_id: token_id.to_string(),
+                sale_price_value: Uint128::new(PRICE),
+                sale_price_denom: UHUAHUA.to_string(),
+                sale_expiration: expiration,
+            },
+        )
+        .unwrap();
+        app.update_block(|block| {
+            block.time = block.time.plus_seconds(60);
+            block.height += 1;
+        });
+    }
+
+    fn token_ids(sales: Vec<NftSale>) -> Vec<String> {
+        sales.into_iter().map(|sale| sale.token_id).collect()
+    }
+
+    #[test]
+    fn test_marketplace_recently_listed_ending_soon_recently_sold() {
+        // - Feeds are available per collection and marketplace wide
+        // - Cursors are the last entry received
+        // - Sold and expired sales leave the listing feeds
+        let (mut app, necessary) = instantiate_necessary_for_tests();
+        let nft_marketplace_smart_contract_addr =
+            Addr::unchecked(necessary.nft_marketplace_smart_contract_addr);
+        let nft_collection1 = necessary.cw2981_nft_contract_addr1;
+        let nft_collection2 = necessary.cw2981_nft_contract_addr2;
+        let nft_contract_info = NftContractInfo {
+            code_id: necessary.cw2981_nft_code_id,
+            nft_contract_type: NftContractType::Cw2981MultiRoyalties,
+        };
+
+        marketplace_test_exec_enable_disable(
+            &mut app,
+            nft_marketplace_smart_contract_addr.to_string(),
+            mock_info(OWNER, &[]),
+        )
+        .unwrap();
+        marketplace_test_exec_add_nft_code_id(
+            &mut app,
+            nft_marketplace_smart_contract_addr.to_string(),
+            vec![nft_contract_info.clone()],
+            mock_info(OWNER, &[]),
+        )
+        .unwrap();
+        for (nft_collection, token_id) in [
+            (&nft_collection1, "Token1"),
+            (&nft_collection1, "Token2"),
+            (&nft_collection2, "Token3"),
+        ] {
+            cw2981_multi_test_exec_mint(
+                &mut app,
+                &Addr::unchecked(nft_collection),
+                mock_info(OWNER, &[]),
+                token_id.to_string(),
+                OWNER.to_string(),
+                None,
+            )
+            .unwrap();
+        }
+        for nft_collection in [&nft_collection1, &nft_collection2] {
+            marketplace_test_exec_add_new_collection(
+                &mut app,
+                &nft_marketplace_smart_contract_addr,
+                mock_info(OWNER, &[]),
+                NftCollectionAddress::from(nft_collection),
+                nft_contract_info.clone(),
+            )
+            .unwrap();
+        }
+
+        // Listed in this order, expiring Token2 -> Token3 -> Token1
+        list_nft(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            &nft_collection1,
+            "Token1",
+            259_200,
+        );
+        list_nft(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            &nft_collection1,
+            "Token2",
+            129_600,
+        );
+        list_nft(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            &nft_collection2,
+            "Token3",
+            172_800,
+        );
+
+        // Recently listed
+        let query_output = marketplace_test_query_get_recently_listed(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            None,
+            None,
+            None,
+        );
+        assert_eq!(
+            query_output
+                .iter()
+                .map(|listing| listing.nft_sale.token_id.clone())
+                .collect::<Vec<_>>(),
+            vec!["Token3", "Token2", "Token1"]
+        );
+        assert!(query_output[0].listed_at > query_output[1].listed_at);
+        let query_output = marketplace_test_query_get_recently_listed(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            None,
+            Some(NftSaleCursor {
+                time: query_output[0].listed_at,
+                nft_collection_address: nft_collection2.clone(),
+                token_id: "Token3".to_string(),
+            }),
+            Some(1),
+        );
+        assert_eq!(query_output.len(), 1);
+        assert_eq!(query_output[0].nft_sale.token_id, "Token2".to_string());
+        let query_output = marketplace_test_query_get_recently_listed(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            Some(nft_collection1.clone()),
+            None,
+            None,
+        );
+        assert_eq!(
+            query_output
+                .into_iter()
+                .map(|listing| listing.nft_sale.token_id)
+                .collect::<Vec<_>>(),
+            vec!["Token2", "Token1"]
+        );
+
+        // Ending soon
+        let query_output = marketplace_test_query_get_ending_soon(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            None,
+            None,
+            None,
+        );
+        assert_eq!(
+            token_ids(query_output.clone()),
+            vec!["Token2", "Token3", "Token1"]
+        );
+        let query_output = marketplace_test_query_get_ending_soon(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            None,
+            Some(NftSaleCursor {
+                time: query_output[0].sale_expiration,
+                nft_collection_address: nft_collection1.clone(),
+                token_id: "Token2".to_string(),
+            }),
+            None,
+        );
+        assert_eq!(token_ids(query_output), vec!["Token3", "Token1"]);
+        let query_output = marketplace_test_query_get_ending_soon(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            Some(nft_collection1.clone()),
+            None,
+            None,
+        );
+        assert_eq!(token_ids(query_output), vec!["Token2", "Token1"]);
+
+        // Token2 is expired
+        app.update_block(|block| {
+            block.time = block.time.plus_seconds(140_000);
+            block.height += 1;
+        });
+        let query_output = marketplace_test_query_get_ending_soon(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            None,
+            None,
+            None,
+        );
+        assert_eq!(token_ids(query_output), vec!["Token3", "Token1"]);
+
+        // Recently sold
+        for (nft_collection, token_id) in
+            [(&nft_collection1, "Token1"), (&nft_collection2, "Token3")]
+        {
+            marketplace_test_exec_buy_nft(
+                &mut app,
+                &nft_marketplace_smart_contract_addr,
+                mock_info(WALLET2, &coins(PRICE, UHUAHUA)),
+                nft_collection.to_string(),
+                token_id.to_string(),
+                None,
+            )
+            .unwrap();
+        }
+        let query_output = marketplace_test_query_get_recently_sold(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            None,
+            None,
+            None,
+        );
+        assert_eq!(query_output.len(), 2);
+        assert_eq!(query_output[0].sale_id, 1);
+        assert_eq!(query_output[0].sale_info.token_id, "Token3".to_string());
+        assert_eq!(query_output[0].sale_info.buyer, WALLET2.to_string());
+        assert_eq!(query_output[1].sale_info.token_id, "Token1".to_string());
+        let query_output = marketplace_test_query_get_recently_sold(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            None,
+            Some(1),
+            None,
+        );
+        assert_eq!(query_output.len(), 1);
+        assert_eq!(query_output[0].sale_id, 0);
+        let query_output = marketplace_test_query_get_recently_sold(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            Some(nft_collection2),
+            None,
+            None,
+        );
+        assert_eq!(query_output.len(), 1);
+        assert_eq!(query_output[0].sale_info.token_id, "Token3".to_string());
+
+        // Sold sales leave the listing feeds
+        let query_output = marketplace_test_query_get_recently_listed(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            None,
+            None,
+            None,
+        );
+        assert_eq!(query_output.len(), 1);
+        assert_eq!(query_output[0].nft_sale.token_id, "Token2".to_string());
+    }
+}
@@ -0,0 +1,266 @@
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::mock_info;
+    use cosmwasm_std::{coins, Addr, Uint128};
+
+    use nft_marketplace_utils::nft_collection::{
+        NftCollectionAddress, NftContractInfo, NftContractType,
+    };
+    use nft_marketplace_utils::nft_sale::{NftSale, PriceOrder};
+
+    use crate::common::utils::constants::{OWNER, UHUAHUA, WALLET2};
+    use crate::common::utils::utils_common::tests::{instantiate_necessary_for_tests, App};
+    use crate::common::utils::utils_marketplace_contract_test::tests::{
+        marketplace_test_exec_add_new_collection, marketplace_test_exec_add_nft_code_id,
+        marketplace_test_exec_buy_nft, marketplace_test_exec_cancel_sale,
+        marketplace_test_exec_enable_disable, marketplace_test_exec_sell_nft,
+        marketplace_test_query_get_coll_nfts_for_sale_by_price,
+        marketplace_test_query_get_nft_coll_info,
+    };
+    use crate::common::utils::utils_nft_contract_test::tests::{
+        cw2981_multi_test_exec_approve, cw2981_multi_test_exec_mint, cw2981_multi_test_exec_revoke,
+    };
+
+    fn list_nft(
+        app: &mut App,
+        nft_marketplace_smart_contract_addr: &Addr,
+        nft_collection: &str,
+        token_id: &str,
+        price: u128,
+    ) {
+        let info = mock_info(OWNER, &coins(6_900_000u128, UHUAHUA));
+        let expiration = app.block_info().time.plus_seconds(87000u64);
+        cw2981_multi_test_exec_approve(
+            app,
+            &Addr::unchecked(nft_collection),
+            nft_marketplace_smart_contract_addr,
+            info.clone(),
+            token_id.to_string(),
+            expiration.seconds(),
+        )
+        .unwrap();
+        marketplace_test_exec_sell_nft(
+            app,
+            nft_marketplace_smart_contract_addr,
+            info,
+            NftSale {
+                seller: OWNER.to_string(),
+                nft_collection_address: nft_collection.to_string(),
+                token_id: token_id.to_string(),
+                sale_price_value: Uint128::new(price),
+                sale_price_denom: UHUAHUA.to_string(),
+                sale_expiration: expiration,
+            },
+        )
+        .unwrap();
+    }
+
+    fn current_floor(
+        app: &App,
+        nft_marketplace_smart_contract_addr: &Addr,
+        nft_collection: &str,
+    ) -> Uint128 {
+        marketplace_test_query_get_nft_coll_info(
+            app,
+            nft_marketplace_smart_contract_addr,
+            nft_collection.to_string(),
+        )
+        .unwrap()
+        .into_iter()
+        .find(|info| info.denom == UHUAHUA)
+        .unwrap()
+        .current_floor
+    }
+
+    fn token_ids(sales: Vec<NftSale>) -> Vec<String> {
+        sales.into_iter().map(|sale| sale.token_id).collect()
+    }
+
+    #[test]
+    fn test_marketplace_listings_sorted_by_price() {
+        // - Listings of a collection can be browsed by price in both directions
+        // - Cursors and the min / max price filter can be combined
+        // - The floor follows the cheapest listing after cancels and sales
+        let (mut app, necessary) = instantiate_necessary_for_tests();
+        let nft_marketplace_smart_contract_addr =
+            Addr::unchecked(necessary.nft_marketplace_smart_contract_addr);
+        let nft_collection = necessary.cw2981_nft_contract_addr1;
+        let nft_contract_info = NftContractInfo {
+            code_id: necessary.cw2981_nft_code_id,
+            nft_contract_type: NftContractType::Cw2981MultiRoyalties,
+        };
+
+        marketplace_test_exec_enable_disable(
+            &mut app,
+            nft_marketplace_smart_contract_addr.to_string(),
+            mock_info(OWNER, &[]),
+        )
+        .unwrap();
+        marketplace_test_exec_add_nft_code_id(
+            &mut app,
+            nft_marketplace_smart_contract_addr.to_string(),
+            vec![nft_contract_info.clone()],
+            mock_info(OWNER, &[]),
+        )
+        .unwrap();
+        let listings = [
+            ("Token1", 300_000_000u128),
+            ("Token2", 100_000_000u128),
+            ("Token3", 500_000_000u128),
+            ("Token4", 100_000_000u128),
+            ("Token5", 200_000_000u128),
+        ];
+        for (token_id, _) in listings {
+            cw2981_multi_test_exec_mint(
+                &mut app,
+                &Addr::unchecked(nft_collection.clone()),
+                mock_info(OWNER, &[]),
+                token_id.to_string(),
+                OWNER.to_string(),
+                None,
+            )
+            .unwrap();
+        }
+        marketplace_test_exec_add_new_collection(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(OWNER, &[]),
+            NftCollectionAddress::from(nft_collection.clone()),
+            nft_contract_info,
+        )
+        .unwrap();
+        for (token_id, price) in listings {
+            list_nft(
+                &mut app,
+                &nft_marketplace_smart_contract_addr,
+                &nft_collection,
+                token_id,
+                price,
+            );
+        }
+        assert_eq!(
+            current_floor(&app, &nft_marketplace_smart_contract_addr, &nft_collection),
+            Uint128::new(100_000_000u128)
+        );
+
+        // Ascending and descending, same prices are ordered by token
+        let query_output = marketplace_test_query_get_coll_nfts_for_sale_by_price(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            nft_collection.clone(),
+            UHUAHUA.to_string(),
+            PriceOrder::Ascending,
+            None,
+            None,
+            None,
+            None,
+        );
+        assert_eq!(
+            token_ids(query_output),
+            vec!["Token2", "Token4", "Token5", "Token1", "Token3"]
+        );
+        let query_output = marketplace_test_query_get_coll_nfts_for_sale_by_price(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            nft_collection.clone(),
+            UHUAHUA.to_string(),
+            PriceOrder::Descending,
+            None,
+            None,
+            None,
+            None,
+        );
+        assert_eq!(
+            token_ids(query_output),
+            vec!["Token3", "Token1", "Token5", "Token4", "Token2"]
+        );
+
+        // Pagination
+        let query_output = marketplace_test_query_get_coll_nfts_for_sale_by_price(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            nft_collection.clone(),
+            UHUAHUA.to_string(),
+            PriceOrder::Ascending,
+            None,
+            None,
+            Some((Uint128::new(100_000_000u128), "Token4".to_string())),
+            Some(2),
+        );
+        assert_eq!(token_ids(query_output), vec!["Token5", "Token1"]);
+
+        // Price filter, both bounds are inclusive
+        let query_output = marketplace_test_query_get_coll_nfts_for_sale_by_price(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            nft_collection.clone(),
+            UHUAHUA.to_string(),
+            PriceOrder::Ascending,
+            Some(Uint128::new(150_000_000u128)),
+            Some(Uint128::new(300_000_000u128)),
+            None,
+            None,
+        );
+        assert_eq!(token_ids(query_output), vec!["Token5", "Token1"]);
+        let query_output = marketplace_test_query_get_coll_nfts_for_sale_by_price(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            nft_collection.clone(),
+            UHUAHUA.to_string(),
+            PriceOrder::Descending,
+            Some(Uint128::new(150_000_000u128)),
+            Some(Uint128::new(300_000_000u128)),
+            Some((Uint128::new(300_000_000u128), "Token1".to_string())),
+            None,
+        );
+        assert_eq!(token_ids(query_output), vec!["Token5"]);
+
+        // Floor after a cancel and a sale
+        cw2981_multi_test_exec_revoke(
+            &mut app,
+            &Addr::unchecked(nft_collection.clone()),
+            &nft_marketplace_smart_contract_addr,
+            mock_info(OWNER, &[]),
+            "Token2".to_string(),
+        )
+        .unwrap();
+        marketplace_test_exec_cancel_sale(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(OWNER, &[]),
+            nft_collection.clone(),
+            "Token2".to_string(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(
+            current_floor(&app, &nft_marketplace_smart_contract_addr, &nft_collection),
+            Uint128::new(100_000_000u128)
+        );
+        marketplace_test_exec_buy_nft(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(WALLET2, &coins(100_000_000u128, UHUAHUA)),
+            nft_collection.clone(),
+            "Token4".to_string(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(
+            current_floor(&app, &nft_marketplace_smart_contract_addr, &nft_collection),
+            Uint128::new(200_000_000u128)
+        );
+        let query_output = marketplace_test_query_get_coll_nfts_for_sale_by_price(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            nft_collection,
+            UHUAHUA.to_string(),
+            PriceOrder::Ascending,
+            None,
+            None,
+            None,
+            None,
+        );
+        assert_eq!(token_ids(query_output), vec!["Token5", "Token1", "Token3"]);
+    }
+}
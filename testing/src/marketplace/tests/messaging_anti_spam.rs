@@ -0,0 +1,289 @@
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::mock_info;
+    use cosmwasm_std::{coins, Addr, Uint128};
+    use cw_multi_test::AppResponse;
+
+    use chihuahua_nft_marketplace::msg::UpdateConfigEnum;
+    use nft_marketplace_utils::messaging::{
+        MessageFee, MessageFeeRecipient, MessageFilter, MessageRateLimitConfig, MessagingConfig,
+    };
+    use nft_marketplace_utils::reward_system::VipLevel;
+
+    use crate::common::utils::constants::{
+        INVALID_REWARD_TOKEN, OWNER, REWARD_TOKEN, UHUAHUA, WALLET2, WALLET3,
+    };
+    use crate::common::utils::utils_common::tests::{instantiate_necessary_for_tests, App};
+    use crate::common::utils::utils_marketplace_contract_test::tests::{
+        marketplace_test_exec_create_my_profile, marketplace_test_exec_enable_disable,
+        marketplace_test_exec_lvl_up_profile, marketplace_test_exec_send_message,
+        marketplace_test_exec_set_message_filter, marketplace_test_exec_update_config,
+        marketplace_test_query_get_message_filter, marketplace_test_query_get_messaging_config,
+        marketplace_test_query_get_mkpc_info,
+    };
+
+    const MESSAGE_FEE: u128 = 1_000u128;
+
+    fn send_message(
+        app: &mut App,
+        nft_marketplace_smart_contract_addr: &Addr,
+        from: &str,
+        fee: u128,
+    ) -> anyhow::Result<AppResponse> {
+        let funds = if fee == 0 {
+            vec![]
+        } else {
+            coins(fee, UHUAHUA)
+        };
+        marketplace_test_exec_send_message(
+            app,
+            nft_marketplace_smart_contract_addr.to_string(),
+            mock_info(from, &funds),
+            OWNER.to_string(),
+            "Hello".to_string(),
+        )
+    }
+
+    fn messaging_config(paid_to: MessageFeeRecipient, denom: &str) -> MessagingConfig {
+        MessagingConfig {
+            rate_limit: Some(MessageRateLimitConfig {
+                window_blocks: 100,
+                messages_per_window: 1,
+                bonus_messages_per_vip_level: 1,
+            }),
+            message_fee: Some(MessageFee {
+                denom: denom.to_string(),
+                amount: Uint128::new(MESSAGE_FEE),
+                paid_to,
+            }),
+        }
+    }
+
+    fn next_window(app: &mut App) {
+        app.update_block(|block| {
+            block.height += 100;
+            block.time = block.time.plus_seconds(600);
+        });
+    }
+
+    #[test]
+    fn test_marketplace_messaging_rate_limit_fee_and_filters() {
+        // - Messages per block window are limited, VIP levels can send more
+        // - The fee per message is paid to the recipient or added to the marketplace fees
+        // - Recipients can accept messages only from traders or VIP profiles
+        let (mut app, necessary) = instantiate_necessary_for_tests();
+        let nft_marketplace_smart_contract_addr =
+            Addr::unchecked(necessary.nft_marketplace_smart_contract_addr);
+
+        marketplace_test_exec_enable_disable(
+            &mut app,
+            nft_marketplace_smart_contract_addr.to_string(),
+            mock_info(OWNER, &[]),
+        )
+        .unwrap();
+        for address in [OWNER, WALLET2, WALLET3] {
+            marketplace_test_exec_create_my_profile(
+                &mut app,
+                nft_marketplace_smart_contract_addr.to_string(),
+                mock_info(address, &[]),
+                None,
+            )
+            .unwrap();
+        }
+        marketplace_test_exec_lvl_up_profile(
+            &mut app,
+            mock_info(WALLET3, &coins(1_000u128, REWARD_TOKEN)),
+            &nft_marketplace_smart_contract_addr,
+        )
+        .unwrap();
+
+        // Nothing is enforced by default, funds are refused without a fee
+        assert_eq!(
+            marketplace_test_query_get_messaging_config(&app, &nft_marketplace_smart_contract_addr),
+            MessagingConfig::default()
+        );
+        send_message(&mut app, &nft_marketplace_smart_contract_addr, WALLET2, 0).unwrap();
+        send_message(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            WALLET2,
+            MESSAGE_FEE,
+        )
+        .unwrap_err();
+
+        // Only the admin can update the config, with an accepted denom
+        marketplace_test_exec_update_config(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(WALLET2, &[]),
+            vec![UpdateConfigEnum::UpdateMessagingConfig {
+                messaging_config: messaging_config(MessageFeeRecipient::Recipient, UHUAHUA),
+            }],
+        )
+        .unwrap_err();
+        let execute_output = marketplace_test_exec_update_config(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(OWNER, &[]),
+            vec![UpdateConfigEnum::UpdateMessagingConfig {
+                messaging_config: messaging_config(
+                    MessageFeeRecipient::Recipient,
+                    INVALID_REWARD_TOKEN,
+                ),
+            }],
+        );
+        assert_eq!(
+            execute_output.unwrap_err().root_cause().to_string(),
+            "InvalidMessagingConfig".to_string()
+        );
+        marketplace_test_exec_update_config(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(OWNER, &[]),
+            vec![UpdateConfigEnum::UpdateMessagingConfig {
+                messaging_config: messaging_config(MessageFeeRecipient::Recipient, UHUAHUA),
+            }],
+        )
+        .unwrap();
+
+        // Fee paid to the recipient, Level0 sends 1 message and Level1 2 messages per window
+        let owner_balance = app.wrap().query_balance(OWNER, UHUAHUA).unwrap().amount;
+        let execute_output =
+            send_message(&mut app, &nft_marketplace_smart_contract_addr, WALLET2, 0);
+        assert_eq!(
+            execute_output.unwrap_err().root_cause().to_string(),
+            "InvalidMessageFee".to_string()
+        );
+        send_message(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            WALLET2,
+            MESSAGE_FEE,
+        )
+        .unwrap();
+        let execute_output = send_message(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            WALLET2,
+            MESSAGE_FEE,
+        );
+        assert_eq!(
+            execute_output.unwrap_err().root_cause().to_string(),
+            "MessageRateLimitReached".to_string()
+        );
+        for _ in 0..2 {
+            send_message(
+                &mut app,
+                &nft_marketplace_smart_contract_addr,
+                WALLET3,
+                MESSAGE_FEE,
+            )
+            .unwrap();
+        }
+        send_message(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            WALLET3,
+            MESSAGE_FEE,
+        )
+        .unwrap_err();
+        assert_eq!(
+            app.wrap().query_balance(OWNER, UHUAHUA).unwrap().amount,
+            owner_balance + Uint128::new(3 * MESSAGE_FEE)
+        );
+
+        // Fee paid to the treasury on the next window
+        next_window(&mut app);
+        marketplace_test_exec_update_config(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(OWNER, &[]),
+            vec![UpdateConfigEnum::UpdateMessagingConfig {
+                messaging_config: messaging_config(MessageFeeRecipient::Treasury, UHUAHUA),
+            }],
+        )
+        .unwrap();
+        let fees_to_claim = |app: &App| {
+            marketplace_test_query_get_mkpc_info(app, &nft_marketplace_smart_contract_addr)
+                .unwrap()
+                .into_iter()
+                .find(|stats| stats.denom == UHUAHUA)
+                .unwrap()
+                .marketplace_fees_to_claim
+        };
+        let fees_before = fees_to_claim(&app);
+        send_message(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            WALLET2,
+            MESSAGE_FEE,
+        )
+        .unwrap();
+        assert_eq!(fees_to_claim(&app), fees_before + Uint128::new(MESSAGE_FEE));
+        assert_eq!(
+            app.wrap().query_balance(OWNER, UHUAHUA).unwrap().amount,
+            owner_balance + Uint128::new(3 * MESSAGE_FEE)
+        );
+
+        // Filters of the recipient
+        next_window(&mut app);
+        let message_filter = MessageFilter {
+            min_trades: Some(1),
+            min_vip_level: Some(VipLevel(1)),
+        };
+        marketplace_test_exec_set_message_filter(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(OWNER, &[]),
+            Some(message_filter.clone()),
+        )
+        .unwrap();
+        assert_eq!(
+            marketplace_test_query_get_message_filter(
+                &app,
+                &nft_marketplace_smart_contract_addr,
+                OWNER.to_string()
+            ),
+            message_filter
+        );
+        let execute_output = send_message(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            WALLET2,
+            MESSAGE_FEE,
+        );
+        assert_eq!(
+            execute_output.unwrap_err().root_cause().to_string(),
+            "RecipientDoesNotAcceptYourMessages".to_string()
+        );
+        send_message(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            WALLET3,
+            MESSAGE_FEE,
+        )
+        .unwrap();
+        marketplace_test_exec_set_message_filter(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(OWNER, &[]),
+            None,
+        )
+        .unwrap();
+        assert_eq!(
+            marketplace_test_query_get_message_filter(
+                &app,
+                &nft_marketplace_smart_contract_addr,
+                OWNER.to_string()
+            ),
+            MessageFilter::default()
+        );
+        send_message(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            WALLET2,
+            MESSAGE_FEE,
+        )
+        .unwrap();
+    }
+}
@@ -0,0 +1,1266 @@
+#[cfg(test)]
+mod tests {
+    use cosmwasm_schema::cw_serde;
+    use cosmwasm_std::testing::mock_info;
+    use cosmwasm_std::{
+        coins, from_slice, to_vec, Addr, Decimal, DepsMut, Env, MessageInfo, Response, StdResult,
+        Storage, Timestamp, Uint128,
+    };
+    use cw_multi_test::{Contract, ContractWrapper, Executor};
+    use cw_storage_plus::Index;
+
+    use chihuahua_nft_marketplace::helpers::inbox::LegacyProfile;
+    use chihuahua_nft_marketplace::msg::{ExecuteMsg, InstantiateMsg};
+    use chihuahua_nft_marketplace::state::{MESSAGE_FILTERS, PROFILES, REWARD_SYSTEM, USERNAMES};
+    use general_utils::error::ContractError;
+    use nft_marketplace_utils::messaging::MessageFilter;
+    use nft_marketplace_utils::nft_collection::{
+        NftCollectionAddress, NftContractInfo, NftContractType,
+    };
+    use nft_marketplace_utils::nft_sale::{
+        define_unique_collection_nft_id, nft_listing_times, nfts_for_sale, NftSale, PriceOrder,
+    };
+    use nft_marketplace_utils::profile::{
+        Profile, ProfileMessage, ProfileMessages, ProfileUpdateAction,
+    };
+    use nft_marketplace_utils::reward_system::{RewardSystem, VipLevel, VipTier};
+    use nft_marketplace_utils::tokenfactory::TokenFactoryMsg;
+    use nft_marketplace_utils::username::{normalize_username, UsernameStatus};
+
+    use crate::common::utils::constants::{OWNER, REWARD_TOKEN, WALLET2, WALLET3};
+    use crate::common::utils::utils_common::tests::{instantiate_necessary_for_tests, App};
+    use crate::common::utils::utils_marketplace_contract_test::tests::{
+        default_init_msg_mkpc, marketplace_test_exec_add_new_collection,
+        marketplace_test_exec_add_nft_code_id, marketplace_test_exec_buy_nft,
+        marketplace_test_exec_cancel_offer, marketplace_test_exec_cancel_sale,
+        marketplace_test_exec_create_my_profile, marketplace_test_exec_enable_disable,
+        marketplace_test_exec_lvl_up_profile, marketplace_test_exec_migrate,
+        marketplace_test_exec_sell_nft, marketplace_test_exec_send_message,
+        marketplace_test_exec_set_message_filter, marketplace_test_exec_update_my_profile,
+        marketplace_test_query_contract_version,
+        marketplace_test_query_get_coll_nfts_for_sale_by_price, marketplace_test_query_get_config,
+        marketplace_test_query_get_ending_soon, marketplace_test_query_get_inbox_thread,
+        marketplace_test_query_get_inbox_threads, marketplace_test_query_get_message_filter,
+        marketplace_test_query_get_nft_for_sale_info, marketplace_test_query_get_profile_info,
+        marketplace_test_query_get_recently_listed, marketplace_test_query_get_username,
+        marketplace_test_query_get_username_status, smart_contract_def_test_nft_marketplace,
+    };
+    use crate::common::utils::utils_nft_contract_test::tests::{
+        cw2981_multi_test_exec_approve, cw2981_multi_test_exec_mint, cw2981_multi_test_exec_revoke,
+        cw2981_multi_test_query_owner_of,
+    };
+
+    const CONTRACT_NAME: &str = "crates.io:chihuahua_nft_marketplace";
+
+    // Reward system stored before 0.0.7: one perk per variant of the former VipLevel enum
+    #[cw_serde]
+    struct LegacyVipPerk {
+        vip_level: String,
+        profile_background: bool,
+        profile_nft_showcase: bool,
+        profile_description: bool,
+        profile_links: bool,
+        marketplace_fees_discount: Decimal,
+        level_up_price_in_reward_tokens: Uint128,
+    }
+
+    #[cw_serde]
+    struct LegacyRewardSystem {
+        reward_token_address: String,
+        reward_token_per_1usdc_volume: Uint128,
+        total_reward_tokens_distributed: Uint128,
+        vip_perks: Vec<LegacyVipPerk>,
+    }
+
+    // Levels were saved as the variant names of the former enum
+    fn save_legacy_vip_levels(storage: &mut dyn Storage, key: &[u8]) {
+        if let Some(value) = storage.get(key) {
+            let mut value = String::from_utf8(value).unwrap();
+            for level in 0..4 {
+                for field in ["\"vip_level\"", "\"min_vip_level\""] {
+                    value = value.replace(
+                        &format!("{}:{}", field, level),
+                        &format!("{}:\"level{}\"", field, level),
+                    );
+                }
+            }
+            storage.set(key, value.as_bytes());
+        }
+    }
+
+    // The current code reads the reward system in the current format, it is converted back and
+    // forth around each legacy call
+    fn save_legacy_reward_system(storage: &mut dyn Storage) -> StdResult<()> {
+        let reward_system = REWARD_SYSTEM.load(storage)?;
+        let mut vip_perks = vec![LegacyVipPerk {
+            vip_level: "level0".to_string(),
+            profile_background: false,
+            profile_nft_showcase: false,
+            profile_description: false,
+            profile_links: false,
+            marketplace_fees_discount: Decimal::zero(),
+            level_up_price_in_reward_tokens: Uint128::zero(),
+        }];
+        for (index, vip_tier) in reward_system.vip_tiers.into_iter().enumerate() {
+            vip_perks.push(LegacyVipPerk {
+                vip_level: format!("level{}", index + 1),
+                profile_background: vip_tier.profile_background,
+                profile_nft_showcase: vip_tier.profile_nft_showcase,
+                profile_description: vip_tier.profile_description,
+                profile_links: vip_tier.profile_links,
+                marketplace_fees_discount: vip_tier.marketplace_fees_discount,
+                level_up_price_in_reward_tokens: vip_tier.level_up_price_in_reward_tokens,
+            });
+        }
+        // Stored in reverse order, the migration sorts the perks by level
+        vip_perks.reverse();
+        storage.set(
+            REWARD_SYSTEM.as_slice(),
+            &to_vec(&LegacyRewardSystem {
+                reward_token_address: reward_system.reward_token_address,
+                reward_token_per_1usdc_volume: reward_system.reward_token_per_1usdc_volume,
+                total_reward_tokens_distributed: reward_system.total_reward_tokens_distributed,
+                vip_perks,
+            })?,
+        );
+        Ok(())
+    }
+
+    fn load_legacy_reward_system(storage: &mut dyn Storage) -> StdResult<()> {
+        let legacy_reward_system: LegacyRewardSystem =
+            from_slice(&storage.get(REWARD_SYSTEM.as_slice()).unwrap())?;
+        let mut vip_perks = legacy_reward_system.vip_perks;
+        vip_perks.retain(|perk| perk.vip_level != "level0");
+        vip_perks.sort_by(|a, b| a.vip_level.cmp(&b.vip_level));
+        REWARD_SYSTEM.save(
+            storage,
+            &RewardSystem {
+                reward_token_address: legacy_reward_system.reward_token_address,
+                reward_token_per_1usdc_volume: legacy_reward_system.reward_token_per_1usdc_volume,
+                total_reward_tokens_distributed: legacy_reward_system
+                    .total_reward_tokens_distributed,
+                vip_tiers: vip_perks
+                    .into_iter()
+                    .map(|perk| VipTier {
+                        name: perk.vip_level.replace("level", "Level"),
+                        level_up_price_in_reward_tokens: perk.level_up_price_in_reward_tokens,
+                        marketplace_fees_discount: perk.marketplace_fees_discount,
+                        profile_background: perk.profile_background,
+                        profile_nft_showcase: perk.profile_nft_showcase,
+                        profile_description: perk.profile_description,
+                        profile_links: perk.profile_links,
+                        max_messages_per_window: None,
+                        subscription_duration: None,
+                        volume_usdc_threshold: None,
+                    })
+                    .collect(),
+                vesting: None,
+            },
+        )
+    }
+
+    // Emulates the 0.0.1 code: same entry points but sales are keyed by the bare token id and VIP
+    // levels are saved with the former format
+    fn legacy_instantiate(
+        mut deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        msg: InstantiateMsg,
+    ) -> Result<Response<TokenFactoryMsg>, ContractError> {
+        let response =
+            chihuahua_nft_marketplace::contract::instantiate(deps.branch(), env, info, msg)?;
+        save_legacy_reward_system(deps.storage)?;
+        cw2::set_contract_version(deps.storage, CONTRACT_NAME, "0.0.1")?;
+        Ok(response)
+    }
+
+    fn legacy_execute(
+        mut deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        msg: ExecuteMsg,
+    ) -> Result<Response<TokenFactoryMsg>, ContractError> {
+        let sale_info = match msg.clone() {
+            ExecuteMsg::SellNft { sale_info } => Some(sale_info),
+            _ => None,
+        };
+        let sender = info.sender.to_string();
+        load_legacy_reward_system(deps.storage)?;
+        let response = chihuahua_nft_marketplace::contract::execute(deps.branch(), env, info, msg)?;
+        save_legacy_reward_system(deps.storage)?;
+        save_legacy_vip_levels(deps.storage, &PROFILES.key(&sender));
+        save_legacy_vip_levels(deps.storage, &MESSAGE_FILTERS.key(&sender));
+        if let Some(sale_info) = sale_info {
+            let unique_id = define_unique_collection_nft_id(
+                &sale_info.nft_collection_address,
+                &sale_info.token_id,
+            );
+            let sale = nfts_for_sale().load(deps.storage, unique_id.clone())?;
+            nfts_for_sale().remove(deps.storage, unique_id)?;
+            nfts_for_sale().save(deps.storage, sale.token_id.clone(), &sale)?;
+        }
+        Ok(response)
+    }
+
+    fn instantiate_at_version(
+        mut deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        msg: InstantiateMsg,
+        version: &str,
+    ) -> Result<Response<TokenFactoryMsg>, ContractError> {
+        let response = legacy_instantiate(deps.branch(), env, info, msg)?;
+        cw2::set_contract_version(deps.storage, CONTRACT_NAME, version)?;
+        Ok(response)
+    }
+
+    // Runs the current code on a reward system saved with the format of the VIP levels enum and
+    // returns the id of the sale listed, if any
+    fn execute_with_legacy_reward_system(
+        mut deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        msg: ExecuteMsg,
+    ) -> Result<(Response<TokenFactoryMsg>, Option<String>), ContractError> {
+        let listed_sale_id = match msg.clone() {
+            ExecuteMsg::SellNft { sale_info } => Some(define_unique_collection_nft_id(
+                &sale_info.nft_collection_address,
+                &sale_info.token_id,
+            )),
+            _ => None,
+        };
+        load_legacy_reward_system(deps.storage)?;
+        let response = chihuahua_nft_marketplace::contract::execute(deps.branch(), env, info, msg)?;
+        save_legacy_reward_system(deps.storage)?;
+        Ok((response, listed_sale_id))
+    }
+
+    // Emulates the 0.0.2 code: sales are keyed with the collection but are not in the price and
+    // expiration indexes
+    fn v0_0_2_instantiate(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        msg: InstantiateMsg,
+    ) -> Result<Response<TokenFactoryMsg>, ContractError> {
+        instantiate_at_version(deps, env, info, msg, "0.0.2")
+    }
+
+    fn v0_0_2_execute(
+        mut deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        msg: ExecuteMsg,
+    ) -> Result<Response<TokenFactoryMsg>, ContractError> {
+        let (response, listed_sale_id) =
+            execute_with_legacy_reward_system(deps.branch(), env, info, msg)?;
+        if let Some(unique_id) = listed_sale_id {
+            let sale = nfts_for_sale().load(deps.storage, unique_id.clone())?;
+            let indexes = nfts_for_sale().idx;
+            let new_indexes: [&dyn Index<NftSale>; 3] = [
+                &indexes.collection_denom_price_index,
+                &indexes.expiration_index,
+                &indexes.collection_expiration_index,
+            ];
+            for index in new_indexes {
+                index.remove(deps.storage, unique_id.as_bytes(), &sale)?;
+            }
+            nft_listing_times().remove(deps.storage, unique_id)?;
+        }
+        Ok(response)
+    }
+
+    // Emulates the 0.0.3 code: sales have no listing time
+    fn v0_0_3_instantiate(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        msg: InstantiateMsg,
+    ) -> Result<Response<TokenFactoryMsg>, ContractError> {
+        instantiate_at_version(deps, env, info, msg, "0.0.3")
+    }
+
+    fn v0_0_3_execute(
+        mut deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        msg: ExecuteMsg,
+    ) -> Result<Response<TokenFactoryMsg>, ContractError> {
+        let (response, listed_sale_id) =
+            execute_with_legacy_reward_system(deps.branch(), env, info, msg)?;
+        if let Some(unique_id) = listed_sale_id {
+            nft_listing_times().remove(deps.storage, unique_id)?;
+        }
+        Ok(response)
+    }
+
+    // Emulates the 0.0.4 code: messages are saved in the profile of the recipient
+    fn v0_0_4_instantiate(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        msg: InstantiateMsg,
+    ) -> Result<Response<TokenFactoryMsg>, ContractError> {
+        instantiate_at_version(deps, env, info, msg, "0.0.4")
+    }
+
+    fn v0_0_4_execute(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        msg: ExecuteMsg,
+    ) -> Result<Response<TokenFactoryMsg>, ContractError> {
+        if let ExecuteMsg::SendMessage { to, message } = msg {
+            let mut legacy_profile: LegacyProfile =
+                from_slice(&deps.storage.get(&PROFILES.key(&to)).unwrap())?;
+            legacy_profile
+                .profile_messages
+                .get_or_insert(ProfileMessages {
+                    display_on_profile: true,
+                    messages: vec![],
+                })
+                .messages
+                .push(ProfileMessage {
+                    from_address: info.sender.to_string(),
+                    from_username: None,
+                    message,
+                });
+            deps.storage
+                .set(&PROFILES.key(&to), &to_vec(&legacy_profile)?);
+            return Ok(Response::new());
+        }
+        Ok(execute_with_legacy_reward_system(deps, env, info, msg)?.0)
+    }
+
+    // Emulates the 0.0.5 code: usernames are saved with their case
+    fn v0_0_5_instantiate(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        msg: InstantiateMsg,
+    ) -> Result<Response<TokenFactoryMsg>, ContractError> {
+        instantiate_at_version(deps, env, info, msg, "0.0.5")
+    }
+
+    fn v0_0_5_execute(
+        mut deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        msg: ExecuteMsg,
+    ) -> Result<Response<TokenFactoryMsg>, ContractError> {
+        let username = match msg.clone() {
+            ExecuteMsg::UpdateMyProfile { profile, .. } => profile.username,
+            _ => None,
+        };
+        let response = execute_with_legacy_reward_system(deps.branch(), env, info, msg)?.0;
+        if let Some(username) = username {
+            let key = normalize_username(&username);
+            let address = USERNAMES.load(deps.storage, &key)?;
+            USERNAMES.remove(deps.storage, &key);
+            USERNAMES.save(deps.storage, &username, &address)?;
+        }
+        Ok(response)
+    }
+
+    fn future_instantiate(
+        mut deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        msg: InstantiateMsg,
+    ) -> Result<Response<TokenFactoryMsg>, ContractError> {
+        let response =
+            chihuahua_nft_marketplace::contract::instantiate(deps.branch(), env, info, msg)?;
+        cw2::set_contract_version(deps.storage, CONTRACT_NAME, "9.9.9")?;
+        Ok(response)
+    }
+
+    fn smart_contract_def_legacy_nft_marketplace() -> Box<dyn Contract<TokenFactoryMsg>> {
+        Box::new(ContractWrapper::new(
+            legacy_execute,
+            legacy_instantiate,
+            chihuahua_nft_marketplace::contract::query,
+        ))
+    }
+
+    fn smart_contract_def_v0_0_2_nft_marketplace() -> Box<dyn Contract<TokenFactoryMsg>> {
+        Box::new(ContractWrapper::new(
+            v0_0_2_execute,
+            v0_0_2_instantiate,
+            chihuahua_nft_marketplace::contract::query,
+        ))
+    }
+
+    fn smart_contract_def_v0_0_3_nft_marketplace() -> Box<dyn Contract<TokenFactoryMsg>> {
+        Box::new(ContractWrapper::new(
+            v0_0_3_execute,
+            v0_0_3_instantiate,
+            chihuahua_nft_marketplace::contract::query,
+        ))
+    }
+
+    fn smart_contract_def_v0_0_4_nft_marketplace() -> Box<dyn Contract<TokenFactoryMsg>> {
+        Box::new(ContractWrapper::new(
+            v0_0_4_execute,
+            v0_0_4_instantiate,
+            chihuahua_nft_marketplace::contract::query,
+        ))
+    }
+
+    fn smart_contract_def_v0_0_5_nft_marketplace() -> Box<dyn Contract<TokenFactoryMsg>> {
+        Box::new(ContractWrapper::new(
+            v0_0_5_execute,
+            v0_0_5_instantiate,
+            chihuahua_nft_marketplace::contract::query,
+        ))
+    }
+
+    fn smart_contract_def_future_nft_marketplace() -> Box<dyn Contract<TokenFactoryMsg>> {
+        Box::new(ContractWrapper::new(
+            chihuahua_nft_marketplace::contract::execute,
+            future_instantiate,
+            chihuahua_nft_marketplace::contract::query,
+        ))
+    }
+
+    fn instantiate_with_admin(
+        app: &mut App,
+        code_id: u64,
+        oracle_contract_address: String,
+    ) -> Addr {
+        let mut init_msg = default_init_msg_mkpc();
+        init_msg.oracle_contract_address = oracle_contract_address;
+        init_msg.reward_system.reward_token_address = REWARD_TOKEN.to_string();
+        app.instantiate_contract(
+            code_id,
+            Addr::unchecked(OWNER),
+            &init_msg,
+            &[],
+            "chihuahua_nft_marketplace_code",
+            Some(OWNER.to_string()),
+        )
+        .unwrap()
+    }
+
+    // Enables the marketplace, adds the collection and lists the tokens at 100_000_000 huahua
+    fn list_tokens(
+        app: &mut App,
+        marketplace_addr: &Addr,
+        nft_collection: &str,
+        nft_code_id: u64,
+        native_huahua: &str,
+        token_ids: &[&str],
+    ) {
+        let info = mock_info(OWNER, &[]);
+        marketplace_test_exec_enable_disable(app, marketplace_addr.to_string(), info.clone())
+            .unwrap();
+        let nft_contract_info = NftContractInfo {
+            code_id: nft_code_id,
+            nft_contract_type: NftContractType::Cw2981MultiRoyalties,
+        };
+        marketplace_test_exec_add_nft_code_id(
+            app,
+            marketplace_addr.to_string(),
+            vec![nft_contract_info.clone()],
+            info.clone(),
+        )
+        .unwrap();
+        for token_id in token_ids {
+            cw2981_multi_test_exec_mint(
+                app,
+                &Addr::unchecked(nft_collection),
+                info.clone(),
+                token_id.to_string(),
+                OWNER.to_string(),
+                None,
+            )
+            .unwrap();
+        }
+        marketplace_test_exec_add_new_collection(
+            app,
+            marketplace_addr,
+            info,
+            NftCollectionAddress::from(nft_collection),
+            nft_contract_info,
+        )
+        .unwrap();
+        let info = mock_info(OWNER, &coins(6_900_000u128, native_huahua));
+        for token_id in token_ids {
+            cw2981_multi_test_exec_approve(
+                app,
+                &Addr::unchecked(nft_collection),
+                marketplace_addr,
+                info.clone(),
+                token_id.to_string(),
+                1571797419u64 + 87000u64,
+            )
+            .unwrap();
+            marketplace_test_exec_sell_nft(
+                app,
+                marketplace_addr,
+                info.clone(),
+                NftSale {
+                    seller: OWNER.to_string(),
+                    nft_collection_address: nft_collection.to_string(),
+                    token_id: token_id.to_string(),
+                    sale_price_value: Uint128::new(100_000_000u128),
+                    sale_price_denom: native_huahua.to_string(),
+                    sale_expiration: Timestamp::from_seconds(1571797419u64 + 87000u64),
+                },
+            )
+            .unwrap();
+        }
+    }
+
+    #[test]
+    fn test_marketplace_smart_contract_migrate() {
+        let (mut app, necessary) = instantiate_necessary_for_tests();
+        let nft_collection = necessary.cw2981_nft_contract_addr1.clone();
+
+        // - Old contracts are migrated in batches and only get the new version once done
+        // - Sales keyed by token id are re-keyed with the collection
+        // - Downgrades and contracts with another name are refused
+
+        let legacy_code_id = app.store_code(smart_contract_def_legacy_nft_marketplace());
+        let new_code_id = app.store_code(smart_contract_def_test_nft_marketplace());
+        let marketplace_addr = instantiate_with_admin(
+            &mut app,
+            legacy_code_id,
+            necessary.price_oracle_contract_addr.clone(),
+        );
+        assert_eq!(
+            marketplace_test_query_contract_version(&app, marketplace_addr.clone()).version,
+            "0.0.1"
+        );
+
+        // Enable, add the collection and list 3 tokens with the legacy code
+        let info = mock_info(OWNER, &[]);
+        marketplace_test_exec_enable_disable(&mut app, marketplace_addr.to_string(), info.clone())
+            .unwrap();
+        marketplace_test_exec_add_nft_code_id(
+            &mut app,
+            marketplace_addr.to_string(),
+            vec![NftContractInfo {
+                code_id: necessary.cw2981_nft_code_id,
+                nft_contract_type: NftContractType::Cw2981MultiRoyalties,
+            }],
+            info.clone(),
+        )
+        .unwrap();
+        for token_number in 1..4 {
+            cw2981_multi_test_exec_mint(
+                &mut app,
+                &Addr::unchecked(nft_collection.clone()),
+                info.clone(),
+                format!("Token{}", token_number),
+                OWNER.to_string(),
+                None,
+            )
+            .unwrap();
+        }
+        marketplace_test_exec_add_new_collection(
+            &mut app,
+            &marketplace_addr,
+            info,
+            NftCollectionAddress::from(nft_collection.clone()),
+            NftContractInfo {
+                code_id: necessary.cw2981_nft_code_id,
+                nft_contract_type: NftContractType::Cw2981MultiRoyalties,
+            },
+        )
+        .unwrap();
+        let info = mock_info(
+            OWNER,
+            &coins(6_900_000u128, necessary.native_huahua.clone()),
+        );
+        for token_number in 1..4 {
+            cw2981_multi_test_exec_approve(
+                &mut app,
+                &Addr::unchecked(nft_collection.clone()),
+                &marketplace_addr,
+                info.clone(),
+                format!("Token{}", token_number),
+                1571797419u64 + 87000u64,
+            )
+            .unwrap();
+            marketplace_test_exec_sell_nft(
+                &mut app,
+                &marketplace_addr,
+                info.clone(),
+                NftSale {
+                    seller: OWNER.to_string(),
+                    nft_collection_address: nft_collection.clone(),
+                    token_id: format!("Token{}", token_number),
+                    sale_price_value: Uint128::new(100_000_000u128),
+                    sale_price_denom: necessary.native_huahua.clone(),
+                    sale_expiration: Timestamp::from_seconds(1571797419u64 + 87000u64),
+                },
+            )
+            .unwrap();
+        }
+
+        // Legacy keys cannot be found by the readers
+        let query_output = marketplace_test_query_get_nft_for_sale_info(
+            &app,
+            marketplace_addr.clone(),
+            nft_collection.clone(),
+            "Token1".to_string(),
+        )
+        .unwrap();
+        assert_eq!(query_output.seller, "".to_string());
+
+        // ----->>> Only the admin can migrate
+        let execute_output = marketplace_test_exec_migrate(
+            &mut app,
+            &marketplace_addr,
+            mock_info(WALLET2, &[]),
+            new_code_id,
+            None,
+        );
+        assert!(execute_output.is_err());
+
+        // ----->>> First batch: not completed and version unchanged
+        let execute_output = marketplace_test_exec_migrate(
+            &mut app,
+            &marketplace_addr,
+            mock_info(OWNER, &[]),
+            new_code_id,
+            Some(2),
+        )
+        .unwrap();
+        assert!(execute_output.events.iter().any(|event| event
+            .attributes
+            .iter()
+            .any(|attr| attr.key == "Migration completed" && attr.value == "false")));
+        assert_eq!(
+            marketplace_test_query_contract_version(&app, marketplace_addr.clone()).version,
+            "0.0.1"
+        );
+        let query_output = marketplace_test_query_get_nft_for_sale_info(
+            &app,
+            marketplace_addr.clone(),
+            nft_collection.clone(),
+            "Token1".to_string(),
+        )
+        .unwrap();
+        assert_eq!(query_output.seller, OWNER.to_string());
+        let query_output = marketplace_test_query_get_nft_for_sale_info(
+            &app,
+            marketplace_addr.clone(),
+            nft_collection.clone(),
+            "Token3".to_string(),
+        )
+        .unwrap();
+        assert_eq!(query_output.seller, "".to_string());
+
+        // ----->>> Users are blocked until the migration is done, the admin is not
+        let execute_output = marketplace_test_exec_buy_nft(
+            &mut app,
+            &marketplace_addr,
+            mock_info(
+                WALLET2,
+                &coins(100_000_000u128, necessary.native_huahua.clone()),
+            ),
+            nft_collection.clone(),
+            "Token1".to_string(),
+            None,
+        );
+        assert_eq!(
+            execute_output.unwrap_err().root_cause().to_string(),
+            "MigrationInProgress".to_string()
+        );
+        // Exit paths are frozen as well, Token3 is still under its legacy key
+        let execute_output = marketplace_test_exec_cancel_sale(
+            &mut app,
+            &marketplace_addr,
+            mock_info(OWNER, &[]),
+            nft_collection.clone(),
+            "Token3".to_string(),
+            None,
+        );
+        assert_eq!(
+            execute_output.unwrap_err().root_cause().to_string(),
+            "MigrationInProgress".to_string()
+        );
+        let execute_output = marketplace_test_exec_cancel_offer(
+            &mut app,
+            &marketplace_addr,
+            mock_info(WALLET2, &[]),
+            nft_collection.clone(),
+            "Token1".to_string(),
+        );
+        assert_eq!(
+            execute_output.unwrap_err().root_cause().to_string(),
+            "MigrationInProgress".to_string()
+        );
+        for _ in 0..2 {
+            marketplace_test_exec_enable_disable(
+                &mut app,
+                marketplace_addr.to_string(),
+                mock_info(OWNER, &[]),
+            )
+            .unwrap();
+        }
+
+        // ----->>> Resume: the remaining sales are migrated and the version is set
+        let execute_output = marketplace_test_exec_migrate(
+            &mut app,
+            &marketplace_addr,
+            mock_info(OWNER, &[]),
+            new_code_id,
+            None,
+        )
+        .unwrap();
+        assert!(execute_output.events.iter().any(|event| event
+            .attributes
+            .iter()
+            .any(|attr| attr.key == "Migration completed" && attr.value == "true")));
+        assert_eq!(
+            marketplace_test_query_contract_version(&app, marketplace_addr.clone()).version,
+            env!("CARGO_PKG_VERSION")
+        );
+        for token_number in 1..4 {
+            let query_output = marketplace_test_query_get_nft_for_sale_info(
+                &app,
+                marketplace_addr.clone(),
+                nft_collection.clone(),
+                format!("Token{}", token_number),
+            )
+            .unwrap();
+            assert_eq!(query_output.seller, OWNER.to_string());
+            assert_eq!(query_output.token_id, format!("Token{}", token_number));
+        }
+        // Migrated sales are in the price index
+        let query_output = marketplace_test_query_get_coll_nfts_for_sale_by_price(
+            &app,
+            marketplace_addr.clone(),
+            nft_collection.clone(),
+            necessary.native_huahua.clone(),
+            PriceOrder::Ascending,
+            None,
+            None,
+            None,
+            None,
+        );
+        assert_eq!(query_output.len(), 3);
+
+        // Migrated sales can be bought
+        marketplace_test_exec_buy_nft(
+            &mut app,
+            &marketplace_addr,
+            mock_info(WALLET2, &coins(100_000_000u128, necessary.native_huahua)),
+            nft_collection.clone(),
+            "Token3".to_string(),
+            None,
+        )
+        .unwrap();
+        let query_output =
+            cw2981_multi_test_query_owner_of(&app, nft_collection.clone(), "Token3".to_string());
+        assert_eq!(query_output.owner, WALLET2.to_string());
+
+        // Exit paths are open again
+        cw2981_multi_test_exec_revoke(
+            &mut app,
+            &Addr::unchecked(nft_collection.clone()),
+            &marketplace_addr,
+            mock_info(OWNER, &[]),
+            "Token2".to_string(),
+        )
+        .unwrap();
+        marketplace_test_exec_cancel_sale(
+            &mut app,
+            &marketplace_addr,
+            mock_info(OWNER, &[]),
+            nft_collection.clone(),
+            "Token2".to_string(),
+            None,
+        )
+        .unwrap();
+        let query_output = marketplace_test_query_get_nft_for_sale_info(
+            &app,
+            marketplace_addr.clone(),
+            nft_collection,
+            "Token2".to_string(),
+        )
+        .unwrap();
+        assert_eq!(query_output.seller, "".to_string());
+
+        // ----->>> Migrating again to the same version is a no-op
+        let execute_output = marketplace_test_exec_migrate(
+            &mut app,
+            &marketplace_addr,
+            mock_info(OWNER, &[]),
+            new_code_id,
+            None,
+        );
+        assert!(execute_output.is_ok());
+
+        // ----->>> Downgrades are refused
+        let future_code_id = app.store_code(smart_contract_def_future_nft_marketplace());
+        let future_marketplace_addr = instantiate_with_admin(
+            &mut app,
+            future_code_id,
+            necessary.price_oracle_contract_addr.clone(),
+        );
+        let execute_output = marketplace_test_exec_migrate(
+            &mut app,
+            &future_marketplace_addr,
+            mock_info(OWNER, &[]),
+            new_code_id,
+            None,
+        );
+        assert_eq!(
+            execute_output.unwrap_err().root_cause().to_string(),
+            "CannotMigrateToOlderVersion".to_string()
+        );
+
+        // ----->>> Another contract cannot be migrated to the marketplace code
+        let oracle_code_id = app
+            .contract_data(&Addr::unchecked(
+                necessary.price_oracle_contract_addr.clone(),
+            ))
+            .unwrap()
+            .code_id;
+        let oracle_with_admin = app
+            .instantiate_contract(
+                oracle_code_id as u64,
+                Addr::unchecked(OWNER),
+                &oracle::msg::InstantiateMsg {
+                    contract_owner: OWNER.to_string(),
+                    prices_feeder: OWNER.to_string(),
+                    max_history_length: 10,
+                    accepted_ibc_denoms: general_utils::denominations::AcceptedDenominations {
+                        list_of_denoms: vec![necessary.native_atom],
+                    },
+                },
+                &[],
+                "price_oracle",
+                Some(OWNER.to_string()),
+            )
+            .unwrap();
+        let execute_output = marketplace_test_exec_migrate(
+            &mut app,
+            &oracle_with_admin,
+            mock_info(OWNER, &[]),
+            new_code_id,
+            None,
+        );
+        assert_eq!(
+            execute_output.unwrap_err().root_cause().to_string(),
+            "InvalidContractName".to_string()
+        );
+    }
+
+    #[test]
+    fn test_marketplace_migrate_vip_tiers() {
+        let (mut app, necessary) = instantiate_necessary_for_tests();
+
+        // - The perks of the former levels become tiers named after them, level 0 has none
+        // - Levels of the profiles and the message filters are saved again as numbers
+
+        let legacy_code_id = app.store_code(smart_contract_def_legacy_nft_marketplace());
+        let new_code_id = app.store_code(smart_contract_def_test_nft_marketplace());
+        let marketplace_addr = instantiate_with_admin(
+            &mut app,
+            legacy_code_id,
+            necessary.price_oracle_contract_addr,
+        );
+        marketplace_test_exec_enable_disable(
+            &mut app,
+            marketplace_addr.to_string(),
+            mock_info(OWNER, &[]),
+        )
+        .unwrap();
+        for address in [WALLET3, WALLET2] {
+            marketplace_test_exec_create_my_profile(
+                &mut app,
+                marketplace_addr.to_string(),
+                mock_info(address, &[]),
+                None,
+            )
+            .unwrap();
+        }
+        marketplace_test_exec_lvl_up_profile(
+            &mut app,
+            mock_info(WALLET3, &coins(1_000u128, REWARD_TOKEN)),
+            &marketplace_addr,
+        )
+        .unwrap();
+        marketplace_test_exec_set_message_filter(
+            &mut app,
+            &marketplace_addr,
+            mock_info(WALLET2, &[]),
+            Some(MessageFilter {
+                min_trades: None,
+                min_vip_level: Some(VipLevel(1)),
+            }),
+        )
+        .unwrap();
+        let raw_profile = app
+            .wrap()
+            .query_wasm_raw(marketplace_addr.to_string(), PROFILES.key(WALLET3).to_vec())
+            .unwrap()
+            .unwrap();
+        assert!(String::from_utf8(raw_profile)
+            .unwrap()
+            .contains("\"vip_level\":\"level1\""));
+
+        marketplace_test_exec_migrate(
+            &mut app,
+            &marketplace_addr,
+            mock_info(OWNER, &[]),
+            new_code_id,
+            None,
+        )
+        .unwrap();
+
+        let config = marketplace_test_query_get_config(&app, marketplace_addr.to_string());
+        assert_eq!(
+            config
+                .reward_system
+                .vip_tiers
+                .iter()
+                .map(|vip_tier| (
+                    vip_tier.name.as_str(),
+                    vip_tier.level_up_price_in_reward_tokens.u128()
+                ))
+                .collect::<Vec<(&str, u128)>>(),
+            vec![("Level1", 1_000), ("Level2", 10_000), ("Level3", 50_000)]
+        );
+        let raw_profile = app
+            .wrap()
+            .query_wasm_raw(marketplace_addr.to_string(), PROFILES.key(WALLET3).to_vec())
+            .unwrap()
+            .unwrap();
+        assert!(String::from_utf8(raw_profile)
+            .unwrap()
+            .contains("\"vip_level\":1"));
+        let raw_message_filter = app
+            .wrap()
+            .query_wasm_raw(
+                marketplace_addr.to_string(),
+                MESSAGE_FILTERS.key(WALLET2).to_vec(),
+            )
+            .unwrap()
+            .unwrap();
+        assert!(String::from_utf8(raw_message_filter)
+            .unwrap()
+            .contains("\"min_vip_level\":1"));
+        let profile = marketplace_test_query_get_profile_info(
+            &app,
+            marketplace_addr.to_string(),
+            WALLET2.to_string(),
+        )
+        .unwrap();
+        assert_eq!(profile.vip_level, Some(VipLevel(0)));
+        let message_filter =
+            marketplace_test_query_get_message_filter(&app, &marketplace_addr, WALLET2.to_string());
+        assert_eq!(message_filter.min_vip_level, Some(VipLevel(1)));
+
+        // Migrated profiles keep leveling up with the tiers
+        marketplace_test_exec_lvl_up_profile(
+            &mut app,
+            mock_info(WALLET3, &coins(10_000u128, REWARD_TOKEN)),
+            &marketplace_addr,
+        )
+        .unwrap();
+        let profile = marketplace_test_query_get_profile_info(
+            &app,
+            marketplace_addr.to_string(),
+            WALLET3.to_string(),
+        )
+        .unwrap();
+        assert_eq!(profile.vip_level, Some(VipLevel(2)));
+    }
+
+    #[test]
+    fn test_marketplace_migrate_sale_indexes() {
+        let (mut app, necessary) = instantiate_necessary_for_tests();
+        let nft_collection = necessary.cw2981_nft_contract_addr1.clone();
+
+        // - Sales listed at 0.0.2 are added to the price and expiration indexes
+
+        let v0_0_2_code_id = app.store_code(smart_contract_def_v0_0_2_nft_marketplace());
+        let new_code_id = app.store_code(smart_contract_def_test_nft_marketplace());
+        let marketplace_addr = instantiate_with_admin(
+            &mut app,
+            v0_0_2_code_id,
+            necessary.price_oracle_contract_addr.clone(),
+        );
+        list_tokens(
+            &mut app,
+            &marketplace_addr,
+            &nft_collection,
+            necessary.cw2981_nft_code_id,
+            &necessary.native_huahua,
+            &["Token1", "Token2"],
+        );
+        let query_output = marketplace_test_query_get_coll_nfts_for_sale_by_price(
+            &app,
+            marketplace_addr.clone(),
+            nft_collection.clone(),
+            necessary.native_huahua.clone(),
+            PriceOrder::Ascending,
+            None,
+            None,
+            None,
+            None,
+        );
+        assert!(query_output.is_empty());
+
+        marketplace_test_exec_migrate(
+            &mut app,
+            &marketplace_addr,
+            mock_info(OWNER, &[]),
+            new_code_id,
+            None,
+        )
+        .unwrap();
+        let query_output = marketplace_test_query_get_coll_nfts_for_sale_by_price(
+            &app,
+            marketplace_addr.clone(),
+            nft_collection,
+            necessary.native_huahua,
+            PriceOrder::Ascending,
+            None,
+            None,
+            None,
+            None,
+        );
+        assert_eq!(query_output.len(), 2);
+        let query_output =
+            marketplace_test_query_get_ending_soon(&app, marketplace_addr, None, None, None);
+        assert_eq!(query_output.len(), 2);
+    }
+
+    #[test]
+    fn test_marketplace_migrate_listing_times() {
+        let (mut app, necessary) = instantiate_necessary_for_tests();
+        let nft_collection = necessary.cw2981_nft_contract_addr1.clone();
+
+        // - Sales listed at 0.0.3 get the time of the migration as listing time
+
+        let v0_0_3_code_id = app.store_code(smart_contract_def_v0_0_3_nft_marketplace());
+        let new_code_id = app.store_code(smart_contract_def_test_nft_marketplace());
+        let marketplace_addr = instantiate_with_admin(
+            &mut app,
+            v0_0_3_code_id,
+            necessary.price_oracle_contract_addr.clone(),
+        );
+        list_tokens(
+            &mut app,
+            &marketplace_addr,
+            &nft_collection,
+            necessary.cw2981_nft_code_id,
+            &necessary.native_huahua,
+            &["Token1", "Token2"],
+        );
+        let query_output =
+            marketplace_test_query_get_recently_listed(&app, &marketplace_addr, None, None, None);
+        assert!(query_output.is_empty());
+
+        app.update_block(|block| {
+            block.time = block.time.plus_seconds(100);
+            block.height += 1;
+        });
+        marketplace_test_exec_migrate(
+            &mut app,
+            &marketplace_addr,
+            mock_info(OWNER, &[]),
+            new_code_id,
+            Some(1),
+        )
+        .unwrap();
+        marketplace_test_exec_migrate(
+            &mut app,
+            &marketplace_addr,
+            mock_info(OWNER, &[]),
+            new_code_id,
+            None,
+        )
+        .unwrap();
+        let query_output =
+            marketplace_test_query_get_recently_listed(&app, &marketplace_addr, None, None, None);
+        assert_eq!(query_output.len(), 2);
+        assert!(query_output
+            .iter()
+            .all(|listing| listing.listed_at == app.block_info().time));
+    }
+
+    #[test]
+    fn test_marketplace_migrate_profile_messages() {
+        let (mut app, necessary) = instantiate_necessary_for_tests();
+
+        // - Messages saved in the profiles at 0.0.4 are moved to the inbox
+        // - Profiles are saved again without them
+
+        let v0_0_4_code_id = app.store_code(smart_contract_def_v0_0_4_nft_marketplace());
+        let new_code_id = app.store_code(smart_contract_def_test_nft_marketplace());
+        let marketplace_addr = instantiate_with_admin(
+            &mut app,
+            v0_0_4_code_id,
+            necessary.price_oracle_contract_addr,
+        );
+        marketplace_test_exec_enable_disable(
+            &mut app,
+            marketplace_addr.to_string(),
+            mock_info(OWNER, &[]),
+        )
+        .unwrap();
+        for address in [WALLET2, WALLET3] {
+            marketplace_test_exec_create_my_profile(
+                &mut app,
+                marketplace_addr.to_string(),
+                mock_info(address, &[]),
+                None,
+            )
+            .unwrap();
+        }
+        for (from, to, message) in [
+            (WALLET2, WALLET3, "Hello"),
+            (WALLET2, WALLET3, "Are you there?"),
+            (WALLET3, WALLET2, "Yes"),
+        ] {
+            marketplace_test_exec_send_message(
+                &mut app,
+                marketplace_addr.to_string(),
+                mock_info(from, &[]),
+                to.to_string(),
+                message.to_string(),
+            )
+            .unwrap();
+        }
+        let raw_profile = app
+            .wrap()
+            .query_wasm_raw(marketplace_addr.to_string(), PROFILES.key(WALLET3).to_vec())
+            .unwrap()
+            .unwrap();
+        assert!(String::from_utf8(raw_profile)
+            .unwrap()
+            .contains("\"profile_messages\""));
+
+        marketplace_test_exec_migrate(
+            &mut app,
+            &marketplace_addr,
+            mock_info(OWNER, &[]),
+            new_code_id,
+            None,
+        )
+        .unwrap();
+
+        let raw_profile = app
+            .wrap()
+            .query_wasm_raw(marketplace_addr.to_string(), PROFILES.key(WALLET3).to_vec())
+            .unwrap()
+            .unwrap();
+        assert!(!String::from_utf8(raw_profile)
+            .unwrap()
+            .contains("\"profile_messages\""));
+        let threads = marketplace_test_query_get_inbox_threads(
+            &app,
+            &marketplace_addr,
+            WALLET3.to_string(),
+            None,
+            None,
+        );
+        assert_eq!(threads.len(), 1);
+        assert_eq!(threads[0].thread.counterparty, WALLET2.to_string());
+        let messages = marketplace_test_query_get_inbox_thread(
+            &app,
+            &marketplace_addr,
+            WALLET3.to_string(),
+            WALLET2.to_string(),
+            None,
+            None,
+        );
+        // Profiles are migrated one after the other, the messages of a thread are not in order
+        let mut messages = messages
+            .iter()
+            .map(|message| {
+                (
+                    message.from_address.as_str(),
+                    message.to_address.as_str(),
+                    message.message.as_str(),
+                )
+            })
+            .collect::<Vec<_>>();
+        messages.sort();
+        let mut expected_messages = vec![
+            (WALLET2, WALLET3, "Are you there?"),
+            (WALLET2, WALLET3, "Hello"),
+            (WALLET3, WALLET2, "Yes"),
+        ];
+        expected_messages.sort();
+        assert_eq!(messages, expected_messages);
+    }
+
+    #[test]
+    fn test_marketplace_migrate_usernames() {
+        let (mut app, necessary) = instantiate_necessary_for_tests();
+
+        // - Usernames saved with their case at 0.0.5 are keyed in lowercase
+        // - When two usernames only differ by their case, the first visited is kept
+
+        let v0_0_5_code_id = app.store_code(smart_contract_def_v0_0_5_nft_marketplace());
+        let new_code_id = app.store_code(smart_contract_def_test_nft_marketplace());
+        let marketplace_addr = instantiate_with_admin(
+            &mut app,
+            v0_0_5_code_id,
+            necessary.price_oracle_contract_addr,
+        );
+        marketplace_test_exec_enable_disable(
+            &mut app,
+            marketplace_addr.to_string(),
+            mock_info(OWNER, &[]),
+        )
+        .unwrap();
+        for (address, username) in [(OWNER, "Doge"), (WALLET2, "Satoshi"), (WALLET3, "SATOSHI")] {
+            marketplace_test_exec_create_my_profile(
+                &mut app,
+                marketplace_addr.to_string(),
+                mock_info(address, &[]),
+                None,
+            )
+            .unwrap();
+            let mut profile = Profile::new(address.to_string());
+            profile.username = Some(username.to_string());
+            marketplace_test_exec_update_my_profile(
+                &mut app,
+                marketplace_addr.to_string(),
+                mock_info(address, &[]),
+                profile,
+                ProfileUpdateAction::Add,
+            )
+            .unwrap();
+        }
+
+        marketplace_test_exec_migrate(
+            &mut app,
+            &marketplace_addr,
+            mock_info(OWNER, &[]),
+            new_code_id,
+            None,
+        )
+        .unwrap();
+
+        for (username, address) in [("DOGE", OWNER), ("Satoshi", WALLET3)] {
+            assert_eq!(
+                marketplace_test_query_get_username_status(
+                    &app,
+                    &marketplace_addr,
+                    username.to_string()
+                ),
+                UsernameStatus::Taken {
+                    address: address.to_string()
+                }
+            );
+        }
+        assert_eq!(
+            marketplace_test_query_get_username(&app, &marketplace_addr, WALLET3.to_string()),
+            Some("SATOSHI".to_string())
+        );
+        assert_eq!(
+            marketplace_test_query_get_username(&app, &marketplace_addr, WALLET2.to_string()),
+            None
+        );
+    }
+}
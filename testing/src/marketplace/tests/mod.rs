@@ -1,9 +1,32 @@
+pub mod activity;
 pub mod add_new_collection;
 pub mod buy;
+pub mod circuit_breaker;
+pub mod competition;
 pub mod cw721_no_royalty_collection;
+pub mod follow_watchlist;
+pub mod inbox;
 pub mod instantiate;
+pub mod leaderboard;
+pub mod listing_feeds;
+pub mod listing_price_index;
+pub mod messaging_anti_spam;
+pub mod migrate;
 pub mod new_cancel_update_sale;
 pub mod offer;
+pub mod partner_perks;
+pub mod price_history;
 pub mod profile_create_update_upgrade_send_msg;
+pub mod reward_minting;
+pub mod reward_pool;
+pub mod reward_vesting;
+pub mod staking;
 pub mod stats_states_behaviours;
 pub mod update_config;
+pub mod username_registry;
+pub mod username_trading;
+pub mod verification;
+pub mod vip_subscriptions;
+pub mod vip_tiers;
+pub mod vip_volume_progression;
+pub mod wash_trading;
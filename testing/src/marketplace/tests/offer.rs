@@ -967,6 +967,8 @@ mod tests {
                 address: WALLET4.to_string(),
                 username: None,
                 vip_level: None,
+                vip_expiration: None,
+                volume_usdc: None,
                 profile_nft_collection: Some(cw2981_base_smart_contract_addr1.clone()),
                 profile_nft_token_id: Some("Token777".to_string()),
                 background_nft_collection: None,
@@ -974,7 +976,6 @@ mod tests {
                 description: None,
                 nft_showcase: None,
                 links: None,
-                profile_messages: None,
                 number_of_trades: None,
                 buy_info: None,
                 sell_info: None,
@@ -991,7 +992,7 @@ mod tests {
         )
         .unwrap();
         assert_eq!(execute_output.address, Addr::unchecked(WALLET4));
-        assert_eq!(execute_output.vip_level, Some(VipLevel::Level0));
+        assert_eq!(execute_output.vip_level, Some(VipLevel(0)));
         assert_eq!(
             execute_output.profile_nft_collection,
             Some(cw2981_base_smart_contract_addr1.to_string())
@@ -1058,7 +1059,7 @@ mod tests {
         )
         .unwrap();
         assert_eq!(execute_output.address, Addr::unchecked(WALLET4));
-        assert_eq!(execute_output.vip_level, Some(VipLevel::Level0));
+        assert_eq!(execute_output.vip_level, Some(VipLevel(0)));
         assert_eq!(execute_output.profile_nft_collection, None);
         assert_eq!(execute_output.profile_nft_token_id, None);
 
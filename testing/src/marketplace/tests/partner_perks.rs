@@ -0,0 +1,359 @@
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::mock_info;
+    use cosmwasm_std::{coins, Addr, Decimal, Uint128};
+
+    use chihuahua_nft_marketplace::constants::SANCTUARY_NFTS;
+    use chihuahua_nft_marketplace::msg::UpdateConfigEnum;
+    use nft_marketplace_utils::nft_collection::{NftContractInfo, NftContractType};
+    use nft_marketplace_utils::nft_sale::NftSale;
+    use nft_marketplace_utils::partner_perks::{PartnerCollection, PerksCombination};
+    use nft_marketplace_utils::profile::{Profile, ProfileUpdateAction};
+    use nft_marketplace_utils::reward_system::{VipLevel, VipTier};
+
+    use crate::common::utils::constants::{OWNER, REWARD_TOKEN, UHUAHUA, WALLET2};
+    use crate::common::utils::utils_common::tests::instantiate_necessary_for_tests;
+    use crate::common::utils::utils_marketplace_contract_test::tests::{
+        marketplace_test_exec_add_new_collection, marketplace_test_exec_add_nft_code_id,
+        marketplace_test_exec_buy_nft, marketplace_test_exec_create_my_profile,
+        marketplace_test_exec_enable_disable, marketplace_test_exec_lvl_up_profile,
+        marketplace_test_exec_sell_nft, marketplace_test_exec_update_config,
+        marketplace_test_exec_update_my_profile, marketplace_test_query_get_config,
+        marketplace_test_query_get_holder_perks, marketplace_test_query_get_partner_perks,
+        marketplace_test_query_get_profile_info,
+    };
+    use crate::common::utils::utils_nft_contract_test::tests::{
+        cw2981_multi_test_exec_approve, cw2981_multi_test_exec_mint,
+        cw2981_multi_test_exec_transfer_nft,
+    };
+
+    const PRICE: u128 = 100_000_000u128;
+    const DAY: u64 = 86_400u64;
+
+    fn vip_tier(name: &str, discount_percent: u64, profile_description: bool) -> VipTier {
+        VipTier {
+            name: name.to_string(),
+            level_up_price_in_reward_tokens: Uint128::new(1_000u128),
+            marketplace_fees_discount: Decimal::percent(discount_percent),
+            profile_background: true,
+            profile_nft_showcase: false,
+            profile_description,
+            profile_links: false,
+            max_messages_per_window: None,
+            subscription_duration: None,
+            volume_usdc_threshold: None,
+        }
+    }
+
+    #[test]
+    fn test_marketplace_partner_collection_perks() {
+        // - Holders of a partner collection get its fee discount and the profile perks of its tier
+        // - Discounts are combined with the VIP level by taking the best or adding them up to a cap
+        // - Holdings are checked live, a partner token sent away loses its perks on the next trade
+        // - Removing the collection removes the perks
+        // - Sanctuary holders are a partner collection from the start
+        let (mut app, necessary) = instantiate_necessary_for_tests();
+        let nft_marketplace_smart_contract_addr =
+            Addr::unchecked(necessary.nft_marketplace_smart_contract_addr);
+        let nft_collection = necessary.cw2981_nft_contract_addr1;
+        let partner_nft_collection = necessary.cw2981_nft_contract_addr2;
+        let nft_contract_info = NftContractInfo {
+            code_id: necessary.cw2981_nft_code_id,
+            nft_contract_type: NftContractType::Cw2981MultiRoyalties,
+        };
+        marketplace_test_exec_enable_disable(
+            &mut app,
+            nft_marketplace_smart_contract_addr.to_string(),
+            mock_info(OWNER, &[]),
+        )
+        .unwrap();
+        let mut reward_system =
+            marketplace_test_query_get_config(&app, &nft_marketplace_smart_contract_addr)
+                .reward_system;
+        reward_system.vip_tiers = vec![vip_tier("Member", 5, false), vip_tier("Gold", 20, true)];
+        marketplace_test_exec_update_config(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(OWNER, &[]),
+            vec![UpdateConfigEnum::UpdateRewardSystem { reward_system }],
+        )
+        .unwrap();
+
+        // Discounts are bounded and the perks must be the ones of an existing tier
+        let partner_collection = |discount_percent: u64, vip_level: Option<u32>| {
+            UpdateConfigEnum::SetPartnerCollection {
+                partner_collection: PartnerCollection {
+                    nft_collection_address: partner_nft_collection.clone(),
+                    marketplace_fees_discount: Decimal::percent(discount_percent),
+                    vip_level: vip_level.map(VipLevel),
+                },
+            }
+        };
+        for (update, error) in [
+            (partner_collection(60, None), "InvalidPartnerCollection"),
+            (partner_collection(0, None), "InvalidPartnerCollection"),
+            (partner_collection(10, Some(3)), "InvalidPartnerCollection"),
+            (
+                UpdateConfigEnum::UpdatePerksCombination {
+                    perks_combination: PerksCombination::Sum {
+                        max_marketplace_fees_discount: Decimal::percent(60),
+                    },
+                },
+                "InvalidPerksCombination",
+            ),
+        ] {
+            let execute_output = marketplace_test_exec_update_config(
+                &mut app,
+                &nft_marketplace_smart_contract_addr,
+                mock_info(OWNER, &[]),
+                vec![update],
+            );
+            assert_eq!(
+                execute_output.unwrap_err().root_cause().to_string(),
+                error.to_string()
+            );
+        }
+        marketplace_test_exec_update_config(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(OWNER, &[]),
+            vec![partner_collection(10, Some(2))],
+        )
+        .unwrap();
+        let partner_perks =
+            marketplace_test_query_get_partner_perks(&app, &nft_marketplace_smart_contract_addr);
+        assert_eq!(
+            partner_perks.partner_collections,
+            vec![
+                PartnerCollection {
+                    nft_collection_address: SANCTUARY_NFTS.to_string(),
+                    marketplace_fees_discount: Decimal::percent(10),
+                    vip_level: None,
+                },
+                PartnerCollection {
+                    nft_collection_address: partner_nft_collection.clone(),
+                    marketplace_fees_discount: Decimal::percent(10),
+                    vip_level: Some(VipLevel(2)),
+                },
+            ]
+        );
+        assert_eq!(partner_perks.perks_combination, PerksCombination::Best);
+
+        // No perks until a token of the collection is held
+        let holder_perks = marketplace_test_query_get_holder_perks(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            WALLET2.to_string(),
+        );
+        assert_eq!(holder_perks.marketplace_fees_discount, Decimal::zero());
+        assert_eq!(holder_perks.perks_vip_level, VipLevel(0));
+        cw2981_multi_test_exec_mint(
+            &mut app,
+            &Addr::unchecked(partner_nft_collection.clone()),
+            mock_info(OWNER, &[]),
+            "Partner1".to_string(),
+            WALLET2.to_string(),
+            None,
+        )
+        .unwrap();
+        let holder_perks = marketplace_test_query_get_holder_perks(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            WALLET2.to_string(),
+        );
+        assert_eq!(
+            holder_perks.partner_collections,
+            vec![partner_nft_collection.clone()]
+        );
+        assert_eq!(holder_perks.marketplace_fees_discount, Decimal::percent(10));
+        assert_eq!(holder_perks.perks_vip_level, VipLevel(2));
+
+        // The description is a Gold perk, unlocked by the holding on a base level profile
+        marketplace_test_exec_create_my_profile(
+            &mut app,
+            nft_marketplace_smart_contract_addr.to_string(),
+            mock_info(WALLET2, &[]),
+            None,
+        )
+        .unwrap();
+        let mut new_profile = Profile::new(WALLET2.to_string());
+        new_profile.description = Some("Partner holder".to_string());
+        marketplace_test_exec_update_my_profile(
+            &mut app,
+            nft_marketplace_smart_contract_addr.to_string(),
+            mock_info(WALLET2, &[]),
+            new_profile,
+            ProfileUpdateAction::Add,
+        )
+        .unwrap();
+        let profile = marketplace_test_query_get_profile_info(
+            &app,
+            nft_marketplace_smart_contract_addr.to_string(),
+            WALLET2.to_string(),
+        )
+        .unwrap();
+        assert_eq!(profile.vip_level, Some(VipLevel(0)));
+        assert_eq!(profile.description, Some("Partner holder".to_string()));
+
+        // Member gives 5%: the best one is kept, the sum is capped at 12%
+        marketplace_test_exec_lvl_up_profile(
+            &mut app,
+            mock_info(WALLET2, &coins(1_000u128, REWARD_TOKEN)),
+            &nft_marketplace_smart_contract_addr,
+        )
+        .unwrap();
+        let holder_perks = marketplace_test_query_get_holder_perks(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            WALLET2.to_string(),
+        );
+        assert_eq!(holder_perks.vip_level, VipLevel(1));
+        assert_eq!(holder_perks.marketplace_fees_discount, Decimal::percent(10));
+        marketplace_test_exec_update_config(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(OWNER, &[]),
+            vec![UpdateConfigEnum::UpdatePerksCombination {
+                perks_combination: PerksCombination::Sum {
+                    max_marketplace_fees_discount: Decimal::percent(12),
+                },
+            }],
+        )
+        .unwrap();
+        let holder_perks = marketplace_test_query_get_holder_perks(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            WALLET2.to_string(),
+        );
+        assert_eq!(holder_perks.marketplace_fees_discount, Decimal::percent(12));
+
+        // WALLET2 buys with 12% off the 4.2% marketplace fees
+        marketplace_test_exec_add_nft_code_id(
+            &mut app,
+            nft_marketplace_smart_contract_addr.to_string(),
+            vec![nft_contract_info.clone()],
+            mock_info(OWNER, &[]),
+        )
+        .unwrap();
+        for token_id in ["Token1", "Token2"] {
+            cw2981_multi_test_exec_mint(
+                &mut app,
+                &Addr::unchecked(nft_collection.clone()),
+                mock_info(OWNER, &[]),
+                token_id.to_string(),
+                OWNER.to_string(),
+                None,
+            )
+            .unwrap();
+        }
+        marketplace_test_exec_add_new_collection(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(OWNER, &[]),
+            nft_collection.clone(),
+            nft_contract_info,
+        )
+        .unwrap();
+        let info = mock_info(OWNER, &coins(6_900_000u128, UHUAHUA));
+        let sale_expiration = app.block_info().time.plus_seconds(20 * DAY);
+        for token_id in ["Token1", "Token2"] {
+            cw2981_multi_test_exec_approve(
+                &mut app,
+                &Addr::unchecked(nft_collection.clone()),
+                &nft_marketplace_smart_contract_addr,
+                info.clone(),
+                token_id.to_string(),
+                sale_expiration.seconds(),
+            )
+            .unwrap();
+            marketplace_test_exec_sell_nft(
+                &mut app,
+                &nft_marketplace_smart_contract_addr,
+                info.clone(),
+                NftSale {
+                    seller: OWNER.to_string(),
+                    nft_collection_address: nft_collection.clone(),
+                    token_id: token_id.to_string(),
+                    sale_price_value: Uint128::new(PRICE),
+                    sale_price_denom: UHUAHUA.to_string(),
+                    sale_expiration,
+                },
+            )
+            .unwrap();
+        }
+        let wallet2_balance = app.wrap().query_balance(WALLET2, UHUAHUA).unwrap().amount;
+        marketplace_test_exec_buy_nft(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(WALLET2, &coins(PRICE, UHUAHUA)),
+            nft_collection.clone(),
+            "Token1".to_string(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(
+            app.wrap().query_balance(WALLET2, UHUAHUA).unwrap().amount,
+            wallet2_balance - Uint128::new(PRICE) + Uint128::new(504_000u128)
+        );
+
+        // The partner token is sent away: the next trade only has the 5% of the VIP level
+        cw2981_multi_test_exec_transfer_nft(
+            &mut app,
+            &Addr::unchecked(partner_nft_collection.clone()),
+            &nft_marketplace_smart_contract_addr,
+            mock_info(WALLET2, &[]),
+            "Partner1".to_string(),
+            OWNER.to_string(),
+        )
+        .unwrap();
+        let holder_perks = marketplace_test_query_get_holder_perks(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            WALLET2.to_string(),
+        );
+        assert!(holder_perks.partner_collections.is_empty());
+        assert_eq!(holder_perks.marketplace_fees_discount, Decimal::percent(5));
+        let wallet2_balance = app.wrap().query_balance(WALLET2, UHUAHUA).unwrap().amount;
+        marketplace_test_exec_buy_nft(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(WALLET2, &coins(PRICE, UHUAHUA)),
+            nft_collection,
+            "Token2".to_string(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(
+            app.wrap().query_balance(WALLET2, UHUAHUA).unwrap().amount,
+            wallet2_balance - Uint128::new(PRICE) + Uint128::new(210_000u128)
+        );
+        cw2981_multi_test_exec_transfer_nft(
+            &mut app,
+            &Addr::unchecked(partner_nft_collection.clone()),
+            &nft_marketplace_smart_contract_addr,
+            mock_info(OWNER, &[]),
+            "Partner1".to_string(),
+            WALLET2.to_string(),
+        )
+        .unwrap();
+
+        // Back to the VIP level once the collection is removed
+        marketplace_test_exec_update_config(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(OWNER, &[]),
+            vec![UpdateConfigEnum::RemovePartnerCollection {
+                nft_collection_address: partner_nft_collection,
+            }],
+        )
+        .unwrap();
+        let holder_perks = marketplace_test_query_get_holder_perks(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            WALLET2.to_string(),
+        );
+        assert!(holder_perks.partner_collections.is_empty());
+        assert_eq!(holder_perks.marketplace_fees_discount, Decimal::percent(5));
+        assert_eq!(holder_perks.perks_vip_level, VipLevel(1));
+    }
+}
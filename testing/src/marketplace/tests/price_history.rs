@@ -0,0 +1,448 @@
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::mock_info;
+    use cosmwasm_std::{coins, Addr, Timestamp, Uint128};
+
+    use nft_marketplace_utils::nft_collection::{
+        NftCollectionAddress, NftContractInfo, NftContractType,
+    };
+    use nft_marketplace_utils::nft_sale::NftSale;
+    use nft_marketplace_utils::price_history::RollingWindow;
+
+    use crate::common::utils::constants::{OWNER, WALLET2};
+    use crate::common::utils::utils_common::tests::{instantiate_necessary_for_tests, App};
+    use crate::common::utils::utils_marketplace_contract_test::tests::{
+        marketplace_test_exec_add_new_collection, marketplace_test_exec_add_nft_code_id,
+        marketplace_test_exec_buy_nft, marketplace_test_exec_enable_disable,
+        marketplace_test_exec_sell_nft, marketplace_test_query_get_coll_daily_price_history,
+        marketplace_test_query_get_config, marketplace_test_query_get_nft_coll_rolling_stats,
+    };
+    use crate::common::utils::utils_nft_contract_test::tests::{
+        cw2981_multi_test_exec_approve, cw2981_multi_test_exec_mint,
+    };
+
+    // The NFT code id needs to be accepted first
+    fn mint_and_add_collection(
+        app: &mut App,
+        nft_marketplace_smart_contract_addr: &Addr,
+        nft_collection: &str,
+        code_id: u64,
+        token_ids: &[&str],
+    ) {
+        let info = mock_info(OWNER, &[]);
+        let nft_contract_info = NftContractInfo {
+            code_id,
+            nft_contract_type: NftContractType::Cw2981MultiRoyalties,
+        };
+        for token_id in token_ids {
+            cw2981_multi_test_exec_mint(
+                app,
+                &Addr::unchecked(nft_collection),
+                info.clone(),
+                token_id.to_string(),
+                OWNER.to_string(),
+                None,
+            )
+            .unwrap();
+        }
+        marketplace_test_exec_add_new_collection(
+            app,
+            nft_marketplace_smart_contract_addr,
+            info,
+            NftCollectionAddress::from(nft_collection),
+            nft_contract_info,
+        )
+        .unwrap();
+    }
+
+    fn sell_and_buy(
+        app: &mut App,
+        nft_marketplace_smart_contract_addr: &Addr,
+        nft_collection: &str,
+        token_id: &str,
+        price: u128,
+        denom: &str,
+    ) {
+        let info = mock_info(OWNER, &coins(6_900_000u128, "uhuahua"));
+        let expiration = app.block_info().time.plus_seconds(87000u64);
+        cw2981_multi_test_exec_approve(
+            app,
+            &Addr::unchecked(nft_collection),
+            nft_marketplace_smart_contract_addr,
+            info.clone(),
+            token_id.to_string(),
+            expiration.seconds(),
+        )
+        .unwrap();
+        marketplace_test_exec_sell_nft(
+            app,
+            nft_marketplace_smart_contract_addr,
+            info,
+            NftSale {
+                seller: OWNER.to_string(),
+                nft_collection_address: nft_collection.to_string(),
+                token_id: token_id.to_string(),
+                sale_price_value: Uint128::new(price),
+                sale_price_denom: denom.to_string(),
+                sale_expiration: expiration,
+            },
+        )
+        .unwrap();
+        marketplace_test_exec_buy_nft(
+            app,
+            nft_marketplace_smart_contract_addr,
+            mock_info(WALLET2, &coins(price, denom)),
+            nft_collection.to_string(),
+            token_id.to_string(),
+            None,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_marketplace_collection_daily_price_history() {
+        // - Each sale updates the OHLC bucket of its day, collection and denom
+        // - Buckets can be paginated and filtered by time range
+        let (mut app, necessary) = instantiate_necessary_for_tests();
+        let native_huahua = necessary.native_huahua;
+        let nft_marketplace_smart_contract_addr =
+            Addr::unchecked(necessary.nft_marketplace_smart_contract_addr);
+        let nft_collection = necessary.cw2981_nft_contract_addr1;
+
+        marketplace_test_exec_enable_disable(
+            &mut app,
+            nft_marketplace_smart_contract_addr.to_string(),
+            mock_info(OWNER, &[]),
+        )
+        .unwrap();
+        marketplace_test_exec_add_nft_code_id(
+            &mut app,
+            nft_marketplace_smart_contract_addr.to_string(),
+            vec![NftContractInfo {
+                code_id: necessary.cw2981_nft_code_id,
+                nft_contract_type: NftContractType::Cw2981MultiRoyalties,
+            }],
+            mock_info(OWNER, &[]),
+        )
+        .unwrap();
+        mint_and_add_collection(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            &nft_collection,
+            necessary.cw2981_nft_code_id,
+            &["Token1", "Token2", "Token3", "Token4", "Token5"],
+        );
+
+        // Day 1: 3 sales in HUAHUA and 1 in ATOM
+        for (token_id, price) in [
+            ("Token1", 100_000_000u128),
+            ("Token2", 300_000_000u128),
+            ("Token3", 200_000_000u128),
+        ] {
+            sell_and_buy(
+                &mut app,
+                &nft_marketplace_smart_contract_addr,
+                &nft_collection,
+                token_id,
+                price,
+                &native_huahua,
+            );
+        }
+        sell_and_buy(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            &nft_collection,
+            "Token4",
+            50_000_000u128,
+            &necessary.native_atom,
+        );
+
+        // Day 2: 1 sale in HUAHUA
+        app.update_block(|block| {
+            block.time = block.time.plus_seconds(86_400u64);
+            block.height += 1;
+        });
+        sell_and_buy(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            &nft_collection,
+            "Token5",
+            150_000_000u128,
+            &native_huahua,
+        );
+
+        let day1 = Timestamp::from_seconds(1571788800u64);
+        let day2 = day1.plus_seconds(86_400u64);
+        let buckets = marketplace_test_query_get_coll_daily_price_history(
+            &app,
+            nft_marketplace_smart_contract_addr.to_string(),
+            nft_collection.clone(),
+            native_huahua.clone(),
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+        assert_eq!(buckets.len(), 2);
+        assert_eq!(buckets[0].day_start, day1);
+        assert_eq!(buckets[0].open, Uint128::new(100_000_000u128));
+        assert_eq!(buckets[0].high, Uint128::new(300_000_000u128));
+        assert_eq!(buckets[0].low, Uint128::new(100_000_000u128));
+        assert_eq!(buckets[0].close, Uint128::new(200_000_000u128));
+        assert_eq!(buckets[0].volume, Uint128::new(600_000_000u128));
+        assert_eq!(buckets[0].trades, 3);
+        assert_eq!(buckets[1].day_start, day2);
+        assert_eq!(buckets[1].open, Uint128::new(150_000_000u128));
+        assert_eq!(buckets[1].close, Uint128::new(150_000_000u128));
+        assert_eq!(buckets[1].trades, 1);
+
+        // Other denoms have their own buckets
+        let buckets = marketplace_test_query_get_coll_daily_price_history(
+            &app,
+            nft_marketplace_smart_contract_addr.to_string(),
+            nft_collection.clone(),
+            necessary.native_atom,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+        assert_eq!(buckets.len(), 1);
+        assert_eq!(buckets[0].volume, Uint128::new(50_000_000u128));
+
+        // Pagination
+        let buckets = marketplace_test_query_get_coll_daily_price_history(
+            &app,
+            nft_marketplace_smart_contract_addr.to_string(),
+            nft_collection.clone(),
+            native_huahua.clone(),
+            None,
+            None,
+            None,
+            Some(1),
+        )
+        .unwrap();
+        assert_eq!(buckets.len(), 1);
+        assert_eq!(buckets[0].day_start, day1);
+        let buckets = marketplace_test_query_get_coll_daily_price_history(
+            &app,
+            nft_marketplace_smart_contract_addr.to_string(),
+            nft_collection.clone(),
+            native_huahua.clone(),
+            None,
+            None,
+            Some(buckets[0].day_start),
+            Some(1),
+        )
+        .unwrap();
+        assert_eq!(buckets.len(), 1);
+        assert_eq!(buckets[0].day_start, day2);
+
+        // Time range
+        let buckets = marketplace_test_query_get_coll_daily_price_history(
+            &app,
+            nft_marketplace_smart_contract_addr.to_string(),
+            nft_collection.clone(),
+            native_huahua.clone(),
+            Some(day2.plus_seconds(3_600u64)),
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+        assert_eq!(buckets.len(), 1);
+        assert_eq!(buckets[0].day_start, day2);
+        let buckets = marketplace_test_query_get_coll_daily_price_history(
+            &app,
+            nft_marketplace_smart_contract_addr.to_string(),
+            nft_collection,
+            native_huahua,
+            None,
+            Some(day2.minus_seconds(1u64)),
+            None,
+            None,
+        )
+        .unwrap();
+        assert_eq!(buckets.len(), 1);
+        assert_eq!(buckets[0].day_start, day1);
+    }
+
+    #[test]
+    fn test_marketplace_collection_rolling_stats() {
+        // - 24h, 7d and 30d windows per denom, in the sale denom and in USDC
+        // - Floor change between the opening of the window and now
+        // - Trending lists only keep collections traded within the window, even without new trades
+        let (mut app, necessary) = instantiate_necessary_for_tests();
+        let native_huahua = necessary.native_huahua;
+        let nft_marketplace_smart_contract_addr =
+            Addr::unchecked(necessary.nft_marketplace_smart_contract_addr);
+        let nft_collection1 = necessary.cw2981_nft_contract_addr1;
+        let nft_collection2 = necessary.cw2981_nft_contract_addr2;
+
+        marketplace_test_exec_enable_disable(
+            &mut app,
+            nft_marketplace_smart_contract_addr.to_string(),
+            mock_info(OWNER, &[]),
+        )
+        .unwrap();
+        marketplace_test_exec_add_nft_code_id(
+            &mut app,
+            nft_marketplace_smart_contract_addr.to_string(),
+            vec![NftContractInfo {
+                code_id: necessary.cw2981_nft_code_id,
+                nft_contract_type: NftContractType::Cw2981MultiRoyalties,
+            }],
+            mock_info(OWNER, &[]),
+        )
+        .unwrap();
+        mint_and_add_collection(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            &nft_collection1,
+            necessary.cw2981_nft_code_id,
+            &["Token1", "Token2", "Token3", "Token4"],
+        );
+        mint_and_add_collection(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            &nft_collection2,
+            necessary.cw2981_nft_code_id,
+            &["Token1"],
+        );
+
+        // Day 0: 2 sales on the collection 1
+        for (token_id, price) in [("Token1", 100_000_000u128), ("Token2", 200_000_000u128)] {
+            sell_and_buy(
+                &mut app,
+                &nft_marketplace_smart_contract_addr,
+                &nft_collection1,
+                token_id,
+                price,
+                &native_huahua,
+            );
+        }
+        let general_stats =
+            marketplace_test_query_get_config(&app, &nft_marketplace_smart_contract_addr)
+                .general_stats;
+        assert_eq!(general_stats.trending_24h_volume_usdc.len(), 1);
+        assert_eq!(
+            general_stats.trending_24h_volume_usdc[0].nft_collection_address,
+            nft_collection1
+        );
+
+        // Day 2: 1 sale on each collection
+        app.update_block(|block| {
+            block.time = block.time.plus_seconds(2 * 86_400u64);
+            block.height += 1;
+        });
+        sell_and_buy(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            &nft_collection1,
+            "Token3",
+            300_000_000u128,
+            &native_huahua,
+        );
+        sell_and_buy(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            &nft_collection2,
+            "Token1",
+            50_000_000u128,
+            &native_huahua,
+        );
+        let general_stats =
+            marketplace_test_query_get_config(&app, &nft_marketplace_smart_contract_addr)
+                .general_stats;
+        assert_eq!(general_stats.trending_24h_volume_usdc.len(), 2);
+        assert_eq!(
+            general_stats.trending_24h_volume_usdc[0].nft_collection_address,
+            nft_collection1
+        );
+        assert_eq!(
+            general_stats.trending_7d_volume_usdc[0].usdc_volume,
+            general_stats.top_10_volume_usdc[0].usdc_volume
+        );
+
+        // Day 10: 1 sale on the collection 1, the collection 2 is not trending anymore
+        app.update_block(|block| {
+            block.time = block.time.plus_seconds(8 * 86_400u64);
+            block.height += 1;
+        });
+        sell_and_buy(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            &nft_collection1,
+            "Token4",
+            400_000_000u128,
+            &native_huahua,
+        );
+        let general_stats =
+            marketplace_test_query_get_config(&app, &nft_marketplace_smart_contract_addr)
+                .general_stats;
+        assert_eq!(general_stats.trending_24h_volume_usdc.len(), 1);
+        assert_eq!(general_stats.trending_7d_volume_usdc.len(), 1);
+        assert_eq!(
+            general_stats.trending_7d_volume_usdc[0].nft_collection_address,
+            nft_collection1
+        );
+
+        let rolling_stats = marketplace_test_query_get_nft_coll_rolling_stats(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            nft_collection1,
+        );
+        let huahua_stats = rolling_stats
+            .iter()
+            .find(|stats| stats.denom == native_huahua)
+            .unwrap();
+        let last_24h = &huahua_stats.windows[0];
+        let last_7d = &huahua_stats.windows[1];
+        let last_30d = &huahua_stats.windows[2];
+        assert_eq!(last_24h.window, RollingWindow::Last24h);
+        assert_eq!(last_24h.volume, Uint128::new(400_000_000u128));
+        assert_eq!(last_24h.trades, 1);
+        assert_eq!(last_24h.opening_floor, Uint128::new(400_000_000u128));
+        assert_eq!(last_24h.current_floor, Uint128::zero());
+        assert_eq!(last_7d.volume, Uint128::new(400_000_000u128));
+        assert_eq!(last_7d.trades, 1);
+        assert_eq!(last_30d.volume, Uint128::new(1_000_000_000u128));
+        assert_eq!(last_30d.trades, 4);
+        assert_eq!(last_30d.opening_floor, Uint128::new(100_000_000u128));
+        assert!(!last_24h.volume_usdc.is_zero());
+        assert_eq!(
+            last_30d.volume_usdc,
+            last_24h.volume_usdc * Uint128::new(10u128) / Uint128::new(4u128)
+        );
+        assert_eq!(
+            general_stats.trending_24h_volume_usdc[0].usdc_volume,
+            last_24h.volume_usdc
+        );
+
+        // Day 12: no trade, the collection 1 is out of the 24h list and keeps its 7d volume
+        app.update_block(|block| {
+            block.time = block.time.plus_seconds(2 * 86_400u64);
+            block.height += 1;
+        });
+        let general_stats =
+            marketplace_test_query_get_config(&app, &nft_marketplace_smart_contract_addr)
+                .general_stats;
+        assert!(general_stats.trending_24h_volume_usdc.is_empty());
+        assert_eq!(
+            general_stats.trending_7d_volume_usdc[0].usdc_volume,
+            last_7d.volume_usdc
+        );
+
+        // Day 20: no trade in the last 7 days
+        app.update_block(|block| {
+            block.time = block.time.plus_seconds(8 * 86_400u64);
+            block.height += 1;
+        });
+        let general_stats =
+            marketplace_test_query_get_config(&app, &nft_marketplace_smart_contract_addr)
+                .general_stats;
+        assert!(general_stats.trending_7d_volume_usdc.is_empty());
+    }
+}
@@ -3,9 +3,7 @@ mod tests {
     use cosmwasm_std::testing::{mock_dependencies, mock_info};
     use cosmwasm_std::{Addr, Coin, Uint128};
 
-    use nft_marketplace_utils::profile::{
-        NftShowcase, Profile, ProfileMessage, ProfileMessages, ProfileUpdateAction, Socials,
-    };
+    use nft_marketplace_utils::profile::{NftShowcase, Profile, ProfileUpdateAction, Socials};
     use nft_marketplace_utils::reward_system::VipLevel;
 
     use crate::common::utils::constants::{INVALID_REWARD_TOKEN, OWNER, REWARD_TOKEN, WALLET2, WALLET3};
@@ -13,7 +11,8 @@ mod tests {
     use crate::common::utils::utils_marketplace_contract_test::tests::{
         marketplace_test_exec_create_my_profile, marketplace_test_exec_enable_disable,
         marketplace_test_exec_lvl_up_profile, marketplace_test_exec_send_message,
-        marketplace_test_exec_update_my_profile, marketplace_test_query_get_profile_info,
+        marketplace_test_exec_update_my_profile, marketplace_test_query_get_inbox,
+        marketplace_test_query_get_profile_info,
     };
     use crate::common::utils::utils_nft_contract_test::tests::cw2981_multi_test_exec_mint;
 
@@ -102,7 +101,7 @@ mod tests {
         )
         .unwrap();
         assert_eq!(execute_output.address, Addr::unchecked(WALLET3));
-        assert_eq!(execute_output.vip_level, Some(VipLevel::Level0));
+        assert_eq!(execute_output.vip_level, Some(VipLevel(0)));
         assert_eq!(execute_output.profile_nft_collection, None);
         assert_eq!(execute_output.profile_nft_token_id, None);
         assert_eq!(execute_output.background_nft_collection, None);
@@ -116,6 +115,8 @@ mod tests {
             address: "".to_string(),
             username: None,
             vip_level: None,
+            vip_expiration: None,
+            volume_usdc: None,
             profile_nft_collection: None,
             profile_nft_token_id: None,
             background_nft_collection: None,
@@ -123,7 +124,6 @@ mod tests {
             description: None,
             nft_showcase: None,
             links: None,
-            profile_messages: None,
             number_of_trades: None,
             buy_info: None,
             sell_info: None,
@@ -159,14 +159,6 @@ mod tests {
         wallet3_profile.profile_nft_collection = Some(_cw721_base_smart_contract_addr1.to_string());
         wallet3_profile.profile_nft_token_id = Some("Token1".to_string());
         wallet3_profile.description = Some("HELLO".to_string());
-        wallet3_profile.profile_messages = Some(ProfileMessages {
-            display_on_profile: true,
-            messages: vec![ProfileMessage {
-                from_address: "HELLO".to_string(),
-                from_username: None,
-                message: "".to_string(),
-            }],
-        });
         let execute_output = marketplace_test_exec_update_my_profile(
             &mut app,
             nft_marketplace_smart_contract_addr.to_string(),
@@ -193,48 +185,6 @@ mod tests {
             Some("Token1".to_string())
         );
         assert_eq!(profile_info_output.description, None);
-        // Messages cannot be sent that way
-        assert_eq!(
-            profile_info_output.profile_messages,
-            Some(ProfileMessages {
-                display_on_profile: true,
-                messages: vec![]
-            })
-        );
-
-        // Change the display of p[rofiel back to false
-        let info = mock_info(WALLET3, &[]);
-        wallet3_profile.profile_messages = Some(ProfileMessages {
-            display_on_profile: false,
-            messages: vec![ProfileMessage {
-                from_address: "HELLO".to_string(),
-                from_username: None,
-                message: "".to_string(),
-            }],
-        });
-        let execute_output = marketplace_test_exec_update_my_profile(
-            &mut app,
-            nft_marketplace_smart_contract_addr.to_string(),
-            info,
-            wallet3_profile.clone(),
-            ProfileUpdateAction::Add,
-        );
-        assert!(execute_output.is_ok());
-
-        // Validate the updated value
-        let profile_info_output = marketplace_test_query_get_profile_info(
-            &app,
-            nft_marketplace_smart_contract_addr.to_string(),
-            WALLET3.to_string(),
-        )
-        .unwrap();
-        assert_eq!(
-            profile_info_output.profile_messages,
-            Some(ProfileMessages {
-                display_on_profile: false,
-                messages: vec![]
-            })
-        );
 
         // Remove the info
         let info = mock_info(WALLET3, &[]);
@@ -329,7 +279,7 @@ mod tests {
             WALLET3.to_string(),
         )
         .unwrap();
-        assert_eq!(profile_info_output.vip_level, Some(VipLevel::Level1));
+        assert_eq!(profile_info_output.vip_level, Some(VipLevel(1)));
         assert_eq!(
             profile_info_output.background_nft_collection,
             Some(_cw721_base_smart_contract_addr1.to_string())
@@ -564,6 +514,28 @@ mod tests {
 
         // Now try to send messages
         // Need to check when sending to a username so we need to set a username and the other not
+        // The sender needs a profile
+        let info = mock_info(OWNER, &[]);
+        let execute_output = marketplace_test_exec_send_message(
+            &mut app,
+            nft_marketplace_smart_contract_addr.to_string(),
+            info,
+            WALLET3.to_string(),
+            "Hello mom!".to_string(),
+        );
+        assert_eq!(
+            execute_output.unwrap_err().source().unwrap().to_string(),
+            "ThisProfileDoesNotExist".to_string()
+        );
+        let info = mock_info(OWNER, &[]);
+        let execute_output = marketplace_test_exec_create_my_profile(
+            &mut app,
+            nft_marketplace_smart_contract_addr.to_string(),
+            info,
+            None,
+        );
+        assert!(execute_output.is_ok());
+
         let info = mock_info(OWNER, &[]);
         let execute_output = marketplace_test_exec_send_message(
             &mut app,
@@ -592,26 +564,27 @@ mod tests {
         )
         .unwrap();
         assert_eq!(profile_info_output.username, Some("BITCOIN".to_string()));
-        assert_eq!(profile_info_output.vip_level, Some(VipLevel::Level3));
+        assert_eq!(profile_info_output.vip_level, Some(VipLevel(3)));
         assert_eq!(profile_info_output.description, None);
+        // Messages are in the inbox, newest first
+        let inbox = marketplace_test_query_get_inbox(
+            &app,
+            nft_marketplace_smart_contract_addr.to_string(),
+            WALLET3.to_string(),
+            None,
+            None,
+        );
         assert_eq!(
-            profile_info_output.profile_messages,
-            Some(ProfileMessages {
-                display_on_profile: false,
-                messages: vec![
-                    ProfileMessage {
-                        from_address: OWNER.to_string(),
-                        from_username: None,
-                        message: "Hello mom!".to_string(),
-                    },
-                    ProfileMessage {
-                        from_address: OWNER.to_string(),
-                        from_username: None,
-                        message: "Hello dad!".to_string(),
-                    },
-                ],
-            })
+            inbox
+                .iter()
+                .map(|message| (message.from_address.clone(), message.message.clone()))
+                .collect::<Vec<_>>(),
+            vec![
+                (OWNER.to_string(), "Hello dad!".to_string()),
+                (OWNER.to_string(), "Hello mom!".to_string()),
+            ]
         );
+        assert_eq!(inbox[0].from_username, None);
 
         let info = mock_info(WALLET3, &[]);
         let execute_output = marketplace_test_exec_send_message(
@@ -624,27 +597,19 @@ mod tests {
         assert!(execute_output.is_ok());
 
         // Validate the updated value
-        let profile_info_output = marketplace_test_query_get_profile_info(
+        let inbox = marketplace_test_query_get_inbox(
             &app,
             nft_marketplace_smart_contract_addr.to_string(),
             OWNER.to_string(),
-        )
-        .unwrap();
-        assert_eq!(profile_info_output.username, None);
-        assert_eq!(
-            profile_info_output.profile_messages,
-            Some(ProfileMessages {
-                display_on_profile: true,
-                messages: vec![ProfileMessage {
-                    from_address: WALLET3.to_string(),
-                    from_username: Some("BITCOIN".to_string()),
-                    message: "Hey son!".to_string(),
-                }],
-            })
-        );
-
-        // THere's 1 msg alreayd and we are adding 11 of them so 2 msgs are popped out and
-        //  it should remain from #3 and onward
+            None,
+            None,
+        );
+        assert_eq!(inbox.len(), 1);
+        assert_eq!(inbox[0].from_address, WALLET3.to_string());
+        assert_eq!(inbox[0].from_username, Some("BITCOIN".to_string()));
+        assert_eq!(inbox[0].message, "Hey son!".to_string());
+
+        // THere's 1 msg already and we are adding 11 of them, the inbox keeps all of them
         for i in 2..=12 {
             let mut msg_to_send: String = "Hey son!".to_string().to_owned();
             msg_to_send.push_str(&i.to_string());
@@ -660,21 +625,16 @@ mod tests {
         }
 
         // Validate the updated value
-        let profile_info_output = marketplace_test_query_get_profile_info(
+        let inbox = marketplace_test_query_get_inbox(
             &app,
             nft_marketplace_smart_contract_addr.to_string(),
             OWNER.to_string(),
-        )
-        .unwrap();
-        assert_eq!(profile_info_output.username, None);
-        assert_eq!(
-            profile_info_output.profile_messages.unwrap().messages[0],
-            ProfileMessage {
-                from_address: WALLET3.to_string(),
-                from_username: Some("BITCOIN".to_string()),
-                message: "Hey son!3".to_string(),
-            }
+            None,
+            None,
         );
+        assert_eq!(inbox.len(), 12);
+        assert_eq!(inbox[0].message, "Hey son!12".to_string());
+        assert_eq!(inbox[11].message, "Hey son!".to_string());
 
         // Check if one can query the profile using the username
         let profile_info_output = marketplace_test_query_get_profile_info(
@@ -690,6 +650,8 @@ mod tests {
             address: "".to_string(),
             username: None,
             vip_level: None,
+            vip_expiration: None,
+            volume_usdc: None,
             profile_nft_collection: None,
             profile_nft_token_id: None,
             background_nft_collection: None,
@@ -702,7 +664,6 @@ mod tests {
                 telegram_link: None,
                 additional_social_link: None,
             }),
-            profile_messages: None,
             number_of_trades: None,
             buy_info: None,
             sell_info: None,
@@ -743,6 +704,8 @@ mod tests {
             address: "".to_string(),
             username: None,
             vip_level: None,
+            vip_expiration: None,
+            volume_usdc: None,
             profile_nft_collection: None,
             profile_nft_token_id: None,
             background_nft_collection: None,
@@ -755,7 +718,6 @@ mod tests {
                 telegram_link: None,
                 additional_social_link: None,
             }),
-            profile_messages: None,
             number_of_trades: None,
             buy_info: None,
             sell_info: None,
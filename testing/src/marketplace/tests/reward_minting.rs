@@ -0,0 +1,344 @@
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::mock_info;
+    use cosmwasm_std::{coins, Addr, Uint128};
+
+    use chihuahua_nft_marketplace::msg::UpdateConfigEnum;
+    use nft_marketplace_utils::nft_collection::{NftContractInfo, NftContractType};
+    use nft_marketplace_utils::nft_sale::NftSale;
+
+    use crate::common::utils::constants::{
+        OWNER, REWARD_TOKEN, UHUAHUA, WALLET2, WALLET3, WALLET4,
+    };
+    use crate::common::utils::utils_common::tests::{instantiate_necessary_for_tests, App};
+    use crate::common::utils::utils_marketplace_contract_test::tests::{
+        marketplace_test_exec_add_new_collection, marketplace_test_exec_add_nft_code_id,
+        marketplace_test_exec_buy_nft, marketplace_test_exec_claim_rewards,
+        marketplace_test_exec_enable_disable, marketplace_test_exec_fund_reward_pool,
+        marketplace_test_exec_sell_nft, marketplace_test_exec_update_config,
+        marketplace_test_query_get_config, marketplace_test_query_get_reward_minting,
+        marketplace_test_query_get_reward_pool, marketplace_test_query_get_rewards_to_claim,
+    };
+    use crate::common::utils::utils_nft_contract_test::tests::{
+        cw2981_multi_test_exec_approve, cw2981_multi_test_exec_mint,
+    };
+
+    // 12_000$USDC per sale, 12_000_000_000 reward tokens per address
+    const PRICE: u128 = 100_000_000_000_000u128;
+    const REWARDS_PER_ADDRESS: u128 = 12_000_000_000u128;
+    const INITIAL_POOL: u128 = 100_000_000_000_000_000_000u128;
+
+    #[test]
+    fn test_marketplace_reward_minting() {
+        // - Enabling the minting is rejected while rewards are still to claim
+        // - Enabling the minting creates the factory denom and makes it the reward token, what is
+        //   left of the pre-funded pool goes back to the owner
+        // - Rewards of a trade are minted to the marketplace up to the emission cap
+        // - Minted rewards are paid out by ClaimRewards
+        let (mut app, necessary) = instantiate_necessary_for_tests();
+        let nft_marketplace_smart_contract_addr =
+            Addr::unchecked(necessary.nft_marketplace_smart_contract_addr);
+        let nft_collection = necessary.cw2981_nft_contract_addr1;
+        let nft_contract_info = NftContractInfo {
+            code_id: necessary.cw2981_nft_code_id,
+            nft_contract_type: NftContractType::Cw2981MultiRoyalties,
+        };
+        let factory_denom = format!("factory/{nft_marketplace_smart_contract_addr}/uwoof");
+        let balance = |app: &App, address: &str, denom: &str| {
+            app.wrap().query_balance(address, denom).unwrap().amount
+        };
+        let rewards_to_claim = |app: &App, address: &str| {
+            marketplace_test_query_get_rewards_to_claim(
+                app,
+                &nft_marketplace_smart_contract_addr,
+                address.to_string(),
+            )
+        };
+        let update_config = |app: &mut App, sender: &str, update: UpdateConfigEnum| {
+            marketplace_test_exec_update_config(
+                app,
+                &nft_marketplace_smart_contract_addr,
+                mock_info(sender, &[]),
+                vec![update],
+            )
+        };
+        let buy_nft = |app: &mut App, buyer: &str, token_id: &str| {
+            marketplace_test_exec_buy_nft(
+                app,
+                &nft_marketplace_smart_contract_addr,
+                mock_info(buyer, &coins(PRICE, UHUAHUA)),
+                nft_collection.clone(),
+                token_id.to_string(),
+                None,
+            )
+            .unwrap();
+        };
+
+        marketplace_test_exec_enable_disable(
+            &mut app,
+            nft_marketplace_smart_contract_addr.to_string(),
+            mock_info(OWNER, &[]),
+        )
+        .unwrap();
+        marketplace_test_exec_add_nft_code_id(
+            &mut app,
+            nft_marketplace_smart_contract_addr.to_string(),
+            vec![nft_contract_info.clone()],
+            mock_info(OWNER, &[]),
+        )
+        .unwrap();
+        for token_id in ["Token1", "Token2", "Token3"] {
+            cw2981_multi_test_exec_mint(
+                &mut app,
+                &Addr::unchecked(nft_collection.clone()),
+                mock_info(OWNER, &[]),
+                token_id.to_string(),
+                OWNER.to_string(),
+                None,
+            )
+            .unwrap();
+        }
+        marketplace_test_exec_add_new_collection(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(OWNER, &[]),
+            nft_collection.clone(),
+            nft_contract_info,
+        )
+        .unwrap();
+        let info = mock_info(OWNER, &coins(6_900_000u128, UHUAHUA));
+        let sale_expiration = app.block_info().time.plus_seconds(87000u64);
+        for token_id in ["Token1", "Token2", "Token3"] {
+            cw2981_multi_test_exec_approve(
+                &mut app,
+                &Addr::unchecked(nft_collection.clone()),
+                &nft_marketplace_smart_contract_addr,
+                info.clone(),
+                token_id.to_string(),
+                sale_expiration.seconds(),
+            )
+            .unwrap();
+            marketplace_test_exec_sell_nft(
+                &mut app,
+                &nft_marketplace_smart_contract_addr,
+                info.clone(),
+                NftSale {
+                    seller: OWNER.to_string(),
+                    nft_collection_address: nft_collection.clone(),
+                    token_id: token_id.to_string(),
+                    sale_price_value: Uint128::new(PRICE),
+                    sale_price_denom: UHUAHUA.to_string(),
+                    sale_expiration,
+                },
+            )
+            .unwrap();
+        }
+
+        // A trade paid by the pre-funded pool
+        buy_nft(&mut app, WALLET2, "Token1");
+        assert_eq!(
+            marketplace_test_query_get_reward_pool(&app, &nft_marketplace_smart_contract_addr)
+                .total_to_claim,
+            Uint128::new(2 * REWARDS_PER_ADDRESS)
+        );
+
+        // Enabling, only the admin with a valid subdenom once the pending rewards are claimed
+        assert_eq!(
+            marketplace_test_query_get_reward_minting(&app, &nft_marketplace_smart_contract_addr),
+            None
+        );
+        let execute_output = update_config(
+            &mut app,
+            OWNER,
+            UpdateConfigEnum::UpdateRewardEmissionCap {
+                emission_cap: Uint128::new(1u128),
+            },
+        );
+        assert_eq!(
+            execute_output.unwrap_err().root_cause().to_string(),
+            "RewardMintingNotEnabled".to_string()
+        );
+        update_config(
+            &mut app,
+            WALLET2,
+            UpdateConfigEnum::EnableRewardMinting {
+                subdenom: "uwoof".to_string(),
+                emission_cap: Uint128::new(5 * REWARDS_PER_ADDRESS),
+            },
+        )
+        .unwrap_err();
+        let execute_output = update_config(
+            &mut app,
+            OWNER,
+            UpdateConfigEnum::EnableRewardMinting {
+                subdenom: "u/woof".to_string(),
+                emission_cap: Uint128::new(5 * REWARDS_PER_ADDRESS),
+            },
+        );
+        assert_eq!(
+            execute_output.unwrap_err().root_cause().to_string(),
+            "InvalidRewardMinting".to_string()
+        );
+        let execute_output = update_config(
+            &mut app,
+            OWNER,
+            UpdateConfigEnum::EnableRewardMinting {
+                subdenom: "uwoof".to_string(),
+                emission_cap: Uint128::new(3 * REWARDS_PER_ADDRESS),
+            },
+        );
+        assert_eq!(
+            execute_output.unwrap_err().root_cause().to_string(),
+            "RewardsStillToClaim".to_string()
+        );
+
+        // The rewards credited before the minting are claimed in the pre-funded token
+        for address in [WALLET2, OWNER] {
+            let address_balance = balance(&app, address, REWARD_TOKEN);
+            marketplace_test_exec_claim_rewards(
+                &mut app,
+                &nft_marketplace_smart_contract_addr,
+                mock_info(address, &[]),
+            )
+            .unwrap();
+            assert_eq!(
+                balance(&app, address, REWARD_TOKEN),
+                address_balance + Uint128::new(REWARDS_PER_ADDRESS)
+            );
+        }
+
+        let owner_balance = balance(&app, OWNER, REWARD_TOKEN);
+        update_config(
+            &mut app,
+            OWNER,
+            UpdateConfigEnum::EnableRewardMinting {
+                subdenom: "uwoof".to_string(),
+                emission_cap: Uint128::new(3 * REWARDS_PER_ADDRESS),
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            balance(&app, OWNER, REWARD_TOKEN),
+            owner_balance + Uint128::new(INITIAL_POOL - 2 * REWARDS_PER_ADDRESS)
+        );
+        assert_eq!(
+            balance(
+                &app,
+                nft_marketplace_smart_contract_addr.as_str(),
+                REWARD_TOKEN
+            ),
+            Uint128::zero()
+        );
+        assert_eq!(
+            marketplace_test_query_get_config(
+                &app,
+                nft_marketplace_smart_contract_addr.to_string()
+            )
+            .reward_system
+            .reward_token_address,
+            factory_denom
+        );
+        let reward_pool =
+            marketplace_test_query_get_reward_pool(&app, &nft_marketplace_smart_contract_addr);
+        assert_eq!(reward_pool.balance, Uint128::zero());
+        assert_eq!(reward_pool.total_to_claim, Uint128::zero());
+        let reward_minting =
+            marketplace_test_query_get_reward_minting(&app, &nft_marketplace_smart_contract_addr)
+                .unwrap();
+        assert_eq!(reward_minting.denom, factory_denom);
+        assert_eq!(reward_minting.total_minted, Uint128::zero());
+
+        // Enabled once, the pool can't be funded anymore
+        let execute_output = update_config(
+            &mut app,
+            OWNER,
+            UpdateConfigEnum::EnableRewardMinting {
+                subdenom: "uwoof2".to_string(),
+                emission_cap: Uint128::new(5 * REWARDS_PER_ADDRESS),
+            },
+        );
+        assert_eq!(
+            execute_output.unwrap_err().root_cause().to_string(),
+            "RewardMintingAlreadyEnabled".to_string()
+        );
+        let execute_output = marketplace_test_exec_fund_reward_pool(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(OWNER, &coins(1_000u128, REWARD_TOKEN)),
+        );
+        assert_eq!(
+            execute_output.unwrap_err().root_cause().to_string(),
+            "RewardMintingAlreadyEnabled".to_string()
+        );
+
+        // Minted trades, the last one only gets what is left under the cap
+        buy_nft(&mut app, WALLET3, "Token2");
+        assert_eq!(
+            rewards_to_claim(&app, WALLET3),
+            Uint128::new(REWARDS_PER_ADDRESS)
+        );
+        buy_nft(&mut app, WALLET4, "Token3");
+        assert_eq!(
+            rewards_to_claim(&app, WALLET4),
+            Uint128::new(REWARDS_PER_ADDRESS)
+        );
+        assert_eq!(
+            rewards_to_claim(&app, OWNER),
+            Uint128::new(REWARDS_PER_ADDRESS)
+        );
+        assert_eq!(
+            marketplace_test_query_get_reward_minting(&app, &nft_marketplace_smart_contract_addr)
+                .unwrap()
+                .total_minted,
+            Uint128::new(3 * REWARDS_PER_ADDRESS)
+        );
+        assert_eq!(
+            balance(
+                &app,
+                nft_marketplace_smart_contract_addr.as_str(),
+                &factory_denom
+            ),
+            Uint128::new(3 * REWARDS_PER_ADDRESS)
+        );
+
+        // The cap can't go below what was minted
+        let execute_output = update_config(
+            &mut app,
+            OWNER,
+            UpdateConfigEnum::UpdateRewardEmissionCap {
+                emission_cap: Uint128::new(REWARDS_PER_ADDRESS),
+            },
+        );
+        assert_eq!(
+            execute_output.unwrap_err().root_cause().to_string(),
+            "RewardEmissionCapBelowMinted".to_string()
+        );
+        update_config(
+            &mut app,
+            OWNER,
+            UpdateConfigEnum::UpdateRewardEmissionCap {
+                emission_cap: Uint128::new(10 * REWARDS_PER_ADDRESS),
+            },
+        )
+        .unwrap();
+
+        // Claims in the minted token
+        for (address, rewards) in [(OWNER, 1u128), (WALLET4, 1u128)] {
+            marketplace_test_exec_claim_rewards(
+                &mut app,
+                &nft_marketplace_smart_contract_addr,
+                mock_info(address, &[]),
+            )
+            .unwrap();
+            assert_eq!(
+                balance(&app, address, &factory_denom),
+                Uint128::new(rewards * REWARDS_PER_ADDRESS)
+            );
+        }
+        assert_eq!(
+            marketplace_test_query_get_reward_pool(&app, &nft_marketplace_smart_contract_addr)
+                .total_to_claim,
+            Uint128::new(REWARDS_PER_ADDRESS)
+        );
+    }
+}
@@ -0,0 +1,273 @@
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::mock_info;
+    use cosmwasm_std::{coins, Addr, Uint128};
+
+    use chihuahua_nft_marketplace::msg::UpdateConfigEnum;
+    use nft_marketplace_utils::nft_collection::{NftContractInfo, NftContractType};
+    use nft_marketplace_utils::nft_sale::NftSale;
+
+    use crate::common::utils::constants::{OWNER, REWARD_TOKEN, UHUAHUA, WALLET2, WALLET3};
+    use crate::common::utils::utils_common::tests::{instantiate_necessary_for_tests, App};
+    use crate::common::utils::utils_marketplace_contract_test::tests::{
+        marketplace_test_exec_add_new_collection, marketplace_test_exec_add_nft_code_id,
+        marketplace_test_exec_buy_nft, marketplace_test_exec_claim_rewards,
+        marketplace_test_exec_enable_disable, marketplace_test_exec_fund_reward_pool,
+        marketplace_test_exec_sell_nft, marketplace_test_exec_update_config,
+        marketplace_test_query_get_config, marketplace_test_query_get_reward_pool,
+        marketplace_test_query_get_rewards_to_claim,
+    };
+    use crate::common::utils::utils_nft_contract_test::tests::{
+        cw2981_multi_test_exec_approve, cw2981_multi_test_exec_mint,
+    };
+
+    // 12_000$USDC per sale
+    const PRICE: u128 = 100_000_000_000_000u128;
+    const INITIAL_POOL: u128 = 100_000_000_000_000_000_000u128;
+
+    #[test]
+    fn test_marketplace_reward_pool_and_claims() {
+        // - The admin funds the pool in the reward token
+        // - Rewards of a trade are capped by the pool balance, an empty pool credits nothing
+        //   and the trade still goes through
+        // - Credited rewards are paid out by ClaimRewards, even when the contract is disabled
+        let (mut app, necessary) = instantiate_necessary_for_tests();
+        let nft_marketplace_smart_contract_addr =
+            Addr::unchecked(necessary.nft_marketplace_smart_contract_addr);
+        let nft_collection = necessary.cw2981_nft_contract_addr1;
+        let nft_contract_info = NftContractInfo {
+            code_id: necessary.cw2981_nft_code_id,
+            nft_contract_type: NftContractType::Cw2981MultiRoyalties,
+        };
+        let rewards_to_claim = |app: &App, address: &str| {
+            marketplace_test_query_get_rewards_to_claim(
+                app,
+                &nft_marketplace_smart_contract_addr,
+                address.to_string(),
+            )
+        };
+
+        marketplace_test_exec_enable_disable(
+            &mut app,
+            nft_marketplace_smart_contract_addr.to_string(),
+            mock_info(OWNER, &[]),
+        )
+        .unwrap();
+        marketplace_test_exec_add_nft_code_id(
+            &mut app,
+            nft_marketplace_smart_contract_addr.to_string(),
+            vec![nft_contract_info.clone()],
+            mock_info(OWNER, &[]),
+        )
+        .unwrap();
+        for token_id in ["Token1", "Token2"] {
+            cw2981_multi_test_exec_mint(
+                &mut app,
+                &Addr::unchecked(nft_collection.clone()),
+                mock_info(OWNER, &[]),
+                token_id.to_string(),
+                OWNER.to_string(),
+                None,
+            )
+            .unwrap();
+        }
+        marketplace_test_exec_add_new_collection(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(OWNER, &[]),
+            nft_collection.clone(),
+            nft_contract_info,
+        )
+        .unwrap();
+        let info = mock_info(OWNER, &coins(6_900_000u128, UHUAHUA));
+        let sale_expiration = app.block_info().time.plus_seconds(87000u64);
+        for token_id in ["Token1", "Token2"] {
+            cw2981_multi_test_exec_approve(
+                &mut app,
+                &Addr::unchecked(nft_collection.clone()),
+                &nft_marketplace_smart_contract_addr,
+                info.clone(),
+                token_id.to_string(),
+                sale_expiration.seconds(),
+            )
+            .unwrap();
+            marketplace_test_exec_sell_nft(
+                &mut app,
+                &nft_marketplace_smart_contract_addr,
+                info.clone(),
+                NftSale {
+                    seller: OWNER.to_string(),
+                    nft_collection_address: nft_collection.clone(),
+                    token_id: token_id.to_string(),
+                    sale_price_value: Uint128::new(PRICE),
+                    sale_price_denom: UHUAHUA.to_string(),
+                    sale_expiration,
+                },
+            )
+            .unwrap();
+        }
+
+        // Funding, only the admin in the reward token
+        let reward_pool =
+            marketplace_test_query_get_reward_pool(&app, &nft_marketplace_smart_contract_addr);
+        assert_eq!(reward_pool.balance, Uint128::new(INITIAL_POOL));
+        assert_eq!(reward_pool.total_to_claim, Uint128::zero());
+        marketplace_test_exec_fund_reward_pool(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(WALLET2, &coins(1_000u128, REWARD_TOKEN)),
+        )
+        .unwrap_err();
+        let execute_output = marketplace_test_exec_fund_reward_pool(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(OWNER, &coins(1_000u128, UHUAHUA)),
+        );
+        assert_eq!(
+            execute_output.unwrap_err().root_cause().to_string(),
+            "InvalidDenominationReceived".to_string()
+        );
+
+        // 6 * 10^19 reward tokens per address for a sale: the seller only gets what is left
+        let mut reward_system = marketplace_test_query_get_config(
+            &app,
+            nft_marketplace_smart_contract_addr.to_string(),
+        )
+        .reward_system;
+        reward_system.reward_token_per_1usdc_volume = Uint128::new(5_000_000_000_000_000u128);
+        marketplace_test_exec_update_config(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(OWNER, &[]),
+            vec![UpdateConfigEnum::UpdateRewardSystem { reward_system }],
+        )
+        .unwrap();
+        marketplace_test_exec_buy_nft(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(WALLET2, &coins(PRICE, UHUAHUA)),
+            nft_collection.clone(),
+            "Token1".to_string(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(
+            rewards_to_claim(&app, WALLET2),
+            Uint128::new(60_000_000_000_000_000_000u128)
+        );
+        assert_eq!(
+            rewards_to_claim(&app, OWNER),
+            Uint128::new(40_000_000_000_000_000_000u128)
+        );
+        let reward_pool =
+            marketplace_test_query_get_reward_pool(&app, &nft_marketplace_smart_contract_addr);
+        assert_eq!(reward_pool.balance, Uint128::zero());
+        assert_eq!(reward_pool.total_to_claim, Uint128::new(INITIAL_POOL));
+
+        // The reward token can't change while credited rewards are still to claim
+        let mut reward_system = marketplace_test_query_get_config(
+            &app,
+            nft_marketplace_smart_contract_addr.to_string(),
+        )
+        .reward_system;
+        reward_system.reward_token_address = UHUAHUA.to_string();
+        let execute_output = marketplace_test_exec_update_config(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(OWNER, &[]),
+            vec![UpdateConfigEnum::UpdateRewardSystem { reward_system }],
+        );
+        assert_eq!(
+            execute_output.unwrap_err().root_cause().to_string(),
+            "RewardsStillToClaim".to_string()
+        );
+
+        // Empty pool: the trade goes through without rewards
+        marketplace_test_exec_buy_nft(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(WALLET3, &coins(PRICE, UHUAHUA)),
+            nft_collection.clone(),
+            "Token2".to_string(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(rewards_to_claim(&app, WALLET3), Uint128::zero());
+        assert_eq!(
+            rewards_to_claim(&app, OWNER),
+            Uint128::new(40_000_000_000_000_000_000u128)
+        );
+        assert_eq!(
+            marketplace_test_query_get_config(
+                &app,
+                nft_marketplace_smart_contract_addr.to_string()
+            )
+            .reward_system
+            .total_reward_tokens_distributed,
+            Uint128::new(INITIAL_POOL)
+        );
+
+        // Claims
+        let execute_output = marketplace_test_exec_claim_rewards(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(WALLET3, &[]),
+        );
+        assert_eq!(
+            execute_output.unwrap_err().root_cause().to_string(),
+            "NoRewardsToClaim".to_string()
+        );
+        let owner_balance = app
+            .wrap()
+            .query_balance(OWNER, REWARD_TOKEN)
+            .unwrap()
+            .amount;
+        marketplace_test_exec_claim_rewards(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(OWNER, &[]),
+        )
+        .unwrap();
+        assert_eq!(
+            app.wrap()
+                .query_balance(OWNER, REWARD_TOKEN)
+                .unwrap()
+                .amount,
+            owner_balance + Uint128::new(40_000_000_000_000_000_000u128)
+        );
+        assert_eq!(rewards_to_claim(&app, OWNER), Uint128::zero());
+        assert_eq!(
+            marketplace_test_query_get_reward_pool(&app, &nft_marketplace_smart_contract_addr)
+                .total_to_claim,
+            Uint128::new(60_000_000_000_000_000_000u128)
+        );
+
+        // Claims stay open when the contract is disabled
+        marketplace_test_exec_enable_disable(
+            &mut app,
+            nft_marketplace_smart_contract_addr.to_string(),
+            mock_info(OWNER, &[]),
+        )
+        .unwrap();
+        marketplace_test_exec_claim_rewards(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(WALLET2, &[]),
+        )
+        .unwrap();
+        assert_eq!(rewards_to_claim(&app, WALLET2), Uint128::zero());
+
+        // Funding again
+        marketplace_test_exec_fund_reward_pool(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(OWNER, &coins(1_000u128, REWARD_TOKEN)),
+        )
+        .unwrap();
+        assert_eq!(
+            marketplace_test_query_get_reward_pool(&app, &nft_marketplace_smart_contract_addr)
+                .balance,
+            Uint128::new(1_000u128)
+        );
+    }
+}
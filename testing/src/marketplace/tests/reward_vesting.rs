@@ -0,0 +1,375 @@
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::mock_info;
+    use cosmwasm_std::{coins, Addr, Timestamp, Uint128};
+
+    use chihuahua_nft_marketplace::msg::UpdateConfigEnum;
+    use nft_marketplace_utils::nft_collection::{NftContractInfo, NftContractType};
+    use nft_marketplace_utils::nft_sale::NftSale;
+    use nft_marketplace_utils::price_history::day_start_seconds;
+    use nft_marketplace_utils::reward_vesting::{RewardVesting, MAX_VESTING_ENTRIES};
+
+    use crate::common::utils::constants::{OWNER, REWARD_TOKEN, UHUAHUA, WALLET2};
+    use crate::common::utils::utils_common::tests::{instantiate_necessary_for_tests, App};
+    use crate::common::utils::utils_marketplace_contract_test::tests::{
+        marketplace_test_exec_add_new_collection, marketplace_test_exec_add_nft_code_id,
+        marketplace_test_exec_buy_nft, marketplace_test_exec_claim_rewards,
+        marketplace_test_exec_enable_disable, marketplace_test_exec_sell_nft,
+        marketplace_test_exec_update_config, marketplace_test_query_get_config,
+        marketplace_test_query_get_reward_vesting, marketplace_test_query_get_rewards_to_claim,
+    };
+    use crate::common::utils::utils_nft_contract_test::tests::{
+        cw2981_multi_test_exec_approve, cw2981_multi_test_exec_mint,
+    };
+
+    // 12_000$USDC per sale, 12_000 * 10^6 reward tokens per address
+    const PRICE: u128 = 100_000_000_000_000u128;
+    const REWARDS: u128 = 12_000_000_000u128;
+    const DAY: u64 = 86_400u64;
+
+    fn buy(
+        app: &mut App,
+        nft_marketplace_smart_contract_addr: &Addr,
+        nft_collection: &str,
+        token_id: &str,
+    ) {
+        marketplace_test_exec_buy_nft(
+            app,
+            nft_marketplace_smart_contract_addr,
+            mock_info(WALLET2, &coins(PRICE, UHUAHUA)),
+            nft_collection.to_string(),
+            token_id.to_string(),
+            None,
+        )
+        .unwrap();
+    }
+
+    // Lists the tokens of the first collection, returns the marketplace and the collection
+    fn setup_listed_tokens(token_ids: &[String]) -> (App, Addr, String) {
+        let (mut app, necessary) = instantiate_necessary_for_tests();
+        let nft_marketplace_smart_contract_addr =
+            Addr::unchecked(necessary.nft_marketplace_smart_contract_addr);
+        let nft_collection = necessary.cw2981_nft_contract_addr1;
+        let nft_contract_info = NftContractInfo {
+            code_id: necessary.cw2981_nft_code_id,
+            nft_contract_type: NftContractType::Cw2981MultiRoyalties,
+        };
+
+        marketplace_test_exec_enable_disable(
+            &mut app,
+            nft_marketplace_smart_contract_addr.to_string(),
+            mock_info(OWNER, &[]),
+        )
+        .unwrap();
+        marketplace_test_exec_add_nft_code_id(
+            &mut app,
+            nft_marketplace_smart_contract_addr.to_string(),
+            vec![nft_contract_info.clone()],
+            mock_info(OWNER, &[]),
+        )
+        .unwrap();
+        for token_id in token_ids.iter() {
+            cw2981_multi_test_exec_mint(
+                &mut app,
+                &Addr::unchecked(nft_collection.clone()),
+                mock_info(OWNER, &[]),
+                token_id.to_string(),
+                OWNER.to_string(),
+                None,
+            )
+            .unwrap();
+        }
+        marketplace_test_exec_add_new_collection(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(OWNER, &[]),
+            nft_collection.clone(),
+            nft_contract_info,
+        )
+        .unwrap();
+        let info = mock_info(OWNER, &coins(6_900_000u128, UHUAHUA));
+        let sale_expiration = app.block_info().time.plus_seconds(100 * DAY);
+        for token_id in token_ids.iter() {
+            cw2981_multi_test_exec_approve(
+                &mut app,
+                &Addr::unchecked(nft_collection.clone()),
+                &nft_marketplace_smart_contract_addr,
+                info.clone(),
+                token_id.to_string(),
+                sale_expiration.seconds(),
+            )
+            .unwrap();
+            marketplace_test_exec_sell_nft(
+                &mut app,
+                &nft_marketplace_smart_contract_addr,
+                info.clone(),
+                NftSale {
+                    seller: OWNER.to_string(),
+                    nft_collection_address: nft_collection.clone(),
+                    token_id: token_id.to_string(),
+                    sale_price_value: Uint128::new(PRICE),
+                    sale_price_denom: UHUAHUA.to_string(),
+                    sale_expiration,
+                },
+            )
+            .unwrap();
+        }
+
+        (app, nft_marketplace_smart_contract_addr, nft_collection)
+    }
+
+    #[test]
+    fn test_marketplace_reward_vesting() {
+        // - With a vesting schedule, rewards vest linearly from the end of the day of the trade
+        // - Nothing is claimable before the cliff, rewards of the same day share one entry
+        // - ClaimRewards pays what has vested, fully claimed entries are removed
+        let token_ids = ["Token1", "Token2", "Token3"].map(String::from);
+        let (mut app, nft_marketplace_smart_contract_addr, nft_collection) =
+            setup_listed_tokens(&token_ids);
+
+        // The schedule is validated
+        let mut reward_system = marketplace_test_query_get_config(
+            &app,
+            nft_marketplace_smart_contract_addr.to_string(),
+        )
+        .reward_system;
+        reward_system.vesting = Some(RewardVesting {
+            cliff_seconds: 11 * DAY,
+            duration_seconds: 10 * DAY,
+        });
+        let execute_output = marketplace_test_exec_update_config(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(OWNER, &[]),
+            vec![UpdateConfigEnum::UpdateRewardSystem {
+                reward_system: reward_system.clone(),
+            }],
+        );
+        assert_eq!(
+            execute_output.unwrap_err().root_cause().to_string(),
+            "InvalidRewards".to_string()
+        );
+        reward_system.vesting = Some(RewardVesting {
+            cliff_seconds: DAY,
+            duration_seconds: 10 * DAY,
+        });
+        marketplace_test_exec_update_config(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(OWNER, &[]),
+            vec![UpdateConfigEnum::UpdateRewardSystem { reward_system }],
+        )
+        .unwrap();
+
+        // Two trades of the same day share one entry, nothing is claimable before the cliff
+        let trade_time = app.block_info().time;
+        let start = Timestamp::from_seconds(day_start_seconds(trade_time) + DAY);
+        buy(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            &nft_collection,
+            "Token1",
+        );
+        app.update_block(|block| {
+            block.time = block.time.plus_seconds(60);
+        });
+        buy(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            &nft_collection,
+            "Token2",
+        );
+        let reward_vesting = marketplace_test_query_get_reward_vesting(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            WALLET2.to_string(),
+        );
+        assert_eq!(reward_vesting.vested, Uint128::zero());
+        assert_eq!(reward_vesting.unvested, Uint128::new(2 * REWARDS));
+        assert_eq!(reward_vesting.entries.len(), 1);
+        assert_eq!(reward_vesting.entries[0].start, start);
+        assert_eq!(reward_vesting.entries[0].cliff_end, start.plus_seconds(DAY));
+        assert_eq!(reward_vesting.entries[0].end, start.plus_seconds(10 * DAY));
+        assert_eq!(
+            marketplace_test_query_get_rewards_to_claim(
+                &app,
+                &nft_marketplace_smart_contract_addr,
+                WALLET2.to_string()
+            ),
+            Uint128::zero()
+        );
+        let execute_output = marketplace_test_exec_claim_rewards(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(WALLET2, &[]),
+        );
+        assert_eq!(
+            execute_output.unwrap_err().root_cause().to_string(),
+            "NoRewardsToClaim".to_string()
+        );
+
+        // Half of the duration: half of the rewards are vested
+        app.update_block(|block| {
+            block.time = start.plus_seconds(5 * DAY);
+        });
+        assert_eq!(
+            marketplace_test_query_get_rewards_to_claim(
+                &app,
+                &nft_marketplace_smart_contract_addr,
+                WALLET2.to_string()
+            ),
+            Uint128::new(REWARDS)
+        );
+        let balance = app
+            .wrap()
+            .query_balance(WALLET2, REWARD_TOKEN)
+            .unwrap()
+            .amount;
+        marketplace_test_exec_claim_rewards(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(WALLET2, &[]),
+        )
+        .unwrap();
+        assert_eq!(
+            app.wrap()
+                .query_balance(WALLET2, REWARD_TOKEN)
+                .unwrap()
+                .amount,
+            balance + Uint128::new(REWARDS)
+        );
+        let reward_vesting = marketplace_test_query_get_reward_vesting(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            WALLET2.to_string(),
+        );
+        assert_eq!(reward_vesting.vested, Uint128::zero());
+        assert_eq!(reward_vesting.unvested, Uint128::new(REWARDS));
+        assert_eq!(reward_vesting.entries[0].claimed, Uint128::new(REWARDS));
+
+        // A later trade has its own entry
+        buy(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            &nft_collection,
+            "Token3",
+        );
+        let reward_vesting = marketplace_test_query_get_reward_vesting(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            WALLET2.to_string(),
+        );
+        assert_eq!(reward_vesting.entries.len(), 2);
+        assert_eq!(reward_vesting.unvested, Uint128::new(2 * REWARDS));
+
+        // Everything is vested, fully claimed entries are removed
+        app.update_block(|block| {
+            block.time = start.plus_seconds(20 * DAY);
+        });
+        marketplace_test_exec_claim_rewards(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(WALLET2, &[]),
+        )
+        .unwrap();
+        assert_eq!(
+            app.wrap()
+                .query_balance(WALLET2, REWARD_TOKEN)
+                .unwrap()
+                .amount,
+            balance + Uint128::new(3 * REWARDS)
+        );
+        let reward_vesting = marketplace_test_query_get_reward_vesting(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            WALLET2.to_string(),
+        );
+        assert_eq!(reward_vesting.vested, Uint128::zero());
+        assert_eq!(reward_vesting.unvested, Uint128::zero());
+        assert!(reward_vesting.entries.is_empty());
+    }
+
+    #[test]
+    fn test_marketplace_reward_vesting_entries_bounded() {
+        // - Fully vested entries are folded into one
+        // - A trade a day never takes more than MAX_VESTING_ENTRIES entries
+        let token_ids: Vec<String> = (0..50).map(|i| format!("Token{}", i)).collect();
+        let (mut app, nft_marketplace_smart_contract_addr, nft_collection) =
+            setup_listed_tokens(&token_ids);
+        let mut reward_system = marketplace_test_query_get_config(
+            &app,
+            nft_marketplace_smart_contract_addr.to_string(),
+        )
+        .reward_system;
+        reward_system.vesting = Some(RewardVesting {
+            cliff_seconds: 0,
+            duration_seconds: 5 * DAY,
+        });
+        marketplace_test_exec_update_config(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(OWNER, &[]),
+            vec![UpdateConfigEnum::UpdateRewardSystem {
+                reward_system: reward_system.clone(),
+            }],
+        )
+        .unwrap();
+
+        // A short schedule: only the entries still vesting stay apart
+        for token_id in token_ids[..10].iter() {
+            buy(
+                &mut app,
+                &nft_marketplace_smart_contract_addr,
+                &nft_collection,
+                token_id,
+            );
+            app.update_block(|block| {
+                block.time = block.time.plus_seconds(DAY);
+            });
+        }
+        let reward_vesting = marketplace_test_query_get_reward_vesting(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            WALLET2.to_string(),
+        );
+        assert!(reward_vesting.entries.len() <= 7);
+        assert_eq!(
+            reward_vesting.vested + reward_vesting.unvested,
+            Uint128::new(10 * REWARDS)
+        );
+
+        // A long schedule: the entry count is capped
+        reward_system.vesting = Some(RewardVesting {
+            cliff_seconds: 0,
+            duration_seconds: 365 * DAY,
+        });
+        marketplace_test_exec_update_config(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(OWNER, &[]),
+            vec![UpdateConfigEnum::UpdateRewardSystem { reward_system }],
+        )
+        .unwrap();
+        for token_id in token_ids[10..].iter() {
+            buy(
+                &mut app,
+                &nft_marketplace_smart_contract_addr,
+                &nft_collection,
+                token_id,
+            );
+            app.update_block(|block| {
+                block.time = block.time.plus_seconds(DAY);
+            });
+        }
+        let reward_vesting = marketplace_test_query_get_reward_vesting(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            WALLET2.to_string(),
+        );
+        assert_eq!(reward_vesting.entries.len(), MAX_VESTING_ENTRIES);
+        assert_eq!(
+            reward_vesting.vested + reward_vesting.unvested,
+            Uint128::new(token_ids.len() as u128 * REWARDS)
+        );
+    }
+}
@@ -0,0 +1,356 @@
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::mock_info;
+    use cosmwasm_std::{coins, Addr, Decimal, Uint128};
+
+    use chihuahua_nft_marketplace::msg::UpdateConfigEnum;
+    use general_utils::denominations::DenominationValue;
+    use nft_marketplace_utils::nft_collection::{NftContractInfo, NftContractType};
+    use nft_marketplace_utils::nft_sale::NftSale;
+    use nft_marketplace_utils::staking::StakingConfig;
+
+    use crate::common::utils::constants::{OWNER, REWARD_TOKEN, UHUAHUA, WALLET2, WALLET3};
+    use crate::common::utils::utils_common::tests::{instantiate_necessary_for_tests, App};
+    use crate::common::utils::utils_marketplace_contract_test::tests::{
+        marketplace_test_exec_add_new_collection, marketplace_test_exec_add_nft_code_id,
+        marketplace_test_exec_buy_nft, marketplace_test_exec_claim_mkpc_fees,
+        marketplace_test_exec_claim_staking_fees, marketplace_test_exec_enable_disable,
+        marketplace_test_exec_sell_nft, marketplace_test_exec_stake, marketplace_test_exec_unstake,
+        marketplace_test_exec_update_config, marketplace_test_exec_withdraw_unbonded,
+        marketplace_test_query_get_config, marketplace_test_query_get_mkpc_info,
+        marketplace_test_query_get_staker_info, marketplace_test_query_get_staking_stats,
+    };
+    use crate::common::utils::utils_nft_contract_test::tests::{
+        cw2981_multi_test_exec_approve, cw2981_multi_test_exec_mint,
+    };
+
+    const PRICE: u128 = 100_000_000u128;
+    const LISTING_FEE: u128 = 6_900_000u128;
+    // 4.2% of PRICE
+    const SALE_FEE: u128 = 4_200_000u128;
+    const DAY: u64 = 86_400u64;
+
+    fn uhuahua_fees_to_claim(app: &App, contract_addr: &Addr) -> u128 {
+        marketplace_test_query_get_mkpc_info(app, contract_addr)
+            .unwrap()
+            .into_iter()
+            .find(|stats| stats.denom == UHUAHUA)
+            .unwrap()
+            .marketplace_fees_to_claim
+            .u128()
+    }
+
+    fn staker_fees(app: &App, contract_addr: &Addr, address: &str) -> Vec<DenominationValue> {
+        marketplace_test_query_get_staker_info(app, contract_addr, address.to_string())
+            .fees_to_claim
+    }
+
+    fn uhuahua(value: u128) -> Vec<DenominationValue> {
+        vec![DenominationValue {
+            denom: UHUAHUA.to_string(),
+            value: Uint128::new(value),
+        }]
+    }
+
+    fn sell(app: &mut App, contract_addr: &Addr, nft_collection: &str, token_id: &str) {
+        let info = mock_info(OWNER, &coins(LISTING_FEE, UHUAHUA));
+        let sale_expiration = app.block_info().time.plus_seconds(20 * DAY);
+        cw2981_multi_test_exec_approve(
+            app,
+            &Addr::unchecked(nft_collection),
+            contract_addr,
+            info.clone(),
+            token_id.to_string(),
+            sale_expiration.seconds(),
+        )
+        .unwrap();
+        marketplace_test_exec_sell_nft(
+            app,
+            contract_addr,
+            info,
+            NftSale {
+                seller: OWNER.to_string(),
+                nft_collection_address: nft_collection.to_string(),
+                token_id: token_id.to_string(),
+                sale_price_value: Uint128::new(PRICE),
+                sale_price_denom: UHUAHUA.to_string(),
+                sale_expiration,
+            },
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_marketplace_reward_token_staking() {
+        // - Stakers share the configured part of the fees collected while they stake, pro rata
+        // - The rest stays with ClaimMarketplaceFees
+        // - Unstaked tokens are withdrawn after the unbonding period
+        let (mut app, necessary) = instantiate_necessary_for_tests();
+        let contract_addr = Addr::unchecked(necessary.nft_marketplace_smart_contract_addr);
+        let nft_collection = necessary.cw2981_nft_contract_addr1;
+        let nft_contract_info = NftContractInfo {
+            code_id: necessary.cw2981_nft_code_id,
+            nft_contract_type: NftContractType::Cw2981MultiRoyalties,
+        };
+        marketplace_test_exec_enable_disable(
+            &mut app,
+            contract_addr.to_string(),
+            mock_info(OWNER, &[]),
+        )
+        .unwrap();
+        marketplace_test_exec_add_nft_code_id(
+            &mut app,
+            contract_addr.to_string(),
+            vec![nft_contract_info.clone()],
+            mock_info(OWNER, &[]),
+        )
+        .unwrap();
+        for token_id in ["Token1", "Token2", "Token3"] {
+            cw2981_multi_test_exec_mint(
+                &mut app,
+                &Addr::unchecked(nft_collection.clone()),
+                mock_info(OWNER, &[]),
+                token_id.to_string(),
+                OWNER.to_string(),
+                None,
+            )
+            .unwrap();
+        }
+        marketplace_test_exec_add_new_collection(
+            &mut app,
+            &contract_addr,
+            mock_info(OWNER, &[]),
+            nft_collection.clone(),
+            nft_contract_info,
+        )
+        .unwrap();
+
+        // Not enabled until configured, the share is at most all the fees
+        let execute_output = marketplace_test_exec_stake(
+            &mut app,
+            &contract_addr,
+            mock_info(WALLET2, &coins(1_000u128, REWARD_TOKEN)),
+        );
+        assert_eq!(
+            execute_output.unwrap_err().root_cause().to_string(),
+            "StakingNotEnabled".to_string()
+        );
+        let staking_config = |fees_share: u64| UpdateConfigEnum::UpdateStakingConfig {
+            staking_config: StakingConfig {
+                fees_share: Decimal::percent(fees_share),
+                unbonding_seconds: 7 * DAY,
+            },
+        };
+        let execute_output = marketplace_test_exec_update_config(
+            &mut app,
+            &contract_addr,
+            mock_info(OWNER, &[]),
+            vec![staking_config(101)],
+        );
+        assert_eq!(
+            execute_output.unwrap_err().root_cause().to_string(),
+            "InvalidStakingConfig".to_string()
+        );
+        marketplace_test_exec_update_config(
+            &mut app,
+            &contract_addr,
+            mock_info(OWNER, &[]),
+            vec![staking_config(50)],
+        )
+        .unwrap();
+
+        // Fees collected before staking stay with the marketplace
+        sell(&mut app, &contract_addr, &nft_collection, "Token1");
+        let fees_before_staking = uhuahua_fees_to_claim(&app, &contract_addr);
+        let execute_output = marketplace_test_exec_stake(
+            &mut app,
+            &contract_addr,
+            mock_info(WALLET2, &coins(1_000u128, UHUAHUA)),
+        );
+        assert_eq!(
+            execute_output.unwrap_err().root_cause().to_string(),
+            "InvalidDenominationReceived".to_string()
+        );
+        marketplace_test_exec_stake(
+            &mut app,
+            &contract_addr,
+            mock_info(WALLET2, &coins(1_000u128, REWARD_TOKEN)),
+        )
+        .unwrap();
+        marketplace_test_exec_stake(
+            &mut app,
+            &contract_addr,
+            mock_info(WALLET3, &coins(3_000u128, REWARD_TOKEN)),
+        )
+        .unwrap();
+        assert!(staker_fees(&app, &contract_addr, WALLET2).is_empty());
+
+        // Half of a listing and a sale, a quarter of it to WALLET2 and the rest to WALLET3
+        sell(&mut app, &contract_addr, &nft_collection, "Token2");
+        marketplace_test_exec_buy_nft(
+            &mut app,
+            &contract_addr,
+            mock_info(WALLET2, &coins(PRICE, UHUAHUA)),
+            nft_collection.clone(),
+            "Token1".to_string(),
+            None,
+        )
+        .unwrap();
+        let shared_fees = (LISTING_FEE + SALE_FEE) / 2;
+        assert_eq!(
+            staker_fees(&app, &contract_addr, WALLET2),
+            uhuahua(shared_fees / 4)
+        );
+        assert_eq!(
+            staker_fees(&app, &contract_addr, WALLET3),
+            uhuahua(3 * shared_fees / 4)
+        );
+        let wallet2_balance = app.wrap().query_balance(WALLET2, UHUAHUA).unwrap().amount;
+        marketplace_test_exec_claim_staking_fees(&mut app, &contract_addr, mock_info(WALLET2, &[]))
+            .unwrap();
+        assert_eq!(
+            app.wrap().query_balance(WALLET2, UHUAHUA).unwrap().amount,
+            wallet2_balance + Uint128::new(shared_fees / 4)
+        );
+        let execute_output = marketplace_test_exec_claim_staking_fees(
+            &mut app,
+            &contract_addr,
+            mock_info(WALLET2, &[]),
+        );
+        assert_eq!(
+            execute_output.unwrap_err().root_cause().to_string(),
+            "NoStakingFeesToClaim".to_string()
+        );
+
+        // The owner claims everything but the share of the stakers
+        let owner_balance = app.wrap().query_balance(OWNER, UHUAHUA).unwrap().amount;
+        marketplace_test_exec_claim_mkpc_fees(
+            &mut app,
+            contract_addr.to_string(),
+            mock_info(OWNER, &[]),
+        )
+        .unwrap();
+        assert_eq!(
+            app.wrap().query_balance(OWNER, UHUAHUA).unwrap().amount,
+            owner_balance + Uint128::new(fees_before_staking + shared_fees)
+        );
+        assert_eq!(uhuahua_fees_to_claim(&app, &contract_addr), 0);
+
+        // Unbonding tokens earn nothing, WALLET3 gets the whole share of the next listing
+        let execute_output = marketplace_test_exec_unstake(
+            &mut app,
+            &contract_addr,
+            mock_info(WALLET2, &[]),
+            Uint128::new(1_001u128),
+        );
+        assert_eq!(
+            execute_output.unwrap_err().root_cause().to_string(),
+            "InvalidUnstakeAmount".to_string()
+        );
+        let unbonding_start = app.block_info().time;
+        marketplace_test_exec_unstake(
+            &mut app,
+            &contract_addr,
+            mock_info(WALLET2, &[]),
+            Uint128::new(1_000u128),
+        )
+        .unwrap();
+
+        // The reward token can't change while tokens are staked or unbonding
+        let mut reward_system =
+            marketplace_test_query_get_config(&app, contract_addr.to_string()).reward_system;
+        reward_system.reward_token_address = UHUAHUA.to_string();
+        let execute_output = marketplace_test_exec_update_config(
+            &mut app,
+            &contract_addr,
+            mock_info(OWNER, &[]),
+            vec![UpdateConfigEnum::UpdateRewardSystem { reward_system }],
+        );
+        assert_eq!(
+            execute_output.unwrap_err().root_cause().to_string(),
+            "RewardTokenStillStaked".to_string()
+        );
+        let staking_stats = marketplace_test_query_get_staking_stats(&app, &contract_addr);
+        assert_eq!(staking_stats.total_staked, Uint128::new(3_000u128));
+        assert_eq!(staking_stats.total_unbonding, Uint128::new(1_000u128));
+        sell(&mut app, &contract_addr, &nft_collection, "Token3");
+        assert!(staker_fees(&app, &contract_addr, WALLET2).is_empty());
+        assert_eq!(
+            staker_fees(&app, &contract_addr, WALLET3),
+            uhuahua(3 * shared_fees / 4 + LISTING_FEE / 2)
+        );
+
+        let execute_output = marketplace_test_exec_withdraw_unbonded(
+            &mut app,
+            &contract_addr,
+            mock_info(WALLET2, &[]),
+        );
+        assert_eq!(
+            execute_output.unwrap_err().root_cause().to_string(),
+            "NoUnbondedTokens".to_string()
+        );
+        app.update_block(|block| {
+            block.time = unbonding_start.plus_seconds(7 * DAY);
+        });
+        let wallet2_balance = app
+            .wrap()
+            .query_balance(WALLET2, REWARD_TOKEN)
+            .unwrap()
+            .amount;
+        marketplace_test_exec_withdraw_unbonded(&mut app, &contract_addr, mock_info(WALLET2, &[]))
+            .unwrap();
+        assert_eq!(
+            app.wrap()
+                .query_balance(WALLET2, REWARD_TOKEN)
+                .unwrap()
+                .amount,
+            wallet2_balance + Uint128::new(1_000u128)
+        );
+        let staker_info =
+            marketplace_test_query_get_staker_info(&app, &contract_addr, WALLET2.to_string());
+        assert!(staker_info.unbondings.is_empty());
+        assert_eq!(staker_info.staked, Uint128::zero());
+
+        // Stakes can still be taken out when the contract is disabled, not made
+        marketplace_test_exec_enable_disable(
+            &mut app,
+            contract_addr.to_string(),
+            mock_info(OWNER, &[]),
+        )
+        .unwrap();
+        let execute_output = marketplace_test_exec_stake(
+            &mut app,
+            &contract_addr,
+            mock_info(WALLET2, &coins(1_000u128, REWARD_TOKEN)),
+        );
+        assert_eq!(
+            execute_output.unwrap_err().root_cause().to_string(),
+            "ContractDisabled".to_string()
+        );
+        marketplace_test_exec_unstake(
+            &mut app,
+            &contract_addr,
+            mock_info(WALLET3, &[]),
+            Uint128::new(3_000u128),
+        )
+        .unwrap();
+        assert_eq!(
+            marketplace_test_query_get_staking_stats(&app, &contract_addr).total_staked,
+            Uint128::zero()
+        );
+        // Nothing staked, but WALLET3 is still unbonding
+        let mut reward_system =
+            marketplace_test_query_get_config(&app, contract_addr.to_string()).reward_system;
+        reward_system.reward_token_address = UHUAHUA.to_string();
+        let execute_output = marketplace_test_exec_update_config(
+            &mut app,
+            &contract_addr,
+            mock_info(OWNER, &[]),
+            vec![UpdateConfigEnum::UpdateRewardSystem { reward_system }],
+        );
+        assert_eq!(
+            execute_output.unwrap_err().root_cause().to_string(),
+            "RewardTokenStillStaked".to_string()
+        );
+    }
+}
@@ -6,9 +6,9 @@ mod tests {
     use cosmwasm_std::{Addr, Decimal, Uint128};
 
     use general_utils::denominations::AcceptedDenominations;
-    use nft_marketplace_utils::reward_system::{RewardSystem, VipLevel, VipPerk};
+    use nft_marketplace_utils::reward_system::{RewardSystem, VipTier};
 
-    use crate::common::utils::constants::{OWNER, WALLET2, WALLET3};
+    use crate::common::utils::constants::{OWNER, REWARD_TOKEN, WALLET2, WALLET3};
     use crate::common::utils::utils_common::tests::instantiate_necessary_for_tests;
     use crate::common::utils::utils_marketplace_contract_test::tests::{
         marketplace_test_exec_create_my_profile, marketplace_test_exec_enable_disable,
@@ -198,6 +198,22 @@ mod tests {
         );
         assert_eq!(query_output.contract_owner, OWNER.to_string());
 
+        // The reward token can't change while the pool holds the previous one
+        let mut reward_system =
+            marketplace_test_query_get_config(&app, nft_marketplace_smart_contract_addr.clone())
+                .reward_system;
+        reward_system.reward_token_address = "ModifiedToken".to_string();
+        let execute_output = marketplace_test_exec_update_config(
+            &mut app,
+            &Addr::unchecked(nft_marketplace_smart_contract_addr.clone()),
+            mock_info(OWNER, &[]),
+            vec![UpdateConfigEnum::UpdateRewardSystem { reward_system }],
+        );
+        assert_eq!(
+            execute_output.unwrap_err().root_cause().to_string(),
+            "RewardPoolNotEmpty".to_string()
+        );
+
         // Then we modify the Vip perks
         let info = mock_info(OWNER, &[]);
         let execute_output = marketplace_test_exec_update_config(
@@ -206,38 +222,48 @@ mod tests {
             info,
             vec![UpdateConfigEnum::UpdateRewardSystem {
                 reward_system: RewardSystem {
-                    reward_token_address: "ModifiedToken".to_string(),
+                    reward_token_address: REWARD_TOKEN.to_string(),
                     reward_token_per_1usdc_volume: Uint128::new(1u128),
                     total_reward_tokens_distributed: Uint128::zero(),
-                    vip_perks: vec![
-                        VipPerk {
-                            vip_level: VipLevel::Level1,
+                    vip_tiers: vec![
+                        VipTier {
+                            name: "Level1".to_string(),
                             profile_background: true,
                             profile_nft_showcase: false,
                             profile_description: false,
                             profile_links: false,
                             marketplace_fees_discount: Decimal::from_str("0.025").unwrap(),
                             level_up_price_in_reward_tokens: Uint128::new(1_000u128),
+                            max_messages_per_window: None,
+                            subscription_duration: None,
+                            volume_usdc_threshold: None,
                         },
-                        VipPerk {
-                            vip_level: VipLevel::Level2,
+                        VipTier {
+                            name: "Level2".to_string(),
                             profile_background: true,
                             profile_nft_showcase: false,
                             profile_description: true,
                             profile_links: false,
                             marketplace_fees_discount: Decimal::from_str("0.07").unwrap(),
                             level_up_price_in_reward_tokens: Uint128::new(20_000u128),
+                            max_messages_per_window: None,
+                            subscription_duration: None,
+                            volume_usdc_threshold: None,
                         },
-                        VipPerk {
-                            vip_level: VipLevel::Level3,
+                        VipTier {
+                            name: "Level3".to_string(),
                             profile_background: true,
                             profile_nft_showcase: true,
                             profile_description: true,
                             profile_links: true,
                             marketplace_fees_discount: Decimal::from_str("0.15").unwrap(),
                             level_up_price_in_reward_tokens: Uint128::new(100_000u128),
+                            max_messages_per_window: None,
+                            subscription_duration: None,
+                            volume_usdc_threshold: None,
                         },
                     ],
+                    vesting: None,
                 },
             }],
         );
@@ -246,14 +272,14 @@ mod tests {
             marketplace_test_query_get_config(&app, nft_marketplace_smart_contract_addr);
         assert_eq!(
             query_output.reward_system.reward_token_address,
-            "ModifiedToken".to_string()
+            REWARD_TOKEN.to_string()
         );
         assert_eq!(
             query_output.reward_system.reward_token_per_1usdc_volume,
             Uint128::new(1u128)
         );
         assert_eq!(
-            query_output.reward_system.vip_perks[2].level_up_price_in_reward_tokens,
+            query_output.reward_system.vip_tiers[2].level_up_price_in_reward_tokens,
             Uint128::new(100_000u128)
         );
     }
@@ -0,0 +1,311 @@
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::mock_info;
+    use cosmwasm_std::Addr;
+    use cw_multi_test::AppResponse;
+
+    use chihuahua_nft_marketplace::msg::UpdateConfigEnum;
+    use nft_marketplace_utils::profile::{Profile, ProfileUpdateAction};
+    use nft_marketplace_utils::username::UsernameStatus;
+
+    use crate::common::utils::constants::{OWNER, WALLET2, WALLET3};
+    use crate::common::utils::utils_common::tests::{instantiate_necessary_for_tests, App};
+    use crate::common::utils::utils_marketplace_contract_test::tests::{
+        marketplace_test_exec_create_my_profile, marketplace_test_exec_enable_disable,
+        marketplace_test_exec_update_config, marketplace_test_exec_update_my_profile,
+        marketplace_test_query_get_profile_info, marketplace_test_query_get_reserved_usernames,
+        marketplace_test_query_get_username, marketplace_test_query_get_username_status,
+    };
+
+    fn update_username(
+        app: &mut App,
+        nft_marketplace_smart_contract_addr: &Addr,
+        sender: &str,
+        username: &str,
+        profile_update_action: ProfileUpdateAction,
+    ) -> anyhow::Result<AppResponse> {
+        marketplace_test_exec_update_my_profile(
+            app,
+            nft_marketplace_smart_contract_addr.to_string(),
+            mock_info(sender, &[]),
+            Profile {
+                address: sender.to_string(),
+                username: Some(username.to_string()),
+                vip_level: None,
+                vip_expiration: None,
+                volume_usdc: None,
+                profile_nft_collection: None,
+                profile_nft_token_id: None,
+                background_nft_collection: None,
+                background_nft_token_id: None,
+                description: None,
+                nft_showcase: None,
+                links: None,
+                number_of_trades: None,
+                buy_info: None,
+                sell_info: None,
+                display_trade_info: None,
+            },
+            profile_update_action,
+        )
+    }
+
+    fn username_status(
+        app: &App,
+        nft_marketplace_smart_contract_addr: &Addr,
+        username: &str,
+    ) -> UsernameStatus {
+        marketplace_test_query_get_username_status(
+            app,
+            nft_marketplace_smart_contract_addr,
+            username.to_string(),
+        )
+    }
+
+    #[test]
+    fn test_marketplace_username_registry() {
+        // - Usernames are validated and unique whatever their case
+        // - A new username can only be registered once the rename cooldown is over
+        // - Removed usernames are released, reserved usernames can't be registered
+        let (mut app, necessary) = instantiate_necessary_for_tests();
+        let nft_marketplace_smart_contract_addr =
+            Addr::unchecked(necessary.nft_marketplace_smart_contract_addr);
+
+        marketplace_test_exec_enable_disable(
+            &mut app,
+            nft_marketplace_smart_contract_addr.to_string(),
+            mock_info(OWNER, &[]),
+        )
+        .unwrap();
+        for address in [OWNER, WALLET2, WALLET3] {
+            marketplace_test_exec_create_my_profile(
+                &mut app,
+                nft_marketplace_smart_contract_addr.to_string(),
+                mock_info(address, &[]),
+                None,
+            )
+            .unwrap();
+        }
+
+        // Validation: length, characters, separators and no address
+        for username in [
+            "Ab",
+            "Bad Name",
+            "-Satoshi",
+            "Satoshi.",
+            "Sa..toshi",
+            "satoshi",
+        ] {
+            assert_eq!(
+                username_status(&app, &nft_marketplace_smart_contract_addr, username),
+                UsernameStatus::Invalid
+            );
+        }
+        assert_eq!(
+            username_status(&app, &nft_marketplace_smart_contract_addr, &"A".repeat(33)),
+            UsernameStatus::Invalid
+        );
+        let execute_output = update_username(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            WALLET2,
+            "Sa..toshi",
+            ProfileUpdateAction::Add,
+        );
+        assert_eq!(
+            execute_output.unwrap_err().root_cause().to_string(),
+            "InvalidUsername".to_string()
+        );
+
+        // Uniqueness whatever the case
+        assert_eq!(
+            username_status(&app, &nft_marketplace_smart_contract_addr, "Satoshi"),
+            UsernameStatus::Available
+        );
+        update_username(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            WALLET2,
+            "Satoshi",
+            ProfileUpdateAction::Add,
+        )
+        .unwrap();
+        assert_eq!(
+            username_status(&app, &nft_marketplace_smart_contract_addr, "SATOSHI"),
+            UsernameStatus::Taken {
+                address: WALLET2.to_string()
+            }
+        );
+        let execute_output = update_username(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            WALLET3,
+            "SATOSHI",
+            ProfileUpdateAction::Add,
+        );
+        assert_eq!(
+            execute_output.unwrap_err().root_cause().to_string(),
+            "ThisUsernameIsAlreadyTaken".to_string()
+        );
+
+        // Lookups
+        assert_eq!(
+            marketplace_test_query_get_username(
+                &app,
+                &nft_marketplace_smart_contract_addr,
+                WALLET2.to_string()
+            ),
+            Some("Satoshi".to_string())
+        );
+        assert_eq!(
+            marketplace_test_query_get_username(
+                &app,
+                &nft_marketplace_smart_contract_addr,
+                WALLET3.to_string()
+            ),
+            None
+        );
+        let profile = marketplace_test_query_get_profile_info(
+            &app,
+            nft_marketplace_smart_contract_addr.to_string(),
+            "SATOSHI".to_string(),
+        )
+        .unwrap();
+        assert_eq!(profile.address, WALLET2.to_string());
+
+        // Changing the case is not a rename, a new username needs the cooldown to be over
+        update_username(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            WALLET2,
+            "SaToShi",
+            ProfileUpdateAction::Add,
+        )
+        .unwrap();
+        let execute_output = update_username(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            WALLET2,
+            "Nakamoto",
+            ProfileUpdateAction::Add,
+        );
+        assert_eq!(
+            execute_output.unwrap_err().root_cause().to_string(),
+            "UsernameRenameCooldownNotOver".to_string()
+        );
+        app.update_block(|block| {
+            block.time = block.time.plus_seconds(604_800);
+        });
+        update_username(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            WALLET2,
+            "Nakamoto",
+            ProfileUpdateAction::Add,
+        )
+        .unwrap();
+        assert_eq!(
+            username_status(&app, &nft_marketplace_smart_contract_addr, "Satoshi"),
+            UsernameStatus::Available
+        );
+        update_username(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            WALLET3,
+            "Satoshi",
+            ProfileUpdateAction::Add,
+        )
+        .unwrap();
+
+        // Release on removal
+        update_username(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            WALLET3,
+            "Satoshi",
+            ProfileUpdateAction::Remove,
+        )
+        .unwrap();
+        assert_eq!(
+            marketplace_test_query_get_username(
+                &app,
+                &nft_marketplace_smart_contract_addr,
+                WALLET3.to_string()
+            ),
+            None
+        );
+        assert_eq!(
+            username_status(&app, &nft_marketplace_smart_contract_addr, "Satoshi"),
+            UsernameStatus::Available
+        );
+
+        // Reserved usernames are managed by the admin
+        marketplace_test_exec_update_config(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(WALLET2, &[]),
+            vec![UpdateConfigEnum::AddReservedUsernames {
+                usernames: vec!["Chihuahua".to_string()],
+            }],
+        )
+        .unwrap_err();
+        marketplace_test_exec_update_config(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(OWNER, &[]),
+            vec![
+                UpdateConfigEnum::AddReservedUsernames {
+                    usernames: vec!["Chihuahua".to_string(), "Admin-Team".to_string()],
+                },
+                UpdateConfigEnum::UpdateUsernameRenameCooldown { seconds: 0 },
+            ],
+        )
+        .unwrap();
+        assert_eq!(
+            marketplace_test_query_get_reserved_usernames(
+                &app,
+                &nft_marketplace_smart_contract_addr,
+                None,
+                None
+            ),
+            vec!["admin-team".to_string(), "chihuahua".to_string()]
+        );
+        assert_eq!(
+            username_status(&app, &nft_marketplace_smart_contract_addr, "CHIHUAHUA"),
+            UsernameStatus::Reserved
+        );
+        let execute_output = update_username(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            WALLET2,
+            "CHIHUAHUA",
+            ProfileUpdateAction::Add,
+        );
+        assert_eq!(
+            execute_output.unwrap_err().root_cause().to_string(),
+            "UsernameIsReserved".to_string()
+        );
+        marketplace_test_exec_update_config(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(OWNER, &[]),
+            vec![UpdateConfigEnum::RemoveReservedUsernames {
+                usernames: vec!["CHIHUAHUA".to_string()],
+            }],
+        )
+        .unwrap();
+        // No cooldown anymore
+        update_username(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            WALLET2,
+            "CHIHUAHUA",
+            ProfileUpdateAction::Add,
+        )
+        .unwrap();
+        assert_eq!(
+            username_status(&app, &nft_marketplace_smart_contract_addr, "Nakamoto"),
+            UsernameStatus::Available
+        );
+    }
+}
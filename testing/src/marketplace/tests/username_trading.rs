@@ -0,0 +1,340 @@
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::mock_info;
+    use cosmwasm_std::{coins, Addr, Uint128};
+
+    use nft_marketplace_utils::profile::{Profile, ProfileUpdateAction};
+    use nft_marketplace_utils::username::UsernameStatus;
+
+    use crate::common::utils::constants::{
+        INVALID_REWARD_TOKEN, OWNER, UHUAHUA, WALLET2, WALLET3, WALLET4,
+    };
+    use crate::common::utils::utils_common::tests::{instantiate_necessary_for_tests, App};
+    use crate::common::utils::utils_marketplace_contract_test::tests::{
+        marketplace_test_exec_buy_username, marketplace_test_exec_cancel_username_sale,
+        marketplace_test_exec_create_my_profile, marketplace_test_exec_enable_disable,
+        marketplace_test_exec_sell_username, marketplace_test_exec_transfer_username,
+        marketplace_test_exec_update_my_profile, marketplace_test_query_get_mkpc_info,
+        marketplace_test_query_get_profile_info, marketplace_test_query_get_username,
+        marketplace_test_query_get_username_sale, marketplace_test_query_get_username_status,
+        marketplace_test_query_get_usernames_for_sale,
+    };
+
+    const USERNAME_PRICE: u128 = 100_000u128;
+    // 4.2% of marketplace fees
+    const USERNAME_MARKETPLACE_FEES: u128 = 4_200u128;
+
+    fn set_username(
+        app: &mut App,
+        nft_marketplace_smart_contract_addr: &Addr,
+        sender: &str,
+        username: &str,
+    ) {
+        marketplace_test_exec_update_my_profile(
+            app,
+            nft_marketplace_smart_contract_addr.to_string(),
+            mock_info(sender, &[]),
+            Profile {
+                address: sender.to_string(),
+                username: Some(username.to_string()),
+                vip_level: None,
+                vip_expiration: None,
+                volume_usdc: None,
+                profile_nft_collection: None,
+                profile_nft_token_id: None,
+                background_nft_collection: None,
+                background_nft_token_id: None,
+                description: None,
+                nft_showcase: None,
+                links: None,
+                number_of_trades: None,
+                buy_info: None,
+                sell_info: None,
+                display_trade_info: None,
+            },
+            ProfileUpdateAction::Add,
+        )
+        .unwrap();
+    }
+
+    fn username_of(
+        app: &App,
+        nft_marketplace_smart_contract_addr: &Addr,
+        address: &str,
+    ) -> Option<String> {
+        marketplace_test_query_get_username(
+            app,
+            nft_marketplace_smart_contract_addr,
+            address.to_string(),
+        )
+    }
+
+    #[test]
+    fn test_marketplace_username_sale_and_transfer() {
+        // - A username can be listed, bought with a marketplace fee or transferred
+        // - The registry and both profiles move together, the previous username of the buyer is released
+        // - A sale is removed once the username changes owner or is released
+        let (mut app, necessary) = instantiate_necessary_for_tests();
+        let nft_marketplace_smart_contract_addr =
+            Addr::unchecked(necessary.nft_marketplace_smart_contract_addr);
+
+        marketplace_test_exec_enable_disable(
+            &mut app,
+            nft_marketplace_smart_contract_addr.to_string(),
+            mock_info(OWNER, &[]),
+        )
+        .unwrap();
+        for address in [OWNER, WALLET2, WALLET3] {
+            marketplace_test_exec_create_my_profile(
+                &mut app,
+                nft_marketplace_smart_contract_addr.to_string(),
+                mock_info(address, &[]),
+                None,
+            )
+            .unwrap();
+        }
+        set_username(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            WALLET2,
+            "Doge",
+        );
+        set_username(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            WALLET3,
+            "Shiba",
+        );
+
+        // Listing
+        let execute_output = marketplace_test_exec_sell_username(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(WALLET4, &[]),
+            Uint128::new(USERNAME_PRICE),
+            UHUAHUA.to_string(),
+        );
+        assert_eq!(
+            execute_output.unwrap_err().root_cause().to_string(),
+            "YouDontHaveAUsername".to_string()
+        );
+        marketplace_test_exec_sell_username(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(WALLET2, &[]),
+            Uint128::new(USERNAME_PRICE),
+            INVALID_REWARD_TOKEN.to_string(),
+        )
+        .unwrap_err();
+        let execute_output = marketplace_test_exec_sell_username(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(WALLET2, &[]),
+            Uint128::new(1u128),
+            UHUAHUA.to_string(),
+        );
+        assert_eq!(
+            execute_output.unwrap_err().root_cause().to_string(),
+            "InvalidPriceForTheSale".to_string()
+        );
+        marketplace_test_exec_sell_username(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(WALLET2, &[]),
+            Uint128::new(USERNAME_PRICE),
+            UHUAHUA.to_string(),
+        )
+        .unwrap();
+        let username_sale = marketplace_test_query_get_username_sale(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            "DOGE".to_string(),
+        )
+        .unwrap();
+        assert_eq!(username_sale.username, "Doge".to_string());
+        assert_eq!(username_sale.seller, WALLET2.to_string());
+        assert_eq!(username_sale.price_value, Uint128::new(USERNAME_PRICE));
+        assert_eq!(
+            marketplace_test_query_get_usernames_for_sale(
+                &app,
+                &nft_marketplace_smart_contract_addr,
+                None,
+                None
+            ),
+            vec![username_sale]
+        );
+
+        // Buying
+        let execute_output = marketplace_test_exec_buy_username(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(WALLET2, &coins(USERNAME_PRICE, UHUAHUA)),
+            "Doge".to_string(),
+        );
+        assert_eq!(
+            execute_output.unwrap_err().root_cause().to_string(),
+            "CantBuyYourOwnUsername".to_string()
+        );
+        let execute_output = marketplace_test_exec_buy_username(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(WALLET3, &coins(USERNAME_PRICE - 1, UHUAHUA)),
+            "Doge".to_string(),
+        );
+        assert_eq!(
+            execute_output.unwrap_err().root_cause().to_string(),
+            "InvalidBuyerInformation".to_string()
+        );
+        let fees_to_claim = |app: &App| {
+            marketplace_test_query_get_mkpc_info(app, &nft_marketplace_smart_contract_addr)
+                .unwrap()
+                .into_iter()
+                .find(|stats| stats.denom == UHUAHUA)
+                .unwrap()
+                .marketplace_fees_to_claim
+        };
+        let fees_before = fees_to_claim(&app);
+        let seller_balance = app.wrap().query_balance(WALLET2, UHUAHUA).unwrap().amount;
+        marketplace_test_exec_buy_username(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(WALLET3, &coins(USERNAME_PRICE, UHUAHUA)),
+            "doge".to_string(),
+        )
+        .unwrap();
+        assert_eq!(
+            app.wrap().query_balance(WALLET2, UHUAHUA).unwrap().amount,
+            seller_balance + Uint128::new(USERNAME_PRICE - USERNAME_MARKETPLACE_FEES)
+        );
+        assert_eq!(
+            fees_to_claim(&app),
+            fees_before + Uint128::new(USERNAME_MARKETPLACE_FEES)
+        );
+        assert_eq!(
+            username_of(&app, &nft_marketplace_smart_contract_addr, WALLET3),
+            Some("Doge".to_string())
+        );
+        assert_eq!(
+            username_of(&app, &nft_marketplace_smart_contract_addr, WALLET2),
+            None
+        );
+        assert_eq!(
+            marketplace_test_query_get_username_status(
+                &app,
+                &nft_marketplace_smart_contract_addr,
+                "Shiba".to_string()
+            ),
+            UsernameStatus::Available
+        );
+        let profile = marketplace_test_query_get_profile_info(
+            &app,
+            nft_marketplace_smart_contract_addr.to_string(),
+            "Doge".to_string(),
+        )
+        .unwrap();
+        assert_eq!(profile.address, WALLET3.to_string());
+        assert!(marketplace_test_query_get_usernames_for_sale(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            None,
+            None
+        )
+        .is_empty());
+        marketplace_test_exec_buy_username(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(OWNER, &coins(USERNAME_PRICE, UHUAHUA)),
+            "Doge".to_string(),
+        )
+        .unwrap_err();
+
+        // Transfer, the profile of the recipient is created
+        let execute_output = marketplace_test_exec_transfer_username(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(WALLET3, &[]),
+            WALLET3.to_string(),
+        );
+        assert_eq!(
+            execute_output.unwrap_err().root_cause().to_string(),
+            "CantTransferUsernameToYourself".to_string()
+        );
+        marketplace_test_exec_transfer_username(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(WALLET3, &[]),
+            WALLET4.to_string(),
+        )
+        .unwrap();
+        assert_eq!(
+            username_of(&app, &nft_marketplace_smart_contract_addr, WALLET4),
+            Some("Doge".to_string())
+        );
+        assert_eq!(
+            username_of(&app, &nft_marketplace_smart_contract_addr, WALLET3),
+            None
+        );
+        let execute_output = marketplace_test_exec_transfer_username(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(WALLET3, &[]),
+            WALLET4.to_string(),
+        );
+        assert_eq!(
+            execute_output.unwrap_err().root_cause().to_string(),
+            "YouDontHaveAUsername".to_string()
+        );
+
+        // Cancellation, and a transfer removes the sale
+        marketplace_test_exec_sell_username(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(WALLET4, &[]),
+            Uint128::new(USERNAME_PRICE),
+            UHUAHUA.to_string(),
+        )
+        .unwrap();
+        marketplace_test_exec_cancel_username_sale(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(WALLET4, &[]),
+        )
+        .unwrap();
+        let execute_output = marketplace_test_exec_cancel_username_sale(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(WALLET4, &[]),
+        );
+        assert_eq!(
+            execute_output.unwrap_err().root_cause().to_string(),
+            "UsernameIsNotForSale".to_string()
+        );
+        marketplace_test_exec_sell_username(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(WALLET4, &[]),
+            Uint128::new(USERNAME_PRICE),
+            UHUAHUA.to_string(),
+        )
+        .unwrap();
+        marketplace_test_exec_transfer_username(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(WALLET4, &[]),
+            OWNER.to_string(),
+        )
+        .unwrap();
+        assert_eq!(
+            marketplace_test_query_get_username_sale(
+                &app,
+                &nft_marketplace_smart_contract_addr,
+                "Doge".to_string()
+            ),
+            None
+        );
+        assert_eq!(
+            username_of(&app, &nft_marketplace_smart_contract_addr, OWNER),
+            Some("Doge".to_string())
+        );
+    }
+}
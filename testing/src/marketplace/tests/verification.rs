@@ -0,0 +1,274 @@
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::mock_info;
+    use cosmwasm_std::Addr;
+
+    use chihuahua_nft_marketplace::msg::UpdateConfigEnum;
+    use nft_marketplace_utils::nft_collection::{NftContractInfo, NftContractType};
+    use nft_marketplace_utils::verification::{VerificationKind, VerificationSubject};
+
+    use crate::common::utils::constants::{OWNER, WALLET2, WALLET3, WALLET4};
+    use crate::common::utils::utils_common::tests::instantiate_necessary_for_tests;
+    use crate::common::utils::utils_marketplace_contract_test::tests::{
+        marketplace_test_exec_add_new_collection, marketplace_test_exec_add_nft_code_id,
+        marketplace_test_exec_create_my_profile, marketplace_test_exec_enable_disable,
+        marketplace_test_exec_revoke_verification, marketplace_test_exec_update_config,
+        marketplace_test_exec_verify, marketplace_test_query_get_moderators,
+        marketplace_test_query_get_nft_coll_response, marketplace_test_query_get_profile_response,
+        marketplace_test_query_get_verifications,
+    };
+    use crate::common::utils::utils_nft_contract_test::tests::cw2981_multi_test_exec_mint;
+
+    #[test]
+    fn test_marketplace_verification_badges() {
+        // - Only the admin or a moderator can verify or revoke a profile or a listed collection
+        // - Active verifications are shown with the profile and the collection info
+        // - Revoked verifications are kept with who revoked them and when
+        let (mut app, necessary) = instantiate_necessary_for_tests();
+        let nft_marketplace_smart_contract_addr =
+            Addr::unchecked(necessary.nft_marketplace_smart_contract_addr);
+        let nft_collection = necessary.cw2981_nft_contract_addr1;
+        let nft_contract_info = NftContractInfo {
+            code_id: necessary.cw2981_nft_code_id,
+            nft_contract_type: NftContractType::Cw2981MultiRoyalties,
+        };
+        let profile_subject = VerificationSubject::Profile {
+            address: WALLET3.to_string(),
+        };
+        let collection_subject = VerificationSubject::Collection {
+            nft_collection_address: nft_collection.clone(),
+        };
+
+        marketplace_test_exec_enable_disable(
+            &mut app,
+            nft_marketplace_smart_contract_addr.to_string(),
+            mock_info(OWNER, &[]),
+        )
+        .unwrap();
+        for address in [OWNER, WALLET2, WALLET3] {
+            marketplace_test_exec_create_my_profile(
+                &mut app,
+                nft_marketplace_smart_contract_addr.to_string(),
+                mock_info(address, &[]),
+                None,
+            )
+            .unwrap();
+        }
+
+        // Only the admin or a moderator, the subject must exist
+        let execute_output = marketplace_test_exec_verify(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(WALLET2, &[]),
+            profile_subject.clone(),
+            VerificationKind::VerifiedCreator,
+        );
+        assert_eq!(
+            execute_output.unwrap_err().root_cause().to_string(),
+            "OnlyTheAdminOrAModeratorCanVerify".to_string()
+        );
+        let execute_output = marketplace_test_exec_verify(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(OWNER, &[]),
+            VerificationSubject::Profile {
+                address: WALLET4.to_string(),
+            },
+            VerificationKind::VerifiedCreator,
+        );
+        assert_eq!(
+            execute_output.unwrap_err().root_cause().to_string(),
+            "ThisProfileDoesNotExist".to_string()
+        );
+        let execute_output = marketplace_test_exec_verify(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(OWNER, &[]),
+            collection_subject.clone(),
+            VerificationKind::OfficialProject,
+        );
+        assert_eq!(
+            execute_output.unwrap_err().root_cause().to_string(),
+            "NftCollectionNotListed".to_string()
+        );
+
+        // Moderators are managed by the admin
+        marketplace_test_exec_update_config(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(WALLET2, &[]),
+            vec![UpdateConfigEnum::AddModerators {
+                addresses: vec![WALLET2.to_string()],
+            }],
+        )
+        .unwrap_err();
+        marketplace_test_exec_update_config(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(OWNER, &[]),
+            vec![UpdateConfigEnum::AddModerators {
+                addresses: vec![WALLET2.to_string()],
+            }],
+        )
+        .unwrap();
+        assert_eq!(
+            marketplace_test_query_get_moderators(
+                &app,
+                &nft_marketplace_smart_contract_addr,
+                None,
+                None
+            ),
+            vec![WALLET2.to_string()]
+        );
+
+        // Profile verified by a moderator
+        let issued_at = app.block_info().time;
+        marketplace_test_exec_verify(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(WALLET2, &[]),
+            profile_subject.clone(),
+            VerificationKind::VerifiedCreator,
+        )
+        .unwrap();
+        let verifications = marketplace_test_query_get_profile_response(
+            &app,
+            nft_marketplace_smart_contract_addr.to_string(),
+            WALLET3.to_string(),
+        )
+        .unwrap()
+        .verifications;
+        assert_eq!(verifications.len(), 1);
+        assert_eq!(verifications[0].kind, VerificationKind::VerifiedCreator);
+        assert_eq!(verifications[0].issuer, WALLET2.to_string());
+        assert_eq!(verifications[0].issued_at, issued_at);
+        assert!(verifications[0].is_active());
+
+        // Listed collection verified by the admin
+        marketplace_test_exec_add_nft_code_id(
+            &mut app,
+            nft_marketplace_smart_contract_addr.to_string(),
+            vec![nft_contract_info.clone()],
+            mock_info(OWNER, &[]),
+        )
+        .unwrap();
+        cw2981_multi_test_exec_mint(
+            &mut app,
+            &Addr::unchecked(nft_collection.clone()),
+            mock_info(OWNER, &[]),
+            "Token1".to_string(),
+            OWNER.to_string(),
+            None,
+        )
+        .unwrap();
+        marketplace_test_exec_add_new_collection(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(OWNER, &[]),
+            nft_collection.clone(),
+            nft_contract_info,
+        )
+        .unwrap();
+        marketplace_test_exec_verify(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(OWNER, &[]),
+            collection_subject.clone(),
+            VerificationKind::OfficialProject,
+        )
+        .unwrap();
+        let nft_collection_info = marketplace_test_query_get_nft_coll_response(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            nft_collection.clone(),
+        )
+        .unwrap();
+        assert!(!nft_collection_info.info_by_denom.is_empty());
+        let verifications = nft_collection_info.verifications;
+        assert_eq!(verifications.len(), 1);
+        assert_eq!(verifications[0].kind, VerificationKind::OfficialProject);
+        assert_eq!(verifications[0].issuer, OWNER.to_string());
+
+        // Revocation
+        marketplace_test_exec_revoke_verification(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(WALLET3, &[]),
+            profile_subject.clone(),
+            VerificationKind::VerifiedCreator,
+        )
+        .unwrap_err();
+        marketplace_test_exec_revoke_verification(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(OWNER, &[]),
+            profile_subject.clone(),
+            VerificationKind::VerifiedCreator,
+        )
+        .unwrap();
+        assert!(marketplace_test_query_get_profile_response(
+            &app,
+            nft_marketplace_smart_contract_addr.to_string(),
+            WALLET3.to_string(),
+        )
+        .unwrap()
+        .verifications
+        .is_empty());
+        assert!(marketplace_test_query_get_verifications(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            profile_subject.clone(),
+            false
+        )
+        .is_empty());
+        let verifications = marketplace_test_query_get_verifications(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            profile_subject.clone(),
+            true,
+        );
+        assert_eq!(verifications.len(), 1);
+        assert_eq!(verifications[0].revoked_by, Some(OWNER.to_string()));
+        assert_eq!(verifications[0].revoked_at, Some(app.block_info().time));
+        let execute_output = marketplace_test_exec_revoke_verification(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(OWNER, &[]),
+            profile_subject.clone(),
+            VerificationKind::VerifiedCreator,
+        );
+        assert_eq!(
+            execute_output.unwrap_err().root_cause().to_string(),
+            "VerificationDoesNotExist".to_string()
+        );
+
+        // A removed moderator can't verify anymore
+        marketplace_test_exec_update_config(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(OWNER, &[]),
+            vec![UpdateConfigEnum::RemoveModerators {
+                addresses: vec![WALLET2.to_string()],
+            }],
+        )
+        .unwrap();
+        assert!(marketplace_test_query_get_moderators(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            None,
+            None
+        )
+        .is_empty());
+        let execute_output = marketplace_test_exec_verify(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(WALLET2, &[]),
+            profile_subject,
+            VerificationKind::Partner,
+        );
+        assert_eq!(
+            execute_output.unwrap_err().root_cause().to_string(),
+            "OnlyTheAdminOrAModeratorCanVerify".to_string()
+        );
+    }
+}
@@ -0,0 +1,266 @@
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::mock_info;
+    use cosmwasm_std::{coins, Addr, Decimal, Uint128};
+
+    use chihuahua_nft_marketplace::msg::UpdateConfigEnum;
+    use nft_marketplace_utils::nft_collection::{NftContractInfo, NftContractType};
+    use nft_marketplace_utils::nft_sale::NftSale;
+    use nft_marketplace_utils::profile::{Profile, ProfileUpdateAction};
+    use nft_marketplace_utils::reward_system::{VipLevel, VipTier};
+
+    use crate::common::utils::constants::{OWNER, REWARD_TOKEN, UHUAHUA, WALLET2};
+    use crate::common::utils::utils_common::tests::{instantiate_necessary_for_tests, App};
+    use crate::common::utils::utils_marketplace_contract_test::tests::{
+        marketplace_test_exec_add_new_collection, marketplace_test_exec_add_nft_code_id,
+        marketplace_test_exec_buy_nft, marketplace_test_exec_create_my_profile,
+        marketplace_test_exec_enable_disable, marketplace_test_exec_lvl_up_profile,
+        marketplace_test_exec_renew_vip_level, marketplace_test_exec_sell_nft,
+        marketplace_test_exec_update_config, marketplace_test_exec_update_my_profile,
+        marketplace_test_query_get_config, marketplace_test_query_get_mkpc_info,
+        marketplace_test_query_get_profile_info,
+    };
+    use crate::common::utils::utils_nft_contract_test::tests::{
+        cw2981_multi_test_exec_approve, cw2981_multi_test_exec_mint,
+    };
+
+    const PRICE: u128 = 100_000_000u128;
+    const DAY: u64 = 86_400u64;
+    const MONTH: u64 = 30 * DAY;
+
+    fn vip_tier(name: &str, price: u128, discount_percent: u64, duration: Option<u64>) -> VipTier {
+        VipTier {
+            name: name.to_string(),
+            level_up_price_in_reward_tokens: Uint128::new(price),
+            marketplace_fees_discount: Decimal::percent(discount_percent),
+            profile_background: true,
+            profile_nft_showcase: false,
+            profile_description: duration.is_some(),
+            profile_links: false,
+            max_messages_per_window: None,
+            subscription_duration: duration,
+            volume_usdc_threshold: None,
+        }
+    }
+
+    fn wallet2_profile(app: &App, contract_addr: &Addr) -> Profile {
+        marketplace_test_query_get_profile_info(app, contract_addr.to_string(), WALLET2.to_string())
+            .unwrap()
+    }
+
+    fn uhuahua_fees_to_claim(app: &App, contract_addr: &Addr) -> Uint128 {
+        marketplace_test_query_get_mkpc_info(app, contract_addr)
+            .unwrap()
+            .into_iter()
+            .find(|stats| stats.denom == UHUAHUA)
+            .unwrap()
+            .marketplace_fees_to_claim
+    }
+
+    #[test]
+    fn test_marketplace_vip_subscriptions() {
+        // - Subscription tiers expire, renewals extend them and an expired level falls back to the
+        //   highest permanent tier below it
+        // - The buyer's VIP discount is refunded out of the marketplace fees
+        let (mut app, necessary) = instantiate_necessary_for_tests();
+        let nft_marketplace_smart_contract_addr =
+            Addr::unchecked(necessary.nft_marketplace_smart_contract_addr);
+        let nft_collection = necessary.cw2981_nft_contract_addr1;
+        let nft_contract_info = NftContractInfo {
+            code_id: necessary.cw2981_nft_code_id,
+            nft_contract_type: NftContractType::Cw2981MultiRoyalties,
+        };
+        marketplace_test_exec_enable_disable(
+            &mut app,
+            nft_marketplace_smart_contract_addr.to_string(),
+            mock_info(OWNER, &[]),
+        )
+        .unwrap();
+        let mut reward_system =
+            marketplace_test_query_get_config(&app, &nft_marketplace_smart_contract_addr)
+                .reward_system;
+        reward_system.vip_tiers = vec![
+            vip_tier("Member", 1_000, 5, None),
+            vip_tier("Silver", 10_000, 10, Some(MONTH)),
+            vip_tier("Gold", 50_000, 20, Some(MONTH)),
+        ];
+        marketplace_test_exec_update_config(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(OWNER, &[]),
+            vec![UpdateConfigEnum::UpdateRewardSystem { reward_system }],
+        )
+        .unwrap();
+        marketplace_test_exec_create_my_profile(
+            &mut app,
+            nft_marketplace_smart_contract_addr.to_string(),
+            mock_info(WALLET2, &[]),
+            None,
+        )
+        .unwrap();
+
+        // Permanent tiers cannot be renewed
+        marketplace_test_exec_lvl_up_profile(
+            &mut app,
+            mock_info(WALLET2, &coins(1_000u128, REWARD_TOKEN)),
+            &nft_marketplace_smart_contract_addr,
+        )
+        .unwrap();
+        assert_eq!(
+            wallet2_profile(&app, &nft_marketplace_smart_contract_addr).vip_expiration,
+            None
+        );
+        let execute_output = marketplace_test_exec_renew_vip_level(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(WALLET2, &coins(1_000u128, REWARD_TOKEN)),
+        );
+        assert_eq!(
+            execute_output.unwrap_err().root_cause().to_string(),
+            "VipLevelNotRenewable".to_string()
+        );
+
+        // Subscription: the expiration is shown on the profile and renewals extend it
+        let now = app.block_info().time;
+        let execute_output = marketplace_test_exec_lvl_up_profile(
+            &mut app,
+            mock_info(WALLET2, &coins(10_000u128, REWARD_TOKEN)),
+            &nft_marketplace_smart_contract_addr,
+        )
+        .unwrap();
+        assert!(execute_output.events.iter().any(|event| event
+            .attributes
+            .iter()
+            .any(|attr| attr.key == "Expiration"
+                && attr.value == now.plus_seconds(MONTH).to_string())));
+        let profile = wallet2_profile(&app, &nft_marketplace_smart_contract_addr);
+        assert_eq!(profile.vip_level, Some(VipLevel(2)));
+        assert_eq!(profile.vip_expiration, Some(now.plus_seconds(MONTH)));
+        let execute_output = marketplace_test_exec_renew_vip_level(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(WALLET2, &coins(1_000u128, REWARD_TOKEN)),
+        );
+        assert_eq!(
+            execute_output.unwrap_err().root_cause().to_string(),
+            "InvalidFundsReceived".to_string()
+        );
+        marketplace_test_exec_renew_vip_level(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(WALLET2, &coins(10_000u128, REWARD_TOKEN)),
+        )
+        .unwrap();
+        assert_eq!(
+            wallet2_profile(&app, &nft_marketplace_smart_contract_addr).vip_expiration,
+            Some(now.plus_seconds(2 * MONTH))
+        );
+
+        // WALLET2 buys with its 10% discount on the 4.2% marketplace fees
+        marketplace_test_exec_add_nft_code_id(
+            &mut app,
+            nft_marketplace_smart_contract_addr.to_string(),
+            vec![nft_contract_info.clone()],
+            mock_info(OWNER, &[]),
+        )
+        .unwrap();
+        cw2981_multi_test_exec_mint(
+            &mut app,
+            &Addr::unchecked(nft_collection.clone()),
+            mock_info(OWNER, &[]),
+            "Token1".to_string(),
+            OWNER.to_string(),
+            None,
+        )
+        .unwrap();
+        marketplace_test_exec_add_new_collection(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(OWNER, &[]),
+            nft_collection.clone(),
+            nft_contract_info,
+        )
+        .unwrap();
+        let info = mock_info(OWNER, &coins(6_900_000u128, UHUAHUA));
+        let sale_expiration = now.plus_seconds(20 * DAY);
+        cw2981_multi_test_exec_approve(
+            &mut app,
+            &Addr::unchecked(nft_collection.clone()),
+            &nft_marketplace_smart_contract_addr,
+            info.clone(),
+            "Token1".to_string(),
+            sale_expiration.seconds(),
+        )
+        .unwrap();
+        marketplace_test_exec_sell_nft(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            info,
+            NftSale {
+                seller: OWNER.to_string(),
+                nft_collection_address: nft_collection.clone(),
+                token_id: "Token1".to_string(),
+                sale_price_value: Uint128::new(PRICE),
+                sale_price_denom: UHUAHUA.to_string(),
+                sale_expiration,
+            },
+        )
+        .unwrap();
+        let wallet2_balance = app.wrap().query_balance(WALLET2, UHUAHUA).unwrap().amount;
+        let fees_to_claim = uhuahua_fees_to_claim(&app, &nft_marketplace_smart_contract_addr);
+        marketplace_test_exec_buy_nft(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(WALLET2, &coins(PRICE, UHUAHUA)),
+            nft_collection,
+            "Token1".to_string(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(
+            app.wrap().query_balance(WALLET2, UHUAHUA).unwrap().amount,
+            wallet2_balance - Uint128::new(PRICE) + Uint128::new(420_000u128)
+        );
+        assert_eq!(
+            uhuahua_fees_to_claim(&app, &nft_marketplace_smart_contract_addr),
+            fees_to_claim + Uint128::new(4_200_000u128 - 420_000u128)
+        );
+
+        // Expired: falls back to the permanent tier, a new subscription starts from there
+        app.update_block(|block| {
+            block.time = now.plus_seconds(2 * MONTH);
+        });
+        let profile = wallet2_profile(&app, &nft_marketplace_smart_contract_addr);
+        assert_eq!(profile.vip_level, Some(VipLevel(1)));
+        assert_eq!(profile.vip_expiration, None);
+        for price in [10_000u128, 50_000u128] {
+            marketplace_test_exec_lvl_up_profile(
+                &mut app,
+                mock_info(WALLET2, &coins(price, REWARD_TOKEN)),
+                &nft_marketplace_smart_contract_addr,
+            )
+            .unwrap();
+        }
+        let profile = wallet2_profile(&app, &nft_marketplace_smart_contract_addr);
+        assert_eq!(profile.vip_level, Some(VipLevel(3)));
+        assert_eq!(profile.vip_expiration, Some(now.plus_seconds(3 * MONTH)));
+
+        // Gold expires below Silver's subscription: back to Member, its perks are checked lazily
+        app.update_block(|block| {
+            block.time = now.plus_seconds(3 * MONTH);
+        });
+        let mut new_profile = Profile::new(WALLET2.to_string());
+        new_profile.description = Some("Expired".to_string());
+        marketplace_test_exec_update_my_profile(
+            &mut app,
+            nft_marketplace_smart_contract_addr.to_string(),
+            mock_info(WALLET2, &[]),
+            new_profile,
+            ProfileUpdateAction::Add,
+        )
+        .unwrap();
+        let profile = wallet2_profile(&app, &nft_marketplace_smart_contract_addr);
+        assert_eq!(profile.vip_level, Some(VipLevel(1)));
+        assert_eq!(profile.description, None);
+    }
+}
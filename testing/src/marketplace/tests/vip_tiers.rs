@@ -0,0 +1,134 @@
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::mock_info;
+    use cosmwasm_std::{coins, Addr, Decimal, Uint128};
+
+    use chihuahua_nft_marketplace::msg::UpdateConfigEnum;
+    use nft_marketplace_utils::reward_system::{VipLevel, VipTier};
+
+    use crate::common::utils::constants::{OWNER, REWARD_TOKEN, WALLET2};
+    use crate::common::utils::utils_common::tests::instantiate_necessary_for_tests;
+    use crate::common::utils::utils_marketplace_contract_test::tests::{
+        marketplace_test_exec_create_my_profile, marketplace_test_exec_enable_disable,
+        marketplace_test_exec_lvl_up_profile, marketplace_test_exec_update_config,
+        marketplace_test_query_get_config, marketplace_test_query_get_profile_info,
+    };
+
+    fn vip_tier(name: &str, price: u128, discount_percent: u64) -> VipTier {
+        VipTier {
+            name: name.to_string(),
+            level_up_price_in_reward_tokens: Uint128::new(price),
+            marketplace_fees_discount: Decimal::percent(discount_percent),
+            profile_background: true,
+            profile_nft_showcase: false,
+            profile_description: false,
+            profile_links: false,
+            max_messages_per_window: None,
+            subscription_duration: None,
+            volume_usdc_threshold: None,
+        }
+    }
+
+    #[test]
+    fn test_marketplace_custom_vip_tiers() {
+        // - The admin replaces the tiers, names must be unique and discounts bounded
+        // - Profiles level up through every tier in order, up to the last one
+        let (mut app, necessary) = instantiate_necessary_for_tests();
+        let nft_marketplace_smart_contract_addr =
+            Addr::unchecked(necessary.nft_marketplace_smart_contract_addr);
+        marketplace_test_exec_enable_disable(
+            &mut app,
+            nft_marketplace_smart_contract_addr.to_string(),
+            mock_info(OWNER, &[]),
+        )
+        .unwrap();
+        let mut reward_system =
+            marketplace_test_query_get_config(&app, &nft_marketplace_smart_contract_addr)
+                .reward_system;
+
+        for (vip_tiers, error) in [
+            (
+                vec![vip_tier("Bronze", 100, 1), vip_tier("Bronze", 200, 2)],
+                "InvalidVipTiers",
+            ),
+            (
+                vec![vip_tier("Bronze", 100, 1), vip_tier("", 200, 2)],
+                "InvalidVipTiers",
+            ),
+            (vec![vip_tier("Bronze", 100, 60)], "InvalidRewards"),
+            (vec![], "NeedToFillAllThePerks"),
+        ] {
+            reward_system.vip_tiers = vip_tiers;
+            let execute_output = marketplace_test_exec_update_config(
+                &mut app,
+                &nft_marketplace_smart_contract_addr,
+                mock_info(OWNER, &[]),
+                vec![UpdateConfigEnum::UpdateRewardSystem {
+                    reward_system: reward_system.clone(),
+                }],
+            );
+            assert_eq!(
+                execute_output.unwrap_err().root_cause().to_string(),
+                error.to_string()
+            );
+        }
+
+        let vip_tiers = vec![
+            vip_tier("Bronze", 100, 1),
+            vip_tier("Silver", 200, 2),
+            vip_tier("Gold", 300, 3),
+            vip_tier("Diamond", 400, 5),
+        ];
+        reward_system.vip_tiers = vip_tiers.clone();
+        marketplace_test_exec_update_config(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(OWNER, &[]),
+            vec![UpdateConfigEnum::UpdateRewardSystem { reward_system }],
+        )
+        .unwrap();
+
+        marketplace_test_exec_create_my_profile(
+            &mut app,
+            nft_marketplace_smart_contract_addr.to_string(),
+            mock_info(WALLET2, &[]),
+            None,
+        )
+        .unwrap();
+        for vip_tier in vip_tiers.iter() {
+            let execute_output = marketplace_test_exec_lvl_up_profile(
+                &mut app,
+                mock_info(
+                    WALLET2,
+                    &coins(
+                        vip_tier.level_up_price_in_reward_tokens.u128(),
+                        REWARD_TOKEN,
+                    ),
+                ),
+                &nft_marketplace_smart_contract_addr,
+            )
+            .unwrap();
+            assert!(execute_output.events.iter().any(|event| event
+                .attributes
+                .iter()
+                .any(|attr| attr.key == "Tier" && attr.value == vip_tier.name)));
+        }
+        let profile = marketplace_test_query_get_profile_info(
+            &app,
+            nft_marketplace_smart_contract_addr.to_string(),
+            WALLET2.to_string(),
+        )
+        .unwrap();
+        assert_eq!(profile.vip_level, Some(VipLevel(4)));
+
+        let execute_output = marketplace_test_exec_lvl_up_profile(
+            &mut app,
+            mock_info(WALLET2, &coins(400u128, REWARD_TOKEN)),
+            &nft_marketplace_smart_contract_addr,
+        );
+        assert_eq!(
+            execute_output.unwrap_err().root_cause().to_string(),
+            "AlreadyMaxVipLevel".to_string()
+        );
+    }
+}
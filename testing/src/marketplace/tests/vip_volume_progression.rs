@@ -0,0 +1,237 @@
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::mock_info;
+    use cosmwasm_std::{coins, Addr, Decimal, Uint128};
+
+    use chihuahua_nft_marketplace::msg::UpdateConfigEnum;
+    use nft_marketplace_utils::nft_collection::{NftContractInfo, NftContractType};
+    use nft_marketplace_utils::nft_sale::NftSale;
+    use nft_marketplace_utils::reward_system::{VipLevel, VipTier};
+
+    use crate::common::utils::constants::{OWNER, UHUAHUA, WALLET2};
+    use crate::common::utils::utils_common::tests::instantiate_necessary_for_tests;
+    use crate::common::utils::utils_marketplace_contract_test::tests::{
+        marketplace_test_exec_add_new_collection, marketplace_test_exec_add_nft_code_id,
+        marketplace_test_exec_buy_nft, marketplace_test_exec_enable_disable,
+        marketplace_test_exec_sell_nft, marketplace_test_exec_update_config,
+        marketplace_test_query_get_config, marketplace_test_query_get_profile_info,
+        marketplace_test_query_get_vip_progress,
+    };
+    use crate::common::utils::utils_nft_contract_test::tests::{
+        cw2981_multi_test_exec_approve, cw2981_multi_test_exec_mint,
+    };
+
+    // 12_000$USDC per sale
+    const PRICE: u128 = 100_000_000_000_000u128;
+    const SALE_USDC: u128 = 12_000_000_000u128;
+    const DAY: u64 = 86_400u64;
+
+    fn vip_tier(name: &str, discount_percent: u64, volume_usdc_threshold: Option<u128>) -> VipTier {
+        VipTier {
+            name: name.to_string(),
+            level_up_price_in_reward_tokens: Uint128::new(1_000u128),
+            marketplace_fees_discount: Decimal::percent(discount_percent),
+            profile_background: true,
+            profile_nft_showcase: false,
+            profile_description: false,
+            profile_links: false,
+            max_messages_per_window: None,
+            subscription_duration: Some(30 * DAY),
+            volume_usdc_threshold: volume_usdc_threshold.map(Uint128::new),
+        }
+    }
+
+    #[test]
+    fn test_marketplace_vip_volume_progression() {
+        // - The buyer and the seller of a trade add its USDC value to their volume
+        // - Passing the threshold of a tier upgrades the profile for good and emits an event
+        // - The progress to the next tier reachable by volume is queryable
+        let (mut app, necessary) = instantiate_necessary_for_tests();
+        let nft_marketplace_smart_contract_addr =
+            Addr::unchecked(necessary.nft_marketplace_smart_contract_addr);
+        let nft_collection = necessary.cw2981_nft_contract_addr1;
+        let nft_contract_info = NftContractInfo {
+            code_id: necessary.cw2981_nft_code_id,
+            nft_contract_type: NftContractType::Cw2981MultiRoyalties,
+        };
+        marketplace_test_exec_enable_disable(
+            &mut app,
+            nft_marketplace_smart_contract_addr.to_string(),
+            mock_info(OWNER, &[]),
+        )
+        .unwrap();
+
+        // Thresholds must grow with the levels
+        let mut reward_system =
+            marketplace_test_query_get_config(&app, &nft_marketplace_smart_contract_addr)
+                .reward_system;
+        reward_system.vip_tiers = vec![
+            vip_tier("Member", 5, Some(2 * SALE_USDC)),
+            vip_tier("Silver", 10, Some(2 * SALE_USDC)),
+        ];
+        let execute_output = marketplace_test_exec_update_config(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(OWNER, &[]),
+            vec![UpdateConfigEnum::UpdateRewardSystem {
+                reward_system: reward_system.clone(),
+            }],
+        );
+        assert_eq!(
+            execute_output.unwrap_err().root_cause().to_string(),
+            "InvalidVipTiers".to_string()
+        );
+        reward_system.vip_tiers = vec![
+            vip_tier("Member", 5, Some(2 * SALE_USDC)),
+            vip_tier("Silver", 10, Some(3 * SALE_USDC)),
+            vip_tier("Gold", 20, None),
+        ];
+        marketplace_test_exec_update_config(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(OWNER, &[]),
+            vec![UpdateConfigEnum::UpdateRewardSystem { reward_system }],
+        )
+        .unwrap();
+
+        let progress = marketplace_test_query_get_vip_progress(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            WALLET2.to_string(),
+        );
+        assert_eq!(progress.vip_level, VipLevel(0));
+        assert_eq!(progress.next_tier_name, Some("Member".to_string()));
+        assert_eq!(
+            progress.volume_usdc_to_next_tier,
+            Some(Uint128::new(2 * SALE_USDC))
+        );
+
+        // OWNER lists 3 tokens, WALLET2 buys them one by one
+        marketplace_test_exec_add_nft_code_id(
+            &mut app,
+            nft_marketplace_smart_contract_addr.to_string(),
+            vec![nft_contract_info.clone()],
+            mock_info(OWNER, &[]),
+        )
+        .unwrap();
+        for token_id in ["Token1", "Token2", "Token3"] {
+            cw2981_multi_test_exec_mint(
+                &mut app,
+                &Addr::unchecked(nft_collection.clone()),
+                mock_info(OWNER, &[]),
+                token_id.to_string(),
+                OWNER.to_string(),
+                None,
+            )
+            .unwrap();
+        }
+        marketplace_test_exec_add_new_collection(
+            &mut app,
+            &nft_marketplace_smart_contract_addr,
+            mock_info(OWNER, &[]),
+            nft_collection.clone(),
+            nft_contract_info,
+        )
+        .unwrap();
+        let info = mock_info(OWNER, &coins(6_900_000u128, UHUAHUA));
+        let sale_expiration = app.block_info().time.plus_seconds(20 * DAY);
+        for token_id in ["Token1", "Token2", "Token3"] {
+            cw2981_multi_test_exec_approve(
+                &mut app,
+                &Addr::unchecked(nft_collection.clone()),
+                &nft_marketplace_smart_contract_addr,
+                info.clone(),
+                token_id.to_string(),
+                sale_expiration.seconds(),
+            )
+            .unwrap();
+            marketplace_test_exec_sell_nft(
+                &mut app,
+                &nft_marketplace_smart_contract_addr,
+                info.clone(),
+                NftSale {
+                    seller: OWNER.to_string(),
+                    nft_collection_address: nft_collection.clone(),
+                    token_id: token_id.to_string(),
+                    sale_price_value: Uint128::new(PRICE),
+                    sale_price_denom: UHUAHUA.to_string(),
+                    sale_expiration,
+                },
+            )
+            .unwrap();
+        }
+
+        let mut upgrades = vec![];
+        for token_id in ["Token1", "Token2", "Token3"] {
+            let execute_output = marketplace_test_exec_buy_nft(
+                &mut app,
+                &nft_marketplace_smart_contract_addr,
+                mock_info(WALLET2, &coins(PRICE, UHUAHUA)),
+                nft_collection.clone(),
+                token_id.to_string(),
+                None,
+            )
+            .unwrap();
+            upgrades.push(
+                execute_output
+                    .events
+                    .iter()
+                    .filter(|event| event.ty == "wasm-VIP Volume Upgrade")
+                    .map(|event| {
+                        event
+                            .attributes
+                            .iter()
+                            .filter(|attr| attr.key == "Address" || attr.key == "Tier")
+                            .map(|attr| attr.value.clone())
+                            .collect::<Vec<String>>()
+                    })
+                    .collect::<Vec<Vec<String>>>(),
+            );
+            if token_id == "Token1" {
+                let progress = marketplace_test_query_get_vip_progress(
+                    &app,
+                    &nft_marketplace_smart_contract_addr,
+                    WALLET2.to_string(),
+                );
+                assert_eq!(progress.volume_usdc, Uint128::new(SALE_USDC));
+                assert_eq!(
+                    progress.volume_usdc_to_next_tier,
+                    Some(Uint128::new(SALE_USDC))
+                );
+            }
+        }
+        assert_eq!(
+            upgrades,
+            vec![
+                vec![],
+                vec![
+                    vec![OWNER.to_string(), "Member".to_string()],
+                    vec![WALLET2.to_string(), "Member".to_string()]
+                ],
+                vec![
+                    vec![OWNER.to_string(), "Silver".to_string()],
+                    vec![WALLET2.to_string(), "Silver".to_string()]
+                ],
+            ]
+        );
+
+        // Levels reached by volume are permanent, Gold can only be bought
+        let profile = marketplace_test_query_get_profile_info(
+            &app,
+            nft_marketplace_smart_contract_addr.to_string(),
+            WALLET2.to_string(),
+        )
+        .unwrap();
+        assert_eq!(profile.vip_level, Some(VipLevel(2)));
+        assert_eq!(profile.vip_expiration, None);
+        assert_eq!(profile.volume_usdc, Some(Uint128::new(3 * SALE_USDC)));
+        let progress = marketplace_test_query_get_vip_progress(
+            &app,
+            &nft_marketplace_smart_contract_addr,
+            WALLET2.to_string(),
+        );
+        assert_eq!(progress.vip_level, VipLevel(2));
+        assert_eq!(progress.next_vip_level, None);
+        assert_eq!(progress.volume_usdc_to_next_tier, None);
+    }
+}
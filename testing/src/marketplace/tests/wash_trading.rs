@@ -0,0 +1,314 @@
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::mock_info;
+    use cosmwasm_std::{coins, Addr, Decimal, Uint128};
+    use cw_multi_test::AppResponse;
+
+    use chihuahua_nft_marketplace::msg::UpdateConfigEnum;
+    use nft_marketplace_utils::nft_collection::{NftContractInfo, NftContractType};
+    use nft_marketplace_utils::nft_sale::NftSale;
+    use nft_marketplace_utils::wash_trading::{EpochRewardCap, WashTradingConfig};
+
+    use crate::common::utils::constants::{OWNER, UHUAHUA, WALLET2, WALLET3};
+    use crate::common::utils::utils_common::tests::{instantiate_necessary_for_tests, App};
+    use crate::common::utils::utils_marketplace_contract_test::tests::{
+        marketplace_test_exec_add_new_collection, marketplace_test_exec_add_nft_code_id,
+        marketplace_test_exec_buy_nft, marketplace_test_exec_enable_disable,
+        marketplace_test_exec_sell_nft, marketplace_test_exec_update_config,
+        marketplace_test_query_get_profile_info, marketplace_test_query_get_rewards_to_claim,
+        marketplace_test_query_get_wash_trading_config,
+    };
+    use crate::common::utils::utils_nft_contract_test::tests::{
+        cw2981_multi_test_exec_approve, cw2981_multi_test_exec_mint,
+    };
+
+    // 12_000$USDC per sale, 1 reward token per micro USDC
+    const PRICE: u128 = 100_000_000_000_000u128;
+    const SALE_REWARDS: u128 = 12_000_000_000u128;
+    const DAY: u64 = 86_400u64;
+
+    fn sell(
+        app: &mut App,
+        contract_addr: &Addr,
+        nft_collection: &str,
+        seller: &str,
+        token_id: &str,
+        price: u128,
+    ) {
+        let info = mock_info(seller, &coins(6_900_000u128, UHUAHUA));
+        let sale_expiration = app.block_info().time.plus_seconds(20 * DAY);
+        cw2981_multi_test_exec_approve(
+            app,
+            &Addr::unchecked(nft_collection),
+            contract_addr,
+            info.clone(),
+            token_id.to_string(),
+            sale_expiration.seconds(),
+        )
+        .unwrap();
+        marketplace_test_exec_sell_nft(
+            app,
+            contract_addr,
+            info,
+            NftSale {
+                seller: seller.to_string(),
+                nft_collection_address: nft_collection.to_string(),
+                token_id: token_id.to_string(),
+                sale_price_value: Uint128::new(price),
+                sale_price_denom: UHUAHUA.to_string(),
+                sale_expiration,
+            },
+        )
+        .unwrap();
+    }
+
+    fn buy(
+        app: &mut App,
+        contract_addr: &Addr,
+        nft_collection: &str,
+        buyer: &str,
+        token_id: &str,
+        price: u128,
+    ) -> AppResponse {
+        marketplace_test_exec_buy_nft(
+            app,
+            contract_addr,
+            mock_info(buyer, &coins(price, UHUAHUA)),
+            nft_collection.to_string(),
+            token_id.to_string(),
+            None,
+        )
+        .unwrap()
+    }
+
+    fn withheld_rewards(execute_output: &AppResponse) -> Vec<Vec<String>> {
+        execute_output
+            .events
+            .iter()
+            .filter(|event| event.ty == "wasm-Withheld Rewards")
+            .map(|event| {
+                event
+                    .attributes
+                    .iter()
+                    .filter(|attr| attr.key != "_contract_addr")
+                    .map(|attr| attr.value.clone())
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn withheld(address: &str, amount: u128, reason: &str) -> Vec<String> {
+        vec![address.to_string(), amount.to_string(), reason.to_string()]
+    }
+
+    #[test]
+    fn test_marketplace_wash_trading_safeguards() {
+        // - A token sold again between the same two addresses within the cooldown earns nothing
+        // - So does a sale far from the floor of the other listings
+        // - Rewards above the cap of the epoch are withheld until the next one
+        // - The trades go through and every withheld reward is in the events
+        // - Trades withheld by the cooldown or the floor don't count towards the VIP volume
+        let (mut app, necessary) = instantiate_necessary_for_tests();
+        let contract_addr = Addr::unchecked(necessary.nft_marketplace_smart_contract_addr);
+        let nft_collection = necessary.cw2981_nft_contract_addr1;
+        let nft_contract_info = NftContractInfo {
+            code_id: necessary.cw2981_nft_code_id,
+            nft_contract_type: NftContractType::Cw2981MultiRoyalties,
+        };
+        let rewards_to_claim = |app: &App, address: &str| {
+            marketplace_test_query_get_rewards_to_claim(app, &contract_addr, address.to_string())
+                .u128()
+        };
+        let volume_usdc = |app: &App, address: &str| {
+            marketplace_test_query_get_profile_info(
+                app,
+                contract_addr.to_string(),
+                address.to_string(),
+            )
+            .unwrap()
+            .volume_usdc
+            .unwrap_or_default()
+            .u128()
+        };
+        marketplace_test_exec_enable_disable(
+            &mut app,
+            contract_addr.to_string(),
+            mock_info(OWNER, &[]),
+        )
+        .unwrap();
+
+        let execute_output = marketplace_test_exec_update_config(
+            &mut app,
+            &contract_addr,
+            mock_info(OWNER, &[]),
+            vec![UpdateConfigEnum::UpdateWashTradingConfig {
+                wash_trading_config: WashTradingConfig {
+                    pair_cooldown_seconds: Some(0),
+                    ..WashTradingConfig::default()
+                },
+            }],
+        );
+        assert_eq!(
+            execute_output.unwrap_err().root_cause().to_string(),
+            "InvalidWashTradingConfig".to_string()
+        );
+        let wash_trading_config = WashTradingConfig {
+            pair_cooldown_seconds: Some(DAY),
+            max_floor_deviation: Some(Decimal::percent(50)),
+            epoch_reward_cap: Some(EpochRewardCap {
+                epoch_seconds: DAY,
+                max_rewards_per_epoch: Uint128::new(3 * SALE_REWARDS / 2),
+            }),
+        };
+        marketplace_test_exec_update_config(
+            &mut app,
+            &contract_addr,
+            mock_info(OWNER, &[]),
+            vec![UpdateConfigEnum::UpdateWashTradingConfig {
+                wash_trading_config: wash_trading_config.clone(),
+            }],
+        )
+        .unwrap();
+        assert_eq!(
+            marketplace_test_query_get_wash_trading_config(&app, &contract_addr),
+            wash_trading_config
+        );
+
+        marketplace_test_exec_add_nft_code_id(
+            &mut app,
+            contract_addr.to_string(),
+            vec![nft_contract_info.clone()],
+            mock_info(OWNER, &[]),
+        )
+        .unwrap();
+        for token_id in ["Token1", "Token2", "Token3"] {
+            cw2981_multi_test_exec_mint(
+                &mut app,
+                &Addr::unchecked(nft_collection.clone()),
+                mock_info(OWNER, &[]),
+                token_id.to_string(),
+                OWNER.to_string(),
+                None,
+            )
+            .unwrap();
+        }
+        marketplace_test_exec_add_new_collection(
+            &mut app,
+            &contract_addr,
+            mock_info(OWNER, &[]),
+            nft_collection.clone(),
+            nft_contract_info,
+        )
+        .unwrap();
+        for token_id in ["Token1", "Token2", "Token3"] {
+            sell(
+                &mut app,
+                &contract_addr,
+                &nft_collection,
+                OWNER,
+                token_id,
+                PRICE,
+            );
+        }
+
+        // Regular sale
+        let execute_output = buy(
+            &mut app,
+            &contract_addr,
+            &nft_collection,
+            WALLET2,
+            "Token1",
+            PRICE,
+        );
+        assert!(withheld_rewards(&execute_output).is_empty());
+        assert_eq!(rewards_to_claim(&app, WALLET2), SALE_REWARDS);
+        assert_eq!(volume_usdc(&app, WALLET2), SALE_REWARDS);
+
+        // Sold back to the seller within the cooldown
+        sell(
+            &mut app,
+            &contract_addr,
+            &nft_collection,
+            WALLET2,
+            "Token1",
+            PRICE,
+        );
+        let execute_output = buy(
+            &mut app,
+            &contract_addr,
+            &nft_collection,
+            OWNER,
+            "Token1",
+            PRICE,
+        );
+        assert_eq!(
+            withheld_rewards(&execute_output),
+            vec![
+                withheld(OWNER, SALE_REWARDS, "Pair cooldown"),
+                withheld(WALLET2, SALE_REWARDS, "Pair cooldown"),
+            ]
+        );
+        assert_eq!(rewards_to_claim(&app, WALLET2), SALE_REWARDS);
+        assert_eq!(volume_usdc(&app, WALLET2), SALE_REWARDS);
+
+        // Three times the floor of Token2 and Token3
+        sell(
+            &mut app,
+            &contract_addr,
+            &nft_collection,
+            OWNER,
+            "Token1",
+            3 * PRICE,
+        );
+        let execute_output = buy(
+            &mut app,
+            &contract_addr,
+            &nft_collection,
+            WALLET3,
+            "Token1",
+            3 * PRICE,
+        );
+        assert_eq!(
+            withheld_rewards(&execute_output),
+            vec![
+                withheld(WALLET3, 3 * SALE_REWARDS, "Floor deviation"),
+                withheld(OWNER, 3 * SALE_REWARDS, "Floor deviation"),
+            ]
+        );
+        assert_eq!(rewards_to_claim(&app, WALLET3), 0);
+        assert_eq!(volume_usdc(&app, WALLET3), 0);
+
+        // Half a sale is left in the epoch of WALLET2 and OWNER
+        let execute_output = buy(
+            &mut app,
+            &contract_addr,
+            &nft_collection,
+            WALLET2,
+            "Token2",
+            PRICE,
+        );
+        assert_eq!(
+            withheld_rewards(&execute_output),
+            vec![
+                withheld(WALLET2, SALE_REWARDS / 2, "Epoch cap"),
+                withheld(OWNER, SALE_REWARDS / 2, "Epoch cap"),
+            ]
+        );
+        assert_eq!(rewards_to_claim(&app, WALLET2), 3 * SALE_REWARDS / 2);
+
+        // Next epoch, past the cooldown and nothing else listed to compare with
+        app.update_block(|block| {
+            block.time = block.time.plus_seconds(2 * DAY);
+        });
+        let execute_output = buy(
+            &mut app,
+            &contract_addr,
+            &nft_collection,
+            WALLET2,
+            "Token3",
+            PRICE,
+        );
+        assert!(withheld_rewards(&execute_output).is_empty());
+        assert_eq!(rewards_to_claim(&app, WALLET2), 5 * SALE_REWARDS / 2);
+    }
+}
@@ -45,7 +45,8 @@ mod testing {
             oracle::contract::execute,
             oracle::contract::instantiate,
             oracle::contract::query,
-        );
+        )
+        .with_migrate(oracle::contract::migrate);
         Box::new(smart_contract)
     }
 
@@ -66,7 +67,7 @@ mod testing {
             &init_msg,
             &[],
             "price_oracle",
-            None,
+            Some(OWNER.to_string()),
         )
         .unwrap()
     }
@@ -811,4 +812,41 @@ mod testing {
             Timestamp::from_seconds(3676589235u64 + 1_u64),
         );
     }
+
+    #[test]
+    fn test_price_oracle_smart_contract_migrate() {
+        let (mut app, _native_huahua, _native_atom, price_oracle_smart_contract_address) =
+            instantiate_necessary_for_tests();
+        let new_code_id = app.store_code(smart_contract_def_test_price_oracle());
+
+        // Error: only the admin can migrate
+        let execute_output = app.migrate_contract(
+            Addr::unchecked(WALLET2),
+            Addr::unchecked(price_oracle_smart_contract_address.clone()),
+            &oracle::msg::MigrateMsg {},
+            new_code_id,
+        );
+        assert!(execute_output.is_err());
+
+        // Migrating to the same version is accepted
+        let execute_output = app
+            .migrate_contract(
+                Addr::unchecked(OWNER),
+                Addr::unchecked(price_oracle_smart_contract_address.clone()),
+                &oracle::msg::MigrateMsg {},
+                new_code_id,
+            )
+            .unwrap();
+        assert!(execute_output.events.iter().any(|event| event
+            .attributes
+            .iter()
+            .any(|attr| attr.key == "To version" && attr.value == env!("CARGO_PKG_VERSION"))));
+
+        // Config is untouched
+        let config_info = oracle_test_query_get_config_and_current_prices(
+            &app,
+            &price_oracle_smart_contract_address,
+        );
+        assert_eq!(config_info.contract_owner, OWNER.to_string());
+    }
 }